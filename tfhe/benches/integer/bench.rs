@@ -4,38 +4,157 @@
 mod utilities;
 use crate::utilities::{write_to_json, OperatorType};
 
-use criterion::{criterion_group, criterion_main, Criterion};
+use criterion::measurement::WallTime;
+use criterion::{criterion_group, criterion_main, BenchmarkGroup, Criterion};
 use itertools::iproduct;
 use rand::Rng;
-use std::array::IntoIter;
 use tfhe::integer::keycache::KEY_CACHE;
-use tfhe::integer::{RadixCiphertextBig, ServerKey};
+use tfhe::integer::{ClientKey, RadixCiphertextBig, ServerKey};
 use tfhe::shortint::keycache::NamedParam;
+use tfhe::shortint::CiphertextModulus;
 
 #[allow(unused_imports)]
 use tfhe::shortint::parameters::{
-    PARAM_MESSAGE_1_CARRY_1, PARAM_MESSAGE_2_CARRY_2, PARAM_MESSAGE_3_CARRY_3,
+    ALL_PARAMETER_VEC, PARAM_MESSAGE_1_CARRY_1, PARAM_MESSAGE_2_CARRY_2, PARAM_MESSAGE_3_CARRY_3,
     PARAM_MESSAGE_4_CARRY_4,
 };
 
+/// Environment variable holding a comma separated list of parameter names to benchmark,
+/// e.g. `PARAM_MESSAGE_2_CARRY_2,PARAM_MESSAGE_3_CARRY_3`
+const PARAMS_ENV_VAR: &str = "TFHE_BENCH_PARAMS";
+/// Environment variable holding a comma separated list of ciphertext bit sizes to benchmark
+const BIT_SIZES_ENV_VAR: &str = "TFHE_BENCH_BITS";
+/// Environment variable holding the number of samples collected by each benchmark
+const SAMPLE_SIZE_ENV_VAR: &str = "TFHE_BENCH_SAMPLE_SIZE";
+/// Environment variable holding the measurement time of each benchmark, in seconds
+const MEASUREMENT_TIME_ENV_VAR: &str = "TFHE_BENCH_MEASUREMENT_TIME";
+/// Environment variable holding the base 2 logarithm of a power of two ciphertext modulus, e.g.
+/// `48`, replacing the native modulus of the benchmarked parameters
+const CIPHERTEXT_MODULUS_LOG2_ENV_VAR: &str = "TFHE_BENCH_CIPHERTEXT_MODULUS_LOG2";
+
+// FIXME One set of parameter is tested by default since we want to benchmark only quickest
+// operations.
+const DEFAULT_PARAMS: [tfhe::shortint::Parameters; 1] = [
+    PARAM_MESSAGE_2_CARRY_2,
+    // PARAM_MESSAGE_3_CARRY_3,
+    // PARAM_MESSAGE_4_CARRY_4,
+];
+const DEFAULT_BIT_SIZES: [usize; 7] = [8, 16, 32, 40, 64, 128, 256];
+const DEFAULT_SAMPLE_SIZE: usize = 15;
+const DEFAULT_MEASUREMENT_TIME_SECS: u64 = 60;
+
+/// Parses the comma separated list held by the environment variable `var_name`, or returns `None`
+/// if the variable is not set.
+fn parse_env_list<T>(var_name: &str, parse: impl Fn(&str) -> Option<T>) -> Option<Vec<T>> {
+    let value = std::env::var(var_name).ok()?;
+    let list = value
+        .split(',')
+        .map(str::trim)
+        .filter(|item| !item.is_empty())
+        .map(|item| parse(item).unwrap_or_else(|| panic!("Invalid value '{item}' in {var_name}")))
+        .collect::<Vec<_>>();
+    Some(list)
+}
+
+fn env_or_default<T: std::str::FromStr>(var_name: &str, default: T) -> T {
+    match std::env::var(var_name) {
+        Ok(value) => value
+            .trim()
+            .parse()
+            .unwrap_or_else(|_| panic!("Invalid value '{value}' for {var_name}")),
+        Err(_) => default,
+    }
+}
+
+fn param_from_name(name: &str) -> Option<tfhe::shortint::Parameters> {
+    ALL_PARAMETER_VEC
+        .into_iter()
+        .find(|param| param.name() == name)
+}
+
+/// Returns the ciphertext modulus set with the [`CIPHERTEXT_MODULUS_LOG2_ENV_VAR`] environment
+/// variable, or `None` if the native modulus is to be used.
+fn ciphertext_modulus_override() -> Option<CiphertextModulus> {
+    let value = std::env::var(CIPHERTEXT_MODULUS_LOG2_ENV_VAR).ok()?;
+    let modulus = value
+        .trim()
+        .parse()
+        .ok()
+        .and_then(|log2| CiphertextModulus::try_new_power_of_2(log2).ok())
+        .unwrap_or_else(|| panic!("Invalid value '{value}' for {CIPHERTEXT_MODULUS_LOG2_ENV_VAR}"));
+    Some(modulus)
+}
+
+/// Name of the parameters in the benchmark ids, parameters using a non native ciphertext modulus
+/// are named after the parameters they are derived from.
+fn bench_param_name(param: &tfhe::shortint::Parameters) -> String {
+    let ciphertext_modulus = param.ciphertext_modulus;
+    if ciphertext_modulus.is_native_modulus() {
+        return param.name();
+    }
+
+    let native_param = tfhe::shortint::Parameters {
+        ciphertext_modulus: CiphertextModulus::new_native(),
+        ..*param
+    };
+    format!(
+        "{}_CT_MODULUS_2_{}",
+        native_param.name(),
+        ciphertext_modulus.get().ilog2()
+    )
+}
+
+/// Returns the keys for the parameters, parameters using a non native ciphertext modulus are not
+/// known by the key cache and get freshly generated keys.
+fn keys_from_params(param: tfhe::shortint::Parameters) -> (ClientKey, ServerKey) {
+    if param.ciphertext_modulus.is_native_modulus() {
+        KEY_CACHE.get_from_params(param)
+    } else {
+        let cks = ClientKey::new(param);
+        let sks = ServerKey::new(&cks);
+        (cks, sks)
+    }
+}
+
+/// Creates a benchmark group whose sample size and measurement time can be overridden with the
+/// [`SAMPLE_SIZE_ENV_VAR`] and [`MEASUREMENT_TIME_ENV_VAR`] environment variables.
+fn new_bench_group<'a>(c: &'a mut Criterion, bench_name: &str) -> BenchmarkGroup<'a, WallTime> {
+    let mut bench_group = c.benchmark_group(bench_name);
+    bench_group
+        .sample_size(env_or_default(SAMPLE_SIZE_ENV_VAR, DEFAULT_SAMPLE_SIZE))
+        .measurement_time(std::time::Duration::from_secs(env_or_default(
+            MEASUREMENT_TIME_ENV_VAR,
+            DEFAULT_MEASUREMENT_TIME_SECS,
+        )));
+    bench_group
+}
+
 /// An iterator that yields a succession of combinations
 /// of parameters and a num_block to achieve a certain bit_size ciphertext
 /// in radix decomposition
+///
+/// The parameters and bit sizes can be overridden with the [`PARAMS_ENV_VAR`] and
+/// [`BIT_SIZES_ENV_VAR`] environment variables, and the ciphertext modulus of the parameters with
+/// the [`CIPHERTEXT_MODULUS_LOG2_ENV_VAR`] environment variable.
 struct ParamsAndNumBlocksIter {
-    params_and_bit_sizes:
-        itertools::Product<IntoIter<tfhe::shortint::Parameters, 1>, IntoIter<usize, 7>>,
+    params_and_bit_sizes: itertools::Product<
+        std::vec::IntoIter<tfhe::shortint::Parameters>,
+        std::vec::IntoIter<usize>,
+    >,
 }
 
 impl Default for ParamsAndNumBlocksIter {
     fn default() -> Self {
-        // FIXME One set of parameter is tested since we want to benchmark only quickest operations.
-        const PARAMS: [tfhe::shortint::Parameters; 1] = [
-            PARAM_MESSAGE_2_CARRY_2,
-            // PARAM_MESSAGE_3_CARRY_3,
-            // PARAM_MESSAGE_4_CARRY_4,
-        ];
-        const BIT_SIZES: [usize; 7] = [8, 16, 32, 40, 64, 128, 256];
-        let params_and_bit_sizes = iproduct!(PARAMS, BIT_SIZES);
+        let mut params = parse_env_list(PARAMS_ENV_VAR, param_from_name)
+            .unwrap_or_else(|| DEFAULT_PARAMS.to_vec());
+        if let Some(ciphertext_modulus) = ciphertext_modulus_override() {
+            for param in params.iter_mut() {
+                param.ciphertext_modulus = ciphertext_modulus;
+            }
+        }
+        let bit_sizes = parse_env_list(BIT_SIZES_ENV_VAR, |item| item.parse().ok())
+            .unwrap_or_else(|| DEFAULT_BIT_SIZES.to_vec());
+        let params_and_bit_sizes = iproduct!(params, bit_sizes);
         Self {
             params_and_bit_sizes,
         }
@@ -63,19 +182,15 @@ fn bench_server_key_binary_function_dirty_inputs<F>(
 ) where
     F: Fn(&ServerKey, &mut RadixCiphertextBig, &mut RadixCiphertextBig),
 {
-    let mut bench_group = c.benchmark_group(bench_name);
-    bench_group
-        .sample_size(15)
-        .measurement_time(std::time::Duration::from_secs(60));
+    let mut bench_group = new_bench_group(c, bench_name);
     let mut rng = rand::thread_rng();
 
     for (param, num_block, bit_size) in ParamsAndNumBlocksIter::default() {
-        let param_name = param.name();
+        let param_name = bench_param_name(&param);
 
         let bench_id = format!("{bench_name}::{param_name}::{bit_size}_bits");
+        let (cks, sks) = keys_from_params(param);
         bench_group.bench_function(&bench_id, |b| {
-            let (cks, sks) = KEY_CACHE.get_from_params(param);
-
             let encrypt_two_values = || {
                 let clearlow = rng.gen::<u128>();
                 let clearhigh = rng.gen::<u128>();
@@ -116,7 +231,7 @@ fn bench_server_key_binary_function_dirty_inputs<F>(
         write_to_json(
             &bench_id,
             param,
-            param.name(),
+            param_name,
             display_name,
             &OperatorType::Atomic,
         );
@@ -135,19 +250,15 @@ fn bench_server_key_binary_function_clean_inputs<F>(
 ) where
     F: Fn(&ServerKey, &mut RadixCiphertextBig, &mut RadixCiphertextBig),
 {
-    let mut bench_group = c.benchmark_group(bench_name);
-    bench_group
-        .sample_size(15)
-        .measurement_time(std::time::Duration::from_secs(60));
+    let mut bench_group = new_bench_group(c, bench_name);
     let mut rng = rand::thread_rng();
 
     for (param, num_block, bit_size) in ParamsAndNumBlocksIter::default() {
-        let param_name = param.name();
+        let param_name = bench_param_name(&param);
 
         let bench_id = format!("{bench_name}::{param_name}::{bit_size}_bits");
+        let (cks, sks) = keys_from_params(param);
         bench_group.bench_function(&bench_id, |b| {
-            let (cks, sks) = KEY_CACHE.get_from_params(param);
-
             let encrypt_two_values = || {
                 let clearlow = rng.gen::<u128>();
                 let clearhigh = rng.gen::<u128>();
@@ -174,7 +285,7 @@ fn bench_server_key_binary_function_clean_inputs<F>(
         write_to_json(
             &bench_id,
             param,
-            param.name(),
+            param_name,
             display_name,
             &OperatorType::Atomic,
         );
@@ -193,20 +304,16 @@ fn bench_server_key_unary_function_dirty_inputs<F>(
 ) where
     F: Fn(&ServerKey, &mut RadixCiphertextBig),
 {
-    let mut bench_group = c.benchmark_group(bench_name);
-    bench_group
-        .sample_size(15)
-        .measurement_time(std::time::Duration::from_secs(60));
+    let mut bench_group = new_bench_group(c, bench_name);
 
     let mut rng = rand::thread_rng();
 
     for (param, num_block, bit_size) in ParamsAndNumBlocksIter::default() {
-        let param_name = param.name();
+        let param_name = bench_param_name(&param);
 
         let bench_id = format!("{bench_name}::{param_name}::{bit_size}_bits");
+        let (cks, sks) = keys_from_params(param);
         bench_group.bench_function(&bench_id, |b| {
-            let (cks, sks) = KEY_CACHE.get_from_params(param);
-
             let encrypt_one_value = || {
                 let clearlow = rng.gen::<u128>();
                 let clearhigh = rng.gen::<u128>();
@@ -243,7 +350,7 @@ fn bench_server_key_unary_function_dirty_inputs<F>(
         write_to_json(
             &bench_id,
             param,
-            param.name(),
+            param_name,
             display_name,
             &OperatorType::Atomic,
         );
@@ -262,20 +369,16 @@ fn bench_server_key_unary_function_clean_inputs<F>(
 ) where
     F: Fn(&ServerKey, &mut RadixCiphertextBig),
 {
-    let mut bench_group = c.benchmark_group(bench_name);
-    bench_group
-        .sample_size(15)
-        .measurement_time(std::time::Duration::from_secs(60));
+    let mut bench_group = new_bench_group(c, bench_name);
 
     let mut rng = rand::thread_rng();
 
     for (param, num_block, bit_size) in ParamsAndNumBlocksIter::default() {
-        let param_name = param.name();
+        let param_name = bench_param_name(&param);
 
         let bench_id = format!("{bench_name}::{param_name}::{bit_size}_bits");
+        let (cks, sks) = keys_from_params(param);
         bench_group.bench_function(&bench_id, |b| {
-            let (cks, sks) = KEY_CACHE.get_from_params(param);
-
             let encrypt_one_value = || {
                 let clearlow = rng.gen::<u128>();
                 let clearhigh = rng.gen::<u128>();
@@ -297,7 +400,7 @@ fn bench_server_key_unary_function_clean_inputs<F>(
         write_to_json(
             &bench_id,
             param,
-            param.name(),
+            param_name,
             display_name,
             &OperatorType::Atomic,
         );
@@ -314,19 +417,15 @@ fn bench_server_key_binary_scalar_function_dirty_inputs<F>(
 ) where
     F: Fn(&ServerKey, &mut RadixCiphertextBig, u64),
 {
-    let mut bench_group = c.benchmark_group(bench_name);
-    bench_group
-        .sample_size(15)
-        .measurement_time(std::time::Duration::from_secs(60));
+    let mut bench_group = new_bench_group(c, bench_name);
     let mut rng = rand::thread_rng();
 
     for (param, num_block, bit_size) in ParamsAndNumBlocksIter::default() {
-        let param_name = param.name();
+        let param_name = bench_param_name(&param);
 
         let bench_id = format!("{bench_name}::{param_name}::{bit_size}_bits");
+        let (cks, sks) = keys_from_params(param);
         bench_group.bench_function(&bench_id, |b| {
-            let (cks, sks) = KEY_CACHE.get_from_params(param);
-
             let encrypt_one_value = || {
                 let clearlow = rng.gen::<u128>();
                 let clearhigh = rng.gen::<u128>();
@@ -364,7 +463,7 @@ fn bench_server_key_binary_scalar_function_dirty_inputs<F>(
         write_to_json(
             &bench_id,
             param,
-            param.name(),
+            param_name,
             display_name,
             &OperatorType::Atomic,
         );
@@ -381,19 +480,15 @@ fn bench_server_key_binary_scalar_function_clean_inputs<F>(
 ) where
     F: Fn(&ServerKey, &mut RadixCiphertextBig, u64),
 {
-    let mut bench_group = c.benchmark_group(bench_name);
-    bench_group
-        .sample_size(15)
-        .measurement_time(std::time::Duration::from_secs(60));
+    let mut bench_group = new_bench_group(c, bench_name);
     let mut rng = rand::thread_rng();
 
     for (param, num_block, bit_size) in ParamsAndNumBlocksIter::default() {
-        let param_name = param.name();
+        let param_name = bench_param_name(&param);
 
         let bench_id = format!("{bench_name}::{param_name}::{bit_size}_bits");
+        let (cks, sks) = keys_from_params(param);
         bench_group.bench_function(&bench_id, |b| {
-            let (cks, sks) = KEY_CACHE.get_from_params(param);
-
             let encrypt_one_value = || {
                 let clearlow = rng.gen::<u128>();
                 let clearhigh = rng.gen::<u128>();
@@ -418,7 +513,7 @@ fn bench_server_key_binary_scalar_function_clean_inputs<F>(
         write_to_json(
             &bench_id,
             param,
-            param.name(),
+            param_name,
             display_name,
             &OperatorType::Atomic,
         );
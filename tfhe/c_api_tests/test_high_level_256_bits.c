@@ -93,6 +93,39 @@ int uint256_public_key(const ClientKey *client_key, const PublicKey *public_key)
   return ok;
 }
 
+int uint256_decrypt_into(const ClientKey *client_key) {
+  int ok;
+  FheUint256 *lhs = NULL;
+  U256 *lhs_clear = NULL;
+  U256 *result_clear = NULL;
+
+  ok = u256_from_u64_words(1, 2, 3, 4, &lhs_clear);
+  assert(ok == 0);
+  ok = u256_from_u64_words(0, 0, 0, 0, &result_clear);
+  assert(ok == 0);
+
+  ok = fhe_uint256_try_encrypt_with_client_key_u256(lhs_clear, client_key, &lhs);
+  assert(ok == 0);
+
+  // The already allocated result_clear is overwritten
+  ok = fhe_uint256_decrypt_into(lhs, client_key, result_clear);
+  assert(ok == 0);
+
+  uint64_t w0, w1, w2, w3;
+  ok = u256_to_u64_words(result_clear, &w0, &w1, &w2, &w3);
+  assert(ok == 0);
+
+  assert(w0 == 1);
+  assert(w1 == 2);
+  assert(w2 == 3);
+  assert(w3 == 4);
+
+  u256_destroy(lhs_clear);
+  u256_destroy(result_clear);
+  fhe_uint256_destroy(lhs);
+  return ok;
+}
+
 int main(void) {
   int ok = 0;
   ConfigBuilder *builder;
@@ -113,6 +146,7 @@ int main(void) {
 
   uint256_client_key(client_key);
   uint256_public_key(client_key, public_key);
+  uint256_decrypt_into(client_key);
 
   client_key_destroy(client_key);
   public_key_destroy(public_key);
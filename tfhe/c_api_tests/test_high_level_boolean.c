@@ -67,6 +67,76 @@ int public_key_test(const ClientKey *client_key, const PublicKey *public_key) {
     return ok;
 }
 
+int serialization_test(const ClientKey *client_key) {
+    int ok;
+    FheBool *value = NULL;
+    FheBool *deserialized_value = NULL;
+    Buffer value_buffer = {.pointer = NULL, .length = 0};
+    BufferView deser_view = {.pointer = NULL, .length = 0};
+
+    bool value_clear = 1;
+
+    ok = fhe_bool_try_encrypt_with_client_key_bool(value_clear, client_key, &value);
+    assert(ok == 0);
+
+    ok = fhe_bool_serialize(value, &value_buffer);
+    assert(ok == 0);
+
+    deser_view.pointer = value_buffer.pointer;
+    deser_view.length = value_buffer.length;
+    ok = fhe_bool_deserialize(deser_view, &deserialized_value);
+    assert(ok == 0);
+
+    bool clear;
+    ok = fhe_bool_decrypt(deserialized_value, client_key, &clear);
+    assert(ok == 0);
+
+    assert(clear == value_clear);
+
+    destroy_buffer(&value_buffer);
+    fhe_bool_destroy(value);
+    fhe_bool_destroy(deserialized_value);
+
+    return ok;
+}
+
+int if_then_else_test(const ClientKey *client_key) {
+    int ok;
+    FheBool *condition = NULL;
+    FheBool *then_value = NULL;
+    FheBool *else_value = NULL;
+    FheBool *result = NULL;
+
+    bool condition_clear = 0;
+    bool then_clear = 1;
+    bool else_clear = 0;
+
+    ok = fhe_bool_try_encrypt_with_client_key_bool(condition_clear, client_key, &condition);
+    assert(ok == 0);
+
+    ok = fhe_bool_try_encrypt_with_client_key_bool(then_clear, client_key, &then_value);
+    assert(ok == 0);
+
+    ok = fhe_bool_try_encrypt_with_client_key_bool(else_clear, client_key, &else_value);
+    assert(ok == 0);
+
+    ok = fhe_bool_if_then_else(condition, then_value, else_value, &result);
+    assert(ok == 0);
+
+    bool clear;
+    ok = fhe_bool_decrypt(result, client_key, &clear);
+    assert(ok == 0);
+
+    assert(clear == (condition_clear ? then_clear : else_clear));
+
+    fhe_bool_destroy(condition);
+    fhe_bool_destroy(then_value);
+    fhe_bool_destroy(else_value);
+    fhe_bool_destroy(result);
+
+    return ok;
+}
+
 int main(void)
 {
   
@@ -88,6 +158,8 @@ int main(void)
 
   client_key_test(client_key);
   public_key_test(client_key, public_key);
+  serialization_test(client_key);
+  if_then_else_test(client_key);
   
   client_key_destroy(client_key);
   public_key_destroy(public_key);
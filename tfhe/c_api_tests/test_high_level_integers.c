@@ -35,6 +35,53 @@ int uint8_client_key(const ClientKey *client_key) {
   return ok;
 }
 
+int uint8_scalar_bitand(const ClientKey *client_key) {
+  int ok;
+  FheUint8 *lhs = NULL;
+  FheUint8 *result = NULL;
+
+  uint8_t lhs_clear = 123;
+  uint8_t mask = 0x0F;
+
+  ok = fhe_uint8_try_encrypt_with_client_key_u8(lhs_clear, client_key, &lhs);
+  assert(ok == 0);
+
+  ok = fhe_uint8_scalar_bitand(lhs, mask, &result);
+  assert(ok == 0);
+
+  uint8_t clear;
+  ok = fhe_uint8_decrypt(result, client_key, &clear);
+  assert(ok == 0);
+
+  assert(clear == (lhs_clear & mask));
+
+  fhe_uint8_destroy(lhs);
+  fhe_uint8_destroy(result);
+  return ok;
+}
+
+int uint8_neg_assign(const ClientKey *client_key) {
+  int ok;
+  FheUint8 *lhs = NULL;
+
+  uint8_t lhs_clear = 123;
+
+  ok = fhe_uint8_try_encrypt_with_client_key_u8(lhs_clear, client_key, &lhs);
+  assert(ok == 0);
+
+  ok = fhe_uint8_neg_assign(lhs);
+  assert(ok == 0);
+
+  uint8_t clear;
+  ok = fhe_uint8_decrypt(lhs, client_key, &clear);
+  assert(ok == 0);
+
+  assert(clear == (uint8_t)(-lhs_clear));
+
+  fhe_uint8_destroy(lhs);
+  return ok;
+}
+
 int uint8_public_key(const ClientKey *client_key, const PublicKey *public_key) {
   int ok;
   FheUint8 *lhs = NULL;
@@ -137,6 +184,29 @@ int uint8_compressed(const ClientKey *client_key) {
   return ok;
 }
 
+int uint8_bytes(const ClientKey *client_key) {
+  int ok;
+  uint8_t clear_bytes[4] = {0, 17, 123, 255};
+  FheUint8 *encrypted_bytes[4] = {NULL, NULL, NULL, NULL};
+  size_t encrypted_len = 0;
+
+  ok = fhe_uint8_try_encrypt_bytes_with_client_key(clear_bytes, 4, client_key, encrypted_bytes,
+                                                    &encrypted_len);
+  assert(ok == 0);
+  assert(encrypted_len == 4);
+
+  uint8_t decrypted_bytes[4] = {0, 0, 0, 0};
+  ok = fhe_uint8_decrypt_bytes((const FheUint8 *const *)encrypted_bytes, encrypted_len,
+                               client_key, decrypted_bytes);
+  assert(ok == 0);
+
+  for (size_t i = 0; i < 4; ++i) {
+    assert(decrypted_bytes[i] == clear_bytes[i]);
+    fhe_uint8_destroy(encrypted_bytes[i]);
+  }
+  return ok;
+}
+
 int main(void) {
   int ok = 0;
   {
@@ -162,6 +232,8 @@ int main(void) {
     assert(ok == 0);
     ok = uint8_compressed(client_key);
     assert(ok == 0);
+    ok = uint8_bytes(client_key);
+    assert(ok == 0);
 
     ok = set_server_key(server_key);
     assert(ok == 0);
@@ -170,6 +242,10 @@ int main(void) {
     assert(ok == 0);
     ok = uint8_public_key(client_key, public_key);
     assert(ok == 0);
+    ok = uint8_scalar_bitand(client_key);
+    assert(ok == 0);
+    ok = uint8_neg_assign(client_key);
+    assert(ok == 0);
 
     client_key_destroy(client_key);
     public_key_destroy(public_key);
@@ -214,6 +214,39 @@ test('shortint_compressed_public_encrypt_decrypt', (t) => {
     assert.deepStrictEqual(decrypted_small, BigInt(1));
 });
 
+test('shortint_parameter_name', (t) => {
+    let params = Shortint.get_parameters(2, 2);
+    assert.deepStrictEqual(Shortint.parameter_name(params), "PARAM_MESSAGE_2_CARRY_2");
+
+    let small_params = Shortint.get_parameters_small(2, 2);
+    assert.deepStrictEqual(Shortint.parameter_name(small_params), "PARAM_SMALL_MESSAGE_2_CARRY_2");
+});
+
+test('shortint_parameters_getters', (t) => {
+    let params = Shortint.get_parameters(2, 3);
+    assert.deepStrictEqual(params.message_modulus(), 4);
+    assert.deepStrictEqual(params.carry_modulus(), 8);
+
+    let custom_params = Shortint.new_parameters(
+        742, 1, 2048, 0.000007069849454709433, 0.00000000000000029403601535432533,
+        23, 1, 3, 5, 1, 23, 0.00000000000000029403601535432533, 0, 0, 4, 4, 64,
+    );
+    assert.deepStrictEqual(custom_params.lwe_dimension(), 742);
+    assert.deepStrictEqual(custom_params.glwe_dimension(), 1);
+    assert.deepStrictEqual(custom_params.polynomial_size(), 2048);
+    assert.deepStrictEqual(custom_params.message_modulus(), 4);
+    assert.deepStrictEqual(custom_params.carry_modulus(), 4);
+});
+
+test('shortint_invalid_parameters', (t) => {
+    // message_modulus * carry_modulus (2^5 * 2^5) and the padding bit do not fit in 2^10
+    let params = Shortint.new_parameters(
+        742, 1, 2048, 0.000007069849454709433, 0.00000000000000029403601535432533,
+        23, 1, 3, 5, 1, 23, 0.00000000000000029403601535432533, 0, 0, 32, 32, 10,
+    );
+    assert.throws(() => Shortint.new_client_key(params));
+});
+
 test('shortint_deterministic_keygen', (t) => {
     const TEST_LOOP_COUNT = 128;
 
@@ -231,3 +264,53 @@ test('shortint_deterministic_keygen', (t) => {
         assert.deepStrictEqual(decrypt_other, random_message);
     }
 });
+
+test('shortint_deserialize_ciphertext_auto', (t) => {
+    // serialize_ciphertext prefixes the shortint ciphertext with the 4 bytes bincode tag of
+    // its kind, removing it gives the ciphertext as serialized on its own by a Rust program
+    const KIND_TAG_SIZE = 4;
+
+    let params = Shortint.get_parameters(2, 2);
+    let cks = Shortint.new_client_key(params);
+    let ct = Shortint.encrypt(cks, BigInt(3));
+
+    let serialized_ct = Shortint.serialize_ciphertext(ct).slice(KIND_TAG_SIZE);
+    let deserialized_ct = Shortint.deserialize_ciphertext_auto(serialized_ct);
+
+    let decrypted = Shortint.decrypt(cks, deserialized_ct);
+    assert.deepStrictEqual(decrypted, BigInt(3));
+
+    let params_small = Shortint.get_parameters_small(2, 2);
+    let cks_small = Shortint.new_client_key(params_small);
+    let ct_small = Shortint.encrypt_small(cks_small, BigInt(2));
+
+    let serialized_ct_small = Shortint.serialize_ciphertext(ct_small).slice(KIND_TAG_SIZE);
+    let deserialized_ct_small = Shortint.deserialize_ciphertext_auto(serialized_ct_small);
+
+    let decrypted_small = Shortint.decrypt(cks_small, deserialized_ct_small);
+    assert.deepStrictEqual(decrypted_small, BigInt(2));
+});
+
+test('shortint_deserialize_client_key_with_progress', (t) => {
+    let params = Shortint.get_parameters(2, 2);
+    let cks = Shortint.new_client_key(params);
+
+    let serialized_cks = Shortint.serialize_client_key(cks);
+
+    let reported = [];
+    let deserialized_cks = Shortint.deserialize_client_key_with_progress(
+        serialized_cks,
+        (percentage) => reported.push(percentage),
+    );
+
+    // The percentages are reported in increasing order, up to completion
+    assert.ok(reported.length > 0);
+    for (let i = 1; i < reported.length; i++) {
+        assert.ok(reported[i] > reported[i - 1]);
+    }
+    assert.deepStrictEqual(reported[reported.length - 1], 100);
+
+    let ct = Shortint.encrypt(cks, BigInt(3));
+    let decrypted = Shortint.decrypt(deserialized_cks, ct);
+    assert.deepStrictEqual(decrypted, BigInt(3));
+});
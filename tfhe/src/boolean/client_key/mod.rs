@@ -45,6 +45,14 @@ impl Debug for ClientKey {
     }
 }
 
+#[cfg(feature = "zeroize-secret-keys")]
+impl Drop for ClientKey {
+    fn drop(&mut self) {
+        self.lwe_secret_key.zeroize();
+        self.glwe_secret_key.zeroize();
+    }
+}
+
 impl ClientKey {
     /// Encrypt a Boolean message using the client key.
     ///
@@ -154,6 +154,47 @@ impl ServerKey {
             engine.mux(ct_condition, ct_then, ct_else, self)
         })
     }
+
+    /// Computes the AND of all the ciphertexts.
+    ///
+    /// The ciphertexts are combined pairwise as a balanced tree, so the depth of the computation
+    /// is logarithmic in the number of ciphertexts. An empty slice gives a trivial encryption of
+    /// `true`.
+    pub fn and_many(&self, cts: &[Ciphertext]) -> Ciphertext {
+        self.reduce_many(cts, true, |lhs, rhs| self.and(lhs, rhs))
+    }
+
+    /// Computes the OR of all the ciphertexts.
+    ///
+    /// The ciphertexts are combined pairwise as a balanced tree, so the depth of the computation
+    /// is logarithmic in the number of ciphertexts. An empty slice gives a trivial encryption of
+    /// `false`.
+    pub fn or_many(&self, cts: &[Ciphertext]) -> Ciphertext {
+        self.reduce_many(cts, false, |lhs, rhs| self.or(lhs, rhs))
+    }
+
+    fn reduce_many<F>(&self, cts: &[Ciphertext], empty_value: bool, gate: F) -> Ciphertext
+    where
+        F: Fn(&Ciphertext, &Ciphertext) -> Ciphertext,
+    {
+        if cts.is_empty() {
+            return self.trivial_encrypt(empty_value);
+        }
+
+        let mut level = cts.to_vec();
+        while level.len() > 1 {
+            level = level
+                .chunks(2)
+                .map(|chunk| match chunk {
+                    [lhs, rhs] => gate(lhs, rhs),
+                    [single] => single.clone(),
+                    _ => unreachable!(),
+                })
+                .collect();
+        }
+
+        level.pop().unwrap()
+    }
 }
 
 impl CompressedServerKey {
@@ -54,6 +54,10 @@ mod default_parameters_tests {
         test_mux_gate(DEFAULT_PARAMETERS);
     }
     #[test]
+    fn test_and_many_or_many_default_parameters() {
+        test_and_many_or_many(DEFAULT_PARAMETERS);
+    }
+    #[test]
     fn test_deep_circuit_default_parameters() {
         test_deep_circuit(DEFAULT_PARAMETERS);
     }
@@ -100,6 +104,10 @@ mod tfhe_lib_parameters_tests {
         test_mux_gate(TFHE_LIB_PARAMETERS);
     }
     #[test]
+    fn test_and_many_or_many_tfhe_lib_parameters() {
+        test_and_many_or_many(TFHE_LIB_PARAMETERS);
+    }
+    #[test]
     fn test_deep_circuit_tfhe_lib_parameters() {
         test_deep_circuit(TFHE_LIB_PARAMETERS);
     }
@@ -236,6 +244,33 @@ fn test_and_gate(parameters: BooleanParameters) {
     }
 }
 
+fn test_and_many_or_many(parameters: BooleanParameters) {
+    // generate the client key set
+    let cks = ClientKey::new(&parameters);
+
+    // generate the server key set
+    let sks = ServerKey::new(&cks);
+
+    for num_ct in 0..=NB_CT {
+        // generation of random booleans
+        let clears = (0..num_ct).map(|_| random_boolean()).collect::<Vec<_>>();
+        let cts = clears
+            .iter()
+            .map(|&b| random_enum_encryption(&cks, &sks, b))
+            .collect::<Vec<_>>();
+
+        // AND of all the ciphertexts, true if there are none
+        let ct_res = sks.and_many(&cts);
+        let dec_and = cks.decrypt(&ct_res);
+        assert_eq!(clears.iter().all(|&b| b), dec_and);
+
+        // OR of all the ciphertexts, false if there are none
+        let ct_res = sks.or_many(&cts);
+        let dec_or = cks.decrypt(&ct_res);
+        assert_eq!(clears.iter().any(|&b| b), dec_or);
+    }
+}
+
 fn test_mux_gate(parameters: BooleanParameters) {
     // generate the client key set
     let cks = ClientKey::new(&parameters);
@@ -6,6 +6,7 @@ pub struct FheBool(crate::high_level_api::FheBool);
 
 impl_destroy_on_type!(FheBool);
 impl_clone_on_type!(FheBool);
+impl_serialize_deserialize_on_type!(FheBool);
 
 impl_binary_fn_on_type!(FheBool => bitand, bitor, bitxor);
 impl_unary_fn_on_type!(FheBool => not);
@@ -14,6 +15,24 @@ impl_decrypt_on_type!(FheBool, bool);
 impl_try_encrypt_with_client_key_on_type!(FheBool{crate::high_level_api::FheBool}, bool);
 impl_try_encrypt_with_public_key_on_type!(FheBool{crate::high_level_api::FheBool}, bool);
 
+#[no_mangle]
+pub unsafe extern "C" fn fhe_bool_if_then_else(
+    condition: *const FheBool,
+    then_value: *const FheBool,
+    else_value: *const FheBool,
+    result: *mut *mut FheBool,
+) -> ::std::os::raw::c_int {
+    crate::c_api::utils::catch_panic(|| {
+        let condition = crate::c_api::utils::get_ref_checked(condition).unwrap();
+        let then_value = crate::c_api::utils::get_ref_checked(then_value).unwrap();
+        let else_value = crate::c_api::utils::get_ref_checked(else_value).unwrap();
+
+        let inner = crate::high_level_api::if_then_else(&condition.0, &then_value.0, &else_value.0);
+
+        *result = Box::into_raw(Box::new(FheBool(inner)));
+    })
+}
+
 pub struct CompressedFheBool(crate::high_level_api::CompressedFheBool);
 
 impl_destroy_on_type!(CompressedFheBool);
@@ -16,12 +16,13 @@ macro_rules! impl_operations_for_integer_type {
         name: $name:ident,
         clear_scalar_type: $clear_scalar_type:ty
     ) => {
-        impl_binary_fn_on_type_mut!($name => add, sub, mul, bitand, bitor, bitxor, eq, ge, gt, le, lt, min, max);
+        impl_binary_fn_on_type_mut!($name => add, sub, mul, bitand, bitor, bitxor, eq, ne, ge, gt, le, lt, min, max);
         impl_binary_assign_fn_on_type_mut!($name => add_assign, sub_assign, mul_assign, bitand_assign, bitor_assign, bitxor_assign);
-        impl_scalar_binary_fn_on_type_mut!($name, $clear_scalar_type => add, sub, mul, shl, shr);
-        impl_scalar_binary_assign_fn_on_type_mut!($name, $clear_scalar_type => add_assign, sub_assign, mul_assign, shl_assign, shr_assign);
+        impl_scalar_binary_fn_on_type_mut!($name, $clear_scalar_type => add, sub, mul, shl, shr, bitand, bitor, bitxor);
+        impl_scalar_binary_assign_fn_on_type_mut!($name, $clear_scalar_type => add_assign, sub_assign, mul_assign, shl_assign, shr_assign, bitand_assign, bitor_assign, bitxor_assign);
 
         impl_unary_fn_on_type_mut!($name => neg);
+        impl_unary_assign_fn_on_type_mut!($name => neg_assign);
     };
 }
 
@@ -252,3 +253,85 @@ pub unsafe extern "C" fn fhe_uint256_decrypt(
         *result = Box::into_raw(Box::new(U256(inner)));
     })
 }
+
+/// Decrypts into an already allocated `U256`, overwriting its value.
+///
+/// Contrary to `fhe_uint256_decrypt`, this does not allocate a new `U256`,
+/// which allows reusing the same `U256` when decrypting many values.
+#[no_mangle]
+pub unsafe extern "C" fn fhe_uint256_decrypt_into(
+    encrypted_value: *const FheUint256,
+    client_key: *const ClientKey,
+    result: *mut U256,
+) -> c_int {
+    catch_panic(|| {
+        let client_key = get_ref_checked(client_key).unwrap();
+        let encrypted_value = get_ref_checked(encrypted_value).unwrap();
+        let result = get_mut_checked(result).unwrap();
+
+        result.0 = encrypted_value.0.decrypt(&client_key.0);
+    })
+}
+
+/// Encrypts each byte of `bytes` into its own `FheUint8`, in a single call.
+///
+/// `out` must point to an array of at least `len` `FheUint8` pointers, each slot is filled
+/// with a newly allocated ciphertext that must be destroyed with `fhe_uint8_destroy`.
+/// The number of ciphertexts written is stored in `out_len`.
+#[no_mangle]
+pub unsafe extern "C" fn fhe_uint8_try_encrypt_bytes_with_client_key(
+    bytes: *const u8,
+    len: usize,
+    client_key: *const ClientKey,
+    out: *mut *mut FheUint8,
+    out_len: *mut usize,
+) -> c_int {
+    catch_panic(|| {
+        let client_key = get_ref_checked(client_key).unwrap();
+        check_ptr_is_non_null_and_aligned(bytes).unwrap();
+        check_ptr_is_non_null_and_aligned(out).unwrap();
+        let out_len = get_mut_checked(out_len).unwrap();
+
+        let bytes = std::slice::from_raw_parts(bytes, len);
+        let out = std::slice::from_raw_parts_mut(out, len);
+
+        // Encrypt everything before handing out pointers, so that nothing leaks on failure
+        let encrypted = bytes
+            .iter()
+            .map(|&byte| {
+                <crate::high_level_api::FheUint8>::try_encrypt(byte, &client_key.0).unwrap()
+            })
+            .collect::<Vec<_>>();
+
+        for (slot, inner) in out.iter_mut().zip(encrypted) {
+            *slot = Box::into_raw(Box::new(FheUint8(inner)));
+        }
+        *out_len = len;
+    })
+}
+
+/// Decrypts `len` `FheUint8` ciphertexts into the `result` byte array, in a single call.
+///
+/// `encrypted_values` must point to an array of `len` `FheUint8` pointers and `result`
+/// to an array of at least `len` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn fhe_uint8_decrypt_bytes(
+    encrypted_values: *const *const FheUint8,
+    len: usize,
+    client_key: *const ClientKey,
+    result: *mut u8,
+) -> c_int {
+    catch_panic(|| {
+        let client_key = get_ref_checked(client_key).unwrap();
+        check_ptr_is_non_null_and_aligned(encrypted_values).unwrap();
+        check_ptr_is_non_null_and_aligned(result).unwrap();
+
+        let encrypted_values = std::slice::from_raw_parts(encrypted_values, len);
+        let result = std::slice::from_raw_parts_mut(result, len);
+
+        for (byte, &encrypted_value) in result.iter_mut().zip(encrypted_values) {
+            let encrypted_value = get_ref_checked(encrypted_value).unwrap();
+            *byte = encrypted_value.0.decrypt(&client_key.0);
+        }
+    })
+}
@@ -215,6 +215,27 @@ macro_rules! impl_unary_fn_on_type_mut {
     };
 }
 
+// Meant for types on which makes use of interior mutability
+#[cfg(feature = "integer")]
+macro_rules! impl_unary_assign_fn_on_type_mut {
+    ($wrapper_type:ty => $($unary_assign_fn_name:ident),* $(,)?) => {
+        $(
+           ::paste::paste! {
+                #[no_mangle]
+                pub unsafe extern "C" fn [<$wrapper_type:snake _ $unary_assign_fn_name>](
+                    lhs: *mut $wrapper_type,
+                ) -> ::std::os::raw::c_int {
+                    $crate::c_api::utils::catch_panic(|| {
+                        let lhs = $crate::c_api::utils::get_mut_checked(lhs).unwrap();
+
+                        lhs.0.$unary_assign_fn_name();
+                    })
+                }
+            }
+        )*
+    };
+}
+
 // Meant for types on which makes use of interior mutability
 #[cfg(feature = "integer")]
 macro_rules! impl_binary_fn_on_type_mut {
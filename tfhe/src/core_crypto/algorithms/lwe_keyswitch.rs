@@ -14,6 +14,13 @@ use crate::core_crypto::entities::*;
 ///
 /// See [`LWE keyswitch key`](`LweKeyswitchKey#lwe-keyswitch`).
 ///
+/// # Note
+///
+/// The keyswitch key, the input and the output ciphertexts must share the same ciphertext
+/// modulus, which can be the native modulus or a power of 2. The keyswitch does not require any
+/// scratch memory, it can therefore be freely composed with the
+/// [`programmable bootstrap`](`programmable_bootstrap_lwe_ciphertext`) in any order.
+///
 /// # Example
 ///
 /// ```
@@ -116,6 +123,27 @@ pub fn keyswitch_lwe_ciphertext<Scalar, KSKCont, InputCont, OutputCont>(
         lwe_keyswitch_key.output_key_lwe_dimension(),
         output_lwe_ciphertext.lwe_size().to_lwe_dimension(),
     );
+    assert_eq!(
+        lwe_keyswitch_key.ciphertext_modulus(),
+        input_lwe_ciphertext.ciphertext_modulus(),
+        "Mismatched CiphertextModulus between LweKeyswitchKey ({:?}) and input ({:?})",
+        lwe_keyswitch_key.ciphertext_modulus(),
+        input_lwe_ciphertext.ciphertext_modulus(),
+    );
+    assert_eq!(
+        lwe_keyswitch_key.ciphertext_modulus(),
+        output_lwe_ciphertext.ciphertext_modulus(),
+        "Mismatched CiphertextModulus between LweKeyswitchKey ({:?}) and output ({:?})",
+        lwe_keyswitch_key.ciphertext_modulus(),
+        output_lwe_ciphertext.ciphertext_modulus(),
+    );
+    assert!(
+        lwe_keyswitch_key
+            .ciphertext_modulus()
+            .is_compatible_with_native_modulus(),
+        "This operation only supports native and power of 2 moduli, got {:?}",
+        lwe_keyswitch_key.ciphertext_modulus(),
+    );
 
     // Clear the output ciphertext, as it will get updated gradually
     output_lwe_ciphertext.as_mut().fill(Scalar::ZERO);
@@ -3,9 +3,27 @@
 
 use crate::core_crypto::algorithms::slice_algorithms::*;
 use crate::core_crypto::commons::numeric::UnsignedInteger;
+use crate::core_crypto::commons::parameters::CiphertextModulus;
 use crate::core_crypto::commons::traits::*;
 use crate::core_crypto::entities::*;
 
+/// Return the value of the [`Plaintext`] as stored in a ciphertext with the given modulus.
+///
+/// Values under a non native power of two modulus are stored in the MSBs of the `Scalar`, so
+/// they are scaled to the native torus, this is a no-op for the native modulus.
+fn plaintext_to_native_torus<Scalar: UnsignedInteger>(
+    plaintext: Plaintext<Scalar>,
+    ciphertext_modulus: CiphertextModulus<Scalar>,
+) -> Scalar {
+    if ciphertext_modulus.is_native_modulus() {
+        plaintext.0
+    } else {
+        plaintext
+            .0
+            .wrapping_mul(ciphertext_modulus.get_scaling_to_native_torus())
+    }
+}
+
 /// Add the right-hand side [`LWE ciphertext`](`LweCiphertext`) to the left-hand side [`LWE
 /// ciphertext`](`LweCiphertext`) updating it in-place.
 ///
@@ -237,15 +255,8 @@ pub fn lwe_ciphertext_plaintext_add_assign<Scalar, InCont>(
     InCont: ContainerMut<Element = Scalar>,
 {
     let body = lhs.get_mut_body();
-    let ciphertext_modulus = body.ciphertext_modulus();
-    if ciphertext_modulus.is_native_modulus() {
-        *body.data = (*body.data).wrapping_add(rhs.0);
-    } else {
-        *body.data = (*body.data).wrapping_add(
-            rhs.0
-                .wrapping_mul(ciphertext_modulus.get_scaling_to_native_torus()),
-        );
-    }
+    let encoded = plaintext_to_native_torus(rhs, body.ciphertext_modulus());
+    *body.data = (*body.data).wrapping_add(encoded);
 }
 
 /// Add the right-hand side encoded [`Plaintext`] to the left-hand side [`LWE
@@ -312,15 +323,8 @@ pub fn lwe_ciphertext_plaintext_sub_assign<Scalar, InCont>(
     InCont: ContainerMut<Element = Scalar>,
 {
     let body = lhs.get_mut_body();
-    let ciphertext_modulus = body.ciphertext_modulus();
-    if ciphertext_modulus.is_native_modulus() {
-        *body.data = (*body.data).wrapping_sub(rhs.0);
-    } else {
-        *body.data = (*body.data).wrapping_sub(
-            rhs.0
-                .wrapping_mul(ciphertext_modulus.get_scaling_to_native_torus()),
-        );
-    }
+    let encoded = plaintext_to_native_torus(rhs, body.ciphertext_modulus());
+    *body.data = (*body.data).wrapping_sub(encoded);
 }
 
 /// Compute the opposite of the input [`LWE ciphertext`](`LweCiphertext`) and update it in place.
@@ -706,3 +710,116 @@ pub fn lwe_ciphertext_sub<Scalar, OutputCont, LhsCont, RhsCont>(
     output.as_mut().copy_from_slice(lhs.as_ref());
     lwe_ciphertext_sub_assign(output, rhs);
 }
+
+/// Compute the weighted sum of the [`LWE ciphertexts`](`LweCiphertext`) of an [`LWE ciphertext
+/// list`](`LweCiphertextList`) with the given cleartext weights, writing the result in the output
+/// [`LWE ciphertext`](`LweCiphertext`).
+///
+/// The `i`-th ciphertext of the list is multiplied by the `i`-th weight and the products are
+/// summed, the previous content of the output is overwritten.
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// // Define parameters for LweCiphertextList creation
+/// let lwe_dimension = LweDimension(742);
+/// let lwe_ciphertext_count = LweCiphertextCount(3);
+/// let lwe_modular_std_dev = StandardDev(0.000007069849454709433);
+/// let ciphertext_modulus = CiphertextModulus::new_native();
+///
+/// // Create the PRNG
+/// let mut seeder = new_seeder();
+/// let seeder = seeder.as_mut();
+/// let mut encryption_generator =
+///     EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed(), seeder);
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+///
+/// // Create the LweSecretKey
+/// let lwe_secret_key =
+///     allocate_and_generate_new_binary_lwe_secret_key(lwe_dimension, &mut secret_generator);
+///
+/// // Create the plaintext
+/// let msg = 1u64;
+/// let encoded_msg = msg << 60;
+/// let plaintext_list = PlaintextList::new(encoded_msg, PlaintextCount(lwe_ciphertext_count.0));
+///
+/// // Create a new LweCiphertextList
+/// let mut lwe_list = LweCiphertextList::new(
+///     0u64,
+///     lwe_dimension.to_lwe_size(),
+///     lwe_ciphertext_count,
+///     ciphertext_modulus,
+/// );
+///
+/// encrypt_lwe_ciphertext_list(
+///     &lwe_secret_key,
+///     &mut lwe_list,
+///     &plaintext_list,
+///     lwe_modular_std_dev,
+///     &mut encryption_generator,
+/// );
+///
+/// let weights = [Cleartext(1u64), Cleartext(2), Cleartext(3)];
+///
+/// let mut output = LweCiphertext::new(0u64, lwe_dimension.to_lwe_size(), ciphertext_modulus);
+///
+/// lwe_ciphertext_list_weighted_sum(&mut output, &lwe_list, &weights);
+///
+/// let decrypted_plaintext = decrypt_lwe_ciphertext(&lwe_secret_key, &output);
+///
+/// // Round and remove encoding
+/// // First create a decomposer working on the high 4 bits corresponding to our encoding.
+/// let decomposer = SignedDecomposer::new(DecompositionBaseLog(4), DecompositionLevelCount(1));
+///
+/// let rounded = decomposer.closest_representable(decrypted_plaintext.0);
+///
+/// // Remove the encoding
+/// let cleartext = rounded >> 60;
+///
+/// // Check we recovered the expected result
+/// assert_eq!(cleartext, msg * 1 + msg * 2 + msg * 3);
+/// ```
+pub fn lwe_ciphertext_list_weighted_sum<Scalar, OutputCont, InputCont>(
+    output: &mut LweCiphertext<OutputCont>,
+    list: &LweCiphertextList<InputCont>,
+    weights: &[Cleartext<Scalar>],
+) where
+    Scalar: UnsignedInteger,
+    OutputCont: ContainerMut<Element = Scalar>,
+    InputCont: Container<Element = Scalar>,
+{
+    assert_eq!(
+        weights.len(),
+        list.lwe_ciphertext_count().0,
+        "Mismatched number of weights ({}) and of ciphertexts in the list ({})",
+        weights.len(),
+        list.lwe_ciphertext_count().0
+    );
+
+    assert_eq!(
+        output.lwe_size(),
+        list.lwe_size(),
+        "Mismatched LweSize between output ({:?}) and list ({:?})",
+        output.lwe_size(),
+        list.lwe_size()
+    );
+
+    assert_eq!(
+        output.ciphertext_modulus(),
+        list.ciphertext_modulus(),
+        "Mismatched moduli between output ({:?}) and list ({:?})",
+        output.ciphertext_modulus(),
+        list.ciphertext_modulus()
+    );
+
+    output.as_mut().fill(Scalar::ZERO);
+
+    for (lwe, weight) in list.iter().zip(weights.iter()) {
+        slice_wrapping_add_scalar_mul_assign(output.as_mut(), lwe.as_ref(), weight.0);
+    }
+}
@@ -0,0 +1,173 @@
+//! Module containing primitives pertaining to the modulus switching of
+//! [`LWE ciphertexts`](`LweCiphertext`).
+
+use crate::core_crypto::commons::numeric::UnsignedInteger;
+use crate::core_crypto::commons::parameters::*;
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::entities::*;
+
+/// Switch an [`LWE ciphertext`](`LweCiphertext`) from its ciphertext modulus to the smaller
+/// ciphertext modulus of the output [`LWE ciphertext`](`LweCiphertext`).
+///
+/// Each coefficient is divided by the ratio between the two moduli and rounded to the nearest
+/// integer, following the same rounding convention as the modulus switch performed at the start
+/// of the programmable bootstrap. Both moduli must be compatible with the native modulus (i.e. be
+/// the native modulus or a power of 2).
+///
+/// The output ciphertext encrypts the same message under the same key, with the additional
+/// rounding noise introduced by the modulus switch.
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// // Define parameters for LweCiphertext creation
+/// let lwe_dimension = LweDimension(742);
+/// let lwe_modular_std_dev = StandardDev(0.000007069849454709433);
+/// let ciphertext_modulus = CiphertextModulus::new_native();
+/// let output_ciphertext_modulus = CiphertextModulus::try_new_power_of_2(32).unwrap();
+///
+/// // Create the PRNG
+/// let mut seeder = new_seeder();
+/// let seeder = seeder.as_mut();
+/// let mut encryption_generator =
+///     EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed(), seeder);
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+///
+/// // Create the LweSecretKey
+/// let lwe_secret_key =
+///     allocate_and_generate_new_binary_lwe_secret_key(lwe_dimension, &mut secret_generator);
+///
+/// // Create the plaintext
+/// let msg = 3u64;
+/// let plaintext = Plaintext(msg << 60);
+///
+/// // Create a new LweCiphertext
+/// let lwe = allocate_and_encrypt_new_lwe_ciphertext(
+///     &lwe_secret_key,
+///     plaintext,
+///     lwe_modular_std_dev,
+///     ciphertext_modulus,
+///     &mut encryption_generator,
+/// );
+///
+/// let mut switched_lwe = LweCiphertext::new(0u64, lwe.lwe_size(), output_ciphertext_modulus);
+///
+/// lwe_ciphertext_modulus_switch(&lwe, &mut switched_lwe);
+///
+/// // The decrypted plaintext is now an integer modulo 2^32
+/// let decrypted_plaintext = decrypt_lwe_ciphertext(&lwe_secret_key, &switched_lwe);
+///
+/// // Round and remove encoding
+/// // First create a decomposer working on the high 4 bits corresponding to our encoding.
+/// let decomposer = SignedDecomposer::new(DecompositionBaseLog(4), DecompositionLevelCount(1));
+///
+/// let rounded = decomposer.closest_representable(decrypted_plaintext.0 << 32);
+///
+/// // Remove the encoding
+/// let cleartext = rounded >> 60;
+///
+/// // Check we recovered the original message
+/// assert_eq!(cleartext, msg);
+/// ```
+pub fn lwe_ciphertext_modulus_switch<Scalar, InputCont, OutputCont>(
+    input_lwe_ciphertext: &LweCiphertext<InputCont>,
+    output_lwe_ciphertext: &mut LweCiphertext<OutputCont>,
+) where
+    Scalar: UnsignedInteger,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+{
+    assert!(
+        input_lwe_ciphertext.lwe_size() == output_lwe_ciphertext.lwe_size(),
+        "Mismatched LweSize. \
+        Input LweCiphertext LweSize: {:?}, output LweCiphertext LweSize {:?}.",
+        input_lwe_ciphertext.lwe_size(),
+        output_lwe_ciphertext.lwe_size(),
+    );
+
+    let input_ciphertext_modulus = input_lwe_ciphertext.ciphertext_modulus();
+    let output_ciphertext_modulus = output_lwe_ciphertext.ciphertext_modulus();
+
+    assert!(
+        input_ciphertext_modulus.is_compatible_with_native_modulus(),
+        "This operation only supports native and power of 2 moduli, got input modulus {:?}.",
+        input_ciphertext_modulus
+    );
+    assert!(
+        output_ciphertext_modulus.is_compatible_with_native_modulus(),
+        "This operation only supports native and power of 2 moduli, got output modulus {:?}.",
+        output_ciphertext_modulus
+    );
+
+    let input_modulus_bits = modulus_bits(input_ciphertext_modulus);
+    let output_modulus_bits = modulus_bits(output_ciphertext_modulus);
+
+    assert!(
+        output_modulus_bits <= input_modulus_bits,
+        "The output modulus {:?} must not be greater than the input modulus {:?}.",
+        output_ciphertext_modulus,
+        input_ciphertext_modulus
+    );
+
+    // Coefficients are stored on the native torus, so we round away the bits below the output
+    // modulus and keep the result scaled to the native torus
+    let shift = Scalar::BITS - output_modulus_bits;
+
+    if shift == 0 {
+        output_lwe_ciphertext
+            .as_mut()
+            .copy_from_slice(input_lwe_ciphertext.as_ref());
+        return;
+    }
+
+    for (dst, &src) in output_lwe_ciphertext
+        .as_mut()
+        .iter_mut()
+        .zip(input_lwe_ciphertext.as_ref().iter())
+    {
+        // Start doing the right shift
+        let mut switched = src >> (shift - 1);
+        // Do the rounding
+        switched = switched.wrapping_add(switched & Scalar::ONE);
+        // Finish the right shift
+        switched >>= 1;
+        // Scale back to the native torus, discarding the carry of the rounding
+        *dst = switched.wrapping_shl(shift as u32);
+    }
+}
+
+/// Allocate a new [`LWE ciphertext`](`LweCiphertext`) and switch the input
+/// [`LWE ciphertext`](`LweCiphertext`) to the given smaller ciphertext modulus in it.
+///
+/// See [`lwe_ciphertext_modulus_switch`] for usage.
+pub fn allocate_and_modulus_switch_lwe_ciphertext<Scalar, InputCont>(
+    input_lwe_ciphertext: &LweCiphertext<InputCont>,
+    target_ciphertext_modulus: CiphertextModulus<Scalar>,
+) -> LweCiphertextOwned<Scalar>
+where
+    Scalar: UnsignedInteger,
+    InputCont: Container<Element = Scalar>,
+{
+    let mut new_ct = LweCiphertextOwned::new(
+        Scalar::ZERO,
+        input_lwe_ciphertext.lwe_size(),
+        target_ciphertext_modulus,
+    );
+
+    lwe_ciphertext_modulus_switch(input_lwe_ciphertext, &mut new_ct);
+
+    new_ct
+}
+
+fn modulus_bits<Scalar: UnsignedInteger>(ciphertext_modulus: CiphertextModulus<Scalar>) -> usize {
+    if ciphertext_modulus.is_native_modulus() {
+        Scalar::BITS
+    } else {
+        ciphertext_modulus.get().ilog2() as usize
+    }
+}
@@ -9,8 +9,10 @@ use crate::core_crypto::fft_impl::common::pbs_modulus_switch;
 use crate::core_crypto::fft_impl::fft64::crypto::ggsw::{
     add_external_product_assign, add_external_product_assign_scratch, update_with_fmadd,
 };
-use crate::core_crypto::fft_impl::fft64::math::fft::Fft;
+use crate::core_crypto::fft_impl::fft64::math::fft::{Fft, FftView};
+use aligned_vec::CACHELINE_ALIGN;
 use concrete_fft::c64;
+use dyn_stack::{PodStack, ReborrowMut, SizeOverflow, StackReq};
 use std::sync::{mpsc, Condvar, Mutex};
 use std::thread;
 
@@ -123,57 +125,8 @@ use std::thread;
 /// // with a cleartext, however it resets the noise in a ciphertext to a nominal level and allows
 /// // to evaluate arbitrary functions so depending on your use case it can be a better fit.
 ///
-/// // Here we will define a helper function to generate an accumulator for a PBS
-/// fn generate_accumulator<F>(
-///     polynomial_size: PolynomialSize,
-///     glwe_size: GlweSize,
-///     message_modulus: usize,
-///     ciphertext_modulus: CiphertextModulus<u64>,
-///     delta: u64,
-///     f: F,
-/// ) -> GlweCiphertextOwned<u64>
-/// where
-///     F: Fn(u64) -> u64,
-/// {
-///     // N/(p/2) = size of each block, to correct noise from the input we introduce the notion of
-///     // box, which manages redundancy to yield a denoised value for several noisy values around
-///     // a true input value.
-///     let box_size = polynomial_size.0 / message_modulus;
-///
-///     // Create the accumulator
-///     let mut accumulator_u64 = vec![0_u64; polynomial_size.0];
-///
-///     // Fill each box with the encoded denoised value
-///     for i in 0..message_modulus {
-///         let index = i * box_size;
-///         accumulator_u64[index..index + box_size]
-///             .iter_mut()
-///             .for_each(|a| *a = f(i as u64) * delta);
-///     }
-///
-///     let half_box_size = box_size / 2;
-///
-///     // Negate the first half_box_size coefficients to manage negacyclicity and rotate
-///     for a_i in accumulator_u64[0..half_box_size].iter_mut() {
-///         *a_i = (*a_i).wrapping_neg();
-///     }
-///
-///     // Rotate the accumulator
-///     accumulator_u64.rotate_left(half_box_size);
-///
-///     let accumulator_plaintext = PlaintextList::from_container(accumulator_u64);
-///
-///     let accumulator = allocate_and_trivially_encrypt_new_glwe_ciphertext(
-///         glwe_size,
-///         &accumulator_plaintext,
-///         ciphertext_modulus,
-///     );
-///
-///     accumulator
-/// }
-///
 /// // Generate the accumulator for our multiplication by 2 using a simple closure
-/// let mut accumulator: GlweCiphertextOwned<u64> = generate_accumulator(
+/// let mut accumulator: GlweCiphertextOwned<u64> = generate_programmable_bootstrap_glwe_lut(
 ///     polynomial_size,
 ///     glwe_dimension.to_glwe_size(),
 ///     message_modulus as usize,
@@ -234,6 +187,54 @@ pub fn multi_bit_blind_rotate_assign<Scalar, InputCont, OutputCont, KeyCont>(
     InputCont: Container<Element = Scalar>,
     OutputCont: ContainerMut<Element = Scalar>,
     KeyCont: Container<Element = c64> + Sync,
+{
+    let mut buffers = ComputationBuffers::new();
+
+    let fft = Fft::new(multi_bit_bsk.polynomial_size());
+    let fft = fft.as_view();
+
+    buffers.resize(
+        multi_bit_blind_rotate_scratch::<Scalar>(
+            multi_bit_bsk.glwe_size(),
+            multi_bit_bsk.polynomial_size(),
+            multi_bit_bsk.decomposition_level_count(),
+            thread_count,
+            fft,
+        )
+        .unwrap()
+        .unaligned_bytes_required(),
+    );
+
+    multi_bit_blind_rotate_assign_mem_optimized(
+        input,
+        accumulator,
+        multi_bit_bsk,
+        thread_count,
+        fft,
+        buffers.stack(),
+    );
+}
+
+/// Memory optimized version of [`multi_bit_blind_rotate_assign`], the caller must provide a
+/// properly configured [`FftView`] object and a `PodStack` used as a memory buffer having a
+/// capacity at least as large as the result of [`multi_bit_blind_rotate_scratch`] for the same
+/// [`ThreadCount`].
+///
+/// The buffers of all the worker threads are carved out of the provided stack, no memory is
+/// allocated during the blind rotation.
+pub fn multi_bit_blind_rotate_assign_mem_optimized<Scalar, InputCont, OutputCont, KeyCont>(
+    input: &LweCiphertext<InputCont>,
+    accumulator: &mut GlweCiphertext<OutputCont>,
+    multi_bit_bsk: &FourierLweMultiBitBootstrapKey<KeyCont>,
+    thread_count: ThreadCount,
+    fft: FftView<'_>,
+    stack: PodStack<'_>,
+) where
+    // CastInto required for PBS modulus switch which returns a usize
+    Scalar: UnsignedTorus + CastInto<usize> + CastFrom<usize> + Sync,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+    KeyCont: Container<Element = c64> + Sync,
 {
     assert_eq!(
         input.lwe_size().to_lwe_dimension(),
@@ -313,12 +314,29 @@ pub fn multi_bit_blind_rotate_assign<Scalar, InputCont, OutputCont, KeyCont>(
             )
         });
 
-    let fourier_multi_bit_ggsw_buffers = (0..thread_buffers)
-        .map(|_| {
+    let fourier_ggsw_len = multi_bit_fourier_ggsw_len(
+        multi_bit_bsk.glwe_size(),
+        multi_bit_bsk.polynomial_size(),
+        multi_bit_bsk.decomposition_level_count(),
+    );
+    let producer_scratch_bytes =
+        multi_bit_producer_scratch::<Scalar>(multi_bit_bsk.polynomial_size(), fft)
+            .unwrap()
+            .unaligned_bytes_required();
+
+    let (mut fourier_ggsw_buffers_data, stack) =
+        stack.make_aligned_raw::<c64>(thread_buffers * fourier_ggsw_len, CACHELINE_ALIGN);
+    let (mut producer_stacks_data, stack) =
+        stack.make_aligned_raw::<u8>(thread_buffers * producer_scratch_bytes, CACHELINE_ALIGN);
+
+    let fourier_multi_bit_ggsw_buffers = fourier_ggsw_buffers_data
+        .chunks_exact_mut(fourier_ggsw_len)
+        .map(|fourier_ggsw_data| {
             (
                 Mutex::new(false),
                 Condvar::new(),
-                Mutex::new(FourierGgswCiphertext::new(
+                Mutex::new(FourierGgswCiphertext::from_container(
+                    fourier_ggsw_data,
                     multi_bit_bsk.glwe_size(),
                     multi_bit_bsk.polynomial_size(),
                     multi_bit_bsk.decomposition_base_log(),
@@ -330,142 +348,153 @@ pub fn multi_bit_blind_rotate_assign<Scalar, InputCont, OutputCont, KeyCont>(
 
     let (tx, rx) = mpsc::channel::<usize>();
 
-    let fft = Fft::new(multi_bit_bsk.polynomial_size());
-    let fft = fft.as_view();
     thread::scope(|s| {
-        let produce_multi_bit_fourier_ggsw = |thread_id: usize, tx: mpsc::Sender<usize>| {
-            let mut buffers = ComputationBuffers::new();
+        let produce_multi_bit_fourier_ggsw =
+            |thread_id: usize, tx: mpsc::Sender<usize>, producer_stack_data: &mut [u8]| {
+                let stack = PodStack::new(producer_stack_data);
 
-            buffers.resize(fft.forward_scratch().unwrap().unaligned_bytes_required());
+                let (mut unit_polynomial_data, stack) = stack.make_aligned_with(
+                    multi_bit_bsk.polynomial_size().0,
+                    CACHELINE_ALIGN,
+                    |_| Scalar::ZERO,
+                );
+                let mut unit_polynomial = Polynomial::from_container(&mut *unit_polynomial_data);
+                unit_polynomial.as_mut()[0] = Scalar::ONE;
+                let (mut a_monomial_data, stack) = stack.make_aligned_with(
+                    multi_bit_bsk.polynomial_size().0,
+                    CACHELINE_ALIGN,
+                    |_| Scalar::ZERO,
+                );
+                let mut a_monomial = Polynomial::from_container(&mut *a_monomial_data);
+                let (mut fourier_a_monomial_data, mut stack) = stack.make_aligned_raw::<c64>(
+                    multi_bit_bsk
+                        .polynomial_size()
+                        .to_fourier_polynomial_size()
+                        .0,
+                    CACHELINE_ALIGN,
+                );
+                let mut fourier_a_monomial = FourierPolynomial {
+                    data: &mut *fourier_a_monomial_data,
+                };
 
-            let mut unit_polynomial =
-                Polynomial::new(Scalar::ZERO, multi_bit_bsk.polynomial_size());
-            unit_polynomial.as_mut()[0] = Scalar::ONE;
-            let mut a_monomial = unit_polynomial.clone();
-            let mut fourier_a_monomial = FourierPolynomial::new(multi_bit_bsk.polynomial_size());
+                let work_queue = &work_queue;
 
-            let work_queue = &work_queue;
+                let dest_idx = thread_id;
+                let (ready_for_consumer_lock, condvar, fourier_ggsw_buffer) =
+                    &fourier_multi_bit_ggsw_buffers[dest_idx];
 
-            let dest_idx = thread_id;
-            let (ready_for_consumer_lock, condvar, fourier_ggsw_buffer) =
-                &fourier_multi_bit_ggsw_buffers[dest_idx];
+                loop {
+                    let maybe_work = {
+                        let mut queue_lock = work_queue.lock().unwrap();
+                        queue_lock.pop()
+                    };
 
-            loop {
-                let maybe_work = {
-                    let mut queue_lock = work_queue.lock().unwrap();
-                    queue_lock.pop()
-                };
+                    let Some((lwe_mask_elements, ggsw_group)) = maybe_work else {
+                        break;
+                    };
+                    let mut ready_for_consumer = ready_for_consumer_lock.lock().unwrap();
 
-                let Some((lwe_mask_elements, ggsw_group)) = maybe_work else {break};
-                let mut ready_for_consumer = ready_for_consumer_lock.lock().unwrap();
+                    // Wait while the buffer is not ready for processing and wait on the condvar
+                    // to get notified when we can start processing again
+                    while *ready_for_consumer {
+                        ready_for_consumer = condvar.wait(ready_for_consumer).unwrap();
+                    }
 
-                // Wait while the buffer is not ready for processing and wait on the condvar
-                // to get notified when we can start processing again
-                while *ready_for_consumer {
-                    ready_for_consumer = condvar.wait(ready_for_consumer).unwrap();
-                }
+                    let mut fourier_ggsw_buffer = fourier_ggsw_buffer.lock().unwrap();
 
-                let mut fourier_ggsw_buffer = fourier_ggsw_buffer.lock().unwrap();
+                    let mut ggsw_group_iter = ggsw_group.iter();
 
-                let mut ggsw_group_iter = ggsw_group.iter();
+                    // Keygen guarantees the first term is a constant term of the polynomial, no
+                    // polynomial multiplication required
+                    let ggsw_a_none = ggsw_group_iter.next().unwrap();
 
-                // Keygen guarantees the first term is a constant term of the polynomial, no
-                // polynomial multiplication required
-                let ggsw_a_none = ggsw_group_iter.next().unwrap();
+                    fourier_ggsw_buffer
+                        .as_mut_view()
+                        .data()
+                        .copy_from_slice(ggsw_a_none.as_view().data());
 
-                fourier_ggsw_buffer
-                    .as_mut_view()
-                    .data()
-                    .copy_from_slice(ggsw_a_none.as_view().data());
+                    let multi_bit_fourier_ggsw = fourier_ggsw_buffer.as_mut_view().data();
 
-                let multi_bit_fourier_ggsw = fourier_ggsw_buffer.as_mut_view().data();
+                    for (ggsw_idx, fourier_ggsw) in ggsw_group_iter.enumerate() {
+                        // We already processed the first ggsw, advance the index by 1
+                        let ggsw_idx = ggsw_idx + 1;
 
-                for (ggsw_idx, fourier_ggsw) in ggsw_group_iter.enumerate() {
-                    // We already processed the first ggsw, advance the index by 1
-                    let ggsw_idx = ggsw_idx + 1;
+                        // Select the proper mask elements to build the monomial degree depending on
+                        // the order the GGSW were generated in, using the bits from mask_idx and
+                        // ggsw_idx as selector bits
+                        let mut monomial_degree = Scalar::ZERO;
+                        for (mask_idx, &mask_element) in lwe_mask_elements.iter().enumerate() {
+                            let mask_position = lwe_mask_elements.len() - (mask_idx + 1);
+                            let selection_bit: Scalar =
+                                Scalar::cast_from((ggsw_idx >> mask_position) & 1);
+                            monomial_degree = monomial_degree
+                                .wrapping_add(selection_bit.wrapping_mul(mask_element));
+                        }
 
-                    // Select the proper mask elements to build the monomial degree depending on
-                    // the order the GGSW were generated in, using the bits from mask_idx and
-                    // ggsw_idx as selector bits
-                    let mut monomial_degree = Scalar::ZERO;
-                    for (mask_idx, &mask_element) in lwe_mask_elements.iter().enumerate() {
-                        let mask_position = lwe_mask_elements.len() - (mask_idx + 1);
-                        let selection_bit: Scalar =
-                            Scalar::cast_from((ggsw_idx >> mask_position) & 1);
-                        monomial_degree =
-                            monomial_degree.wrapping_add(selection_bit.wrapping_mul(mask_element));
-                    }
+                        let switched_degree = pbs_modulus_switch(
+                            monomial_degree,
+                            lut_poly_size,
+                            ModulusSwitchOffset(0),
+                            LutCountLog(0),
+                        );
 
-                    let switched_degree = pbs_modulus_switch(
-                        monomial_degree,
-                        lut_poly_size,
-                        ModulusSwitchOffset(0),
-                        LutCountLog(0),
-                    );
-
-                    a_monomial
-                        .as_mut()
-                        .copy_from_slice(unit_polynomial.as_ref());
-                    polynomial_wrapping_monic_monomial_mul_assign(
-                        &mut a_monomial,
-                        MonomialDegree(switched_degree),
-                    );
-
-                    fft.forward_as_integer(
-                        fourier_a_monomial.as_mut_view(),
-                        a_monomial.as_view(),
-                        buffers.stack(),
-                    );
-
-                    update_with_fmadd(
-                        multi_bit_fourier_ggsw,
-                        fourier_ggsw.as_view().data(),
-                        fourier_a_monomial.as_view().data,
-                        false,
-                        lut_poly_size.to_fourier_polynomial_size().0,
-                    );
-                }
+                        a_monomial
+                            .as_mut()
+                            .copy_from_slice(unit_polynomial.as_ref());
+                        polynomial_wrapping_monic_monomial_mul_assign(
+                            &mut a_monomial,
+                            MonomialDegree(switched_degree),
+                        );
+
+                        fft.forward_as_integer(
+                            fourier_a_monomial.as_mut_view(),
+                            a_monomial.as_view(),
+                            stack.rb_mut(),
+                        );
+
+                        update_with_fmadd(
+                            multi_bit_fourier_ggsw,
+                            fourier_ggsw.as_view().data(),
+                            fourier_a_monomial.as_view().data,
+                            false,
+                            lut_poly_size.to_fourier_polynomial_size().0,
+                        );
+                    }
 
-                // Drop the lock before we wake other threads
-                drop(fourier_ggsw_buffer);
+                    // Drop the lock before we wake other threads
+                    drop(fourier_ggsw_buffer);
 
-                *ready_for_consumer = true;
-                tx.send(dest_idx).unwrap();
+                    *ready_for_consumer = true;
+                    tx.send(dest_idx).unwrap();
 
-                // Wake threads waiting on the condvar
-                condvar.notify_all();
-            }
-        };
+                    // Wake threads waiting on the condvar
+                    condvar.notify_all();
+                }
+            };
 
-        let threads: Vec<_> = (0..thread_count.0)
-            .map(|id| {
+        let threads: Vec<_> = producer_stacks_data
+            .chunks_exact_mut(producer_scratch_bytes)
+            .enumerate()
+            .map(|(id, producer_stack_data)| {
                 let tx = tx.clone();
-                s.spawn(move || produce_multi_bit_fourier_ggsw(id, tx))
+                s.spawn(move || produce_multi_bit_fourier_ggsw(id, tx, producer_stack_data))
             })
             .collect();
 
         // We initialize ct0 for the successive external products
         let ct0 = accumulator;
-        let mut ct1 = GlweCiphertext::new(
-            Scalar::ZERO,
-            ct0.glwe_size(),
+        let (mut ct1_data, mut stack) = stack.make_aligned_with(
+            ct0.glwe_size().0 * ct0.polynomial_size().0,
+            CACHELINE_ALIGN,
+            |_| Scalar::ZERO,
+        );
+        let mut ct1 = GlweCiphertextMutView::from_container(
+            &mut *ct1_data,
             ct0.polynomial_size(),
             ct0.ciphertext_modulus(),
         );
         let ct1 = &mut ct1;
 
-        let mut buffers = ComputationBuffers::new();
-
-        buffers.resize(
-            add_external_product_assign_scratch::<Scalar>(
-                multi_bit_bsk.glwe_size(),
-                multi_bit_bsk.polynomial_size(),
-                fft,
-            )
-            .unwrap()
-            .unaligned_bytes_required(),
-        );
-
         let mut src_idx = 1usize;
 
         for _ in 0..multi_bit_bsk.multi_bit_input_lwe_dimension().0 {
@@ -491,7 +520,7 @@ pub fn multi_bit_blind_rotate_assign<Scalar, InputCont, OutputCont, KeyCont>(
                 multi_bit_fourier_ggsw.as_view(),
                 src_ct,
                 fft,
-                buffers.stack(),
+                stack.rb_mut(),
             );
             drop(multi_bit_fourier_ggsw);
 
@@ -524,6 +553,67 @@ pub fn multi_bit_blind_rotate_assign<Scalar, InputCont, OutputCont, KeyCont>(
     });
 }
 
+/// Return the size of the buffer holding a multi-bit [`FourierGgswCiphertext`].
+fn multi_bit_fourier_ggsw_len(
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+    decomposition_level_count: DecompositionLevelCount,
+) -> usize {
+    polynomial_size.to_fourier_polynomial_size().0
+        * glwe_size.0
+        * glwe_size.0
+        * decomposition_level_count.0
+}
+
+/// Return the memory required by a single thread building the multi-bit
+/// [`FourierGgswCiphertext`] in [`multi_bit_blind_rotate_assign_mem_optimized`].
+fn multi_bit_producer_scratch<Scalar>(
+    polynomial_size: PolynomialSize,
+    fft: FftView<'_>,
+) -> Result<StackReq, SizeOverflow> {
+    StackReq::try_all_of([
+        // unit polynomial and monomial
+        StackReq::try_new_aligned::<Scalar>(polynomial_size.0, CACHELINE_ALIGN)?,
+        StackReq::try_new_aligned::<Scalar>(polynomial_size.0, CACHELINE_ALIGN)?,
+        // fourier monomial
+        StackReq::try_new_aligned::<c64>(
+            polynomial_size.to_fourier_polynomial_size().0,
+            CACHELINE_ALIGN,
+        )?,
+        fft.forward_scratch()?,
+    ])
+}
+
+/// Return the required memory for [`multi_bit_blind_rotate_assign_mem_optimized`].
+///
+/// Each of the `thread_count` worker threads gets its own multi-bit
+/// [`FourierGgswCiphertext`] and FFT buffers, the requirement therefore grows linearly with the
+/// [`ThreadCount`].
+pub fn multi_bit_blind_rotate_scratch<Scalar>(
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+    decomposition_level_count: DecompositionLevelCount,
+    thread_count: ThreadCount,
+    fft: FftView<'_>,
+) -> Result<StackReq, SizeOverflow> {
+    let fourier_ggsw_buffer = StackReq::try_new_aligned::<c64>(
+        multi_bit_fourier_ggsw_len(glwe_size, polynomial_size, decomposition_level_count),
+        CACHELINE_ALIGN,
+    )?;
+    let producer_stack = StackReq::try_new_aligned::<u8>(
+        multi_bit_producer_scratch::<Scalar>(polynomial_size, fft)?.unaligned_bytes_required(),
+        CACHELINE_ALIGN,
+    )?;
+
+    StackReq::try_all_of([
+        StackReq::try_all_of((0..thread_count.0).map(|_| fourier_ggsw_buffer))?,
+        StackReq::try_all_of((0..thread_count.0).map(|_| producer_stack))?,
+        // ct1 used for the successive external products
+        StackReq::try_new_aligned::<Scalar>(glwe_size.0 * polynomial_size.0, CACHELINE_ALIGN)?,
+        add_external_product_assign_scratch::<Scalar>(glwe_size, polynomial_size, fft)?,
+    ])
+}
+
 /// Perform a programmable bootstrap with given an input [`LWE ciphertext`](`LweCiphertext`), a
 /// look-up table passed as a [`GLWE ciphertext`](`GlweCiphertext`) and an [`LWE multi-bit bootstrap
 /// key`](`LweMultiBitBootstrapKey`) in the fourier domain. The result is written in the provided
@@ -634,57 +724,8 @@ pub fn multi_bit_blind_rotate_assign<Scalar, InputCont, OutputCont, KeyCont>(
 /// // with a cleartext, however it resets the noise in a ciphertext to a nominal level and allows
 /// // to evaluate arbitrary functions so depending on your use case it can be a better fit.
 ///
-/// // Here we will define a helper function to generate an accumulator for a PBS
-/// fn generate_accumulator<F>(
-///     polynomial_size: PolynomialSize,
-///     glwe_size: GlweSize,
-///     message_modulus: usize,
-///     ciphertext_modulus: CiphertextModulus<u64>,
-///     delta: u64,
-///     f: F,
-/// ) -> GlweCiphertextOwned<u64>
-/// where
-///     F: Fn(u64) -> u64,
-/// {
-///     // N/(p/2) = size of each block, to correct noise from the input we introduce the notion of
-///     // box, which manages redundancy to yield a denoised value for several noisy values around
-///     // a true input value.
-///     let box_size = polynomial_size.0 / message_modulus;
-///
-///     // Create the accumulator
-///     let mut accumulator_u64 = vec![0_u64; polynomial_size.0];
-///
-///     // Fill each box with the encoded denoised value
-///     for i in 0..message_modulus {
-///         let index = i * box_size;
-///         accumulator_u64[index..index + box_size]
-///             .iter_mut()
-///             .for_each(|a| *a = f(i as u64) * delta);
-///     }
-///
-///     let half_box_size = box_size / 2;
-///
-///     // Negate the first half_box_size coefficients to manage negacyclicity and rotate
-///     for a_i in accumulator_u64[0..half_box_size].iter_mut() {
-///         *a_i = (*a_i).wrapping_neg();
-///     }
-///
-///     // Rotate the accumulator
-///     accumulator_u64.rotate_left(half_box_size);
-///
-///     let accumulator_plaintext = PlaintextList::from_container(accumulator_u64);
-///
-///     let accumulator = allocate_and_trivially_encrypt_new_glwe_ciphertext(
-///         glwe_size,
-///         &accumulator_plaintext,
-///         ciphertext_modulus,
-///     );
-///
-///     accumulator
-/// }
-///
 /// // Generate the accumulator for our multiplication by 2 using a simple closure
-/// let accumulator: GlweCiphertextOwned<u64> = generate_accumulator(
+/// let accumulator: GlweCiphertextOwned<u64> = generate_programmable_bootstrap_glwe_lut(
 ///     polynomial_size,
 ///     glwe_dimension.to_glwe_size(),
 ///     message_modulus as usize,
@@ -748,6 +789,190 @@ pub fn multi_bit_programmable_bootstrap_lwe_ciphertext<
     OutputCont: ContainerMut<Element = Scalar>,
     AccCont: Container<Element = Scalar>,
     KeyCont: Container<Element = c64> + Sync,
+{
+    let mut buffers = ComputationBuffers::new();
+
+    let fft = Fft::new(multi_bit_bsk.polynomial_size());
+    let fft = fft.as_view();
+
+    buffers.resize(
+        multi_bit_programmable_bootstrap_scratch::<Scalar>(
+            multi_bit_bsk.glwe_size(),
+            multi_bit_bsk.polynomial_size(),
+            multi_bit_bsk.decomposition_level_count(),
+            thread_count,
+            fft,
+        )
+        .unwrap()
+        .unaligned_bytes_required(),
+    );
+
+    multi_bit_programmable_bootstrap_lwe_ciphertext_mem_optimized(
+        input,
+        output,
+        accumulator,
+        multi_bit_bsk,
+        thread_count,
+        fft,
+        buffers.stack(),
+    );
+}
+
+/// Memory optimized version of [`multi_bit_programmable_bootstrap_lwe_ciphertext`], the caller
+/// must provide a properly configured [`FftView`] object and a `PodStack` used as a memory buffer
+/// having a capacity at least as large as the result of
+/// [`multi_bit_programmable_bootstrap_scratch`] for the same [`ThreadCount`].
+///
+/// As all the temporary buffers of the worker threads are carved out of the provided stack, the
+/// memory used by the bootstrap is bounded by the size of the buffer given by the caller.
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// let small_lwe_dimension = LweDimension(742);
+/// let glwe_dimension = GlweDimension(1);
+/// let polynomial_size = PolynomialSize(2048);
+/// let lwe_modular_std_dev = StandardDev(0.000007069849454709433);
+/// let glwe_modular_std_dev = StandardDev(0.00000000000000029403601535432533);
+/// let pbs_base_log = DecompositionBaseLog(23);
+/// let pbs_level = DecompositionLevelCount(1);
+/// let grouping_factor = LweBskGroupingFactor(2);
+/// let thread_count = ThreadCount(4);
+/// let ciphertext_modulus = CiphertextModulus::new_native();
+///
+/// let mut boxed_seeder = new_seeder();
+/// let seeder = boxed_seeder.as_mut();
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+/// let mut encryption_generator =
+///     EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed(), seeder);
+///
+/// let small_lwe_sk =
+///     LweSecretKey::generate_new_binary(small_lwe_dimension, &mut secret_generator);
+/// let glwe_sk =
+///     GlweSecretKey::generate_new_binary(glwe_dimension, polynomial_size, &mut secret_generator);
+/// let big_lwe_sk = glwe_sk.clone().into_lwe_secret_key();
+///
+/// let mut bsk = LweMultiBitBootstrapKey::new(
+///     0u64,
+///     glwe_dimension.to_glwe_size(),
+///     polynomial_size,
+///     pbs_base_log,
+///     pbs_level,
+///     small_lwe_dimension,
+///     grouping_factor,
+///     ciphertext_modulus,
+/// );
+///
+/// par_generate_lwe_multi_bit_bootstrap_key(
+///     &small_lwe_sk,
+///     &glwe_sk,
+///     &mut bsk,
+///     glwe_modular_std_dev,
+///     &mut encryption_generator,
+/// );
+///
+/// let mut multi_bit_bsk = FourierLweMultiBitBootstrapKey::new(
+///     bsk.input_lwe_dimension(),
+///     bsk.glwe_size(),
+///     bsk.polynomial_size(),
+///     bsk.decomposition_base_log(),
+///     bsk.decomposition_level_count(),
+///     bsk.grouping_factor(),
+/// );
+///
+/// convert_standard_lwe_multi_bit_bootstrap_key_to_fourier(&bsk, &mut multi_bit_bsk);
+///
+/// let message_modulus = 1u64 << 4;
+/// let input_message = 3u64;
+/// let delta = (1_u64 << 63) / message_modulus;
+///
+/// let lwe_ciphertext_in: LweCiphertextOwned<u64> = allocate_and_encrypt_new_lwe_ciphertext(
+///     &small_lwe_sk,
+///     Plaintext(input_message * delta),
+///     lwe_modular_std_dev,
+///     ciphertext_modulus,
+///     &mut encryption_generator,
+/// );
+///
+/// let accumulator: GlweCiphertextOwned<u64> = generate_programmable_bootstrap_glwe_lut(
+///     polynomial_size,
+///     glwe_dimension.to_glwe_size(),
+///     message_modulus as usize,
+///     ciphertext_modulus,
+///     delta,
+///     |x: u64| 2 * x,
+/// );
+///
+/// let mut pbs_multiplication_ct = LweCiphertext::new(
+///     0u64,
+///     big_lwe_sk.lwe_dimension().to_lwe_size(),
+///     ciphertext_modulus,
+/// );
+///
+/// // Size the computation buffers once for the chosen number of threads, they can then be
+/// // reused for any number of bootstraps with the same parameters
+/// let fft = Fft::new(polynomial_size);
+/// let fft = fft.as_view();
+/// let mut buffers = ComputationBuffers::new();
+/// buffers.resize(
+///     multi_bit_programmable_bootstrap_scratch::<u64>(
+///         glwe_dimension.to_glwe_size(),
+///         polynomial_size,
+///         pbs_level,
+///         thread_count,
+///         fft,
+///     )
+///     .unwrap()
+///     .unaligned_bytes_required(),
+/// );
+///
+/// multi_bit_programmable_bootstrap_lwe_ciphertext_mem_optimized(
+///     &lwe_ciphertext_in,
+///     &mut pbs_multiplication_ct,
+///     &accumulator,
+///     &multi_bit_bsk,
+///     thread_count,
+///     fft,
+///     buffers.stack(),
+/// );
+///
+/// let pbs_multipliation_plaintext: Plaintext<u64> =
+///     decrypt_lwe_ciphertext(&big_lwe_sk, &pbs_multiplication_ct);
+///
+/// let signed_decomposer =
+///     SignedDecomposer::new(DecompositionBaseLog(5), DecompositionLevelCount(1));
+///
+/// let pbs_multiplication_result: u64 =
+///     signed_decomposer.closest_representable(pbs_multipliation_plaintext.0) / delta;
+///
+/// assert_eq!(6, pbs_multiplication_result);
+/// ```
+pub fn multi_bit_programmable_bootstrap_lwe_ciphertext_mem_optimized<
+    Scalar,
+    InputCont,
+    OutputCont,
+    AccCont,
+    KeyCont,
+>(
+    input: &LweCiphertext<InputCont>,
+    output: &mut LweCiphertext<OutputCont>,
+    accumulator: &GlweCiphertext<AccCont>,
+    multi_bit_bsk: &FourierLweMultiBitBootstrapKey<KeyCont>,
+    thread_count: ThreadCount,
+    fft: FftView<'_>,
+    stack: PodStack<'_>,
+) where
+    // CastInto required for PBS modulus switch which returns a usize
+    Scalar: UnsignedTorus + CastInto<usize> + CastFrom<usize> + Sync,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+    AccCont: Container<Element = Scalar>,
+    KeyCont: Container<Element = c64> + Sync,
 {
     assert_eq!(
         input.lwe_size().to_lwe_dimension(),
@@ -801,17 +1026,47 @@ pub fn multi_bit_programmable_bootstrap_lwe_ciphertext<
         accumulator.ciphertext_modulus(),
     );
 
-    let mut local_accumulator = GlweCiphertext::new(
-        Scalar::ZERO,
-        accumulator.glwe_size(),
+    let (mut local_accumulator_data, stack) =
+        stack.collect_aligned(CACHELINE_ALIGN, accumulator.as_ref().iter().copied());
+    let mut local_accumulator = GlweCiphertextMutView::from_container(
+        &mut *local_accumulator_data,
         accumulator.polynomial_size(),
         accumulator.ciphertext_modulus(),
     );
-    local_accumulator
-        .as_mut()
-        .copy_from_slice(accumulator.as_ref());
 
-    multi_bit_blind_rotate_assign(input, &mut local_accumulator, multi_bit_bsk, thread_count);
+    multi_bit_blind_rotate_assign_mem_optimized(
+        input,
+        &mut local_accumulator,
+        multi_bit_bsk,
+        thread_count,
+        fft,
+        stack,
+    );
 
     extract_lwe_sample_from_glwe_ciphertext(&local_accumulator, output, MonomialDegree(0));
 }
+
+/// Return the required memory for
+/// [`multi_bit_programmable_bootstrap_lwe_ciphertext_mem_optimized`].
+///
+/// The requirement grows linearly with the [`ThreadCount`], as each worker thread needs its own
+/// buffers.
+pub fn multi_bit_programmable_bootstrap_scratch<Scalar>(
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+    decomposition_level_count: DecompositionLevelCount,
+    thread_count: ThreadCount,
+    fft: FftView<'_>,
+) -> Result<StackReq, SizeOverflow> {
+    multi_bit_blind_rotate_scratch::<Scalar>(
+        glwe_size,
+        polynomial_size,
+        decomposition_level_count,
+        thread_count,
+        fft,
+    )?
+    .try_and(StackReq::try_new_aligned::<Scalar>(
+        glwe_size.0 * polynomial_size.0,
+        CACHELINE_ALIGN,
+    )?)
+}
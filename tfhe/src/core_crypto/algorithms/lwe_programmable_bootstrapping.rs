@@ -1,6 +1,7 @@
 //! Module containing primitives pertaining to the [`LWE programmable
 //! bootstrap`](`LweBootstrapKey#programmable-bootstrapping`).
 
+use crate::core_crypto::algorithms::allocate_and_trivially_encrypt_new_glwe_ciphertext;
 use crate::core_crypto::commons::computation_buffers::ComputationBuffers;
 use crate::core_crypto::commons::math::decomposition::SignedDecomposer;
 use crate::core_crypto::commons::parameters::*;
@@ -20,9 +21,109 @@ use crate::core_crypto::fft_impl::fft64::crypto::ggsw::{
 };
 use crate::core_crypto::fft_impl::fft64::crypto::wop_pbs::blind_rotate_assign_scratch;
 use crate::core_crypto::fft_impl::fft64::math::fft::{Fft, FftView};
+use aligned_vec::CACHELINE_ALIGN;
 use concrete_fft::c64;
 use dyn_stack::{PodStack, SizeOverflow, StackReq};
 
+/// Allocate a new [`GLWE ciphertext`](`GlweCiphertext`) trivially encrypting the look-up table
+/// (also called accumulator) of the function `f` over a message space of size `message_modulus`,
+/// ready to be used in a programmable bootstrap.
+///
+/// Each input value is mapped to a box of `polynomial_size / message_modulus` redundant
+/// coefficients holding `f(input) * delta`. The look-up table is then rotated by half a box so
+/// that noisy inputs around a given value land in the box of that value, the coefficients wrapping
+/// around being negated to account for the negacyclicity of the polynomial ring.
+///
+/// `message_modulus` is the number of values in the message space, including the carry space if
+/// any, but not the padding bit.
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::prelude::*;
+///
+/// let polynomial_size = PolynomialSize(2048);
+/// let glwe_size = GlweSize(2);
+/// let ciphertext_modulus = CiphertextModulus::new_native();
+///
+/// // Our 4 bits message space
+/// let message_modulus = 1u64 << 4;
+///
+/// // Delta used to encode 4 bits of message + a bit of padding on u64
+/// let delta = (1_u64 << 63) / message_modulus;
+///
+/// let accumulator = generate_programmable_bootstrap_glwe_lut(
+///     polynomial_size,
+///     glwe_size,
+///     message_modulus as usize,
+///     ciphertext_modulus,
+///     delta,
+///     |x: u64| 2 * x,
+/// );
+///
+/// let box_size = polynomial_size.0 / message_modulus as usize;
+/// let half_box_size = box_size / 2;
+/// let body = accumulator.get_body();
+/// // The first coefficients hold f(0) and the box of 1 starts half a box later
+/// assert_eq!(body.as_ref()[0], 0);
+/// assert_eq!(body.as_ref()[half_box_size], 2 * delta);
+/// // The last half box holds the negated f(0) which is 0 as well
+/// assert_eq!(body.as_ref()[polynomial_size.0 - 1], 0);
+/// ```
+pub fn generate_programmable_bootstrap_glwe_lut<F, Scalar>(
+    polynomial_size: PolynomialSize,
+    glwe_size: GlweSize,
+    message_modulus: usize,
+    ciphertext_modulus: CiphertextModulus<Scalar>,
+    delta: Scalar,
+    f: F,
+) -> GlweCiphertextOwned<Scalar>
+where
+    F: Fn(Scalar) -> Scalar,
+    Scalar: UnsignedTorus + CastFrom<usize>,
+{
+    assert!(
+        message_modulus <= polynomial_size.0,
+        "The message modulus ({message_modulus}) cannot be greater than the polynomial size ({})",
+        polynomial_size.0
+    );
+
+    // N/(p/2) = size of each block, to correct noise from the input we introduce the
+    // notion of box, which manages redundancy to yield a denoised value
+    // for several noisy values around a true input value.
+    let box_size = polynomial_size.0 / message_modulus;
+
+    // Create the accumulator
+    let mut accumulator_scalar = vec![Scalar::ZERO; polynomial_size.0];
+
+    // Fill each box with the encoded denoised value
+    for i in 0..message_modulus {
+        let index = i * box_size;
+        let f_eval = f(Scalar::cast_from(i)).wrapping_mul(delta);
+        accumulator_scalar[index..index + box_size]
+            .iter_mut()
+            .for_each(|a| *a = f_eval);
+    }
+
+    let half_box_size = box_size / 2;
+
+    // Negate the first half_box_size coefficients to manage negacyclicity and rotate
+    for a_i in accumulator_scalar[0..half_box_size].iter_mut() {
+        *a_i = (*a_i).wrapping_neg();
+    }
+
+    // Rotate the accumulator
+    accumulator_scalar.rotate_left(half_box_size);
+
+    let accumulator_plaintext = PlaintextList::from_container(accumulator_scalar);
+
+    allocate_and_trivially_encrypt_new_glwe_ciphertext(
+        glwe_size,
+        &accumulator_plaintext,
+        ciphertext_modulus,
+    )
+}
+
 /// Perform a blind rotation given an input [`LWE ciphertext`](`LweCiphertext`), modifying a look-up
 /// table passed as a [`GLWE ciphertext`](`GlweCiphertext`) and an [`LWE bootstrap
 /// key`](`LweBootstrapKey`) in the fourier domain.
@@ -134,57 +235,8 @@ use dyn_stack::{PodStack, SizeOverflow, StackReq};
 /// // with a cleartext, however it resets the noise in a ciphertext to a nominal level and allows
 /// // to evaluate arbitrary functions so depending on your use case it can be a better fit.
 ///
-/// // Here we will define a helper function to generate an accumulator for a PBS
-/// fn generate_accumulator<F>(
-///     polynomial_size: PolynomialSize,
-///     glwe_size: GlweSize,
-///     message_modulus: usize,
-///     ciphertext_modulus: CiphertextModulus<u64>,
-///     delta: u64,
-///     f: F,
-/// ) -> GlweCiphertextOwned<u64>
-/// where
-///     F: Fn(u64) -> u64,
-/// {
-///     // N/(p/2) = size of each block, to correct noise from the input we introduce the notion of
-///     // box, which manages redundancy to yield a denoised value for several noisy values around
-///     // a true input value.
-///     let box_size = polynomial_size.0 / message_modulus;
-///
-///     // Create the accumulator
-///     let mut accumulator_u64 = vec![0_u64; polynomial_size.0];
-///
-///     // Fill each box with the encoded denoised value
-///     for i in 0..message_modulus {
-///         let index = i * box_size;
-///         accumulator_u64[index..index + box_size]
-///             .iter_mut()
-///             .for_each(|a| *a = f(i as u64) * delta);
-///     }
-///
-///     let half_box_size = box_size / 2;
-///
-///     // Negate the first half_box_size coefficients to manage negacyclicity and rotate
-///     for a_i in accumulator_u64[0..half_box_size].iter_mut() {
-///         *a_i = (*a_i).wrapping_neg();
-///     }
-///
-///     // Rotate the accumulator
-///     accumulator_u64.rotate_left(half_box_size);
-///
-///     let accumulator_plaintext = PlaintextList::from_container(accumulator_u64);
-///
-///     let accumulator = allocate_and_trivially_encrypt_new_glwe_ciphertext(
-///         glwe_size,
-///         &accumulator_plaintext,
-///         ciphertext_modulus,
-///     );
-///
-///     accumulator
-/// }
-///
 /// // Generate the accumulator for our multiplication by 2 using a simple closure
-/// let mut accumulator: GlweCiphertextOwned<u64> = generate_accumulator(
+/// let mut accumulator: GlweCiphertextOwned<u64> = generate_programmable_bootstrap_glwe_lut(
 ///     polynomial_size,
 ///     glwe_dimension.to_glwe_size(),
 ///     message_modulus as usize,
@@ -809,6 +861,184 @@ pub fn cmux_assign_mem_optimized_requirement<Scalar>(
     cmux_scratch::<Scalar>(glwe_size, polynomial_size, fft)
 }
 
+/// Select between the input `ct0` and `ct1` using `ggsw_selector`, the result is stored in `ct0`.
+///
+/// Contrary to [`cmux_assign_mem_optimized`], `ct1` is left untouched: the difference `ct1 - ct0`
+/// required by the cmux is computed in a temporary buffer taken from `stack`, which must have a
+/// capacity at least as large as the result of [`glwe_cmux_requirement`].
+///
+/// Assuming a binary (0 or 1) value encrypted in `ggsw_selector` this computes:
+///
+/// ```text
+/// def glwe_cmux(ct0, ct1, ggsw_selector):
+///     if ggsw_selector == 1:
+///         ct0 = ct1
+///     else:
+///         ct0 = ct0
+/// ```
+///
+/// Encrypting values other than 0 or 1 in `ggsw_selector` yields a linear combination of `ct0`
+/// and `ct1`, see [`cmux_assign`] for the details.
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::prelude::*;
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// let glwe_size = GlweSize(2);
+/// let polynomial_size = PolynomialSize(1024);
+/// let decomp_base_log = DecompositionBaseLog(23);
+/// let decomp_level_count = DecompositionLevelCount(1);
+/// let glwe_modular_std_dev = StandardDev(0.00000000000000029403601535432533);
+/// let ciphertext_modulus = CiphertextModulus::new_native();
+///
+/// // Create the PRNG
+/// let mut seeder = new_seeder();
+/// let seeder = seeder.as_mut();
+/// let mut encryption_generator =
+///     EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed(), seeder);
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+///
+/// // Create the GlweSecretKey
+/// let glwe_secret_key = allocate_and_generate_new_binary_glwe_secret_key(
+///     glwe_size.to_glwe_dimension(),
+///     polynomial_size,
+///     &mut secret_generator,
+/// );
+///
+/// // Encrypt the selector
+/// let mut ggsw_selector = GgswCiphertext::new(
+///     0u64,
+///     glwe_size,
+///     polynomial_size,
+///     decomp_base_log,
+///     decomp_level_count,
+///     ciphertext_modulus,
+/// );
+///
+/// encrypt_constant_ggsw_ciphertext(
+///     &glwe_secret_key,
+///     &mut ggsw_selector,
+///     Plaintext(1u64),
+///     glwe_modular_std_dev,
+///     &mut encryption_generator,
+/// );
+///
+/// let ct0_plaintexts = PlaintextList::new(1u64 << 60, PlaintextCount(polynomial_size.0));
+/// let ct1_plaintexts = PlaintextList::new(3u64 << 60, PlaintextCount(polynomial_size.0));
+///
+/// let mut ct0 = GlweCiphertext::new(0u64, glwe_size, polynomial_size, ciphertext_modulus);
+/// let mut ct1 = GlweCiphertext::new(0u64, glwe_size, polynomial_size, ciphertext_modulus);
+///
+/// encrypt_glwe_ciphertext(
+///     &glwe_secret_key,
+///     &mut ct0,
+///     &ct0_plaintexts,
+///     glwe_modular_std_dev,
+///     &mut encryption_generator,
+/// );
+///
+/// encrypt_glwe_ciphertext(
+///     &glwe_secret_key,
+///     &mut ct1,
+///     &ct1_plaintexts,
+///     glwe_modular_std_dev,
+///     &mut encryption_generator,
+/// );
+///
+/// let fft = Fft::new(polynomial_size);
+/// let fft = fft.as_view();
+/// let mut buffers = ComputationBuffers::new();
+///
+/// let buffer_size_req = glwe_cmux_requirement::<u64>(glwe_size, polynomial_size, fft)
+///     .unwrap()
+///     .unaligned_bytes_required()
+///     .max(
+///         convert_standard_ggsw_ciphertext_to_fourier_mem_optimized_requirement(fft)
+///             .unwrap()
+///             .unaligned_bytes_required(),
+///     );
+///
+/// buffers.resize(buffer_size_req);
+///
+/// let mut fourier_ggsw_selector = FourierGgswCiphertext::new(
+///     glwe_size,
+///     polynomial_size,
+///     decomp_base_log,
+///     decomp_level_count,
+/// );
+///
+/// convert_standard_ggsw_ciphertext_to_fourier_mem_optimized(
+///     &ggsw_selector,
+///     &mut fourier_ggsw_selector,
+///     fft,
+///     buffers.stack(),
+/// );
+///
+/// let ct1_before = ct1.clone();
+///
+/// glwe_cmux(&mut ct0, &ct1, &fourier_ggsw_selector, fft, buffers.stack());
+///
+/// // ct1 is not modified
+/// assert_eq!(ct1, ct1_before);
+///
+/// let mut output_plaintext_list = PlaintextList::new(0u64, ct1_plaintexts.plaintext_count());
+///
+/// decrypt_glwe_ciphertext(&glwe_secret_key, &ct0, &mut output_plaintext_list);
+///
+/// let signed_decomposer =
+///     SignedDecomposer::new(DecompositionBaseLog(4), DecompositionLevelCount(1));
+///
+/// output_plaintext_list
+///     .iter_mut()
+///     .for_each(|x| *x.0 = signed_decomposer.closest_representable(*x.0));
+///
+/// assert!(output_plaintext_list.iter().all(|x| *x.0 == 3u64 << 60));
+/// ```
+pub fn glwe_cmux<Scalar, Cont0, Cont1, GgswCont>(
+    ct0: &mut GlweCiphertext<Cont0>,
+    ct1: &GlweCiphertext<Cont1>,
+    ggsw_selector: &FourierGgswCiphertext<GgswCont>,
+    fft: FftView<'_>,
+    stack: PodStack<'_>,
+) where
+    Scalar: UnsignedTorus,
+    Cont0: ContainerMut<Element = Scalar>,
+    Cont1: Container<Element = Scalar>,
+    GgswCont: Container<Element = c64>,
+{
+    assert_eq!(ct0.ciphertext_modulus(), ct1.ciphertext_modulus());
+    assert_eq!(ct0.polynomial_size(), ct1.polynomial_size());
+    assert_eq!(ct0.glwe_size(), ct1.glwe_size());
+
+    let (mut ct1_copy, stack) =
+        stack.collect_aligned(CACHELINE_ALIGN, ct1.as_ref().iter().copied());
+    let mut ct1_copy = GlweCiphertextMutView::from_container(
+        &mut *ct1_copy,
+        ct1.polynomial_size(),
+        ct1.ciphertext_modulus(),
+    );
+
+    cmux_assign_mem_optimized(ct0, &mut ct1_copy, ggsw_selector, fft, stack);
+}
+
+/// Return the required memory for [`glwe_cmux`].
+pub fn glwe_cmux_requirement<Scalar>(
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+    fft: FftView<'_>,
+) -> Result<StackReq, SizeOverflow> {
+    let ct1_copy =
+        StackReq::try_new_aligned::<Scalar>(glwe_size.0 * polynomial_size.0, CACHELINE_ALIGN)?;
+    ct1_copy.try_and(cmux_assign_mem_optimized_requirement::<Scalar>(
+        glwe_size,
+        polynomial_size,
+        fft,
+    )?)
+}
+
 /// Perform a programmable bootstrap given an input [`LWE ciphertext`](`LweCiphertext`), a
 /// look-up table passed as a [`GLWE ciphertext`](`GlweCiphertext`) and an [`LWE bootstrap
 /// key`](`LweBootstrapKey`) in the fourier domain. The result is written in the provided output
@@ -919,57 +1149,8 @@ pub fn cmux_assign_mem_optimized_requirement<Scalar>(
 /// // with a cleartext, however it resets the noise in a ciphertext to a nominal level and allows
 /// // to evaluate arbitrary functions so depending on your use case it can be a better fit.
 ///
-/// // Here we will define a helper function to generate an accumulator for a PBS
-/// fn generate_accumulator<F>(
-///     polynomial_size: PolynomialSize,
-///     glwe_size: GlweSize,
-///     message_modulus: usize,
-///     ciphertext_modulus: CiphertextModulus<u64>,
-///     delta: u64,
-///     f: F,
-/// ) -> GlweCiphertextOwned<u64>
-/// where
-///     F: Fn(u64) -> u64,
-/// {
-///     // N/(p/2) = size of each block, to correct noise from the input we introduce the notion of
-///     // box, which manages redundancy to yield a denoised value for several noisy values around
-///     // a true input value.
-///     let box_size = polynomial_size.0 / message_modulus;
-///
-///     // Create the accumulator
-///     let mut accumulator_u64 = vec![0_u64; polynomial_size.0];
-///
-///     // Fill each box with the encoded denoised value
-///     for i in 0..message_modulus {
-///         let index = i * box_size;
-///         accumulator_u64[index..index + box_size]
-///             .iter_mut()
-///             .for_each(|a| *a = f(i as u64) * delta);
-///     }
-///
-///     let half_box_size = box_size / 2;
-///
-///     // Negate the first half_box_size coefficients to manage negacyclicity and rotate
-///     for a_i in accumulator_u64[0..half_box_size].iter_mut() {
-///         *a_i = (*a_i).wrapping_neg();
-///     }
-///
-///     // Rotate the accumulator
-///     accumulator_u64.rotate_left(half_box_size);
-///
-///     let accumulator_plaintext = PlaintextList::from_container(accumulator_u64);
-///
-///     let accumulator = allocate_and_trivially_encrypt_new_glwe_ciphertext(
-///         glwe_size,
-///         &accumulator_plaintext,
-///         ciphertext_modulus,
-///     );
-///
-///     accumulator
-/// }
-///
 /// // Generate the accumulator for our multiplication by 2 using a simple closure
-/// let accumulator: GlweCiphertextOwned<u64> = generate_accumulator(
+/// let accumulator: GlweCiphertextOwned<u64> = generate_programmable_bootstrap_glwe_lut(
 ///     polynomial_size,
 ///     glwe_dimension.to_glwe_size(),
 ///     message_modulus as usize,
@@ -1228,57 +1409,8 @@ pub fn programmable_bootstrap_lwe_ciphertext_mem_optimized_requirement<Scalar>(
 /// // with a cleartext, however it resets the noise in a ciphertext to a nominal level and allows
 /// // to evaluate arbitrary functions so depending on your use case it can be a better fit.
 ///
-/// // Here we will define a helper function to generate an accumulator for a PBS
-/// fn generate_accumulator<F>(
-///     polynomial_size: PolynomialSize,
-///     glwe_size: GlweSize,
-///     message_modulus: usize,
-///     ciphertext_modulus: CiphertextModulus<u128>,
-///     delta: u128,
-///     f: F,
-/// ) -> GlweCiphertextOwned<u128>
-/// where
-///     F: Fn(u128) -> u128,
-/// {
-///     // N/(p/2) = size of each block, to correct noise from the input we introduce the notion of
-///     // box, which manages redundancy to yield a denoised value for several noisy values around
-///     // a true input value.
-///     let box_size = polynomial_size.0 / message_modulus;
-///
-///     // Create the accumulator
-///     let mut accumulator_u128 = vec![0_u128; polynomial_size.0];
-///
-///     // Fill each box with the encoded denoised value
-///     for i in 0..message_modulus {
-///         let index = i * box_size;
-///         accumulator_u128[index..index + box_size]
-///             .iter_mut()
-///             .for_each(|a| *a = f(i as u128) * delta);
-///     }
-///
-///     let half_box_size = box_size / 2;
-///
-///     // Negate the first half_box_size coefficients to manage negacyclicity and rotate
-///     for a_i in accumulator_u128[0..half_box_size].iter_mut() {
-///         *a_i = (*a_i).wrapping_neg();
-///     }
-///
-///     // Rotate the accumulator
-///     accumulator_u128.rotate_left(half_box_size);
-///
-///     let accumulator_plaintext = PlaintextList::from_container(accumulator_u128);
-///
-///     let accumulator = allocate_and_trivially_encrypt_new_glwe_ciphertext(
-///         glwe_size,
-///         &accumulator_plaintext,
-///         ciphertext_modulus,
-///     );
-///
-///     accumulator
-/// }
-///
 /// // Generate the accumulator for our multiplication by 2 using a simple closure
-/// let accumulator: GlweCiphertextOwned<u128> = generate_accumulator(
+/// let accumulator: GlweCiphertextOwned<u128> = generate_programmable_bootstrap_glwe_lut(
 ///     polynomial_size,
 ///     glwe_dimension.to_glwe_size(),
 ///     message_modulus as usize,
@@ -0,0 +1,113 @@
+//! Module containing primitives pertaining to the decoding of [`plaintexts`](`Plaintext`).
+
+use crate::core_crypto::commons::math::decomposition::SignedDecomposer;
+use crate::core_crypto::commons::numeric::UnsignedInteger;
+use crate::core_crypto::commons::parameters::*;
+use crate::core_crypto::entities::*;
+
+/// Decode a [`Plaintext`] obtained by decrypting a ciphertext which encodes its message with one
+/// bit of padding followed by `carry_modulus * message_modulus` values, i.e. the encoding used by
+/// shortint.
+///
+/// The plaintext is rounded to the closest encoded value using a [`SignedDecomposer`] working on
+/// the padding bit and the carry and message bits, and the encoding is removed. The returned value
+/// therefore contains the message and carry, as well as the padding bit if it has been consumed.
+///
+/// Both moduli must be powers of 2 and the ciphertext modulus must be compatible with the native
+/// modulus (i.e. be the native modulus or a power of 2), power of 2 moduli being stored in the
+/// most significant bits of the native torus.
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// // Define parameters for LweCiphertext creation
+/// let lwe_dimension = LweDimension(742);
+/// let lwe_modular_std_dev = StandardDev(0.000007069849454709433);
+/// let ciphertext_modulus = CiphertextModulus::new_native();
+/// let message_modulus = 4;
+/// let carry_modulus = 4;
+///
+/// // Create the PRNG
+/// let mut seeder = new_seeder();
+/// let seeder = seeder.as_mut();
+/// let mut encryption_generator =
+///     EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed(), seeder);
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+///
+/// // Create the LweSecretKey
+/// let lwe_secret_key =
+///     allocate_and_generate_new_binary_lwe_secret_key(lwe_dimension, &mut secret_generator);
+///
+/// // Create the plaintext, with one bit of padding
+/// let msg = 3u64;
+/// let delta = (1u64 << 63) / (message_modulus * carry_modulus) as u64;
+/// let plaintext = Plaintext(msg * delta);
+///
+/// // Create a new LweCiphertext
+/// let lwe = allocate_and_encrypt_new_lwe_ciphertext(
+///     &lwe_secret_key,
+///     plaintext,
+///     lwe_modular_std_dev,
+///     ciphertext_modulus,
+///     &mut encryption_generator,
+/// );
+///
+/// let decrypted_plaintext = decrypt_lwe_ciphertext(&lwe_secret_key, &lwe);
+///
+/// // Round and remove the encoding
+/// let cleartext = decode_plaintext(
+///     decrypted_plaintext,
+///     message_modulus,
+///     carry_modulus,
+///     ciphertext_modulus,
+/// );
+///
+/// // Check we recovered the original message
+/// assert_eq!(cleartext, msg);
+/// ```
+pub fn decode_plaintext<Scalar: UnsignedInteger>(
+    plaintext: Plaintext<Scalar>,
+    message_modulus: usize,
+    carry_modulus: usize,
+    ciphertext_modulus: CiphertextModulus<Scalar>,
+) -> Scalar {
+    assert!(
+        message_modulus.is_power_of_two() && carry_modulus.is_power_of_two(),
+        "The message modulus ({message_modulus}) and carry modulus ({carry_modulus}) \
+        must be powers of 2"
+    );
+    assert!(
+        ciphertext_modulus.is_compatible_with_native_modulus(),
+        "This operation only supports native and power of 2 moduli, got modulus {:?}.",
+        ciphertext_modulus
+    );
+
+    // One bit of padding, then the carry and message bits
+    let encoded_bits = (message_modulus * carry_modulus).ilog2() as usize + 1;
+    let modulus_bits = if ciphertext_modulus.is_native_modulus() {
+        Scalar::BITS
+    } else {
+        ciphertext_modulus.get().ilog2() as usize
+    };
+
+    assert!(
+        encoded_bits <= modulus_bits,
+        "The encoding requires {encoded_bits} bits, \
+        which does not fit in the ciphertext modulus {:?}.",
+        ciphertext_modulus
+    );
+
+    let decomposer = SignedDecomposer::new(
+        DecompositionBaseLog(encoded_bits),
+        DecompositionLevelCount(1),
+    );
+
+    let rounded = decomposer.closest_representable(plaintext.0);
+
+    rounded >> (Scalar::BITS - encoded_bits)
+}
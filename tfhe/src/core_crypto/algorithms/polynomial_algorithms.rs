@@ -179,6 +179,9 @@ pub fn polynomial_wrapping_add_mul_assign<Scalar, OutputCont, InputCont1, InputC
 /// Divides (mod $(X^{N}+1)$), the output polynomial with a monic monomial of a given degree i.e.
 /// $X^{degree}$.
 ///
+/// This is the rotation applied to the lookup table by the body of the input LWE ciphertext at the
+/// start of a blind rotation, a degree of $2N$ being the identity.
+///
 /// # Note
 ///
 /// Computations wrap around (similar to computing modulo $2^{n\_{bits}}$) when exceeding the
@@ -194,6 +197,45 @@ pub fn polynomial_wrapping_add_mul_assign<Scalar, OutputCont, InputCont1, InputC
 /// polynomial_wrapping_monic_monomial_div_assign(&mut poly, MonomialDegree(2));
 /// assert_eq!(poly.as_ref(), &[3, 255, 254]);
 /// ```
+///
+/// Rotating every polynomial of a GLWE accumulator, as done before a blind rotation:
+///
+/// ```
+/// use tfhe::core_crypto::prelude::*;
+///
+/// let glwe_size = GlweSize(2);
+/// let polynomial_size = PolynomialSize(4);
+/// let ciphertext_modulus = CiphertextModulus::new_native();
+///
+/// let mut accumulator = GlweCiphertext::from_container(
+///     vec![1u64, 2, 3, 4, 5, 6, 7, 8],
+///     polynomial_size,
+///     ciphertext_modulus,
+/// );
+/// assert_eq!(accumulator.glwe_size(), glwe_size);
+///
+/// accumulator
+///     .as_mut_polynomial_list()
+///     .iter_mut()
+///     .for_each(|mut poly| {
+///         polynomial_wrapping_monic_monomial_div_assign(&mut poly, MonomialDegree(1))
+///     });
+///
+/// // Dividing by X shifts the coefficients down, the constant term wraps around negated
+/// assert_eq!(
+///     accumulator.as_ref(),
+///     &[2, 3, 4, 1u64.wrapping_neg(), 6, 7, 8, 5u64.wrapping_neg()]
+/// );
+///
+/// // Multiplying by the same monomial undoes the rotation
+/// accumulator
+///     .as_mut_polynomial_list()
+///     .iter_mut()
+///     .for_each(|mut poly| {
+///         polynomial_wrapping_monic_monomial_mul_assign(&mut poly, MonomialDegree(1))
+///     });
+/// assert_eq!(accumulator.as_ref(), &[1, 2, 3, 4, 5, 6, 7, 8]);
+/// ```
 pub fn polynomial_wrapping_monic_monomial_div_assign<Scalar, OutputCont>(
     output: &mut Polynomial<OutputCont>,
     monomial_degree: MonomialDegree,
@@ -221,6 +263,9 @@ pub fn polynomial_wrapping_monic_monomial_div_assign<Scalar, OutputCont>(
 /// Multiply (mod $(X^{N}+1)$), the output polynomial with a monic monomial of a given degree i.e.
 /// $X^{degree}$.
 ///
+/// This is the inverse of [`polynomial_wrapping_monic_monomial_div_assign`], see its documentation
+/// for an example rotating a GLWE accumulator.
+///
 /// # Note
 ///
 /// Computations wrap around (similar to computing modulo $2^{n\_{bits}}$) when exceeding the
@@ -1,9 +1,11 @@
 //! Module with primitives pertaining to [`SeededLweBootstrapKey`] decompression.
 
 use crate::core_crypto::algorithms::*;
+use crate::core_crypto::commons::generators::mask_bytes_per_ggsw;
 use crate::core_crypto::commons::math::random::RandomGenerator;
 use crate::core_crypto::commons::traits::*;
 use crate::core_crypto::entities::*;
+use rayon::prelude::*;
 
 /// Convenience function to share the core logic of the decompression algorithm for
 /// [`SeededLweBootstrapKey`] between all functions needing it.
@@ -61,3 +63,52 @@ pub fn decompress_seeded_lwe_bootstrap_key<Scalar, InputCont, OutputCont, Gen>(
         &mut generator,
     )
 }
+
+/// Parallel variant of [`decompress_seeded_lwe_bootstrap_key`].
+///
+/// The mask generator is forked once per GGSW ciphertext of the key, in the same way as it is
+/// forked during the generation of the key, which allows decompressing all GGSW ciphertexts
+/// independently.
+pub fn par_decompress_seeded_lwe_bootstrap_key<Scalar, InputCont, OutputCont, Gen>(
+    output_bsk: &mut LweBootstrapKey<OutputCont>,
+    input_bsk: &SeededLweBootstrapKey<InputCont>,
+) where
+    Scalar: UnsignedTorus + Send + Sync,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+    Gen: ParallelByteRandomGenerator,
+{
+    assert_eq!(
+        output_bsk.ciphertext_modulus(),
+        input_bsk.ciphertext_modulus(),
+        "Mismatched CiphertextModulus \
+    between input SeededLweBootstrapKey ({:?}) and output LweBootstrapKey ({:?})",
+        input_bsk.ciphertext_modulus(),
+        output_bsk.ciphertext_modulus(),
+    );
+
+    let mut generator = RandomGenerator::<Gen>::new(input_bsk.compression_seed().seed);
+
+    let gen_iter = generator
+        .par_try_fork(
+            input_bsk.input_lwe_dimension().0,
+            mask_bytes_per_ggsw::<Scalar>(
+                input_bsk.decomposition_level_count(),
+                input_bsk.glwe_size(),
+                input_bsk.polynomial_size(),
+            ),
+        )
+        .unwrap();
+
+    output_bsk
+        .par_iter_mut()
+        .zip(input_bsk.par_iter())
+        .zip(gen_iter)
+        .for_each(|((mut ggsw_out, ggsw_in), mut generator)| {
+            decompress_seeded_ggsw_ciphertext_with_existing_generator::<_, _, _, Gen>(
+                &mut ggsw_out,
+                &ggsw_in,
+                &mut generator,
+            );
+        });
+}
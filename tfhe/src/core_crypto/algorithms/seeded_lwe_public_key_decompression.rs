@@ -1,9 +1,12 @@
 //! Module with primitives pertaining to [`SeededLwePublicKey`] decompression.
 
+use crate::core_crypto::algorithms::slice_algorithms::slice_wrapping_scalar_mul_assign;
 use crate::core_crypto::algorithms::*;
+use crate::core_crypto::commons::generators::mask_bytes_per_lwe;
 use crate::core_crypto::commons::math::random::RandomGenerator;
 use crate::core_crypto::commons::traits::*;
 use crate::core_crypto::entities::*;
+use rayon::prelude::*;
 
 /// Decompress a [`SeededLwePublicKey`], without consuming it, into a standard
 /// [`LwePublicKey`].
@@ -32,3 +35,59 @@ pub fn decompress_seeded_lwe_public_key<Scalar, InputCont, OutputCont, Gen>(
         &mut generator,
     );
 }
+
+/// Parallel variant of [`decompress_seeded_lwe_public_key`].
+///
+/// The mask generator is forked once per zero encryption of the key, in the same way as it is
+/// forked during the generation of the key, which allows decompressing all zero encryptions
+/// independently.
+pub fn par_decompress_seeded_lwe_public_key<Scalar, InputCont, OutputCont, Gen>(
+    output_pk: &mut LwePublicKey<OutputCont>,
+    input_pk: &SeededLwePublicKey<InputCont>,
+) where
+    Scalar: UnsignedTorus + Send + Sync,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+    Gen: ParallelByteRandomGenerator,
+{
+    assert_eq!(
+        output_pk.ciphertext_modulus(),
+        input_pk.ciphertext_modulus(),
+        "Mismatched CiphertextModulus \
+    between input SeededLwePublicKey ({:?}) and output LwePublicKey ({:?})",
+        output_pk.ciphertext_modulus(),
+        input_pk.ciphertext_modulus(),
+    );
+
+    let ciphertext_modulus = output_pk.ciphertext_modulus();
+
+    let mut generator = RandomGenerator::<Gen>::new(input_pk.compression_seed().seed);
+
+    let gen_iter = generator
+        .par_try_fork(
+            input_pk.zero_encryption_count().0,
+            mask_bytes_per_lwe::<Scalar>(input_pk.lwe_size().to_lwe_dimension()),
+        )
+        .unwrap();
+
+    output_pk
+        .par_iter_mut()
+        .zip(input_pk.par_iter())
+        .zip(gen_iter)
+        .for_each(|((mut lwe_out, body_in), mut generator)| {
+            let (mut output_mask, output_body) = lwe_out.get_mut_mask_and_body();
+
+            // generate a uniformly random mask
+            generator.fill_slice_with_random_uniform_custom_mod(
+                output_mask.as_mut(),
+                ciphertext_modulus,
+            );
+            if !ciphertext_modulus.is_native_modulus() {
+                slice_wrapping_scalar_mul_assign(
+                    output_mask.as_mut(),
+                    ciphertext_modulus.get_scaling_to_native_torus(),
+                );
+            }
+            *output_body.data = *body_in.data;
+        });
+}
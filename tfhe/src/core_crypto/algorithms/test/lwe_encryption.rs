@@ -577,6 +577,54 @@ fn lwe_seeded_public_encrypt_decrypt_custom_mod<Scalar: UnsignedTorus>(params: T
 
 create_parametrized_test!(lwe_seeded_public_encrypt_decrypt_custom_mod);
 
+fn lwe_seeded_public_key_par_decompression_custom_mod<Scalar: UnsignedTorus + Sync + Send>(
+    params: TestParams<Scalar>,
+) {
+    let lwe_dimension = params.lwe_dimension;
+    let lwe_modular_std_dev = params.lwe_modular_std_dev;
+    let ciphertext_modulus = params.ciphertext_modulus;
+    let zero_encryption_count = LwePublicKeyZeroEncryptionCount(10);
+
+    let mut rsc = TestResources::new();
+
+    const NB_TESTS: usize = 10;
+
+    for _ in 0..NB_TESTS {
+        let lwe_sk = allocate_and_generate_new_binary_lwe_secret_key(
+            lwe_dimension,
+            &mut rsc.secret_random_generator,
+        );
+
+        let mut seeded_pk = SeededLwePublicKey::new(
+            Scalar::ZERO,
+            lwe_dimension.to_lwe_size(),
+            zero_encryption_count,
+            rsc.seeder.seed().into(),
+            ciphertext_modulus,
+        );
+
+        generate_seeded_lwe_public_key(
+            &lwe_sk,
+            &mut seeded_pk,
+            lwe_modular_std_dev,
+            rsc.seeder.as_mut(),
+        );
+
+        let par_decompressed_pk = seeded_pk.clone().par_decompress_into_lwe_public_key();
+
+        assert!(check_content_respects_mod(
+            &*par_decompressed_pk,
+            ciphertext_modulus
+        ));
+
+        let decompressed_pk = seeded_pk.decompress_into_lwe_public_key();
+
+        assert_eq!(par_decompressed_pk, decompressed_pk);
+    }
+}
+
+create_parametrized_test!(lwe_seeded_public_key_par_decompression_custom_mod);
+
 fn lwe_seeded_list_par_encrypt_decrypt_custom_mod<Scalar: UnsignedTorus + Sync + Send>(
     params: TestParams<Scalar>,
 ) {
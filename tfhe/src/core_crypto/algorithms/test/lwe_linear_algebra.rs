@@ -550,3 +550,75 @@ fn lwe_encrypt_sub_decrypt_custom_mod<Scalar: UnsignedTorus>(params: TestParams<
 }
 
 create_parametrized_test!(lwe_encrypt_sub_decrypt_custom_mod);
+
+fn lwe_encrypt_list_weighted_sum_decrypt_custom_mod<Scalar: UnsignedTorus>(
+    params: TestParams<Scalar>,
+) {
+    let lwe_dimension = params.lwe_dimension;
+    let lwe_modular_std_dev = params.lwe_modular_std_dev;
+    let ciphertext_modulus = params.ciphertext_modulus;
+    let message_modulus_log = params.message_modulus_log;
+    let encoding_with_padding = get_encoding_with_padding(ciphertext_modulus);
+
+    let mut rsc = TestResources::new();
+
+    const NB_TESTS: usize = 10;
+    let msg_modulus = Scalar::ONE.shl(message_modulus_log.0);
+    let mut msg = msg_modulus;
+    let delta: Scalar = encoding_with_padding / msg_modulus;
+    let weights = [
+        Cleartext(Scalar::ONE),
+        Cleartext(Scalar::TWO),
+        Cleartext(Scalar::ONE),
+    ];
+    let weight_sum = weights
+        .iter()
+        .fold(Scalar::ZERO, |acc, weight| acc.wrapping_add(weight.0));
+
+    while msg != Scalar::ZERO {
+        msg = msg.wrapping_sub(Scalar::ONE);
+        for _ in 0..NB_TESTS {
+            let lwe_sk = allocate_and_generate_new_binary_lwe_secret_key(
+                lwe_dimension,
+                &mut rsc.secret_random_generator,
+            );
+
+            let mut list = LweCiphertextList::new(
+                Scalar::ZERO,
+                lwe_dimension.to_lwe_size(),
+                LweCiphertextCount(weights.len()),
+                ciphertext_modulus,
+            );
+
+            let plaintext_list = PlaintextList::new(msg * delta, PlaintextCount(weights.len()));
+
+            encrypt_lwe_ciphertext_list(
+                &lwe_sk,
+                &mut list,
+                &plaintext_list,
+                lwe_modular_std_dev,
+                &mut rsc.encryption_random_generator,
+            );
+
+            assert!(check_content_respects_mod(&list, ciphertext_modulus));
+
+            let mut res = LweCiphertext::new(
+                Scalar::ZERO,
+                lwe_dimension.to_lwe_size(),
+                ciphertext_modulus,
+            );
+
+            lwe_ciphertext_list_weighted_sum(&mut res, &list, &weights);
+
+            assert!(check_content_respects_mod(&res, ciphertext_modulus));
+
+            let decrypted = decrypt_lwe_ciphertext(&lwe_sk, &res);
+
+            let decoded = round_decode(decrypted.0, delta) % msg_modulus;
+
+            assert_eq!((msg * weight_sum) % msg_modulus, decoded);
+        }
+    }
+}
+
+create_parametrized_test!(lwe_encrypt_list_weighted_sum_decrypt_custom_mod);
@@ -0,0 +1,32 @@
+use super::*;
+
+#[test]
+fn lwe_modulus_switch_rounding_edge() {
+    let lwe_size = LweSize(2);
+
+    // With a single bit removed the rounding carry can overflow for values close to the max
+    for modulus_bits in [63, 32, 1] {
+        let output_ciphertext_modulus =
+            CiphertextModulus::try_new_power_of_2(modulus_bits).unwrap();
+        let shift = u64::BITS as usize - modulus_bits;
+
+        for value in [
+            u64::MAX,
+            u64::MAX - 1,
+            1 << (shift - 1),
+            (1 << (shift - 1)) - 1,
+        ] {
+            let input = LweCiphertext::from_container(
+                vec![value; lwe_size.0],
+                CiphertextModulus::new_native(),
+            );
+            let mut output = LweCiphertext::new(0u64, lwe_size, output_ciphertext_modulus);
+
+            lwe_ciphertext_modulus_switch(&input, &mut output);
+
+            // Round to the closest multiple of 2^shift, ties rounding up, on the native torus
+            let expected = ((value as u128 + (1 << (shift - 1))) >> shift << shift) as u64;
+            assert!(output.as_ref().iter().all(|&x| x == expected));
+        }
+    }
+}
@@ -45,7 +45,7 @@ fn lwe_encrypt_multi_bit_pbs_decrypt_custom_mod<
     let mut msg = msg_modulus;
     const NB_TESTS: usize = 10;
 
-    let accumulator = generate_accumulator(
+    let accumulator = generate_programmable_bootstrap_glwe_lut(
         polynomial_size,
         glwe_dimension.to_glwe_size(),
         msg_modulus.cast_into(),
@@ -229,3 +229,38 @@ pub fn test_lwe_encrypt_multi_bit_pbs_decrypt_factor_3_thread_12_custom_mod() {
         },
     );
 }
+
+#[test]
+pub fn test_multi_bit_pbs_scratch_grows_with_thread_count() {
+    let glwe_size = GlweSize(3);
+    let polynomial_size = PolynomialSize(1024);
+    let decomp_level_count = DecompositionLevelCount(1);
+    let fft = Fft::new(polynomial_size);
+    let fft = fft.as_view();
+
+    let scratch = |thread_count| {
+        multi_bit_programmable_bootstrap_scratch::<u64>(
+            glwe_size,
+            polynomial_size,
+            decomp_level_count,
+            ThreadCount(thread_count),
+            fft,
+        )
+        .unwrap()
+        .unaligned_bytes_required()
+    };
+
+    let fourier_ggsw_bytes = polynomial_size.to_fourier_polynomial_size().0
+        * glwe_size.0
+        * glwe_size.0
+        * decomp_level_count.0
+        * std::mem::size_of::<concrete_fft::c64>();
+
+    // Each additional thread needs at least its own multi-bit fourier GGSW buffer
+    let mut previous = scratch(1);
+    for thread_count in 2..=8 {
+        let current = scratch(thread_count);
+        assert!(current >= previous + fourier_ggsw_bytes);
+        previous = current;
+    }
+}
@@ -29,7 +29,7 @@ fn lwe_encrypt_pbs_decrypt_custom_mod<
     let mut msg = msg_modulus;
     const NB_TESTS: usize = 10;
 
-    let accumulator = generate_accumulator(
+    let accumulator = generate_programmable_bootstrap_glwe_lut(
         polynomial_size,
         glwe_dimension.to_glwe_size(),
         msg_modulus.cast_into(),
@@ -196,7 +196,7 @@ fn lwe_encrypt_pbs_f128_decrypt_custom_mod<
     let mut msg = msg_modulus;
     const NB_TESTS: usize = 10;
 
-    let accumulator = generate_accumulator(
+    let accumulator = generate_programmable_bootstrap_glwe_lut(
         polynomial_size,
         glwe_dimension.to_glwe_size(),
         msg_modulus.cast_into(),
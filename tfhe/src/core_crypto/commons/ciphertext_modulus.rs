@@ -4,6 +4,22 @@ use crate::core_crypto::commons::traits::UnsignedInteger;
 use std::marker::PhantomData;
 
 #[derive(Clone, Copy, PartialEq, Eq)]
+/// The modulus used for the coefficients of ciphertexts.
+///
+/// # Supported moduli
+///
+/// All algorithms support the native modulus of the `Scalar` type, i.e. $2^{\text{BITS}}$, and
+/// power of two moduli smaller than the native modulus, see
+/// [`CiphertextModulus::is_compatible_with_native_modulus`]. Non native power of two moduli are
+/// handled by storing the values in the MSBs of the `Scalar`, which is what the algorithms
+/// branching on [`CiphertextModulus::is_native_modulus`] do.
+///
+/// Arbitrary moduli, e.g. primes, can be created with [`CiphertextModulus::try_new`], but they are
+/// only supported by the random generation primitives which sample values modulo the
+/// ciphertext modulus (uniform masks and gaussian noise). Encryption, decryption, linear algebra,
+/// keyswitch, bootstrap and modulus switch require a power of two modulus and must not be used
+/// with arbitrary moduli.
+///
 /// A value of 0 is always interpreted as a native modulus, this is useful to work with u128 using
 /// the native modulus as $2^{128}$ cannot be stored in a u128 value.
 ///
@@ -70,6 +86,45 @@ impl<Scalar: UnsignedInteger> CiphertextModulus<Scalar> {
         }
     }
 
+    /// Create a [`CiphertextModulus`] from an arbitrary `modulus` value, which does not need to be
+    /// a power of two.
+    ///
+    /// A `modulus` equal to $2^{\text{BITS}}$ of the `Scalar` type is the native modulus, use
+    /// [`CiphertextModulus::new_native`] for 128 bits scalars as $2^{128}$ cannot be stored in a
+    /// u128. See the [`type level documentation`](`CiphertextModulus#supported-moduli`) for the
+    /// operations supporting arbitrary moduli.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `modulus` is 0 or 1, or if it is bigger than the native modulus of the
+    /// `Scalar` type.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tfhe::core_crypto::prelude::*;
+    ///
+    /// let prime_modulus = CiphertextModulus::<u64>::try_new((1 << 64) - (1 << 32) + 1).unwrap();
+    /// assert!(!prime_modulus.is_compatible_with_native_modulus());
+    ///
+    /// let native_modulus = CiphertextModulus::<u64>::try_new(1 << 64).unwrap();
+    /// assert!(native_modulus.is_native_modulus());
+    ///
+    /// assert!(CiphertextModulus::<u64>::try_new(0).is_err());
+    /// assert!(CiphertextModulus::<u32>::try_new(1 << 33).is_err());
+    /// ```
+    pub const fn try_new(modulus: u128) -> Result<Self, &'static str> {
+        if modulus == 0 {
+            Err("Modulus cannot be zero, use CiphertextModulus::new_native for the native modulus")
+        } else if modulus == 1 {
+            Err("Modulus must be at least 2")
+        } else if Scalar::BITS < 128 && modulus > (1 << Scalar::BITS) {
+            Err("Modulus is bigger than the maximum value of the associated Scalar type")
+        } else {
+            Ok(Self(modulus, PhantomData).canonicalize())
+        }
+    }
+
     #[cfg(test)]
     pub const fn new_unchecked(modulus: u128) -> Self {
         Self(modulus, PhantomData)
@@ -183,5 +238,36 @@ mod tests {
             let mod_128 = mod_128_res.unwrap();
             assert_eq!(mod_128.get(), 1 << 64);
         }
+
+        {
+            let prime_mod_64 = CiphertextModulus::<u64>::try_new((1 << 64) - (1 << 32) + 1);
+            assert!(prime_mod_64.is_ok());
+
+            let prime_mod_64 = prime_mod_64.unwrap();
+            assert!(!prime_mod_64.is_native_modulus());
+            assert!(!prime_mod_64.is_compatible_with_native_modulus());
+            assert_eq!(prime_mod_64.get(), (1 << 64) - (1 << 32) + 1);
+        }
+
+        {
+            let native_mod_32 = CiphertextModulus::<u32>::try_new(1 << 32).unwrap();
+            assert!(native_mod_32.is_native_modulus());
+            assert_eq!(native_mod_32.get(), 0);
+
+            let mod_128 = CiphertextModulus::<u128>::try_new(u128::MAX).unwrap();
+            assert!(!mod_128.is_native_modulus());
+        }
+
+        {
+            assert!(CiphertextModulus::<u64>::try_new(0).is_err());
+            assert!(CiphertextModulus::<u64>::try_new(1).is_err());
+            match CiphertextModulus::<u32>::try_new((1 << 32) + 1) {
+                Ok(_) => unreachable!(),
+                Err(e) => assert_eq!(
+                    e,
+                    "Modulus is bigger than the maximum value of the associated Scalar type"
+                ),
+            }
+        }
     }
 }
@@ -467,7 +467,7 @@ fn mask_bytes_per_ggsw_level<T: UnsignedInteger>(
     glwe_size.0 * mask_bytes_per_glwe::<T>(glwe_size.to_glwe_dimension(), poly_size)
 }
 
-fn mask_bytes_per_lwe<T: UnsignedInteger>(lwe_dimension: LweDimension) -> usize {
+pub(crate) fn mask_bytes_per_lwe<T: UnsignedInteger>(lwe_dimension: LweDimension) -> usize {
     lwe_dimension.0 * mask_bytes_per_coef::<T>()
 }
 
@@ -475,7 +475,7 @@ fn mask_bytes_per_gsw_level<T: UnsignedInteger>(lwe_size: LweSize) -> usize {
     lwe_size.0 * mask_bytes_per_lwe::<T>(lwe_size.to_lwe_dimension())
 }
 
-fn mask_bytes_per_ggsw<T: UnsignedInteger>(
+pub(crate) fn mask_bytes_per_ggsw<T: UnsignedInteger>(
     level: DecompositionLevelCount,
     glwe_size: GlweSize,
     poly_size: PolynomialSize,
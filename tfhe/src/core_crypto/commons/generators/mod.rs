@@ -3,6 +3,7 @@
 
 mod encryption;
 pub use encryption::EncryptionRandomGenerator;
+pub(crate) use encryption::{mask_bytes_per_ggsw, mask_bytes_per_lwe};
 
 mod secret;
 pub use secret::SecretRandomGenerator;
@@ -2,8 +2,9 @@
 //! generation.
 
 use crate::core_crypto::commons::math::random::{
-    ByteRandomGenerator, RandomGenerable, RandomGenerator, Seed, UniformBinary,
+    ByteRandomGenerator, RandomGenerable, RandomGenerator, Seed, UniformBinary, UniformTernary,
 };
+use crate::core_crypto::commons::numeric::{CastFrom, SignedInteger, UnsignedInteger};
 
 /// A random number generator which can be used to generate secret keys.
 pub struct SecretRandomGenerator<G: ByteRandomGenerator>(RandomGenerator<G>);
@@ -25,4 +26,25 @@ impl<G: ByteRandomGenerator> SecretRandomGenerator<G> {
     {
         self.0.fill_slice_with_random_uniform_binary(slice);
     }
+
+    pub(crate) fn fill_slice_with_random_uniform_ternary<Scalar>(&mut self, slice: &mut [Scalar])
+    where
+        Scalar: RandomGenerable<UniformTernary>,
+    {
+        Scalar::fill_slice(&mut self.0, UniformTernary, slice);
+    }
+
+    /// Fill the slice with integers drawn from a centered gaussian distribution with standard
+    /// deviation `std`, negative values being stored in two's complement.
+    pub(crate) fn fill_slice_with_random_gaussian<Scalar>(&mut self, slice: &mut [Scalar], std: f64)
+    where
+        Scalar: UnsignedInteger,
+    {
+        slice.chunks_mut(2).for_each(|chunk| {
+            let (g1, g2): (f64, f64) = self.0.random_gaussian(0., std);
+            for (elem, g) in chunk.iter_mut().zip([g1, g2]) {
+                *elem = Scalar::Signed::cast_from(g.round()).into_unsigned();
+            }
+        });
+    }
 }
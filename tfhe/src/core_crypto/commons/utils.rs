@@ -25,6 +25,16 @@ pub trait ZipChecked: IntoIterator + Sized {
 
 impl<A: IntoIterator> ZipChecked for A {}
 
+/// Overwrite every element of a slice with zero in a way the compiler cannot elide, even if the
+/// slice is never read again (e.g. right before being freed).
+pub(crate) fn zeroize_slice<T: crate::core_crypto::commons::numeric::Numeric>(slice: &mut [T]) {
+    for elt in slice.iter_mut() {
+        // SAFETY: elt is a valid, aligned and exclusive reference
+        unsafe { core::ptr::write_volatile(elt, T::ZERO) };
+    }
+    core::sync::atomic::compiler_fence(core::sync::atomic::Ordering::SeqCst);
+}
+
 // https://docs.rs/itertools/0.7.8/src/itertools/lib.rs.html#247-269
 #[allow(unused_macros)]
 #[macro_export]
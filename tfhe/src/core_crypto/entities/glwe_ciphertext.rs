@@ -1,5 +1,6 @@
 //! Module containing the definition of the GlweCiphertext.
 
+use crate::core_crypto::algorithms::slice_algorithms::slice_wrapping_scalar_mul_assign;
 use crate::core_crypto::commons::parameters::*;
 use crate::core_crypto::commons::traits::*;
 use crate::core_crypto::entities::*;
@@ -475,6 +476,39 @@ impl<Scalar: UnsignedInteger, C: Container<Element = Scalar>> GlweCiphertext<C>
     pub fn into_container(self) -> C {
         self.data
     }
+
+    /// Return `true` if both [`GlweCiphertext`] have the same [`CiphertextModulus`], the same
+    /// [`GlweSize`], the same [`PolynomialSize`] and the same content, regardless of the container
+    /// types they use.
+    ///
+    /// ```
+    /// use tfhe::core_crypto::prelude::*;
+    ///
+    /// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+    /// // computations
+    /// // Define parameters for GlweCiphertext creation
+    /// let glwe_size = GlweSize(2);
+    /// let polynomial_size = PolynomialSize(1024);
+    /// let ciphertext_modulus = CiphertextModulus::new_native();
+    ///
+    /// let mut glwe = GlweCiphertext::new(0u64, glwe_size, polynomial_size, ciphertext_modulus);
+    /// glwe.get_mut_body().as_mut()[0] = 1;
+    ///
+    /// // Compare the owned ciphertext with a view on its own data
+    /// assert!(glwe.ct_eq(&glwe.as_view()));
+    ///
+    /// let other_glwe = GlweCiphertext::new(0u64, glwe_size, polynomial_size, ciphertext_modulus);
+    /// assert!(!glwe.ct_eq(&other_glwe));
+    /// ```
+    pub fn ct_eq<OtherCont: Container<Element = Scalar>>(
+        &self,
+        other: &GlweCiphertext<OtherCont>,
+    ) -> bool {
+        self.ciphertext_modulus() == other.ciphertext_modulus()
+            && self.glwe_size() == other.glwe_size()
+            && self.polynomial_size() == other.polynomial_size()
+            && self.as_ref() == other.as_ref()
+    }
 }
 
 impl<Scalar: UnsignedInteger, C: ContainerMut<Element = Scalar>> GlweCiphertext<C> {
@@ -571,6 +605,61 @@ impl<Scalar: UnsignedInteger> GlweCiphertextOwned<Scalar> {
             ciphertext_modulus,
         )
     }
+
+    /// Allocate memory and create a new owned [`GlweCiphertext`] trivially encrypting the given
+    /// polynomial, i.e. with a mask full of zeros and the polynomial as body.
+    ///
+    /// As for [`crate::core_crypto::algorithms::trivially_encrypt_glwe_ciphertext`], the
+    /// polynomial is expected to be encoded on the native torus and is scaled accordingly when
+    /// the ciphertext modulus is not the native one. The [`PolynomialSize`] of the ciphertext is
+    /// the one of the input polynomial.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tfhe::core_crypto::prelude::*;
+    ///
+    /// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+    /// // computations
+    /// // Define parameters for GlweCiphertext creation
+    /// let glwe_size = GlweSize(2);
+    /// let polynomial_size = PolynomialSize(1024);
+    /// let ciphertext_modulus = CiphertextModulus::new_native();
+    ///
+    /// let mut body = Polynomial::new(0u64, polynomial_size);
+    /// body.as_mut()
+    ///     .iter_mut()
+    ///     .enumerate()
+    ///     .for_each(|(idx, coeff)| *coeff = (idx as u64 % 4) << 60);
+    ///
+    /// let glwe = GlweCiphertext::new_trivial(&body, glwe_size, ciphertext_modulus);
+    ///
+    /// assert_eq!(glwe.glwe_size(), glwe_size);
+    /// assert_eq!(glwe.polynomial_size(), polynomial_size);
+    /// assert_eq!(glwe.get_body().as_ref(), body.as_ref());
+    /// assert!(glwe.get_mask().as_ref().iter().all(|&elt| elt == 0));
+    /// ```
+    pub fn new_trivial<InputCont: Container<Element = Scalar>>(
+        value_polynomial: &Polynomial<InputCont>,
+        glwe_size: GlweSize,
+        ciphertext_modulus: CiphertextModulus<Scalar>,
+    ) -> GlweCiphertextOwned<Scalar> {
+        let polynomial_size = value_polynomial.polynomial_size();
+        let mut new_ct =
+            GlweCiphertextOwned::new(Scalar::ZERO, glwe_size, polynomial_size, ciphertext_modulus);
+
+        let mut body = new_ct.get_mut_body();
+        body.as_mut().copy_from_slice(value_polynomial.as_ref());
+
+        if !ciphertext_modulus.is_native_modulus() {
+            slice_wrapping_scalar_mul_assign(
+                body.as_mut(),
+                ciphertext_modulus.get_scaling_to_native_torus(),
+            );
+        }
+
+        new_ct
+    }
 }
 
 /// Metadata used in the [`CreateFrom`] implementation to create [`GlweCiphertext`] entities.
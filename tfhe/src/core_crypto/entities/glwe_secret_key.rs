@@ -110,6 +110,33 @@ impl<Scalar, C: Container<Element = Scalar>> GlweSecretKey<C> {
         LweSecretKey::from_container(self.data)
     }
 
+    /// Interpret the [`GlweSecretKey`] as an [`LweSecretKey`] without consuming it.
+    ///
+    /// The returned view borrows the same underlying data.
+    ///
+    /// ```
+    /// use tfhe::core_crypto::prelude::*;
+    ///
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(1024);
+    ///
+    /// let glwe_secret_key = GlweSecretKey::new_empty_key(0u64, glwe_dimension, polynomial_size);
+    ///
+    /// let lwe_secret_key = glwe_secret_key.as_lwe_secret_key();
+    ///
+    /// assert_eq!(
+    ///     lwe_secret_key.lwe_dimension(),
+    ///     LweDimension(glwe_dimension.0 * polynomial_size.0)
+    /// );
+    /// assert_eq!(
+    ///     lwe_secret_key.as_ref().as_ptr(),
+    ///     glwe_secret_key.as_ref().as_ptr()
+    /// );
+    /// ```
+    pub fn as_lwe_secret_key(&self) -> LweSecretKeyView<'_, C::Element> {
+        LweSecretKey::from_container(self.as_ref())
+    }
+
     /// Interpret the [`GlweSecretKey`] as a [`PolynomialList`].
     pub fn as_polynomial_list(&self) -> PolynomialListView<'_, C::Element> {
         PolynomialListView::from_container(self.as_ref(), self.polynomial_size)
@@ -123,6 +150,36 @@ impl<Scalar, C: Container<Element = Scalar>> GlweSecretKey<C> {
     }
 }
 
+impl<Scalar: Numeric, C: ContainerMut<Element = Scalar>> GlweSecretKey<C> {
+    /// Overwrite the coefficients of the [`GlweSecretKey`] with zeros.
+    ///
+    /// The writes are volatile so that they are not optimized away when the key is about to be
+    /// dropped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tfhe::core_crypto::prelude::*;
+    ///
+    /// let mut seeder = new_seeder();
+    /// let seeder = seeder.as_mut();
+    /// let mut secret_generator =
+    ///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+    ///
+    /// let mut glwe_secret_key: GlweSecretKeyOwned<u64> = GlweSecretKey::generate_new_binary(
+    ///     GlweDimension(1),
+    ///     PolynomialSize(1024),
+    ///     &mut secret_generator,
+    /// );
+    ///
+    /// glwe_secret_key.zeroize();
+    /// assert!(glwe_secret_key.as_ref().iter().all(|&elt| elt == 0));
+    /// ```
+    pub fn zeroize(&mut self) {
+        crate::core_crypto::commons::utils::zeroize_slice(self.data.as_mut());
+    }
+}
+
 /// A [`GlweSecretKey`] owning the memory for its own storage.
 pub type GlweSecretKeyOwned<Scalar> = GlweSecretKey<Vec<Scalar>>;
 
@@ -345,6 +345,36 @@ impl<Scalar: UnsignedInteger, C: Container<Element = Scalar>> LweCiphertext<C> {
     pub fn ciphertext_modulus(&self) -> CiphertextModulus<C::Element> {
         self.ciphertext_modulus
     }
+
+    /// Return `true` if both [`LweCiphertext`] have the same [`CiphertextModulus`], the same
+    /// [`LweSize`] and the same content, regardless of the container types they use.
+    ///
+    /// ```
+    /// use tfhe::core_crypto::prelude::*;
+    ///
+    /// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+    /// // computations
+    /// // Define parameters for LweCiphertext creation
+    /// let lwe_size = LweSize(601);
+    /// let ciphertext_modulus = CiphertextModulus::new_native();
+    ///
+    /// let mut lwe = LweCiphertext::new(0u64, lwe_size, ciphertext_modulus);
+    /// *lwe.get_mut_body().data = 1;
+    ///
+    /// // Compare the owned ciphertext with a view on its own data
+    /// assert!(lwe.ct_eq(&lwe.as_view()));
+    ///
+    /// let other_lwe = LweCiphertext::new(0u64, lwe_size, ciphertext_modulus);
+    /// assert!(!lwe.ct_eq(&other_lwe));
+    /// ```
+    pub fn ct_eq<OtherCont: Container<Element = Scalar>>(
+        &self,
+        other: &LweCiphertext<OtherCont>,
+    ) -> bool {
+        self.ciphertext_modulus() == other.ciphertext_modulus()
+            && self.lwe_size() == other.lwe_size()
+            && self.as_ref() == other.as_ref()
+    }
 }
 
 impl<Scalar: UnsignedInteger, C: ContainerMut<Element = Scalar>> LweCiphertext<C> {
@@ -90,8 +90,37 @@ impl<Scalar, C: Container<Element = Scalar>> LweSecretKey<C> {
     }
 }
 
+impl<Scalar: Numeric, C: ContainerMut<Element = Scalar>> LweSecretKey<C> {
+    /// Overwrite the coefficients of the [`LweSecretKey`] with zeros.
+    ///
+    /// The writes are volatile so that they are not optimized away when the key is about to be
+    /// dropped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tfhe::core_crypto::prelude::*;
+    ///
+    /// let mut seeder = new_seeder();
+    /// let seeder = seeder.as_mut();
+    /// let mut secret_generator =
+    ///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+    ///
+    /// let mut lwe_secret_key: LweSecretKeyOwned<u64> =
+    ///     LweSecretKey::generate_new_binary(LweDimension(742), &mut secret_generator);
+    ///
+    /// lwe_secret_key.zeroize();
+    /// assert!(lwe_secret_key.as_ref().iter().all(|&elt| elt == 0));
+    /// ```
+    pub fn zeroize(&mut self) {
+        crate::core_crypto::commons::utils::zeroize_slice(self.data.as_mut());
+    }
+}
+
 /// An [`LweSecretKey`] owning the memory for its own storage.
 pub type LweSecretKeyOwned<Scalar> = LweSecretKey<Vec<Scalar>>;
+/// An [`LweSecretKey`] immutably borrowing memory for its own storage.
+pub type LweSecretKeyView<'data, Scalar> = LweSecretKey<&'data [Scalar]>;
 
 impl<Scalar> LweSecretKeyOwned<Scalar>
 where
@@ -68,9 +68,15 @@ impl<Scalar: UnsignedInteger> SeededLweCiphertext<Scalar> {
     /// assert_eq!(seeded_lwe.lwe_size(), lwe_dimension.to_lwe_size());
     /// assert_eq!(seeded_lwe.ciphertext_modulus(), ciphertext_modulus);
     ///
+    /// // Decompress the ciphertext into an existing LweCiphertext
+    /// let mut lwe = LweCiphertext::new(0u64, seeded_lwe.lwe_size(), ciphertext_modulus);
+    /// seeded_lwe.decompress_into(&mut lwe);
+    ///
     /// // Decompress the list
     /// let lwe_list = seeded_lwe.decompress_into_lwe_ciphertext();
     ///
+    /// assert_eq!(lwe_list, lwe);
+    ///
     /// assert_eq!(lwe_list.lwe_size(), lwe_dimension.to_lwe_size());
     /// assert_eq!(lwe_list.ciphertext_modulus(), ciphertext_modulus);
     /// ```
@@ -133,6 +139,27 @@ impl<Scalar: UnsignedInteger> SeededLweCiphertext<Scalar> {
         decompressed_ct
     }
 
+    /// Decompress the [`SeededLweCiphertext`], without consuming it, into an existing
+    /// [`LweCiphertext`], regenerating the mask from the stored seed.
+    ///
+    /// See [`SeededLweCiphertext::from_scalar`] for usage.
+    pub fn decompress_into<OutputCont>(&self, output: &mut LweCiphertext<OutputCont>)
+    where
+        Scalar: UnsignedTorus,
+        OutputCont: ContainerMut<Element = Scalar>,
+    {
+        assert_eq!(
+            output.lwe_size(),
+            self.lwe_size(),
+            "Mismatched LweSize between input SeededLweCiphertext ({:?}) \
+            and output LweCiphertext ({:?})",
+            self.lwe_size(),
+            output.lwe_size(),
+        );
+
+        decompress_seeded_lwe_ciphertext::<_, _, ActivatedRandomGenerator>(output, self);
+    }
+
     pub fn ciphertext_modulus(&self) -> CiphertextModulus<Scalar> {
         self.ciphertext_modulus
     }
@@ -184,6 +184,24 @@ impl<Scalar: UnsignedInteger, C: Container<Element = Scalar>> SeededLwePublicKey
         decompressed_list
     }
 
+    /// Parallel variant of [`SeededLwePublicKey::decompress_into_lwe_public_key`].
+    pub fn par_decompress_into_lwe_public_key(self) -> LwePublicKeyOwned<Scalar>
+    where
+        Scalar: UnsignedTorus + Send + Sync,
+    {
+        let mut decompressed_list = LwePublicKey::new(
+            Scalar::ZERO,
+            self.lwe_size(),
+            self.zero_encryption_count(),
+            self.ciphertext_modulus(),
+        );
+        par_decompress_seeded_lwe_public_key::<_, _, _, ActivatedRandomGenerator>(
+            &mut decompressed_list,
+            &self,
+        );
+        decompressed_list
+    }
+
     /// Return a view of the [`SeededLwePublicKey`]. This is useful if an algorithm takes a view by
     /// value.
     pub fn as_view(&self) -> SeededLwePublicKey<&'_ [Scalar]> {
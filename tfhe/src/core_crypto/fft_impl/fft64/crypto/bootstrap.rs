@@ -2,7 +2,10 @@ use super::super::math::fft::{Fft, FftView, FourierPolynomialList};
 use super::ggsw::{cmux, *};
 use crate::core_crypto::algorithms::extract_lwe_sample_from_glwe_ciphertext;
 use crate::core_crypto::algorithms::polynomial_algorithms::*;
+use crate::core_crypto::commons::dispersion::StandardDev;
+use crate::core_crypto::commons::generators::EncryptionRandomGenerator;
 use crate::core_crypto::commons::math::decomposition::SignedDecomposer;
+use crate::core_crypto::commons::math::random::ByteRandomGenerator;
 use crate::core_crypto::commons::math::torus::UnsignedTorus;
 use crate::core_crypto::commons::numeric::CastInto;
 use crate::core_crypto::commons::parameters::{
@@ -122,6 +125,31 @@ impl<C: Container<Element = c64>> FourierLweBootstrapKey<C> {
         }
     }
 
+    /// Serialize the key with `serializer`, storing the Fourier coefficients in the columnar
+    /// layout of [`FourierPolynomialList::serialize_split`].
+    ///
+    /// The key is serialized as a sequence holding its parameters followed by the Fourier
+    /// coefficients. It can be read back with [`FourierLweBootstrapKey::deserialize_split`].
+    pub fn serialize_split<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeSeq;
+
+        struct SplitFourier<'a, C: Container<Element = c64>>(&'a FourierPolynomialList<C>);
+
+        impl<C: Container<Element = c64>> serde::Serialize for SplitFourier<'_, C> {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                self.0.serialize_split(serializer)
+            }
+        }
+
+        let mut state = serializer.serialize_seq(Some(5))?;
+        state.serialize_element(&self.input_lwe_dimension)?;
+        state.serialize_element(&self.glwe_size)?;
+        state.serialize_element(&self.decomposition_base_log)?;
+        state.serialize_element(&self.decomposition_level_count)?;
+        state.serialize_element(&SplitFourier(&self.fourier))?;
+        state.end()
+    }
+
     pub fn as_mut_view(&mut self) -> FourierLweBootstrapKeyMutView<'_>
     where
         C: AsMut<[c64]>,
@@ -170,6 +198,93 @@ impl FourierLweBootstrapKey<ABox<[c64]>> {
     }
 }
 
+impl<C: IntoContainerOwned<Element = c64>> FourierLweBootstrapKey<C> {
+    /// Deserialize a key with `deserializer`, serialized with
+    /// [`FourierLweBootstrapKey::serialize_split`].
+    pub fn deserialize_split<'de, D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        use std::marker::PhantomData;
+
+        struct SplitFourier<C: IntoContainerOwned<Element = c64>>(PhantomData<fn() -> C>);
+
+        impl<'de, C: IntoContainerOwned<Element = c64>> serde::de::DeserializeSeed<'de>
+            for SplitFourier<C>
+        {
+            type Value = FourierPolynomialList<C>;
+
+            fn deserialize<D: serde::Deserializer<'de>>(
+                self,
+                deserializer: D,
+            ) -> Result<Self::Value, D::Error> {
+                FourierPolynomialList::deserialize_split(deserializer)
+            }
+        }
+
+        struct SplitVisitor<C: IntoContainerOwned<Element = c64>>(PhantomData<fn() -> C>);
+
+        impl<'de, C: IntoContainerOwned<Element = c64>> serde::de::Visitor<'de> for SplitVisitor<C> {
+            type Value = FourierLweBootstrapKey<C>;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                formatter.write_str(
+                    "a sequence of the parameters of a bootstrap key followed by its Fourier \
+                    coefficients",
+                )
+            }
+
+            fn visit_seq<A: serde::de::SeqAccess<'de>>(
+                self,
+                mut seq: A,
+            ) -> Result<Self::Value, A::Error> {
+                use serde::de::Error;
+
+                let str = "sequence of four parameters and Fourier coefficients";
+                let input_lwe_dimension: LweDimension = seq
+                    .next_element()?
+                    .ok_or_else(|| A::Error::invalid_length(0, &str))?;
+                let glwe_size: GlweSize = seq
+                    .next_element()?
+                    .ok_or_else(|| A::Error::invalid_length(1, &str))?;
+                let decomposition_base_log: DecompositionBaseLog = seq
+                    .next_element()?
+                    .ok_or_else(|| A::Error::invalid_length(2, &str))?;
+                let decomposition_level_count: DecompositionLevelCount = seq
+                    .next_element()?
+                    .ok_or_else(|| A::Error::invalid_length(3, &str))?;
+                let fourier = seq
+                    .next_element_seed(SplitFourier::<C>(PhantomData))?
+                    .ok_or_else(|| A::Error::invalid_length(4, &str))?;
+
+                let expected_len = [
+                    input_lwe_dimension.0,
+                    fourier.polynomial_size.to_fourier_polynomial_size().0,
+                    decomposition_level_count.0,
+                    glwe_size.0,
+                    glwe_size.0,
+                ]
+                .into_iter()
+                .try_fold(1usize, |acc, x| acc.checked_mul(x));
+                if expected_len != Some(fourier.data.container_len()) {
+                    return Err(A::Error::custom(
+                        "the size of the Fourier data does not match the parameters of the key",
+                    ));
+                }
+
+                Ok(FourierLweBootstrapKey {
+                    fourier,
+                    input_lwe_dimension,
+                    glwe_size,
+                    decomposition_base_log,
+                    decomposition_level_count,
+                })
+            }
+        }
+
+        deserializer.deserialize_seq(SplitVisitor::<C>(PhantomData))
+    }
+}
+
 /// Return the required memory for [`FourierLweBootstrapKeyMutView::fill_with_forward_fourier`].
 pub fn fill_with_forward_fourier_scratch(fft: FftView<'_>) -> Result<StackReq, SizeOverflow> {
     fft.forward_scratch()
@@ -217,17 +332,71 @@ impl<'a> FourierLweBootstrapKeyView<'a> {
     // CastInto required for PBS modulus switch which returns a usize
     pub fn blind_rotate_assign<Scalar: UnsignedTorus + CastInto<usize>>(
         self,
-        mut lut: GlweCiphertextMutView<'_, Scalar>,
+        lut: GlweCiphertextMutView<'_, Scalar>,
         lwe: &[Scalar],
         fft: FftView<'_>,
-        mut stack: PodStack<'_>,
+        stack: PodStack<'_>,
     ) {
         let (lwe_body, lwe_mask) = lwe.split_last().unwrap();
 
+        self.blind_rotate_assign_with_body(lut, *lwe_body, lwe_mask, fft, stack);
+    }
+
+    /// Same as [`Self::blind_rotate_assign`], but a gaussian noise with standard deviation
+    /// `noise_std` is added to the body of `lwe` before it is switched to the `2 * N` modulus.
+    ///
+    /// The modulus switch rounds the body and the mask, this deliberate noise addition hides the
+    /// rounding error of the body behind a fresh noise. `noise_std` is expressed as a fraction of
+    /// the torus, like a [`StandardDev`], whatever the ciphertext modulus of `lut`. The input
+    /// ciphertext is not modified.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the ciphertext modulus of `lut` is neither the native modulus nor a power of two.
+    // CastInto required for PBS modulus switch which returns a usize
+    pub fn blind_rotate_assign_with_ms_noise<Scalar, Gen>(
+        self,
+        lut: GlweCiphertextMutView<'_, Scalar>,
+        lwe: &[Scalar],
+        noise_std: f64,
+        generator: &mut EncryptionRandomGenerator<Gen>,
+        fft: FftView<'_>,
+        stack: PodStack<'_>,
+    ) where
+        Scalar: UnsignedTorus + CastInto<usize>,
+        Gen: ByteRandomGenerator,
+    {
+        let (lwe_body, lwe_mask) = lwe.split_last().unwrap();
+
+        let ciphertext_modulus = lut.ciphertext_modulus();
+        assert!(
+            ciphertext_modulus.is_compatible_with_native_modulus(),
+            "The ciphertext modulus must be the native modulus or a power of two, got {:?}",
+            ciphertext_modulus
+        );
+
+        // Ciphertexts with a power of two modulus are stored scaled to the native torus, the noise
+        // is sampled on the ciphertext modulus and scaled the same way
+        let noise: Scalar = generator
+            .random_noise_custom_mod(StandardDev(noise_std), ciphertext_modulus)
+            .wrapping_mul(ciphertext_modulus.get_scaling_to_native_torus());
+        let noisy_body = lwe_body.wrapping_add(noise);
+
+        self.blind_rotate_assign_with_body(lut, noisy_body, lwe_mask, fft, stack);
+    }
+
+    fn blind_rotate_assign_with_body<Scalar: UnsignedTorus + CastInto<usize>>(
+        self,
+        mut lut: GlweCiphertextMutView<'_, Scalar>,
+        lwe_body: Scalar,
+        lwe_mask: &[Scalar],
+        fft: FftView<'_>,
+        mut stack: PodStack<'_>,
+    ) {
         let lut_poly_size = lut.polynomial_size();
         let ciphertext_modulus = lut.ciphertext_modulus();
         let monomial_degree = pbs_modulus_switch(
-            *lwe_body,
+            lwe_body,
             lut_poly_size,
             ModulusSwitchOffset(0),
             LutCountLog(0),
@@ -324,6 +493,56 @@ impl<'a> FourierLweBootstrapKeyView<'a> {
             MonomialDegree(0),
         );
     }
+
+    /// Same as [`Self::bootstrap`], but the accumulator is copied to the caller provided
+    /// `scratch_glwe` buffer instead of a buffer collected from the stack.
+    ///
+    /// The same scratch buffer can be reused for many bootstraps with the same accumulator shape,
+    /// and the stack only needs to hold the memory required by [`blind_rotate_scratch`]. After the
+    /// call, `scratch_glwe` contains the blind rotated accumulator.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `scratch_glwe` does not have the same glwe size, polynomial size and ciphertext
+    /// modulus as `accumulator`.
+    pub fn bootstrap_with_scratch<Scalar>(
+        self,
+        mut lwe_out: LweCiphertextMutView<'_, Scalar>,
+        lwe_in: LweCiphertextView<'_, Scalar>,
+        accumulator: GlweCiphertextView<'_, Scalar>,
+        mut scratch_glwe: GlweCiphertextMutView<'_, Scalar>,
+        fft: FftView<'_>,
+        stack: PodStack<'_>,
+    ) where
+        // CastInto required for PBS modulus switch which returns a usize
+        Scalar: UnsignedTorus + CastInto<usize>,
+    {
+        debug_assert_eq!(lwe_out.ciphertext_modulus(), lwe_in.ciphertext_modulus());
+        debug_assert_eq!(
+            lwe_in.ciphertext_modulus(),
+            accumulator.ciphertext_modulus()
+        );
+        assert_eq!(
+            scratch_glwe.glwe_size(),
+            accumulator.glwe_size(),
+            "Mismatched GlweSize between the scratch buffer and the accumulator"
+        );
+        assert_eq!(
+            scratch_glwe.polynomial_size(),
+            accumulator.polynomial_size(),
+            "Mismatched PolynomialSize between the scratch buffer and the accumulator"
+        );
+        assert_eq!(
+            scratch_glwe.ciphertext_modulus(),
+            accumulator.ciphertext_modulus(),
+            "Mismatched CiphertextModulus between the scratch buffer and the accumulator"
+        );
+
+        scratch_glwe.as_mut().copy_from_slice(accumulator.as_ref());
+        self.blind_rotate_assign(scratch_glwe.as_mut_view(), lwe_in.as_ref(), fft, stack);
+
+        extract_lwe_sample_from_glwe_ciphertext(&scratch_glwe, &mut lwe_out, MonomialDegree(0));
+    }
 }
 
 impl<Scalar> FourierBootstrapKey<Scalar> for FourierLweBootstrapKeyOwned
@@ -1,6 +1,10 @@
 use crate::core_crypto::fft_impl::common::tests::test_bootstrap_generic;
-use crate::core_crypto::fft_impl::fft64::crypto::bootstrap::FourierLweBootstrapKeyOwned;
+use crate::core_crypto::fft_impl::fft64::crypto::bootstrap::{
+    blind_rotate_scratch, bootstrap_scratch, fill_with_forward_fourier_scratch,
+    FourierLweBootstrapKey, FourierLweBootstrapKeyOwned,
+};
 use crate::core_crypto::prelude::*;
+use dyn_stack::{GlobalPodBuffer, PodStack, ReborrowMut, StackReq};
 
 #[test]
 fn test_bootstrap_u64() {
@@ -17,3 +21,376 @@ fn test_bootstrap_u32() {
         StandardDev(0.00000000000000029403601535432533),
     );
 }
+
+#[test]
+fn test_bootstrap_with_scratch_matches_bootstrap() {
+    // Define settings for an insecure toy example
+    let polynomial_size = PolynomialSize(1024);
+    let glwe_dimension = GlweDimension(1);
+    let lwe_dimension = LweDimension(585);
+
+    let level_bsk = DecompositionLevelCount(2);
+    let base_log_bsk = DecompositionBaseLog(10);
+
+    let std = LogStandardDev::from_log_standard_dev(-60.);
+
+    let ciphertext_modulus = CiphertextModulus::new_native();
+
+    let mut seeder = new_seeder();
+    let seeder = seeder.as_mut();
+
+    let mut secret_generator =
+        SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+    let mut encryption_generator =
+        EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed(), seeder);
+
+    let glwe_sk: GlweSecretKeyOwned<u64> = allocate_and_generate_new_binary_glwe_secret_key(
+        glwe_dimension,
+        polynomial_size,
+        &mut secret_generator,
+    );
+    let lwe_sk: LweSecretKeyOwned<u64> =
+        allocate_and_generate_new_binary_lwe_secret_key(lwe_dimension, &mut secret_generator);
+
+    let std_bsk: LweBootstrapKeyOwned<u64> = allocate_and_generate_new_lwe_bootstrap_key(
+        &lwe_sk,
+        &glwe_sk,
+        base_log_bsk,
+        level_bsk,
+        std,
+        ciphertext_modulus,
+        &mut encryption_generator,
+    );
+
+    let mut fourier_bsk = FourierLweBootstrapKey::new(
+        lwe_dimension,
+        glwe_dimension.to_glwe_size(),
+        polynomial_size,
+        base_log_bsk,
+        level_bsk,
+    );
+
+    let fft = Fft::new(polynomial_size);
+    let fft = fft.as_view();
+
+    let req = StackReq::try_any_of([
+        fill_with_forward_fourier_scratch(fft).unwrap(),
+        bootstrap_scratch::<u64>(glwe_dimension.to_glwe_size(), polynomial_size, fft).unwrap(),
+        blind_rotate_scratch::<u64>(glwe_dimension.to_glwe_size(), polynomial_size, fft).unwrap(),
+    ])
+    .unwrap();
+    let mut mem = GlobalPodBuffer::new(req);
+    let mut stack = PodStack::new(&mut mem);
+
+    fourier_bsk
+        .as_mut_view()
+        .fill_with_forward_fourier(std_bsk.as_view(), fft, stack.rb_mut());
+
+    // Accumulator encoding the identity on a 3 bits message space with a padding bit
+    let message_modulus = 1usize << 3;
+    let delta = (1u64 << 63) / message_modulus as u64;
+    let box_size = polynomial_size.0 / message_modulus;
+    let mut accumulator_body = vec![0u64; polynomial_size.0];
+    for (i, chunk) in accumulator_body.chunks_mut(box_size).enumerate() {
+        chunk.fill(i as u64 * delta);
+    }
+    let accumulator = allocate_and_trivially_encrypt_new_glwe_ciphertext(
+        glwe_dimension.to_glwe_size(),
+        &PlaintextList::from_container(accumulator_body),
+        ciphertext_modulus,
+    );
+
+    let mut scratch_glwe = GlweCiphertext::new(
+        0u64,
+        glwe_dimension.to_glwe_size(),
+        polynomial_size,
+        ciphertext_modulus,
+    );
+
+    let output_lwe_size = LweDimension(glwe_dimension.0 * polynomial_size.0).to_lwe_size();
+
+    for msg in 0..message_modulus as u64 {
+        let lwe_in = allocate_and_encrypt_new_lwe_ciphertext(
+            &lwe_sk,
+            Plaintext(msg * delta),
+            std,
+            ciphertext_modulus,
+            &mut encryption_generator,
+        );
+
+        let mut expected = LweCiphertext::new(0u64, output_lwe_size, ciphertext_modulus);
+        let mut lwe_out = LweCiphertext::new(0u64, output_lwe_size, ciphertext_modulus);
+
+        fourier_bsk.as_view().bootstrap(
+            expected.as_mut_view(),
+            lwe_in.as_view(),
+            accumulator.as_view(),
+            fft,
+            stack.rb_mut(),
+        );
+
+        // The scratch buffer is reused across iterations
+        fourier_bsk.as_view().bootstrap_with_scratch(
+            lwe_out.as_mut_view(),
+            lwe_in.as_view(),
+            accumulator.as_view(),
+            scratch_glwe.as_mut_view(),
+            fft,
+            stack.rb_mut(),
+        );
+
+        assert_eq!(expected, lwe_out);
+    }
+}
+
+#[test]
+fn test_bootstrap_key_split_serialization_roundtrip() {
+    // Define settings for an insecure toy example
+    let polynomial_size = PolynomialSize(512);
+    let glwe_dimension = GlweDimension(1);
+    let lwe_dimension = LweDimension(10);
+
+    let level_bsk = DecompositionLevelCount(2);
+    let base_log_bsk = DecompositionBaseLog(10);
+
+    let std = LogStandardDev::from_log_standard_dev(-60.);
+
+    let ciphertext_modulus = CiphertextModulus::new_native();
+
+    let mut seeder = new_seeder();
+    let seeder = seeder.as_mut();
+
+    let mut secret_generator =
+        SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+    let mut encryption_generator =
+        EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed(), seeder);
+
+    let glwe_sk: GlweSecretKeyOwned<u64> = allocate_and_generate_new_binary_glwe_secret_key(
+        glwe_dimension,
+        polynomial_size,
+        &mut secret_generator,
+    );
+    let lwe_sk: LweSecretKeyOwned<u64> =
+        allocate_and_generate_new_binary_lwe_secret_key(lwe_dimension, &mut secret_generator);
+
+    let std_bsk: LweBootstrapKeyOwned<u64> = allocate_and_generate_new_lwe_bootstrap_key(
+        &lwe_sk,
+        &glwe_sk,
+        base_log_bsk,
+        level_bsk,
+        std,
+        ciphertext_modulus,
+        &mut encryption_generator,
+    );
+
+    let mut fourier_bsk = FourierLweBootstrapKey::new(
+        lwe_dimension,
+        glwe_dimension.to_glwe_size(),
+        polynomial_size,
+        base_log_bsk,
+        level_bsk,
+    );
+
+    let fft = Fft::new(polynomial_size);
+    let fft = fft.as_view();
+
+    let mut mem = GlobalPodBuffer::new(fill_with_forward_fourier_scratch(fft).unwrap());
+    let mut stack = PodStack::new(&mut mem);
+
+    fourier_bsk
+        .as_mut_view()
+        .fill_with_forward_fourier(std_bsk.as_view(), fft, stack.rb_mut());
+
+    let mut buffer = vec![];
+    fourier_bsk
+        .serialize_split(&mut bincode::Serializer::new(
+            &mut buffer,
+            bincode::DefaultOptions::new(),
+        ))
+        .unwrap();
+
+    let deserialize_split = |bytes: &[u8]| {
+        FourierLweBootstrapKeyOwned::deserialize_split(&mut bincode::Deserializer::from_slice(
+            bytes,
+            bincode::DefaultOptions::new(),
+        ))
+    };
+
+    let deserialized = deserialize_split(&buffer).unwrap();
+    assert_eq!(fourier_bsk, deserialized);
+
+    // The split layout holds the same values as the serde one, in a different order
+    let serde_bytes = bincode::serialize(&fourier_bsk).unwrap();
+    let serde_deserialized: FourierLweBootstrapKeyOwned =
+        bincode::deserialize(&serde_bytes).unwrap();
+    assert_eq!(serde_deserialized, deserialized);
+
+    // A truncated buffer is an error, not a panic
+    assert!(deserialize_split(&buffer[..buffer.len() - 1]).is_err());
+}
+
+/// Blind rotates an encryption of each message of a 3 bits message space with
+/// `blind_rotate_assign_with_ms_noise` and returns the number of messages that are not decrypted
+/// correctly.
+fn blind_rotate_with_ms_noise_decryption_failures(
+    ms_noise_std: f64,
+    ciphertext_modulus: CiphertextModulus<u64>,
+) -> usize {
+    // Define settings for an insecure toy example
+    let polynomial_size = PolynomialSize(1024);
+    let glwe_dimension = GlweDimension(1);
+    let lwe_dimension = LweDimension(585);
+
+    let level_bsk = DecompositionLevelCount(2);
+    let base_log_bsk = DecompositionBaseLog(10);
+
+    let std = LogStandardDev::from_log_standard_dev(-60.);
+
+    let mut seeder = new_seeder();
+    let seeder = seeder.as_mut();
+
+    let mut secret_generator =
+        SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+    let mut encryption_generator =
+        EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed(), seeder);
+
+    let glwe_sk: GlweSecretKeyOwned<u64> = allocate_and_generate_new_binary_glwe_secret_key(
+        glwe_dimension,
+        polynomial_size,
+        &mut secret_generator,
+    );
+    let lwe_sk: LweSecretKeyOwned<u64> =
+        allocate_and_generate_new_binary_lwe_secret_key(lwe_dimension, &mut secret_generator);
+
+    let std_bsk: LweBootstrapKeyOwned<u64> = allocate_and_generate_new_lwe_bootstrap_key(
+        &lwe_sk,
+        &glwe_sk,
+        base_log_bsk,
+        level_bsk,
+        std,
+        ciphertext_modulus,
+        &mut encryption_generator,
+    );
+
+    let mut fourier_bsk = FourierLweBootstrapKey::new(
+        lwe_dimension,
+        glwe_dimension.to_glwe_size(),
+        polynomial_size,
+        base_log_bsk,
+        level_bsk,
+    );
+
+    let fft = Fft::new(polynomial_size);
+    let fft = fft.as_view();
+
+    let req = StackReq::try_any_of([
+        fill_with_forward_fourier_scratch(fft).unwrap(),
+        blind_rotate_scratch::<u64>(glwe_dimension.to_glwe_size(), polynomial_size, fft).unwrap(),
+    ])
+    .unwrap();
+    let mut mem = GlobalPodBuffer::new(req);
+    let mut stack = PodStack::new(&mut mem);
+
+    fourier_bsk
+        .as_mut_view()
+        .fill_with_forward_fourier(std_bsk.as_view(), fft, stack.rb_mut());
+
+    // Accumulator encoding the identity on a 3 bits message space with a padding bit
+    let message_modulus = 1usize << 3;
+    let modulus_log2 = if ciphertext_modulus.is_native_modulus() {
+        u64::BITS
+    } else {
+        ciphertext_modulus.get().ilog2()
+    };
+    let delta = (1u64 << (modulus_log2 - 1)) / message_modulus as u64;
+    let box_size = polynomial_size.0 / message_modulus;
+    let mut accumulator_body = vec![0u64; polynomial_size.0];
+    for (i, chunk) in accumulator_body.chunks_mut(box_size).enumerate() {
+        chunk.fill(i as u64 * delta);
+    }
+    // Half box rotation so that the noise around a message stays in its box
+    accumulator_body.rotate_left(box_size / 2);
+    let accumulator = allocate_and_trivially_encrypt_new_glwe_ciphertext(
+        glwe_dimension.to_glwe_size(),
+        &PlaintextList::from_container(accumulator_body),
+        ciphertext_modulus,
+    );
+
+    let output_lwe_size = LweDimension(glwe_dimension.0 * polynomial_size.0).to_lwe_size();
+    let output_lwe_sk = glwe_sk.as_lwe_secret_key();
+
+    let mut failures = 0;
+    for msg in 0..message_modulus as u64 {
+        let lwe_in = allocate_and_encrypt_new_lwe_ciphertext(
+            &lwe_sk,
+            Plaintext(msg * delta),
+            std,
+            ciphertext_modulus,
+            &mut encryption_generator,
+        );
+
+        let mut rotated_accumulator = accumulator.clone();
+        fourier_bsk.as_view().blind_rotate_assign_with_ms_noise(
+            rotated_accumulator.as_mut_view(),
+            lwe_in.as_ref(),
+            ms_noise_std,
+            &mut encryption_generator,
+            fft,
+            stack.rb_mut(),
+        );
+
+        let mut lwe_out = LweCiphertext::new(0u64, output_lwe_size, ciphertext_modulus);
+        extract_lwe_sample_from_glwe_ciphertext(
+            &rotated_accumulator,
+            &mut lwe_out,
+            MonomialDegree(0),
+        );
+
+        let decrypted = decrypt_lwe_ciphertext(&output_lwe_sk, &lwe_out);
+        let decoded = (decrypted.0.wrapping_add(delta / 2) / delta) % message_modulus as u64;
+        if decoded != msg {
+            failures += 1;
+        }
+    }
+
+    failures
+}
+
+#[test]
+fn test_blind_rotate_with_ms_noise() {
+    // Small enough compared to the message encoding for the result to stay correct
+    let ms_noise_std = 2.0f64.powi(-20);
+
+    let failures = blind_rotate_with_ms_noise_decryption_failures(
+        ms_noise_std,
+        CiphertextModulus::new_native(),
+    );
+    assert_eq!(failures, 0);
+}
+
+#[test]
+fn test_blind_rotate_with_ms_noise_power_of_two_modulus() {
+    // The noise is a fraction of the torus whatever the ciphertext modulus, so the same noise
+    // keeps the result correct
+    let ms_noise_std = 2.0f64.powi(-20);
+
+    let failures = blind_rotate_with_ms_noise_decryption_failures(
+        ms_noise_std,
+        CiphertextModulus::try_new_power_of_2(62).unwrap(),
+    );
+    assert_eq!(failures, 0);
+}
+
+#[test]
+fn test_blind_rotate_with_large_ms_noise() {
+    // A noise this large spreads the body over the whole torus, each message is then decrypted
+    // correctly with a probability of about 1/16, so all of them being correct means the noise
+    // was not added
+    let ms_noise_std = 2.0f64.powi(-2);
+
+    let failures = blind_rotate_with_ms_noise_decryption_failures(
+        ms_noise_std,
+        CiphertextModulus::new_native(),
+    );
+    assert!(failures > 0);
+}
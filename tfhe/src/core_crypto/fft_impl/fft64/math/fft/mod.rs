@@ -1,4 +1,4 @@
-use super::polynomial::{FourierPolynomialMutView, FourierPolynomialView};
+use super::polynomial::{FourierPolynomial, FourierPolynomialMutView, FourierPolynomialView};
 use crate::core_crypto::commons::math::torus::UnsignedTorus;
 use crate::core_crypto::commons::numeric::CastInto;
 use crate::core_crypto::commons::parameters::{PolynomialCount, PolynomialSize};
@@ -193,6 +193,141 @@ impl Fft {
             plan: get_plan().unwrap(),
         }
     }
+
+    /// Return the polynomial size that this FFT was made for.
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.as_view().polynomial_size()
+    }
+
+    /// Return the memory required for [`Self::forward`].
+    pub fn forward_scratch(&self) -> Result<StackReq, SizeOverflow> {
+        self.as_view().forward_scratch()
+    }
+
+    /// Return the memory required for [`Self::backward`].
+    pub fn backward_scratch(&self) -> Result<StackReq, SizeOverflow> {
+        self.as_view().backward_scratch()
+    }
+
+    /// Perform a negacyclic real FFT of `polynomial`, viewed as torus elements, and store the
+    /// result in `fourier_polynomial`.
+    ///
+    /// The `stack` must have a capacity at least as large as the result of
+    /// [`Self::forward_scratch`]. See [`FftView::forward_as_integer`] to transform polynomials
+    /// with small integer coefficients, like secret keys.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `polynomial` does not have the polynomial size of this FFT, or if
+    /// `fourier_polynomial` does not have half that size.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tfhe::core_crypto::prelude::*;
+    ///
+    /// let polynomial_size = PolynomialSize(1024);
+    /// let fft = Fft::new(polynomial_size);
+    ///
+    /// let mut polynomial = Polynomial::new(0u64, polynomial_size);
+    /// polynomial.as_mut()[1] = 1 << 60;
+    ///
+    /// let mut fourier_polynomial = FourierPolynomial::new(polynomial_size);
+    /// let mut roundtrip = Polynomial::new(0u64, polynomial_size);
+    ///
+    /// let mut buffers = ComputationBuffers::new();
+    /// buffers.resize(
+    ///     fft.forward_scratch()
+    ///         .unwrap()
+    ///         .try_or(fft.backward_scratch().unwrap())
+    ///         .unwrap()
+    ///         .unaligned_bytes_required(),
+    /// );
+    ///
+    /// fft.forward(&polynomial, &mut fourier_polynomial, buffers.stack());
+    /// fft.backward(&fourier_polynomial, &mut roundtrip, buffers.stack());
+    ///
+    /// // The roundtrip is exact up to the precision of the floating point representation
+    /// for (expected, actual) in polynomial.as_ref().iter().zip(roundtrip.as_ref().iter()) {
+    ///     let diff = expected.wrapping_sub(*actual) as i64;
+    ///     assert!(diff.unsigned_abs() < 1 << 20);
+    /// }
+    /// ```
+    pub fn forward<Scalar, InputCont, OutputCont>(
+        &self,
+        polynomial: &Polynomial<InputCont>,
+        fourier_polynomial: &mut FourierPolynomial<OutputCont>,
+        stack: PodStack<'_>,
+    ) where
+        Scalar: UnsignedTorus,
+        InputCont: Container<Element = Scalar>,
+        OutputCont: ContainerMut<Element = c64>,
+    {
+        let fft = self.as_view();
+        assert_eq!(
+            polynomial.polynomial_size(),
+            fft.polynomial_size(),
+            "Mismatched PolynomialSize between the input polynomial ({:?}) and the FFT ({:?})",
+            polynomial.polynomial_size(),
+            fft.polynomial_size(),
+        );
+        assert_eq!(
+            fourier_polynomial.data.container_len(),
+            fft.polynomial_size().to_fourier_polynomial_size().0,
+            "The output FourierPolynomial must have {} coefficients, got {}",
+            fft.polynomial_size().to_fourier_polynomial_size().0,
+            fourier_polynomial.data.container_len(),
+        );
+
+        fft.forward_as_torus(
+            fourier_polynomial.as_mut_view(),
+            polynomial.as_view(),
+            stack,
+        );
+    }
+
+    /// Perform an inverse negacyclic real FFT of `fourier_polynomial` and store the result in
+    /// `polynomial`, viewed as torus elements.
+    ///
+    /// The `stack` must have a capacity at least as large as the result of
+    /// [`Self::backward_scratch`]. See [`Self::forward`] for an example.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `polynomial` does not have the polynomial size of this FFT, or if
+    /// `fourier_polynomial` does not have half that size.
+    pub fn backward<Scalar, InputCont, OutputCont>(
+        &self,
+        fourier_polynomial: &FourierPolynomial<InputCont>,
+        polynomial: &mut Polynomial<OutputCont>,
+        stack: PodStack<'_>,
+    ) where
+        Scalar: UnsignedTorus,
+        InputCont: Container<Element = c64>,
+        OutputCont: ContainerMut<Element = Scalar>,
+    {
+        let fft = self.as_view();
+        assert_eq!(
+            polynomial.polynomial_size(),
+            fft.polynomial_size(),
+            "Mismatched PolynomialSize between the output polynomial ({:?}) and the FFT ({:?})",
+            polynomial.polynomial_size(),
+            fft.polynomial_size(),
+        );
+        assert_eq!(
+            fourier_polynomial.data.container_len(),
+            fft.polynomial_size().to_fourier_polynomial_size().0,
+            "The input FourierPolynomial must have {} coefficients, got {}",
+            fft.polynomial_size().to_fourier_polynomial_size().0,
+            fourier_polynomial.data.container_len(),
+        );
+
+        fft.backward_as_torus(
+            polynomial.as_mut_view(),
+            fourier_polynomial.as_view(),
+            stack,
+        );
+    }
 }
 
 #[cfg_attr(__profiling, inline(never))]
@@ -543,6 +678,427 @@ impl<C: ContainerMut<Element = c64>> FourierPolynomialList<C> {
     }
 }
 
+struct SingleFourierPolynomial<'a> {
+    fft: FftView<'a>,
+    buf: &'a [c64],
+}
+
+impl<'a> serde::Serialize for SingleFourierPolynomial<'a> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.fft.serialize_fourier_buffer(serializer, self.buf)
+    }
+}
+
+struct FillFourier<'a> {
+    fft: FftView<'a>,
+    buf: &'a mut [c64],
+}
+
+impl<'de, 'a> serde::de::DeserializeSeed<'de> for FillFourier<'a> {
+    type Value = ();
+
+    fn deserialize<D: serde::Deserializer<'de>>(
+        self,
+        deserializer: D,
+    ) -> Result<Self::Value, D::Error> {
+        self.fft.deserialize_fourier_buffer(deserializer, self.buf)
+    }
+}
+
+/// Serializer collecting the coefficients of a Fourier polynomial serialized by the FFT plan,
+/// which writes them in the standard order as a sequence of `(re, im)` pairs.
+struct StandardOrderCollector<'a> {
+    out: &'a mut [c64],
+    // Number of `f64` values collected so far
+    len: usize,
+}
+
+fn unexpected_value() -> serde::de::value::Error {
+    serde::ser::Error::custom("unexpected value in a serialized Fourier polynomial")
+}
+
+macro_rules! unexpected_values {
+    ($($method:ident($($arg:ty),*) -> $ok:ty;)*) => {
+        $(
+            fn $method(self, $(_: $arg),*) -> Result<$ok, Self::Error> {
+                Err(unexpected_value())
+            }
+        )*
+    };
+}
+
+impl<'a, 'b> serde::Serializer for &'b mut StandardOrderCollector<'a> {
+    type Ok = ();
+    type Error = serde::de::value::Error;
+    type SerializeSeq = Self;
+    type SerializeTuple = Self;
+    type SerializeTupleStruct = serde::ser::Impossible<(), Self::Error>;
+    type SerializeTupleVariant = serde::ser::Impossible<(), Self::Error>;
+    type SerializeMap = serde::ser::Impossible<(), Self::Error>;
+    type SerializeStruct = serde::ser::Impossible<(), Self::Error>;
+    type SerializeStructVariant = serde::ser::Impossible<(), Self::Error>;
+
+    fn serialize_f64(self, value: f64) -> Result<(), Self::Error> {
+        let z = self
+            .out
+            .get_mut(self.len / 2)
+            .ok_or_else(unexpected_value)?;
+        if self.len % 2 == 0 {
+            z.re = value;
+        } else {
+            z.im = value;
+        }
+        self.len += 1;
+        Ok(())
+    }
+
+    fn serialize_seq(self, _: Option<usize>) -> Result<Self, Self::Error> {
+        Ok(self)
+    }
+
+    fn serialize_tuple(self, _: usize) -> Result<Self, Self::Error> {
+        Ok(self)
+    }
+
+    fn serialize_newtype_struct<T: ?Sized + serde::Serialize>(
+        self,
+        _: &'static str,
+        value: &T,
+    ) -> Result<(), Self::Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_some<T: ?Sized + serde::Serialize>(self, value: &T) -> Result<(), Self::Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: ?Sized + serde::Serialize>(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: &T,
+    ) -> Result<(), Self::Error> {
+        Err(unexpected_value())
+    }
+
+    unexpected_values! {
+        serialize_bool(bool) -> ();
+        serialize_i8(i8) -> ();
+        serialize_i16(i16) -> ();
+        serialize_i32(i32) -> ();
+        serialize_i64(i64) -> ();
+        serialize_u8(u8) -> ();
+        serialize_u16(u16) -> ();
+        serialize_u32(u32) -> ();
+        serialize_u64(u64) -> ();
+        serialize_f32(f32) -> ();
+        serialize_char(char) -> ();
+        serialize_str(&str) -> ();
+        serialize_bytes(&[u8]) -> ();
+        serialize_none() -> ();
+        serialize_unit() -> ();
+        serialize_unit_struct(&'static str) -> ();
+        serialize_unit_variant(&'static str, u32, &'static str) -> ();
+        serialize_tuple_struct(&'static str, usize) -> Self::SerializeTupleStruct;
+        serialize_tuple_variant(&'static str, u32, &'static str, usize)
+            -> Self::SerializeTupleVariant;
+        serialize_map(Option<usize>) -> Self::SerializeMap;
+        serialize_struct(&'static str, usize) -> Self::SerializeStruct;
+        serialize_struct_variant(&'static str, u32, &'static str, usize)
+            -> Self::SerializeStructVariant;
+    }
+}
+
+impl<'a, 'b> serde::ser::SerializeSeq for &'b mut StandardOrderCollector<'a> {
+    type Ok = ();
+    type Error = serde::de::value::Error;
+
+    fn serialize_element<T: ?Sized + serde::Serialize>(
+        &mut self,
+        value: &T,
+    ) -> Result<(), Self::Error> {
+        value.serialize(&mut **self)
+    }
+
+    fn end(self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+impl<'a, 'b> serde::ser::SerializeTuple for &'b mut StandardOrderCollector<'a> {
+    type Ok = ();
+    type Error = serde::de::value::Error;
+
+    fn serialize_element<T: ?Sized + serde::Serialize>(
+        &mut self,
+        value: &T,
+    ) -> Result<(), Self::Error> {
+        value.serialize(&mut **self)
+    }
+
+    fn end(self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+/// Deserializer of a coefficient of a Fourier polynomial in the standard order, as the `(re, im)`
+/// pair expected by the FFT plan.
+struct StandardOrderCoefficient(c64);
+
+impl<'de> serde::Deserializer<'de> for StandardOrderCoefficient {
+    type Error = serde::de::value::Error;
+
+    fn deserialize_any<V: serde::de::Visitor<'de>>(
+        self,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        visitor.visit_seq(serde::de::value::SeqDeserializer::new(
+            [self.0.re, self.0.im].into_iter(),
+        ))
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string bytes byte_buf option
+        unit unit_struct newtype_struct seq tuple tuple_struct map struct enum identifier
+        ignored_any
+    }
+}
+
+impl<'de> serde::de::IntoDeserializer<'de, serde::de::value::Error> for StandardOrderCoefficient {
+    type Deserializer = Self;
+
+    fn into_deserializer(self) -> Self {
+        self
+    }
+}
+
+// The data of a Fourier polynomial is stored in an order that depends on the FFT plan, which can
+// differ from one machine to the other, the serde implementation of the plan converts it from and
+// to the standard order.
+fn fourier_polynomial_to_standard_order(
+    fft: FftView<'_>,
+    buf: &[c64],
+    out: &mut [c64],
+) -> Result<(), serde::de::value::Error> {
+    let mut collector = StandardOrderCollector { out, len: 0 };
+    fft.serialize_fourier_buffer(&mut collector, buf)?;
+    if collector.len != 2 * collector.out.len() {
+        return Err(unexpected_value());
+    }
+    Ok(())
+}
+
+fn fourier_polynomial_from_standard_order(
+    fft: FftView<'_>,
+    standard: &[c64],
+    buf: &mut [c64],
+) -> Result<(), serde::de::value::Error> {
+    let deserializer = serde::de::value::SeqDeserializer::new(
+        standard.iter().map(|&z| StandardOrderCoefficient(z)),
+    );
+    fft.deserialize_fourier_buffer(deserializer, buf)
+}
+
+/// Real or imaginary parts of a list of coefficients, serialized as a sequence of `f64`.
+struct Column<'a> {
+    coefficients: &'a [c64],
+    imaginary: bool,
+}
+
+impl serde::Serialize for Column<'_> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let imaginary = self.imaginary;
+        serializer.collect_seq(
+            self.coefficients
+                .iter()
+                .map(|z| if imaginary { z.im } else { z.re }),
+        )
+    }
+}
+
+/// Fills the real or imaginary parts of a list of coefficients from a sequence of `f64`.
+struct FillColumn<'a> {
+    coefficients: &'a mut [c64],
+    imaginary: bool,
+}
+
+impl<'de> serde::de::DeserializeSeed<'de> for FillColumn<'_> {
+    type Value = ();
+
+    fn deserialize<D: serde::Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'de> serde::de::Visitor<'de> for FillColumn<'_> {
+    type Value = ();
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(formatter, "a sequence of {} f64", self.coefficients.len())
+    }
+
+    fn visit_seq<A: serde::de::SeqAccess<'de>>(self, mut seq: A) -> Result<(), A::Error> {
+        let str = "sequence of real or imaginary parts of Fourier coefficients";
+        let FillColumn {
+            coefficients,
+            imaginary,
+        } = self;
+        let len = coefficients.len();
+        for (i, z) in coefficients.iter_mut().enumerate() {
+            let value = seq
+                .next_element::<f64>()?
+                .ok_or_else(|| serde::de::Error::invalid_length(i, &str))?;
+            if imaginary {
+                z.im = value;
+            } else {
+                z.re = value;
+            }
+        }
+        if seq.next_element::<serde::de::IgnoredAny>()?.is_some() {
+            return Err(serde::de::Error::invalid_length(len + 1, &str));
+        }
+        Ok(())
+    }
+}
+
+impl<C: Container<Element = c64>> FourierPolynomialList<C> {
+    /// Serialize the list with `serializer` in a columnar layout: the real parts of all the
+    /// coefficients are serialized first, followed by all the imaginary parts.
+    ///
+    /// The serde implementation of the list interleaves the real and imaginary parts of each
+    /// coefficient, this layout groups them instead, which can help general purpose compressors
+    /// such as gzip. The actual gain depends on the data and on the compressor.
+    ///
+    /// The list is serialized as a sequence of 4 elements: the polynomial size, the polynomial
+    /// count, and the real and imaginary parts as two sequences of `f64`, with the coefficients in
+    /// the standard order whatever the FFT plan. The list can be read back with
+    /// [`FourierPolynomialList::deserialize_split`].
+    pub fn serialize_split<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::{Error, SerializeSeq};
+
+        let polynomial_size = self.polynomial_size;
+        let chunk_count = if polynomial_size.0 == 0 {
+            0
+        } else {
+            self.polynomial_count().0
+        };
+
+        let mut standard = vec![c64::default(); self.data.container_len()];
+        if chunk_count != 0 {
+            let fft = Fft::new(polynomial_size);
+            let fft = fft.as_view();
+            let fourier_polynomial_size = polynomial_size.to_fourier_polynomial_size().0;
+            for (buf, out) in self
+                .data
+                .as_ref()
+                .chunks_exact(fourier_polynomial_size)
+                .zip(standard.chunks_exact_mut(fourier_polynomial_size))
+            {
+                fourier_polynomial_to_standard_order(fft, buf, out).map_err(S::Error::custom)?;
+            }
+        }
+
+        let mut state = serializer.serialize_seq(Some(4))?;
+        state.serialize_element(&polynomial_size)?;
+        state.serialize_element(&chunk_count)?;
+        state.serialize_element(&Column {
+            coefficients: &standard,
+            imaginary: false,
+        })?;
+        state.serialize_element(&Column {
+            coefficients: &standard,
+            imaginary: true,
+        })?;
+        state.end()
+    }
+}
+
+impl<C: IntoContainerOwned<Element = c64>> FourierPolynomialList<C> {
+    /// Deserialize a list with `deserializer`, serialized with
+    /// [`FourierPolynomialList::serialize_split`].
+    pub fn deserialize_split<'de, D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        use std::marker::PhantomData;
+        struct SplitVisitor<C: IntoContainerOwned<Element = c64>>(PhantomData<fn() -> C>);
+
+        impl<'de, C: IntoContainerOwned<Element = c64>> serde::de::Visitor<'de> for SplitVisitor<C> {
+            type Value = FourierPolynomialList<C>;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                formatter.write_str(
+                    "a sequence of two fields followed by the real and imaginary parts of \
+                    polynomials in the Fourier domain",
+                )
+            }
+
+            fn visit_seq<A: serde::de::SeqAccess<'de>>(
+                self,
+                mut seq: A,
+            ) -> Result<Self::Value, A::Error> {
+                use serde::de::Error;
+
+                let str = "sequence of two fields and two columns of Fourier coefficients";
+                let polynomial_size = seq
+                    .next_element::<PolynomialSize>()?
+                    .ok_or_else(|| A::Error::invalid_length(0, &str))?;
+                let chunk_count = seq
+                    .next_element::<usize>()?
+                    .ok_or_else(|| A::Error::invalid_length(1, &str))?;
+
+                if chunk_count != 0
+                    && (polynomial_size.0 < 2 || !polynomial_size.0.is_power_of_two())
+                {
+                    return Err(A::Error::custom(format!(
+                        "invalid polynomial size {}, expected a power of two",
+                        polynomial_size.0
+                    )));
+                }
+
+                let fourier_polynomial_size = if chunk_count == 0 {
+                    0
+                } else {
+                    polynomial_size.to_fourier_polynomial_size().0
+                };
+                let len = fourier_polynomial_size
+                    .checked_mul(chunk_count)
+                    .ok_or_else(|| A::Error::custom("polynomial count overflows"))?;
+
+                let mut standard = vec![c64::default(); len];
+                for (i, imaginary) in [(2, false), (3, true)] {
+                    seq.next_element_seed(FillColumn {
+                        coefficients: &mut standard,
+                        imaginary,
+                    })?
+                    .ok_or_else(|| A::Error::invalid_length(i, &str))?;
+                }
+
+                let mut data = C::collect((0..len).map(|_| c64::default()));
+                if chunk_count != 0 {
+                    let fft = Fft::new(polynomial_size);
+                    let fft = fft.as_view();
+                    for (buf, polynomial) in data
+                        .as_mut()
+                        .chunks_exact_mut(fourier_polynomial_size)
+                        .zip(standard.chunks_exact(fourier_polynomial_size))
+                    {
+                        fourier_polynomial_from_standard_order(fft, polynomial, buf)
+                            .map_err(A::Error::custom)?;
+                    }
+                }
+
+                Ok(FourierPolynomialList {
+                    data,
+                    polynomial_size,
+                })
+            }
+        }
+
+        deserializer.deserialize_seq(SplitVisitor::<C>(PhantomData))
+    }
+}
+
 impl<C: Container<Element = c64>> serde::Serialize for FourierPolynomialList<C> {
     fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
         fn serialize_impl<S: serde::Serializer>(
@@ -552,20 +1108,6 @@ impl<C: Container<Element = c64>> serde::Serialize for FourierPolynomialList<C>
         ) -> Result<S::Ok, S::Error> {
             use crate::core_crypto::commons::traits::Split;
 
-            pub struct SingleFourierPolynomial<'a> {
-                fft: FftView<'a>,
-                buf: &'a [c64],
-            }
-
-            impl<'a> serde::Serialize for SingleFourierPolynomial<'a> {
-                fn serialize<S: serde::Serializer>(
-                    &self,
-                    serializer: S,
-                ) -> Result<S::Ok, S::Error> {
-                    self.fft.serialize_fourier_buffer(serializer, self.buf)
-                }
-            }
-
             use serde::ser::SerializeSeq;
             let chunk_count = if polynomial_size.0 == 0 {
                 0
@@ -624,22 +1166,6 @@ impl<'de, C: IntoContainerOwned<Element = c64>> serde::Deserialize<'de>
                     None => return Err(serde::de::Error::invalid_length(1, &str)),
                 };
 
-                struct FillFourier<'a> {
-                    fft: FftView<'a>,
-                    buf: &'a mut [c64],
-                }
-
-                impl<'de, 'a> serde::de::DeserializeSeed<'de> for FillFourier<'a> {
-                    type Value = ();
-
-                    fn deserialize<D: serde::Deserializer<'de>>(
-                        self,
-                        deserializer: D,
-                    ) -> Result<Self::Value, D::Error> {
-                        self.fft.deserialize_fourier_buffer(deserializer, self.buf)
-                    }
-                }
-
                 let mut data = C::collect(
                     (0..(polynomial_size.to_fourier_polynomial_size().0 * chunk_count))
                         .map(|_| c64::default()),
@@ -165,6 +165,47 @@ fn test_roundtrip_u64() {
     test_roundtrip::<u64>();
 }
 
+#[test]
+fn test_fft_forward_backward_match_views() {
+    let mut generator = new_random_generator();
+    let size = 1024;
+
+    let fft = Fft::new(PolynomialSize(size));
+    let fft_view = fft.as_view();
+
+    let mut poly = Polynomial::from_container(avec![0u64; size].into_boxed_slice());
+    for x in poly.as_mut().iter_mut() {
+        *x = generator.random_uniform();
+    }
+
+    let mut mem = GlobalPodBuffer::new(
+        fft.forward_scratch()
+            .unwrap()
+            .and(fft.backward_scratch().unwrap()),
+    );
+    let mut stack = PodStack::new(&mut mem);
+
+    let mut fourier = FourierPolynomial::new(PolynomialSize(size));
+    let mut expected_fourier = FourierPolynomial::new(PolynomialSize(size));
+    fft.forward(&poly, &mut fourier, stack.rb_mut());
+    fft_view.forward_as_torus(
+        expected_fourier.as_mut_view(),
+        poly.as_view(),
+        stack.rb_mut(),
+    );
+    assert_eq!(fourier.data, expected_fourier.data);
+
+    let mut roundtrip = Polynomial::from_container(avec![0u64; size].into_boxed_slice());
+    let mut expected_roundtrip = Polynomial::from_container(avec![0u64; size].into_boxed_slice());
+    fft.backward(&fourier, &mut roundtrip, stack.rb_mut());
+    fft_view.backward_as_torus(
+        expected_roundtrip.as_mut_view(),
+        fourier.as_view(),
+        stack.rb_mut(),
+    );
+    assert_eq!(roundtrip.as_ref(), expected_roundtrip.as_ref());
+}
+
 #[test]
 fn f64_to_i64_bit_twiddles() {
     for x in [
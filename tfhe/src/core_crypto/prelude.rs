@@ -3,6 +3,9 @@
 //! The TFHE-rs preludes include convenient imports.
 //! Having `tfhe::core_crypto::prelude::*;` should be enough to start using the lib.
 
+pub use super::algorithms::polynomial_algorithms::{
+    polynomial_wrapping_monic_monomial_div_assign, polynomial_wrapping_monic_monomial_mul_assign,
+};
 pub use super::algorithms::{
     add_external_product_assign, polynomial_algorithms, slice_algorithms, *,
 };
@@ -2,7 +2,7 @@ pub(crate) use keys::{
     BooleanClientKey, BooleanCompressedPublicKey, BooleanConfig, BooleanPublicKey, BooleanServerKey,
 };
 pub use parameters::FheBoolParameters;
-pub use types::{CompressedFheBool, FheBool, GenericBool};
+pub use types::{if_then_else, CompressedFheBool, FheBool, GenericBool};
 
 mod client_key;
 mod keys;
@@ -1,6 +1,7 @@
 use super::client_key::GenericBoolClientKey;
 use super::parameters::BooleanParameterSet;
 use super::types::GenericBool;
+use crate::boolean::ciphertext::Ciphertext;
 use crate::boolean::server_key::{BinaryBooleanGates, ServerKey};
 
 #[cfg_attr(all(doc, not(doctest)), cfg(feature = "boolean"))]
@@ -69,12 +70,29 @@ where
         GenericBool::<P>::new(ciphertext, lhs.id)
     }
 
+    pub(in crate::high_level_api::booleans) fn and_many(
+        &self,
+        ciphertexts: &[Ciphertext],
+        id: P::Id,
+    ) -> GenericBool<P> {
+        let ciphertext = self.key.and_many(ciphertexts);
+        GenericBool::<P>::new(ciphertext, id)
+    }
+
+    pub(in crate::high_level_api::booleans) fn or_many(
+        &self,
+        ciphertexts: &[Ciphertext],
+        id: P::Id,
+    ) -> GenericBool<P> {
+        let ciphertext = self.key.or_many(ciphertexts);
+        GenericBool::<P>::new(ciphertext, id)
+    }
+
     pub(in crate::high_level_api::booleans) fn not(&self, lhs: &GenericBool<P>) -> GenericBool<P> {
         let ciphertext = self.key.not(&lhs.ciphertext);
         GenericBool::<P>::new(ciphertext, lhs.id)
     }
 
-    #[allow(dead_code)]
     pub(in crate::high_level_api::booleans) fn mux(
         &self,
         condition: &GenericBool<P>,
@@ -5,11 +5,8 @@
 #![allow(clippy::bool_assert_comparison)]
 use std::ops::{BitAnd, BitOr, BitXor, Not};
 
-use crate::high_level_api::prelude::*;
-use crate::high_level_api::{
-    generate_keys, set_server_key, ClientKey, CompressedFheBool, ConfigBuilder, FheBool,
-    FheBoolParameters,
-};
+use crate::high_level_api::full_prelude::*;
+use crate::high_level_api::{CompressedFheBool, FheBoolParameters};
 
 fn setup_static_default() -> ClientKey {
     let config = ConfigBuilder::all_disabled().enable_default_bool().build();
@@ -111,6 +108,26 @@ fn test_not_truth_table_static_tfhe() {
     not_truth_table(&ttrue, &ffalse, &keys);
 }
 
+#[test]
+fn test_all_any_static_default() {
+    let keys = setup_static_default();
+
+    let empty: [FheBool; 0] = [];
+    assert_eq!(FheBool::all(&empty).decrypt(&keys), true);
+    assert_eq!(FheBool::any(&empty).decrypt(&keys), false);
+
+    let clears = [true, true, false, true, true];
+    let bools = clears
+        .iter()
+        .map(|&b| FheBool::encrypt(b, &keys))
+        .collect::<Vec<_>>();
+
+    assert_eq!(FheBool::all(&bools).decrypt(&keys), false);
+    assert_eq!(FheBool::any(&bools).decrypt(&keys), true);
+    assert_eq!(FheBool::all(&bools[..2]).decrypt(&keys), true);
+    assert_eq!(FheBool::any(&bools[2..3]).decrypt(&keys), false);
+}
+
 fn xor_truth_table<'a, BoolType>(ttrue: &'a BoolType, ffalse: &'a BoolType, key: &ClientKey)
 where
     &'a BoolType: BitXor<&'a BoolType, Output = BoolType>,
@@ -190,3 +207,26 @@ fn test_compressed_bool() {
     assert_eq!(a.decrypt(&keys), true);
     assert_eq!(b.decrypt(&keys), false);
 }
+
+#[test]
+fn test_trivial_bool() {
+    let keys = setup_static_default();
+
+    let ttrue = FheBool::encrypt_trivial(true);
+    let ffalse = FheBool::encrypt_trivial(false);
+
+    assert_eq!(ttrue.decrypt(&keys), true);
+    assert_eq!(ffalse.decrypt(&keys), false);
+
+    xor_truth_table(&ttrue, &ffalse, &keys);
+    and_truth_table(&ttrue, &ffalse, &keys);
+    or_truth_table(&ttrue, &ffalse, &keys);
+    not_truth_table(&ttrue, &ffalse, &keys);
+
+    // Trivial constants mixed with encrypted values
+    let a = FheBool::encrypt(true, &keys);
+    assert_eq!((&a & &ttrue).decrypt(&keys), true);
+    assert_eq!((&a & &ffalse).decrypt(&keys), false);
+    assert_eq!((&ffalse | &a).decrypt(&keys), true);
+    assert_eq!((&ttrue ^ &a).decrypt(&keys), false);
+}
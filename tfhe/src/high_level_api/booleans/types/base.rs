@@ -89,6 +89,100 @@ where
     }
 }
 
+impl<P> GenericBool<P>
+where
+    P: BooleanParameterSet,
+    P::Id: Default + WithGlobalKey<Key = GenericBoolServerKey<P>>,
+{
+    /// Homomorphically computes the AND of all the booleans.
+    ///
+    /// The booleans are combined as a balanced tree, which keeps the depth of the computation
+    /// logarithmic in the number of booleans. An empty input gives a trivial encryption of
+    /// `true`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheBool};
+    ///
+    /// let config = ConfigBuilder::all_disabled().enable_default_bool().build();
+    /// let (client_key, server_key) = generate_keys(config);
+    ///
+    /// let conditions = [true, true, false, true]
+    ///     .iter()
+    ///     .map(|&b| FheBool::encrypt(b, &client_key))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// set_server_key(server_key);
+    ///
+    /// let fhe_result = FheBool::all(&conditions);
+    ///
+    /// let clear_result = fhe_result.decrypt(&client_key);
+    /// assert_eq!(clear_result, false);
+    /// ```
+    pub fn all<I, B>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = B>,
+        B: Borrow<Self>,
+    {
+        let (ciphertexts, id) = Self::collect_ciphertexts(iter);
+        id.with_unwrapped_global(|key| key.and_many(&ciphertexts, id))
+    }
+
+    /// Homomorphically computes the OR of all the booleans.
+    ///
+    /// The booleans are combined as a balanced tree, which keeps the depth of the computation
+    /// logarithmic in the number of booleans. An empty input gives a trivial encryption of
+    /// `false`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheBool};
+    ///
+    /// let config = ConfigBuilder::all_disabled().enable_default_bool().build();
+    /// let (client_key, server_key) = generate_keys(config);
+    ///
+    /// let conditions = [false, false, true, false]
+    ///     .iter()
+    ///     .map(|&b| FheBool::encrypt(b, &client_key))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// set_server_key(server_key);
+    ///
+    /// let fhe_result = FheBool::any(&conditions);
+    ///
+    /// let clear_result = fhe_result.decrypt(&client_key);
+    /// assert_eq!(clear_result, true);
+    /// ```
+    pub fn any<I, B>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = B>,
+        B: Borrow<Self>,
+    {
+        let (ciphertexts, id) = Self::collect_ciphertexts(iter);
+        id.with_unwrapped_global(|key| key.or_many(&ciphertexts, id))
+    }
+
+    fn collect_ciphertexts<I, B>(iter: I) -> (Vec<Ciphertext>, P::Id)
+    where
+        I: IntoIterator<Item = B>,
+        B: Borrow<Self>,
+    {
+        let mut id = None;
+        let ciphertexts = iter
+            .into_iter()
+            .map(|b| {
+                let b = b.borrow();
+                id.get_or_insert(b.id);
+                b.ciphertext.clone()
+            })
+            .collect();
+
+        (ciphertexts, id.unwrap_or_default())
+    }
+}
+
 impl<P, B> FheEq<B> for GenericBool<P>
 where
     B: Borrow<Self>,
@@ -101,9 +195,34 @@ where
         self.id
             .with_unwrapped_global(|key| key.xnor(self, other.borrow()))
     }
+
+    fn ne(&self, other: B) -> Self {
+        self.id
+            .with_unwrapped_global(|key| key.xor(self, other.borrow()))
+    }
 }
 
-#[allow(dead_code)]
+/// Homomorphically selects between `ct_then` and `ct_else` depending on `ct_condition`.
+///
+/// # Example
+/// ```rust
+/// use tfhe::prelude::*;
+/// use tfhe::{generate_keys, if_then_else, set_server_key, ConfigBuilder, FheBool};
+///
+/// let config = ConfigBuilder::all_disabled().enable_default_bool().build();
+/// let (client_key, server_key) = generate_keys(config);
+///
+/// let condition = FheBool::encrypt(true, &client_key);
+/// let a = FheBool::encrypt(false, &client_key);
+/// let b = FheBool::encrypt(true, &client_key);
+///
+/// set_server_key(server_key);
+///
+/// let fhe_result = if_then_else(&condition, &a, &b);
+///
+/// let clear_result = fhe_result.decrypt(&client_key);
+/// assert_eq!(clear_result, false);
+/// ```
 #[cfg_attr(all(doc, not(doctest)), cfg(feature = "boolean"))]
 pub fn if_then_else<B1, B2, P>(ct_condition: B1, ct_then: B2, ct_else: B2) -> GenericBool<P>
 where
@@ -1,4 +1,4 @@
-pub use base::{CompressedBool, GenericBool};
+pub use base::{if_then_else, CompressedBool, GenericBool};
 pub use static_::{CompressedFheBool, FheBool};
 
 mod base;
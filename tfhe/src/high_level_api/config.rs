@@ -260,6 +260,43 @@ impl ConfigBuilder {
         self
     }
 
+    /// Enables all the integer types using the given block parameters.
+    ///
+    /// This allows to use parameters that differ from the ones provided by the crate, for example
+    /// ones with a power of two ciphertext modulus created with
+    /// `CiphertextModulus::try_new_power_of_2`.
+    ///
+    /// Each type uses as many blocks as needed to represent its number of bits. An error is
+    /// returned if the parameters are not supported, or if the number of bits of one of the types
+    /// is not a multiple of the number of message bits of a block, e.g. `FheUint10` and
+    /// `FheUint14` cannot be represented with blocks of 3 or 4 bits.
+    ///
+    /// Functions evaluated with the WoP-PBS (`FheBootstrap` and `bivariate_function`) are not
+    /// available when the ciphertext modulus is not the native one.
+    #[cfg(feature = "integer")]
+    pub fn enable_custom_integer(
+        mut self,
+        block_parameters: crate::shortint::Parameters,
+    ) -> Result<Self, crate::high_level_api::UnsupportedIntegerParameters> {
+        use crate::high_level_api::integers::{
+            FheUint10Parameters, FheUint128Parameters, FheUint12Parameters, FheUint14Parameters,
+            FheUint16Parameters, FheUint256Parameters, FheUint32Parameters, FheUint64Parameters,
+            FheUint8Parameters,
+        };
+
+        let integer_config = &mut self.config.integer_config;
+        integer_config.uint8_params = Some(FheUint8Parameters::custom(block_parameters)?);
+        integer_config.uint10_params = Some(FheUint10Parameters::custom(block_parameters)?);
+        integer_config.uint12_params = Some(FheUint12Parameters::custom(block_parameters)?);
+        integer_config.uint14_params = Some(FheUint14Parameters::custom(block_parameters)?);
+        integer_config.uint16_params = Some(FheUint16Parameters::custom(block_parameters)?);
+        integer_config.uint32_params = Some(FheUint32Parameters::custom(block_parameters)?);
+        integer_config.uint64_params = Some(FheUint64Parameters::custom(block_parameters)?);
+        integer_config.uint128_params = Some(FheUint128Parameters::custom(block_parameters)?);
+        integer_config.uint256_params = Some(FheUint256Parameters::custom(block_parameters)?);
+        Ok(self)
+    }
+
     pub fn build(self) -> Config {
         self.config
     }
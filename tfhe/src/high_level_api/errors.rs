@@ -122,6 +122,25 @@ impl Display for OutOfRangeError {
 
 impl std::error::Error for OutOfRangeError {}
 
+/// Error returned when an integer type cannot be enabled with custom block parameters
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UnsupportedIntegerParameters {
+    pub(crate) num_bits: usize,
+    pub(crate) reason: String,
+}
+
+impl Display for UnsupportedIntegerParameters {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Unsupported parameters for {} bits integers: {}",
+            self.num_bits, self.reason
+        )
+    }
+}
+
+impl std::error::Error for UnsupportedIntegerParameters {}
+
 #[non_exhaustive]
 #[derive(Debug, Eq, PartialEq)]
 pub enum Error {
@@ -0,0 +1,26 @@
+//! The purpose of this module is to make it easier to have the most commonly needed
+//! items of the high level API: the traits of the [`prelude`](crate::prelude), the
+//! configuration and key generation functions, and the most used types.
+//!
+//! It is meant to be glob imported:
+//! ```
+//! use tfhe::full_prelude::*;
+//! ```
+//!
+//! Unlike the [`prelude`](crate::prelude), it exports types and functions whose names may
+//! conflict with the ones of the other modules of this crate, e.g. `ClientKey` and `ServerKey`.
+pub use crate::high_level_api::prelude::*;
+
+pub use crate::high_level_api::{
+    generate_keys, set_server_key, ClientKey, ConfigBuilder, ServerKey,
+};
+
+#[cfg(feature = "boolean")]
+pub use crate::high_level_api::FheBool;
+#[cfg(feature = "integer")]
+pub use crate::high_level_api::{
+    FheUint10, FheUint12, FheUint128, FheUint14, FheUint16, FheUint256, FheUint32, FheUint64,
+    FheUint8,
+};
+#[cfg(feature = "shortint")]
+pub use crate::high_level_api::{FheUint2, FheUint3, FheUint4};
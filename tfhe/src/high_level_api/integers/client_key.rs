@@ -28,22 +28,27 @@ where
         let params = parameters.into();
         #[cfg(feature = "internal-keycache")]
         {
+            use crate::high_level_api::keys::key_cache_enabled;
             use crate::integer::keycache::KEY_CACHE;
-            let key = KEY_CACHE.get_from_params(params.block_parameters).0;
-            let inner = crate::integer::RadixClientKey::from((key, params.num_block));
-            Self {
-                inner,
-                pbs_order: params.pbs_order,
+            // Parameters with a custom modulus are not part of the key cache
+            if params
+                .block_parameters
+                .ciphertext_modulus
+                .is_native_modulus()
+                && key_cache_enabled()
+            {
+                let key = KEY_CACHE.get_from_params(params.block_parameters).0;
+                let inner = crate::integer::RadixClientKey::from((key, params.num_block));
+                return Self {
+                    inner,
+                    pbs_order: params.pbs_order,
+                };
             }
         }
-        #[cfg(not(feature = "internal-keycache"))]
-        {
-            let inner =
-                crate::integer::RadixClientKey::new(params.block_parameters, params.num_block);
-            Self {
-                inner,
-                pbs_order: params.pbs_order,
-            }
+        let inner = crate::integer::RadixClientKey::new(params.block_parameters, params.num_block);
+        Self {
+            inner,
+            pbs_order: params.pbs_order,
         }
     }
 }
@@ -138,6 +143,9 @@ where
     P::InnerClientKey: FromParameters<P>,
 {
     fn from(params: P) -> Self {
+        if let Err(err) = params.validate() {
+            panic!("Unsupported integer parameters: {err}");
+        }
         let key = P::InnerClientKey::from_parameters(params.clone());
         Self { inner: key, params }
     }
@@ -17,6 +17,37 @@ pub struct RadixParameters {
     pub wopbs_block_parameters: crate::shortint::Parameters,
 }
 
+impl RadixParameters {
+    /// Checks that the parameters are supported and represent exactly `num_bits` bits of message.
+    pub(in crate::high_level_api) fn validate(&self, num_bits: usize) -> Result<(), String> {
+        self.block_parameters.validate()?;
+
+        let message_modulus = self.block_parameters.message_modulus.0;
+        if !message_modulus.is_power_of_two() {
+            return Err(format!(
+                "the message modulus of radix blocks must be a power of two, got {message_modulus}"
+            ));
+        }
+        let block_bits = message_modulus.ilog2() as usize;
+        if self.num_block * block_bits != num_bits {
+            return Err(format!(
+                "{} blocks of {block_bits} bits cannot represent exactly {num_bits} bits",
+                self.num_block
+            ));
+        }
+
+        let ciphertext_modulus = self.block_parameters.ciphertext_modulus;
+        if !ciphertext_modulus.is_compatible_with_native_modulus() {
+            return Err(format!(
+                "only native and power of two ciphertext moduli are supported, got \
+                {ciphertext_modulus:?}"
+            ));
+        }
+
+        Ok(())
+    }
+}
+
 /// Parameters for 'CRT' decomposition
 ///
 /// (Chinese Remainder Theorem)
@@ -47,14 +78,14 @@ where
         let params = parameters.into();
         #[cfg(feature = "internal-keycache")]
         {
+            use crate::high_level_api::keys::key_cache_enabled;
             use crate::integer::keycache::KEY_CACHE;
-            let key = KEY_CACHE.get_from_params(params.block_parameters).0;
-            crate::integer::CrtClientKey::from((key, params.moduli))
-        }
-        #[cfg(not(feature = "internal-keycache"))]
-        {
-            crate::integer::CrtClientKey::new(params.block_parameters, params.moduli)
+            if key_cache_enabled() {
+                let key = KEY_CACHE.get_from_params(params.block_parameters).0;
+                return crate::integer::CrtClientKey::from((key, params.moduli));
+            }
         }
+        crate::integer::CrtClientKey::new(params.block_parameters, params.moduli)
     }
 }
 
@@ -63,6 +94,11 @@ pub trait IntegerParameter: ParameterType {
     fn wopbs_block_parameters(&self) -> crate::shortint::Parameters;
 
     fn block_parameters(&self) -> crate::shortint::Parameters;
+
+    /// Checks that keys can be generated for these parameters.
+    fn validate(&self) -> Result<(), String> {
+        Ok(())
+    }
 }
 
 /// Marker struct for the RadixRepresentation
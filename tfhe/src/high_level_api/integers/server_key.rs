@@ -10,7 +10,8 @@ use crate::integer::wopbs::WopbsKey;
 #[derive(Clone, serde::Deserialize, serde::Serialize)]
 pub struct GenericIntegerServerKey<P: IntegerParameter> {
     pub(in crate::high_level_api::integers) inner: P::InnerServerKey,
-    pub(in crate::high_level_api::integers) wopbs_key: WopbsKey,
+    // The WoP-PBS only supports the native ciphertext modulus
+    pub(in crate::high_level_api::integers) wopbs_key: Option<WopbsKey>,
     _marker: PhantomData<P>,
 }
 
@@ -21,11 +22,18 @@ where
 {
     pub(super) fn new(client_key: &GenericIntegerClientKey<P>) -> Self {
         let inner = P::InnerServerKey::new(&client_key.inner);
-        let wopbs_key = P::InnerServerKey::new_wopbs_key(
-            &client_key.inner,
-            &inner,
-            client_key.params.wopbs_block_parameters(),
-        );
+        let wopbs_key = client_key
+            .params
+            .block_parameters()
+            .ciphertext_modulus
+            .is_native_modulus()
+            .then(|| {
+                P::InnerServerKey::new_wopbs_key(
+                    &client_key.inner,
+                    &inner,
+                    client_key.params.wopbs_block_parameters(),
+                )
+            });
         Self {
             inner,
             wopbs_key,
@@ -34,6 +42,16 @@ where
     }
 }
 
+impl<P: IntegerParameter> GenericIntegerServerKey<P> {
+    #[track_caller]
+    pub(in crate::high_level_api::integers) fn wopbs_key(&self) -> &WopbsKey {
+        self.wopbs_key.as_ref().expect(
+            "Functions evaluated with the WoP-PBS are not supported with a non native ciphertext \
+            modulus",
+        )
+    }
+}
+
 pub(crate) fn wopbs_radix<O>(
     wopbs_key: &WopbsKey,
     server_key: &crate::integer::ServerKey,
@@ -255,6 +273,10 @@ pub(super) trait SmartNeg<Ciphertext> {
     fn smart_neg(&self, lhs: Ciphertext) -> Self::Output;
 }
 
+pub(super) trait SmartNegAssign<Ciphertext> {
+    fn smart_neg_assign(&self, lhs: Ciphertext);
+}
+
 macro_rules! define_smart_server_key_op {
     ($op_name:ident) => {
         paste::paste! {
@@ -285,9 +307,21 @@ macro_rules! define_smart_server_key_op {
 }
 
 define_smart_server_key_op!(
-    Add, Sub, Mul, BitAnd, BitOr, BitXor, Shl, Shr, Eq, Ge, Gt, Le, Lt, Max, Min
+    Add, Sub, Mul, BitAnd, BitOr, BitXor, Shl, Shr, Eq, Ne, Ge, Gt, Le, Lt, Max, Min
 );
 
+pub trait SmartSaturatingAdd<Lhs, Rhs> {
+    type Output;
+
+    fn smart_saturating_add(&self, lhs: Lhs, rhs: Rhs) -> Self::Output;
+}
+
+pub trait SmartSaturatingSub<Lhs, Rhs> {
+    type Output;
+
+    fn smart_saturating_sub(&self, lhs: Lhs, rhs: Rhs) -> Self::Output;
+}
+
 #[derive(Clone, serde::Deserialize, serde::Serialize)]
 pub enum RadixCiphertextDyn {
     Big(crate::integer::RadixCiphertextBig),
@@ -308,6 +342,147 @@ impl SmartNeg<&mut RadixCiphertextDyn> for crate::integer::ServerKey {
     }
 }
 
+impl SmartNegAssign<&mut RadixCiphertextDyn> for crate::integer::ServerKey {
+    fn smart_neg_assign(&self, lhs: &mut RadixCiphertextDyn) {
+        match lhs {
+            RadixCiphertextDyn::Big(lhs) => self.smart_neg_assign_parallelized(lhs),
+            RadixCiphertextDyn::Small(lhs) => self.smart_neg_assign_parallelized(lhs),
+        }
+    }
+}
+
+pub trait SmartIsZero<Ciphertext> {
+    type Output;
+    fn smart_is_zero(&self, lhs: Ciphertext) -> Self::Output;
+}
+
+impl SmartIsZero<&mut RadixCiphertextDyn> for crate::integer::ServerKey {
+    type Output = RadixCiphertextDyn;
+    fn smart_is_zero(&self, lhs: &mut RadixCiphertextDyn) -> Self::Output {
+        match lhs {
+            RadixCiphertextDyn::Big(lhs) => RadixCiphertextDyn::Big(self.is_zero_parallelized(lhs)),
+            RadixCiphertextDyn::Small(lhs) => {
+                RadixCiphertextDyn::Small(self.is_zero_parallelized(lhs))
+            }
+        }
+    }
+}
+
+pub trait SmartReverseBits<Ciphertext> {
+    type Output;
+    fn smart_reverse_bits(&self, lhs: Ciphertext) -> Self::Output;
+}
+
+impl SmartReverseBits<&mut RadixCiphertextDyn> for crate::integer::ServerKey {
+    type Output = RadixCiphertextDyn;
+    fn smart_reverse_bits(&self, lhs: &mut RadixCiphertextDyn) -> Self::Output {
+        match lhs {
+            RadixCiphertextDyn::Big(lhs) => {
+                RadixCiphertextDyn::Big(self.reverse_bits_parallelized(lhs))
+            }
+            RadixCiphertextDyn::Small(lhs) => {
+                RadixCiphertextDyn::Small(self.reverse_bits_parallelized(lhs))
+            }
+        }
+    }
+}
+
+pub trait SmartIlog2<Ciphertext> {
+    type Output;
+    fn smart_ilog2(&self, lhs: Ciphertext) -> Self::Output;
+}
+
+impl SmartIlog2<&mut RadixCiphertextDyn> for crate::integer::ServerKey {
+    type Output = RadixCiphertextDyn;
+    fn smart_ilog2(&self, lhs: &mut RadixCiphertextDyn) -> Self::Output {
+        match lhs {
+            RadixCiphertextDyn::Big(lhs) => RadixCiphertextDyn::Big(self.ilog2_parallelized(lhs)),
+            RadixCiphertextDyn::Small(lhs) => {
+                RadixCiphertextDyn::Small(self.ilog2_parallelized(lhs))
+            }
+        }
+    }
+}
+
+pub trait SmartParity<Ciphertext> {
+    type Output;
+    fn smart_parity(&self, lhs: Ciphertext) -> Self::Output;
+}
+
+impl SmartParity<&mut RadixCiphertextDyn> for crate::integer::ServerKey {
+    type Output = RadixCiphertextDyn;
+    fn smart_parity(&self, lhs: &mut RadixCiphertextDyn) -> Self::Output {
+        match lhs {
+            RadixCiphertextDyn::Big(lhs) => RadixCiphertextDyn::Big(self.parity_parallelized(lhs)),
+            RadixCiphertextDyn::Small(lhs) => {
+                RadixCiphertextDyn::Small(self.parity_parallelized(lhs))
+            }
+        }
+    }
+}
+
+pub trait SmartInRange<Ciphertext> {
+    type Output;
+    fn smart_in_range(&self, lhs: Ciphertext, lo: u64, hi: u64) -> Self::Output;
+}
+
+impl SmartInRange<&mut RadixCiphertextDyn> for crate::integer::ServerKey {
+    type Output = RadixCiphertextDyn;
+    fn smart_in_range(&self, lhs: &mut RadixCiphertextDyn, lo: u64, hi: u64) -> Self::Output {
+        match lhs {
+            RadixCiphertextDyn::Big(lhs) => {
+                RadixCiphertextDyn::Big(self.scalar_in_range_parallelized(lhs, lo, hi))
+            }
+            RadixCiphertextDyn::Small(lhs) => {
+                RadixCiphertextDyn::Small(self.scalar_in_range_parallelized(lhs, lo, hi))
+            }
+        }
+    }
+}
+
+pub(super) trait SmartMaxOfMany<Ciphertext> {
+    fn smart_max_of_many(&self, cts: &[Ciphertext]) -> Ciphertext;
+}
+
+pub(super) trait SmartMinOfMany<Ciphertext> {
+    fn smart_min_of_many(&self, cts: &[Ciphertext]) -> Ciphertext;
+}
+
+macro_rules! impl_smart_many_op_for_tfhe_integer_server_key_dyn {
+    ($smart_trait:ident($smart_trait_fn:ident) => $method:ident) => {
+        impl $smart_trait<RadixCiphertextDyn> for crate::integer::ServerKey {
+            fn $smart_trait_fn(&self, cts: &[RadixCiphertextDyn]) -> RadixCiphertextDyn {
+                match cts {
+                    [RadixCiphertextDyn::Small(_), ..] => {
+                        let cts = cts
+                            .iter()
+                            .map(|ct| match ct {
+                                RadixCiphertextDyn::Small(ct) => ct.clone(),
+                                RadixCiphertextDyn::Big(_) => {
+                                    unreachable!("internal error: mismatched big and small integer")
+                                }
+                            })
+                            .collect::<Vec<_>>();
+                        RadixCiphertextDyn::Small(self.$method(&cts))
+                    }
+                    _ => {
+                        let cts = cts
+                            .iter()
+                            .map(|ct| match ct {
+                                RadixCiphertextDyn::Big(ct) => ct.clone(),
+                                RadixCiphertextDyn::Small(_) => {
+                                    unreachable!("internal error: mismatched big and small integer")
+                                }
+                            })
+                            .collect::<Vec<_>>();
+                        RadixCiphertextDyn::Big(self.$method(&cts))
+                    }
+                }
+            }
+        }
+    };
+}
+
 macro_rules! impl_smart_op_for_tfhe_integer_server_key_dyn {
     ($smart_trait:ident($smart_trait_fn:ident) => $method:ident) => {
         impl $smart_trait<&mut RadixCiphertextDyn, &mut RadixCiphertextDyn>
@@ -358,18 +533,28 @@ macro_rules! impl_smart_assign_op_for_tfhe_integer_server_key_dyn {
     };
 }
 
+/// Converts a clear shift amount to the `usize` taken by the integer server key, amounts that do
+/// not fit saturate as the integer server key shifts all the bits out for them anyway
+fn shift_amount(rhs: u64) -> usize {
+    usize::try_from(rhs).unwrap_or(usize::MAX)
+}
+
 macro_rules! impl_smart_scalar_op_for_tfhe_integer_server_key_dyn {
     ($smart_trait:ident($smart_trait_fn:ident) => $method:ident) => {
+        impl_smart_scalar_op_for_tfhe_integer_server_key_dyn!(
+            $smart_trait($smart_trait_fn) => $method(std::convert::identity)
+        );
+    };
+    ($smart_trait:ident($smart_trait_fn:ident) => $method:ident($convert_rhs:path)) => {
         impl $smart_trait<&mut RadixCiphertextDyn, u64> for crate::integer::ServerKey {
             type Output = RadixCiphertextDyn;
 
             fn $smart_trait_fn(&self, lhs: &mut RadixCiphertextDyn, rhs: u64) -> Self::Output {
+                let rhs = $convert_rhs(rhs);
                 match lhs {
-                    RadixCiphertextDyn::Big(lhs) => {
-                        RadixCiphertextDyn::Big(self.$method(lhs, rhs.try_into().unwrap()))
-                    }
+                    RadixCiphertextDyn::Big(lhs) => RadixCiphertextDyn::Big(self.$method(lhs, rhs)),
                     RadixCiphertextDyn::Small(lhs) => {
-                        RadixCiphertextDyn::Small(self.$method(lhs, rhs.try_into().unwrap()))
+                        RadixCiphertextDyn::Small(self.$method(lhs, rhs))
                     }
                 }
             }
@@ -379,15 +564,17 @@ macro_rules! impl_smart_scalar_op_for_tfhe_integer_server_key_dyn {
 
 macro_rules! impl_smart_scalar_assign_op_for_tfhe_integer_server_key_dyn {
     ($smart_trait:ident($smart_trait_fn:ident) => $method_assign:ident) => {
+        impl_smart_scalar_assign_op_for_tfhe_integer_server_key_dyn!(
+            $smart_trait($smart_trait_fn) => $method_assign(std::convert::identity)
+        );
+    };
+    ($smart_trait:ident($smart_trait_fn:ident) => $method_assign:ident($convert_rhs:path)) => {
         impl $smart_trait<RadixCiphertextDyn, u64> for crate::integer::ServerKey {
             fn $smart_trait_fn(&self, lhs: &mut RadixCiphertextDyn, rhs: u64) {
+                let rhs = $convert_rhs(rhs);
                 match lhs {
-                    RadixCiphertextDyn::Big(lhs) => {
-                        self.$method_assign(lhs, rhs.try_into().unwrap())
-                    }
-                    RadixCiphertextDyn::Small(lhs) => {
-                        self.$method_assign(lhs, rhs.try_into().unwrap())
-                    }
+                    RadixCiphertextDyn::Big(lhs) => self.$method_assign(lhs, rhs),
+                    RadixCiphertextDyn::Small(lhs) => self.$method_assign(lhs, rhs),
                 }
             }
         }
@@ -401,12 +588,18 @@ impl_smart_op_for_tfhe_integer_server_key_dyn!(SmartBitAnd(smart_bitand) => bita
 impl_smart_op_for_tfhe_integer_server_key_dyn!(SmartBitOr(smart_bitor) => bitor_parallelized);
 impl_smart_op_for_tfhe_integer_server_key_dyn!(SmartBitXor(smart_bitxor) => bitxor_parallelized);
 impl_smart_op_for_tfhe_integer_server_key_dyn!(SmartEq(smart_eq) => eq_parallelized);
+impl_smart_op_for_tfhe_integer_server_key_dyn!(SmartNe(smart_ne) => ne_parallelized);
 impl_smart_op_for_tfhe_integer_server_key_dyn!(SmartGe(smart_ge) => ge_parallelized);
 impl_smart_op_for_tfhe_integer_server_key_dyn!(SmartGt(smart_gt) => gt_parallelized);
 impl_smart_op_for_tfhe_integer_server_key_dyn!(SmartLe(smart_le) => le_parallelized);
 impl_smart_op_for_tfhe_integer_server_key_dyn!(SmartLt(smart_lt) => lt_parallelized);
 impl_smart_op_for_tfhe_integer_server_key_dyn!(SmartMax(smart_max) => max_parallelized);
 impl_smart_op_for_tfhe_integer_server_key_dyn!(SmartMin(smart_min) => min_parallelized);
+impl_smart_op_for_tfhe_integer_server_key_dyn!(SmartSaturatingAdd(smart_saturating_add) => saturating_add_parallelized);
+impl_smart_op_for_tfhe_integer_server_key_dyn!(SmartSaturatingSub(smart_saturating_sub) => saturating_sub_parallelized);
+
+impl_smart_many_op_for_tfhe_integer_server_key_dyn!(SmartMaxOfMany(smart_max_of_many) => max_of_many_parallelized);
+impl_smart_many_op_for_tfhe_integer_server_key_dyn!(SmartMinOfMany(smart_min_of_many) => min_of_many_parallelized);
 
 impl_smart_assign_op_for_tfhe_integer_server_key_dyn!(SmartAddAssign(smart_add_assign) => add_assign_parallelized);
 impl_smart_assign_op_for_tfhe_integer_server_key_dyn!(SmartSubAssign(smart_sub_assign) => sub_assign_parallelized);
@@ -418,11 +611,23 @@ impl_smart_assign_op_for_tfhe_integer_server_key_dyn!(SmartBitXorAssign(smart_bi
 impl_smart_scalar_op_for_tfhe_integer_server_key_dyn!(SmartAdd(smart_add) => scalar_add_parallelized);
 impl_smart_scalar_op_for_tfhe_integer_server_key_dyn!(SmartSub(smart_sub) => scalar_sub_parallelized);
 impl_smart_scalar_op_for_tfhe_integer_server_key_dyn!(SmartMul(smart_mul) => scalar_mul_parallelized);
-impl_smart_scalar_op_for_tfhe_integer_server_key_dyn!(SmartShl(smart_shl) => scalar_left_shift_parallelized);
-impl_smart_scalar_op_for_tfhe_integer_server_key_dyn!(SmartShr(smart_shr) => scalar_right_shift_parallelized);
+impl_smart_scalar_op_for_tfhe_integer_server_key_dyn!(SmartShl(smart_shl) => scalar_left_shift_parallelized(shift_amount));
+impl_smart_scalar_op_for_tfhe_integer_server_key_dyn!(SmartShr(smart_shr) => scalar_right_shift_parallelized(shift_amount));
+impl_smart_scalar_op_for_tfhe_integer_server_key_dyn!(SmartBitAnd(smart_bitand) => scalar_bitand_parallelized);
+impl_smart_scalar_op_for_tfhe_integer_server_key_dyn!(SmartBitOr(smart_bitor) => scalar_bitor_parallelized);
+impl_smart_scalar_op_for_tfhe_integer_server_key_dyn!(SmartBitXor(smart_bitxor) => scalar_bitxor_parallelized);
+impl_smart_scalar_op_for_tfhe_integer_server_key_dyn!(SmartEq(smart_eq) => scalar_eq_parallelized);
+impl_smart_scalar_op_for_tfhe_integer_server_key_dyn!(SmartNe(smart_ne) => scalar_ne_parallelized);
+impl_smart_scalar_op_for_tfhe_integer_server_key_dyn!(SmartGe(smart_ge) => scalar_ge_parallelized);
+impl_smart_scalar_op_for_tfhe_integer_server_key_dyn!(SmartGt(smart_gt) => scalar_gt_parallelized);
+impl_smart_scalar_op_for_tfhe_integer_server_key_dyn!(SmartLe(smart_le) => scalar_le_parallelized);
+impl_smart_scalar_op_for_tfhe_integer_server_key_dyn!(SmartLt(smart_lt) => scalar_lt_parallelized);
 
 impl_smart_scalar_assign_op_for_tfhe_integer_server_key_dyn!(SmartAddAssign(smart_add_assign) => scalar_add_assign_parallelized);
 impl_smart_scalar_assign_op_for_tfhe_integer_server_key_dyn!(SmartSubAssign(smart_sub_assign) => scalar_sub_assign_parallelized);
 impl_smart_scalar_assign_op_for_tfhe_integer_server_key_dyn!(SmartMulAssign(smart_mul_assign) => scalar_mul_assign_parallelized);
-impl_smart_scalar_assign_op_for_tfhe_integer_server_key_dyn!(SmartShlAssign(smart_shl_assign) => scalar_left_shift_assign_parallelized);
-impl_smart_scalar_assign_op_for_tfhe_integer_server_key_dyn!(SmartShrAssign(smart_shr_assign) => scalar_right_shift_assign_parallelized);
+impl_smart_scalar_assign_op_for_tfhe_integer_server_key_dyn!(SmartShlAssign(smart_shl_assign) => scalar_left_shift_assign_parallelized(shift_amount));
+impl_smart_scalar_assign_op_for_tfhe_integer_server_key_dyn!(SmartShrAssign(smart_shr_assign) => scalar_right_shift_assign_parallelized(shift_amount));
+impl_smart_scalar_assign_op_for_tfhe_integer_server_key_dyn!(SmartBitAndAssign(smart_bitand_assign) => scalar_bitand_assign_parallelized);
+impl_smart_scalar_assign_op_for_tfhe_integer_server_key_dyn!(SmartBitOrAssign(smart_bitor_assign) => scalar_bitor_assign_parallelized);
+impl_smart_scalar_assign_op_for_tfhe_integer_server_key_dyn!(SmartBitXorAssign(smart_bitxor_assign) => scalar_bitxor_assign_parallelized);
@@ -48,6 +48,15 @@ fn test_uint8_compare() {
     let clear_result = u8::from(clear_a == clear_b);
     assert_eq!(decrypted_result, clear_result);
 
+    let result = &a.ne(&b);
+    let decrypted_result: u8 = result.decrypt(&client_key);
+    let clear_result = u8::from(clear_a != clear_b);
+    assert_eq!(decrypted_result, clear_result);
+
+    let result = &a.ne(&a);
+    let decrypted_result: u8 = result.decrypt(&client_key);
+    assert_eq!(decrypted_result, 0);
+
     let result = &a.le(&b);
     let decrypted_result: u8 = result.decrypt(&client_key);
     let clear_result = u8::from(clear_a <= clear_b);
@@ -69,6 +78,201 @@ fn test_uint8_compare() {
     assert_eq!(decrypted_result, clear_result);
 }
 
+#[test]
+fn test_uint8_max_min_of() {
+    let config = ConfigBuilder::all_disabled().enable_default_uint8().build();
+
+    let (client_key, server_key) = generate_keys(config);
+
+    set_server_key(server_key);
+
+    let clears = [97u8, 3, 164, 42, 12];
+    let values = clears
+        .iter()
+        .map(|&v| FheUint8::encrypt(v, &client_key))
+        .collect::<Vec<_>>();
+
+    let result = FheUint8::max_of(&values);
+    let decrypted_result: u8 = result.decrypt(&client_key);
+    assert_eq!(decrypted_result, *clears.iter().max().unwrap());
+
+    let result = FheUint8::min_of(&values);
+    let decrypted_result: u8 = result.decrypt(&client_key);
+    assert_eq!(decrypted_result, *clears.iter().min().unwrap());
+
+    let result = FheUint8::max_of(&values[..1]);
+    let decrypted_result: u8 = result.decrypt(&client_key);
+    assert_eq!(decrypted_result, clears[0]);
+}
+
+#[test]
+fn test_uint8_is_zero() {
+    let config = ConfigBuilder::all_disabled().enable_default_uint8().build();
+
+    let (client_key, server_key) = generate_keys(config);
+
+    set_server_key(server_key);
+
+    for clear in [0u8, 1, 16, 255] {
+        let a = FheUint8::encrypt(clear, &client_key);
+        let result = a.is_zero();
+        let decrypted_result: u8 = result.decrypt(&client_key);
+        assert_eq!(decrypted_result, u8::from(clear == 0));
+    }
+}
+
+#[test]
+fn test_uint8_neg() {
+    let config = ConfigBuilder::all_disabled().enable_default_uint8().build();
+
+    let (client_key, server_key) = generate_keys(config);
+
+    set_server_key(server_key);
+
+    for clear in [0u8, 1, 128, 255] {
+        let mut a = FheUint8::encrypt(clear, &client_key);
+
+        let result = -&a;
+        let decrypted_result: u8 = result.decrypt(&client_key);
+        assert_eq!(decrypted_result, clear.wrapping_neg());
+
+        a.neg_assign();
+        let decrypted_result: u8 = a.decrypt(&client_key);
+        assert_eq!(decrypted_result, clear.wrapping_neg());
+    }
+}
+
+#[test]
+fn test_uint8_saturating_add_sub() {
+    let config = ConfigBuilder::all_disabled().enable_default_uint8().build();
+
+    let (client_key, server_key) = generate_keys(config);
+
+    set_server_key(server_key);
+
+    for (clear_a, clear_b) in [
+        (0u8, 0u8),
+        (255, 1),
+        (1, 255),
+        (128, 127),
+        (128, 128),
+        (97, 42),
+    ] {
+        let a = FheUint8::encrypt(clear_a, &client_key);
+        let b = FheUint8::encrypt(clear_b, &client_key);
+
+        let result = a.saturating_add(&b);
+        let decrypted_result: u8 = result.decrypt(&client_key);
+        assert_eq!(decrypted_result, clear_a.saturating_add(clear_b));
+
+        let result = a.saturating_sub(&b);
+        let decrypted_result: u8 = result.decrypt(&client_key);
+        assert_eq!(decrypted_result, clear_a.saturating_sub(clear_b));
+    }
+}
+
+#[test]
+fn test_uint8_scalar_compare() {
+    let config = ConfigBuilder::all_disabled().enable_default_uint8().build();
+
+    let (client_key, server_key) = generate_keys(config);
+
+    set_server_key(server_key);
+
+    let clear_a = 27u8;
+    let a = FheUint8::encrypt(clear_a, &client_key);
+
+    for clear_b in [0u8, 26, 27, 28, 255] {
+        let result = &a.eq(clear_b);
+        let decrypted_result: u8 = result.decrypt(&client_key);
+        assert_eq!(decrypted_result, u8::from(clear_a == clear_b));
+
+        let result = &a.ne(clear_b);
+        let decrypted_result: u8 = result.decrypt(&client_key);
+        assert_eq!(decrypted_result, u8::from(clear_a != clear_b));
+
+        let result = &a.le(clear_b);
+        let decrypted_result: u8 = result.decrypt(&client_key);
+        assert_eq!(decrypted_result, u8::from(clear_a <= clear_b));
+
+        let result = &a.lt(clear_b);
+        let decrypted_result: u8 = result.decrypt(&client_key);
+        assert_eq!(decrypted_result, u8::from(clear_a < clear_b));
+
+        let result = &a.ge(clear_b);
+        let decrypted_result: u8 = result.decrypt(&client_key);
+        assert_eq!(decrypted_result, u8::from(clear_a >= clear_b));
+
+        let result = &a.gt(clear_b);
+        let decrypted_result: u8 = result.decrypt(&client_key);
+        assert_eq!(decrypted_result, u8::from(clear_a > clear_b));
+    }
+
+    // Scalars that do not fit in the encrypted type are greater than it
+    let result = &a.lt(256u64);
+    let decrypted_result: u8 = result.decrypt(&client_key);
+    assert_eq!(decrypted_result, 1);
+}
+
+#[test]
+fn test_uint8_scalar_bitwise() {
+    let config = ConfigBuilder::all_disabled().enable_default_uint8().build();
+
+    let (client_key, server_key) = generate_keys(config);
+
+    set_server_key(server_key);
+
+    let clear_a = 0b1011_0110u8;
+    let clear_mask = 0b0110_1100u8;
+
+    let mut a = FheUint8::encrypt(clear_a, &client_key);
+
+    let result = &a & clear_mask;
+    let decrypted_result: u8 = result.decrypt(&client_key);
+    assert_eq!(decrypted_result, clear_a & clear_mask);
+
+    let result = &a | clear_mask;
+    let decrypted_result: u8 = result.decrypt(&client_key);
+    assert_eq!(decrypted_result, clear_a | clear_mask);
+
+    let result = &a ^ clear_mask;
+    let decrypted_result: u8 = result.decrypt(&client_key);
+    assert_eq!(decrypted_result, clear_a ^ clear_mask);
+
+    a ^= clear_mask;
+    let decrypted_result: u8 = a.decrypt(&client_key);
+    assert_eq!(decrypted_result, clear_a ^ clear_mask);
+}
+
+#[test]
+fn test_uint8_scalar_shift_out_of_range() {
+    let config = ConfigBuilder::all_disabled().enable_default_uint8().build();
+
+    let (client_key, server_key) = generate_keys(config);
+
+    set_server_key(server_key);
+
+    let clear_a = 0b1011_0110u8;
+
+    let a = FheUint8::encrypt(clear_a, &client_key);
+
+    // Shifting by the number of bits or more shifts all the bits out
+    for shift in [8u64, 63, 64, 65, 200, u64::MAX] {
+        let result = &a << shift;
+        let decrypted_result: u8 = result.decrypt(&client_key);
+        assert_eq!(decrypted_result, 0, "left shift by {shift}");
+
+        let result = &a >> shift;
+        let decrypted_result: u8 = result.decrypt(&client_key);
+        assert_eq!(decrypted_result, 0, "right shift by {shift}");
+    }
+
+    let mut b = a.clone();
+    b <<= 64u64;
+    let decrypted_result: u8 = b.decrypt(&client_key);
+    assert_eq!(decrypted_result, 0);
+}
+
 #[test]
 fn test_integer_compressed_can_be_serialized() {
     let config = ConfigBuilder::all_disabled()
@@ -201,3 +405,149 @@ fn test_integer_compressed_public_key() {
     let clear: u8 = a.decrypt(&client_key);
     assert_eq!(clear, 213u8);
 }
+
+#[test]
+fn test_custom_integer_power_of_two_modulus() {
+    use crate::shortint::parameters::{CiphertextModulus, PARAM_MESSAGE_2_CARRY_2};
+
+    let mut block_parameters = PARAM_MESSAGE_2_CARRY_2;
+    block_parameters.ciphertext_modulus = CiphertextModulus::try_new_power_of_2(62).unwrap();
+
+    let config = ConfigBuilder::all_disabled()
+        .enable_custom_integer(block_parameters)
+        .unwrap()
+        .build();
+    let (client_key, server_key) = generate_keys(config);
+
+    set_server_key(server_key);
+
+    let clear_a = 27u8;
+    let clear_b = 240u8;
+
+    let a = FheUint8::encrypt(clear_a, &client_key);
+    let b = FheUint8::encrypt(clear_b, &client_key);
+
+    let c = a + b;
+
+    let decrypted: u8 = c.decrypt(&client_key);
+    assert_eq!(decrypted, clear_a.wrapping_add(clear_b));
+}
+
+#[test]
+fn test_custom_integer_rejects_unrepresentable_types() {
+    use crate::shortint::parameters::{PARAM_MESSAGE_3_CARRY_3, PARAM_MESSAGE_4_CARRY_4};
+
+    // 8 bits cannot be split in blocks of 3 bits
+    let err = ConfigBuilder::all_disabled()
+        .enable_custom_integer(PARAM_MESSAGE_3_CARRY_3)
+        .unwrap_err();
+    assert_eq!(err.num_bits, 8);
+
+    // 10 bits cannot be split in blocks of 4 bits
+    let err = ConfigBuilder::all_disabled()
+        .enable_custom_integer(PARAM_MESSAGE_4_CARRY_4)
+        .unwrap_err();
+    assert_eq!(err.num_bits, 10);
+}
+
+#[test]
+fn test_custom_integer_rejects_unsupported_parameters() {
+    use crate::shortint::parameters::{CiphertextModulus, PARAM_MESSAGE_2_CARRY_2};
+
+    // Only native and power of two ciphertext moduli are supported
+    let mut block_parameters = PARAM_MESSAGE_2_CARRY_2;
+    block_parameters.ciphertext_modulus = CiphertextModulus::try_new((1 << 62) - 57).unwrap();
+
+    let err = ConfigBuilder::all_disabled()
+        .enable_custom_integer(block_parameters)
+        .unwrap_err();
+    assert!(err.reason.contains("ciphertext moduli"));
+}
+
+#[test]
+fn test_integer_serialization_checks_type() {
+    let config = ConfigBuilder::all_disabled()
+        .enable_default_uint8()
+        .enable_default_uint16()
+        .build();
+    let (client_key, _) = generate_keys(config);
+
+    let a = FheUint16::encrypt(12345u16, &client_key);
+    let data = bincode::serialize(&a).unwrap();
+
+    let a: FheUint16 = bincode::deserialize(&data).unwrap();
+    let clear: u16 = a.decrypt(&client_key);
+    assert_eq!(clear, 12345u16);
+
+    // Mismatched bit width
+    let err = bincode::deserialize::<FheUint8>(&data).err().unwrap();
+    assert!(err.to_string().contains("mismatched integer bit width"));
+
+    // Regular ciphertext deserialized as a compressed one
+    let err = bincode::deserialize::<CompressedFheUint16>(&data)
+        .err()
+        .unwrap();
+    assert!(err.to_string().contains("mismatched integer kind"));
+
+    let compressed = CompressedFheUint16::try_encrypt(54321u16, &client_key).unwrap();
+    let data = bincode::serialize(&compressed).unwrap();
+
+    let compressed: CompressedFheUint16 = bincode::deserialize(&data).unwrap();
+    let a = FheUint16::from(compressed);
+    let clear: u16 = a.decrypt(&client_key);
+    assert_eq!(clear, 54321u16);
+
+    let err = bincode::deserialize::<FheUint16>(&data).err().unwrap();
+    assert!(err.to_string().contains("mismatched integer kind"));
+}
+
+#[test]
+fn test_generic_fhe_integer() {
+    use crate::high_level_api::traits::FheInteger;
+
+    fn clamped_sum<T: FheInteger>(a: &T, b: &T, bound: &T) -> T {
+        let sum = a.clone() + b;
+        FheInteger::min(&sum, bound)
+    }
+
+    let config = ConfigBuilder::all_disabled()
+        .enable_default_uint8()
+        .enable_default_uint16()
+        .build();
+    let (client_key, server_key) = generate_keys(config);
+    set_server_key(server_key);
+
+    assert_eq!(<FheUint8 as FheInteger>::NUM_BITS, 8);
+    assert_eq!(<FheUint16 as FheInteger>::NUM_BITS, 16);
+
+    let a = FheUint8::try_encrypt(20u8, &client_key).unwrap();
+    let b = FheUint8::try_encrypt(30u8, &client_key).unwrap();
+    let bound = FheUint8::try_encrypt(42u8, &client_key).unwrap();
+    let clear: u8 = clamped_sum(&a, &b, &bound).decrypt(&client_key);
+    assert_eq!(clear, 42u8);
+
+    let a = FheUint16::try_encrypt(1000u16, &client_key).unwrap();
+    let b = FheUint16::try_encrypt(2000u16, &client_key).unwrap();
+    let bound = FheUint16::try_encrypt(5000u16, &client_key).unwrap();
+    let clear: u16 = clamped_sum(&a, &b, &bound).decrypt(&client_key);
+    assert_eq!(clear, 3000u16);
+}
+
+#[test]
+fn test_integer_encrypt_decrypt_many() {
+    let config = ConfigBuilder::all_disabled().enable_default_uint8().build();
+    let (client_key, _server_key) = generate_keys(config);
+
+    let clears = [0u8, 1, 27, 128, 255];
+    let cts = FheUint8::try_encrypt_many(&clears, &client_key).unwrap();
+    assert_eq!(cts.len(), clears.len());
+
+    let decrypted: Vec<u8> = FheUint8::decrypt_many(&cts, &client_key);
+    assert_eq!(decrypted, clears);
+
+    let cts = FheUint8::try_encrypt_many::<u8>(&[], &client_key).unwrap();
+    assert!(cts.is_empty());
+
+    // The type was not enabled in the config
+    assert!(FheUint16::try_encrypt_many(&[1u16], &client_key).is_err());
+}
@@ -13,7 +13,9 @@ use crate::high_level_api::integers::public_key::GenericIntegerPublicKey;
 use crate::high_level_api::integers::server_key::{
     GenericIntegerServerKey, RadixCiphertextDyn, SmartAdd, SmartAddAssign, SmartBitAnd,
     SmartBitAndAssign, SmartBitOr, SmartBitOrAssign, SmartBitXor, SmartBitXorAssign, SmartEq,
-    SmartGe, SmartGt, SmartLe, SmartLt, SmartMax, SmartMin, SmartMul, SmartMulAssign, SmartNeg,
+    SmartGe, SmartGt, SmartIlog2, SmartInRange, SmartIsZero, SmartLe, SmartLt, SmartMax,
+    SmartMaxOfMany, SmartMin, SmartMinOfMany, SmartMul, SmartMulAssign, SmartNe, SmartNeg,
+    SmartNegAssign, SmartParity, SmartReverseBits, SmartSaturatingAdd, SmartSaturatingSub,
     SmartShl, SmartShlAssign, SmartShr, SmartShrAssign, SmartSub, SmartSubAssign,
 };
 use crate::high_level_api::internal_traits::{DecryptionKey, EncryptionKey};
@@ -45,6 +47,9 @@ use crate::integer::U256;
 /// since the `GenericInteger` type is not `Copy` the operators are also overloaded
 /// to work with references.
 ///
+/// Serialized integers start with a header recording their bit width and whether they are
+/// compressed, deserializing them as a different integer type returns an error.
+///
 ///
 /// To be able to use this type, the cargo feature `integers` must be enabled,
 /// and your config should also enable the type with either default parameters or custom ones.
@@ -54,7 +59,7 @@ use crate::integer::U256;
 /// [FheUint12]: crate::high_level_api::FheUint12
 /// [FheUint16]: crate::high_level_api::FheUint16
 #[cfg_attr(all(doc, not(doctest)), doc(cfg(feature = "integer")))]
-#[derive(Clone, serde::Deserialize, serde::Serialize)]
+#[derive(Clone)]
 pub struct GenericInteger<P: IntegerParameter> {
     pub(in crate::high_level_api::integers) ciphertext: RefCell<P::InnerCiphertext>,
     pub(in crate::high_level_api::integers) id: P::Id,
@@ -166,6 +171,72 @@ where
     }
 }
 
+impl<P> GenericInteger<P>
+where
+    P: IntegerParameter,
+    P::Id: RefKeyFromKeyChain<Key = GenericIntegerClientKey<P>> + Default,
+    P::InnerClientKey: EncryptionKey<U256, P::InnerCiphertext>,
+{
+    /// Encrypts each value of the slice, in order.
+    ///
+    /// The key for this integer type is looked up once for the whole slice, and an error is
+    /// returned if it was not enabled in the config.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, ConfigBuilder, FheUint8};
+    ///
+    /// let config = ConfigBuilder::all_disabled().enable_default_uint8().build();
+    /// let (client_key, _server_key) = generate_keys(config);
+    ///
+    /// let clears = [1u8, 2, 3, 255];
+    /// let cts = FheUint8::try_encrypt_many(&clears, &client_key)?;
+    ///
+    /// let decrypted: Vec<u8> = FheUint8::decrypt_many(&cts, &client_key);
+    /// assert_eq!(decrypted, clears);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn try_encrypt_many<T>(
+        values: &[T],
+        key: &ClientKey,
+    ) -> Result<Vec<Self>, crate::high_level_api::errors::Error>
+    where
+        T: Into<U256> + Copy,
+    {
+        let id = P::Id::default();
+        let key = id.ref_key(key)?;
+        Ok(values
+            .iter()
+            .map(|&value| Self::new(key.inner.encrypt(value.into()), id))
+            .collect())
+    }
+}
+
+impl<P> GenericInteger<P>
+where
+    P: IntegerParameter,
+{
+    /// Decrypts each ciphertext of the slice, in order.
+    ///
+    /// See [`try_encrypt_many`](Self::try_encrypt_many) for an example.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the client key does not have the key for this integer type.
+    #[track_caller]
+    pub fn decrypt_many<ClearType>(ciphertexts: &[Self], key: &ClientKey) -> Vec<ClearType>
+    where
+        Self: FheDecrypt<ClearType>,
+    {
+        ciphertexts
+            .iter()
+            .map(|ciphertext| ciphertext.decrypt(key))
+            .collect()
+    }
+}
+
 impl<P> GenericInteger<P>
 where
     P: IntegerParameter,
@@ -230,6 +301,423 @@ where
     }
 }
 
+impl<P> GenericInteger<P>
+where
+    P: IntegerParameter,
+    P::Id: WithGlobalKey<Key = GenericIntegerServerKey<P>>,
+    P::InnerServerKey: for<'a> SmartIsZero<&'a mut P::InnerCiphertext, Output = P::InnerCiphertext>,
+{
+    /// Homomorphically computes whether the integer is zero.
+    ///
+    /// The result is an encryption of 1 if the integer is zero and 0 otherwise, it is cheaper
+    /// than comparing the integer to the scalar 0 with [`FheEq::eq`].
+    ///
+    /// # Example
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint8};
+    ///
+    /// let config = ConfigBuilder::all_disabled().enable_default_uint8().build();
+    /// let (client_key, server_key) = generate_keys(config);
+    ///
+    /// let a = FheUint8::encrypt(42u8, &client_key);
+    /// let b = FheUint8::encrypt(42u8, &client_key);
+    ///
+    /// set_server_key(server_key);
+    ///
+    /// let fhe_result = (&a - &b).is_zero();
+    ///
+    /// let clear_result: u8 = fhe_result.decrypt(&client_key);
+    /// assert_eq!(clear_result, 1);
+    /// ```
+    pub fn is_zero(&self) -> Self {
+        let inner_result = self.id.with_unwrapped_global(|server_key| {
+            server_key
+                .inner
+                .smart_is_zero(&mut self.ciphertext.borrow_mut())
+        });
+
+        GenericInteger::new(inner_result, self.id)
+    }
+}
+
+impl<P> GenericInteger<P>
+where
+    P: IntegerParameter,
+    P::Id: WithGlobalKey<Key = GenericIntegerServerKey<P>>,
+    P::InnerServerKey:
+        for<'a> SmartReverseBits<&'a mut P::InnerCiphertext, Output = P::InnerCiphertext>,
+{
+    /// Homomorphically reverses the order of the bits of the integer.
+    ///
+    /// The bits are reversed within the full width of the underlying radix ciphertext, which is
+    /// the number of bits of the type for the default parameters.
+    ///
+    /// # Example
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint8};
+    ///
+    /// let config = ConfigBuilder::all_disabled().enable_default_uint8().build();
+    /// let (client_key, server_key) = generate_keys(config);
+    ///
+    /// let a = FheUint8::encrypt(0b0001_1011u8, &client_key);
+    ///
+    /// set_server_key(server_key);
+    ///
+    /// let fhe_result = a.reverse_bits();
+    ///
+    /// let clear_result: u8 = fhe_result.decrypt(&client_key);
+    /// assert_eq!(clear_result, 0b0001_1011u8.reverse_bits());
+    /// ```
+    pub fn reverse_bits(&self) -> Self {
+        let inner_result = self.id.with_unwrapped_global(|server_key| {
+            server_key
+                .inner
+                .smart_reverse_bits(&mut self.ciphertext.borrow_mut())
+        });
+
+        GenericInteger::new(inner_result, self.id)
+    }
+}
+
+impl<P> GenericInteger<P>
+where
+    P: IntegerParameter,
+    P::Id: WithGlobalKey<Key = GenericIntegerServerKey<P>>,
+    P::InnerServerKey: for<'a> SmartIlog2<&'a mut P::InnerCiphertext, Output = P::InnerCiphertext>,
+{
+    /// Homomorphically computes the base 2 logarithm of the integer, rounded down, i.e. the
+    /// position of its most significant set bit.
+    ///
+    /// Contrary to the clear `ilog2`, this does not panic on 0: the logarithm of 0 is defined as
+    /// 0, the same result as for 1. [`is_zero`](Self::is_zero) can be used to tell them apart.
+    ///
+    /// # Example
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint8};
+    ///
+    /// let config = ConfigBuilder::all_disabled().enable_default_uint8().build();
+    /// let (client_key, server_key) = generate_keys(config);
+    ///
+    /// let a = FheUint8::encrypt(42u8, &client_key);
+    /// let zero = FheUint8::encrypt(0u8, &client_key);
+    ///
+    /// set_server_key(server_key);
+    ///
+    /// let fhe_result = a.ilog2();
+    /// let clear_result: u8 = fhe_result.decrypt(&client_key);
+    /// assert_eq!(u32::from(clear_result), 42u8.ilog2());
+    ///
+    /// let fhe_result = zero.ilog2();
+    /// let clear_result: u8 = fhe_result.decrypt(&client_key);
+    /// assert_eq!(clear_result, 0);
+    /// ```
+    pub fn ilog2(&self) -> Self {
+        let inner_result = self.id.with_unwrapped_global(|server_key| {
+            server_key
+                .inner
+                .smart_ilog2(&mut self.ciphertext.borrow_mut())
+        });
+
+        GenericInteger::new(inner_result, self.id)
+    }
+}
+
+impl<P> GenericInteger<P>
+where
+    P: IntegerParameter,
+    P::Id: WithGlobalKey<Key = GenericIntegerServerKey<P>>,
+    P::InnerServerKey: for<'a> SmartParity<&'a mut P::InnerCiphertext, Output = P::InnerCiphertext>,
+{
+    /// Homomorphically computes the parity of the integer, i.e. the XOR of all its bits.
+    ///
+    /// The result is 1 if the integer has an odd number of set bits, 0 otherwise.
+    ///
+    /// # Example
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint8};
+    ///
+    /// let config = ConfigBuilder::all_disabled().enable_default_uint8().build();
+    /// let (client_key, server_key) = generate_keys(config);
+    ///
+    /// let a = FheUint8::encrypt(0b0101_1011u8, &client_key);
+    ///
+    /// set_server_key(server_key);
+    ///
+    /// let fhe_result = a.parity();
+    ///
+    /// let clear_result: u8 = fhe_result.decrypt(&client_key);
+    /// assert_eq!(u32::from(clear_result), 0b0101_1011u8.count_ones() & 1);
+    /// ```
+    pub fn parity(&self) -> Self {
+        let inner_result = self.id.with_unwrapped_global(|server_key| {
+            server_key
+                .inner
+                .smart_parity(&mut self.ciphertext.borrow_mut())
+        });
+
+        GenericInteger::new(inner_result, self.id)
+    }
+}
+
+impl<P> GenericInteger<P>
+where
+    P: IntegerParameter,
+    P::Id: WithGlobalKey<Key = GenericIntegerServerKey<P>>,
+    P::InnerServerKey:
+        for<'a> SmartInRange<&'a mut P::InnerCiphertext, Output = P::InnerCiphertext>,
+{
+    /// Homomorphically computes whether the integer is in the range `[lo, hi]`, i.e. whether
+    /// `lo <= self <= hi`, both bounds being inclusive.
+    ///
+    /// The result is an encryption of 1 if the integer is in the range and 0 otherwise. An empty
+    /// range, i.e. `lo > hi`, always gives an encryption of 0.
+    ///
+    /// This is cheaper than computing the two comparisons with [`FheOrd`] and combining them
+    /// with a bitwise AND.
+    ///
+    /// # Example
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint8};
+    ///
+    /// let config = ConfigBuilder::all_disabled().enable_default_uint8().build();
+    /// let (client_key, server_key) = generate_keys(config);
+    ///
+    /// let a = FheUint8::encrypt(42u8, &client_key);
+    ///
+    /// set_server_key(server_key);
+    ///
+    /// let fhe_result = a.is_in_range(10, 42);
+    /// let clear_result: u8 = fhe_result.decrypt(&client_key);
+    /// assert_eq!(clear_result, 1);
+    ///
+    /// let fhe_result = a.is_in_range(43, 100);
+    /// let clear_result: u8 = fhe_result.decrypt(&client_key);
+    /// assert_eq!(clear_result, 0);
+    /// ```
+    pub fn is_in_range(&self, lo: u64, hi: u64) -> Self {
+        let inner_result = self.id.with_unwrapped_global(|server_key| {
+            server_key
+                .inner
+                .smart_in_range(&mut self.ciphertext.borrow_mut(), lo, hi)
+        });
+
+        GenericInteger::new(inner_result, self.id)
+    }
+}
+
+impl<P> GenericInteger<P>
+where
+    P: IntegerParameter,
+    GenericInteger<P>: Clone,
+    P::Id: WithGlobalKey<Key = GenericIntegerServerKey<P>>,
+    P::InnerServerKey: for<'a> SmartSaturatingAdd<
+            &'a mut P::InnerCiphertext,
+            &'a mut P::InnerCiphertext,
+            Output = P::InnerCiphertext,
+        > + for<'a> SmartSaturatingSub<
+            &'a mut P::InnerCiphertext,
+            &'a mut P::InnerCiphertext,
+            Output = P::InnerCiphertext,
+        >,
+{
+    /// Homomorphically computes the addition of the integers, saturating at the maximum value
+    /// instead of wrapping around, like Rust's `saturating_add`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint8};
+    ///
+    /// let config = ConfigBuilder::all_disabled().enable_default_uint8().build();
+    /// let (client_key, server_key) = generate_keys(config);
+    ///
+    /// let a = FheUint8::encrypt(200u8, &client_key);
+    /// let b = FheUint8::encrypt(97u8, &client_key);
+    ///
+    /// set_server_key(server_key);
+    ///
+    /// let fhe_result = a.saturating_add(&b);
+    ///
+    /// let clear_result: u8 = fhe_result.decrypt(&client_key);
+    /// assert_eq!(clear_result, u8::MAX);
+    /// ```
+    pub fn saturating_add(&self, rhs: &Self) -> Self {
+        let inner_result = self.id.with_unwrapped_global(|server_key| {
+            if std::ptr::eq(self, rhs) {
+                let cloned = (*rhs).clone();
+                let r = server_key.inner.smart_saturating_add(
+                    &mut self.ciphertext.borrow_mut(),
+                    &mut cloned.ciphertext.borrow_mut(),
+                );
+                r
+            } else {
+                server_key.inner.smart_saturating_add(
+                    &mut self.ciphertext.borrow_mut(),
+                    &mut rhs.ciphertext.borrow_mut(),
+                )
+            }
+        });
+
+        GenericInteger::new(inner_result, self.id)
+    }
+
+    /// Homomorphically computes the subtraction of the integers, saturating at zero instead of
+    /// wrapping around, like Rust's `saturating_sub`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint8};
+    ///
+    /// let config = ConfigBuilder::all_disabled().enable_default_uint8().build();
+    /// let (client_key, server_key) = generate_keys(config);
+    ///
+    /// let a = FheUint8::encrypt(42u8, &client_key);
+    /// let b = FheUint8::encrypt(97u8, &client_key);
+    ///
+    /// set_server_key(server_key);
+    ///
+    /// let fhe_result = a.saturating_sub(&b);
+    ///
+    /// let clear_result: u8 = fhe_result.decrypt(&client_key);
+    /// assert_eq!(clear_result, 0);
+    /// ```
+    pub fn saturating_sub(&self, rhs: &Self) -> Self {
+        let inner_result = self.id.with_unwrapped_global(|server_key| {
+            if std::ptr::eq(self, rhs) {
+                let cloned = (*rhs).clone();
+                let r = server_key.inner.smart_saturating_sub(
+                    &mut self.ciphertext.borrow_mut(),
+                    &mut cloned.ciphertext.borrow_mut(),
+                );
+                r
+            } else {
+                server_key.inner.smart_saturating_sub(
+                    &mut self.ciphertext.borrow_mut(),
+                    &mut rhs.ciphertext.borrow_mut(),
+                )
+            }
+        });
+
+        GenericInteger::new(inner_result, self.id)
+    }
+}
+
+impl<P> GenericInteger<P>
+where
+    P: IntegerParameter,
+    P::InnerCiphertext: Clone,
+    P::Id: WithGlobalKey<Key = GenericIntegerServerKey<P>>,
+    P::InnerServerKey: SmartMaxOfMany<P::InnerCiphertext> + SmartMinOfMany<P::InnerCiphertext>,
+{
+    /// Homomorphically computes the maximum of all the integers.
+    ///
+    /// The integers are reduced pairwise in a balanced tournament, which keeps the depth of the
+    /// computation logarithmic in the number of integers.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the iterator is empty.
+    ///
+    /// # Example
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint8};
+    ///
+    /// let config = ConfigBuilder::all_disabled().enable_default_uint8().build();
+    /// let (client_key, server_key) = generate_keys(config);
+    ///
+    /// let values = [97u8, 3, 164, 42, 12]
+    ///     .iter()
+    ///     .map(|&v| FheUint8::encrypt(v, &client_key))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// set_server_key(server_key);
+    ///
+    /// let fhe_result = FheUint8::max_of(&values);
+    ///
+    /// let clear_result: u8 = fhe_result.decrypt(&client_key);
+    /// assert_eq!(clear_result, 164);
+    /// ```
+    pub fn max_of<I, B>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = B>,
+        B: Borrow<Self>,
+    {
+        let (id, cts) = clone_ciphertexts(iter, "Cannot compute the maximum of an empty iterator");
+        let inner_result =
+            id.with_unwrapped_global(|server_key| server_key.inner.smart_max_of_many(&cts));
+
+        GenericInteger::new(inner_result, id)
+    }
+
+    /// Homomorphically computes the minimum of all the integers.
+    ///
+    /// The integers are reduced pairwise in a balanced tournament, which keeps the depth of the
+    /// computation logarithmic in the number of integers.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the iterator is empty.
+    ///
+    /// # Example
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint8};
+    ///
+    /// let config = ConfigBuilder::all_disabled().enable_default_uint8().build();
+    /// let (client_key, server_key) = generate_keys(config);
+    ///
+    /// let values = [97u8, 3, 164, 42, 12]
+    ///     .iter()
+    ///     .map(|&v| FheUint8::encrypt(v, &client_key))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// set_server_key(server_key);
+    ///
+    /// let fhe_result = FheUint8::min_of(&values);
+    ///
+    /// let clear_result: u8 = fhe_result.decrypt(&client_key);
+    /// assert_eq!(clear_result, 3);
+    /// ```
+    pub fn min_of<I, B>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = B>,
+        B: Borrow<Self>,
+    {
+        let (id, cts) = clone_ciphertexts(iter, "Cannot compute the minimum of an empty iterator");
+        let inner_result =
+            id.with_unwrapped_global(|server_key| server_key.inner.smart_min_of_many(&cts));
+
+        GenericInteger::new(inner_result, id)
+    }
+}
+
+/// Clones the ciphertexts of the non-empty integers, returning them with the id of the type.
+fn clone_ciphertexts<P, I, B>(iter: I, empty_msg: &str) -> (P::Id, Vec<P::InnerCiphertext>)
+where
+    P: IntegerParameter,
+    P::InnerCiphertext: Clone,
+    I: IntoIterator<Item = B>,
+    B: Borrow<GenericInteger<P>>,
+{
+    let mut id = None;
+    let cts = iter
+        .into_iter()
+        .map(|value| {
+            let value = value.borrow();
+            id = Some(value.id);
+            value.ciphertext.borrow().clone()
+        })
+        .collect::<Vec<_>>();
+    (id.expect(empty_msg), cts)
+}
+
 impl<P, B> FheEq<B> for GenericInteger<P>
 where
     B: Borrow<GenericInteger<P>>,
@@ -237,10 +725,14 @@ where
     GenericInteger<P>: Clone,
     P::Id: WithGlobalKey<Key = GenericIntegerServerKey<P>>,
     P::InnerServerKey: for<'a> SmartEq<
-        &'a mut P::InnerCiphertext,
-        &'a mut P::InnerCiphertext,
-        Output = P::InnerCiphertext,
-    >,
+            &'a mut P::InnerCiphertext,
+            &'a mut P::InnerCiphertext,
+            Output = P::InnerCiphertext,
+        > + for<'a> SmartNe<
+            &'a mut P::InnerCiphertext,
+            &'a mut P::InnerCiphertext,
+            Output = P::InnerCiphertext,
+        >,
 {
     type Output = Self;
 
@@ -264,6 +756,27 @@ where
 
         GenericInteger::new(inner_result, self.id)
     }
+
+    fn ne(&self, rhs: B) -> Self::Output {
+        let inner_result = self.id.with_unwrapped_global(|server_key| {
+            let borrowed = rhs.borrow();
+            if std::ptr::eq(self, borrowed) {
+                let cloned = (*borrowed).clone();
+                let r = server_key.inner.smart_ne(
+                    &mut self.ciphertext.borrow_mut(),
+                    &mut cloned.ciphertext.borrow_mut(),
+                );
+                r
+            } else {
+                server_key.inner.smart_ne(
+                    &mut self.ciphertext.borrow_mut(),
+                    &mut borrowed.ciphertext.borrow_mut(),
+                )
+            }
+        });
+
+        GenericInteger::new(inner_result, self.id)
+    }
 }
 
 impl<P, B> FheOrd<B> for GenericInteger<P>
@@ -391,7 +904,7 @@ where
         use crate::high_level_api::integers::server_key::WopbsEvaluationKey;
         self.id.with_unwrapped_global(|key| {
             let ct = self.ciphertext.borrow();
-            let res = key.wopbs_key.apply_wopbs(&key.inner, &ct, func);
+            let res = key.wopbs_key().apply_wopbs(&key.inner, &ct, func);
             GenericInteger::<P>::new(res, self.id)
         })
     }
@@ -421,7 +934,7 @@ where
             let lhs = self.ciphertext.borrow();
             let rhs = other.ciphertext.borrow();
             let res = key
-                .wopbs_key
+                .wopbs_key()
                 .apply_bivariate_wopbs(&key.inner, &lhs, &rhs, func);
             GenericInteger::<P>::new(res, self.id)
         })
@@ -645,12 +1158,126 @@ generic_integer_impl_scalar_operation!(Sub(sub, SmartSub) => smart_sub(u8, u16,
 generic_integer_impl_scalar_operation!(Mul(mul, SmartMul) => smart_mul(u8, u16, u32, u64));
 generic_integer_impl_scalar_operation!(Shl(shl, SmartShl) => smart_shl(u8, u16, u32, u64));
 generic_integer_impl_scalar_operation!(Shr(shr, SmartShr) => smart_shr(u8, u16, u32, u64));
+generic_integer_impl_scalar_operation!(BitAnd(bitand, SmartBitAnd) => smart_bitand(u8, u16, u32, u64));
+generic_integer_impl_scalar_operation!(BitOr(bitor, SmartBitOr) => smart_bitor(u8, u16, u32, u64));
+generic_integer_impl_scalar_operation!(BitXor(bitxor, SmartBitXor) => smart_bitxor(u8, u16, u32, u64));
 
 generic_integer_impl_scalar_operation_assign!(AddAssign(add_assign, SmartAddAssign) => smart_add_assign(u8, u16, u32, u64));
 generic_integer_impl_scalar_operation_assign!(SubAssign(sub_assign, SmartSubAssign) => smart_sub_assign(u8, u16, u32, u64));
 generic_integer_impl_scalar_operation_assign!(MulAssign(mul_assign, SmartMulAssign) => smart_mul_assign(u8, u16, u32, u64));
 generic_integer_impl_scalar_operation_assign!(ShlAssign(shl_assign, SmartShlAssign) => smart_shl_assign(u8, u16, u32, u64));
 generic_integer_impl_scalar_operation_assign!(ShrAssign(shr_assign, SmartShrAssign) => smart_shr_assign(u8, u16, u32, u64));
+generic_integer_impl_scalar_operation_assign!(BitAndAssign(bitand_assign, SmartBitAndAssign) => smart_bitand_assign(u8, u16, u32, u64));
+generic_integer_impl_scalar_operation_assign!(BitOrAssign(bitor_assign, SmartBitOrAssign) => smart_bitor_assign(u8, u16, u32, u64));
+generic_integer_impl_scalar_operation_assign!(BitXorAssign(bitxor_assign, SmartBitXorAssign) => smart_bitxor_assign(u8, u16, u32, u64));
+
+macro_rules! generic_integer_impl_scalar_comparison {
+    ($($scalar_type:ty),*) => {
+        $(
+            impl<P> FheEq<$scalar_type> for GenericInteger<P>
+            where
+                P: IntegerParameter,
+                P::Id: WithGlobalKey<Key=GenericIntegerServerKey<P>>,
+                P::InnerServerKey: for<'a> SmartEq<
+                                            &'a mut P::InnerCiphertext,
+                                            u64,
+                                            Output=P::InnerCiphertext>
+                                 + for<'a> SmartNe<
+                                            &'a mut P::InnerCiphertext,
+                                            u64,
+                                            Output=P::InnerCiphertext>,
+            {
+                type Output = Self;
+
+                fn eq(&self, rhs: $scalar_type) -> Self::Output {
+                    let inner_result = self.id.with_unwrapped_global(|server_key| {
+                        server_key
+                            .inner
+                            .smart_eq(&mut self.ciphertext.borrow_mut(), u64::from(rhs))
+                    });
+
+                    GenericInteger::new(inner_result, self.id)
+                }
+
+                fn ne(&self, rhs: $scalar_type) -> Self::Output {
+                    let inner_result = self.id.with_unwrapped_global(|server_key| {
+                        server_key
+                            .inner
+                            .smart_ne(&mut self.ciphertext.borrow_mut(), u64::from(rhs))
+                    });
+
+                    GenericInteger::new(inner_result, self.id)
+                }
+            }
+
+            impl<P> FheOrd<$scalar_type> for GenericInteger<P>
+            where
+                P: IntegerParameter,
+                P::Id: WithGlobalKey<Key=GenericIntegerServerKey<P>>,
+                P::InnerServerKey: for<'a> SmartGe<
+                                            &'a mut P::InnerCiphertext,
+                                            u64,
+                                            Output=P::InnerCiphertext>
+                                 + for<'a> SmartGt<
+                                            &'a mut P::InnerCiphertext,
+                                            u64,
+                                            Output=P::InnerCiphertext>
+                                 + for<'a> SmartLe<
+                                            &'a mut P::InnerCiphertext,
+                                            u64,
+                                            Output=P::InnerCiphertext>
+                                 + for<'a> SmartLt<
+                                            &'a mut P::InnerCiphertext,
+                                            u64,
+                                            Output=P::InnerCiphertext>,
+            {
+                type Output = Self;
+
+                fn lt(&self, rhs: $scalar_type) -> Self::Output {
+                    let inner_result = self.id.with_unwrapped_global(|server_key| {
+                        server_key
+                            .inner
+                            .smart_lt(&mut self.ciphertext.borrow_mut(), u64::from(rhs))
+                    });
+
+                    GenericInteger::new(inner_result, self.id)
+                }
+
+                fn le(&self, rhs: $scalar_type) -> Self::Output {
+                    let inner_result = self.id.with_unwrapped_global(|server_key| {
+                        server_key
+                            .inner
+                            .smart_le(&mut self.ciphertext.borrow_mut(), u64::from(rhs))
+                    });
+
+                    GenericInteger::new(inner_result, self.id)
+                }
+
+                fn gt(&self, rhs: $scalar_type) -> Self::Output {
+                    let inner_result = self.id.with_unwrapped_global(|server_key| {
+                        server_key
+                            .inner
+                            .smart_gt(&mut self.ciphertext.borrow_mut(), u64::from(rhs))
+                    });
+
+                    GenericInteger::new(inner_result, self.id)
+                }
+
+                fn ge(&self, rhs: $scalar_type) -> Self::Output {
+                    let inner_result = self.id.with_unwrapped_global(|server_key| {
+                        server_key
+                            .inner
+                            .smart_ge(&mut self.ciphertext.borrow_mut(), u64::from(rhs))
+                    });
+
+                    GenericInteger::new(inner_result, self.id)
+                }
+            }
+        )*
+    };
+}
+
+generic_integer_impl_scalar_comparison!(u8, u16, u32, u64);
 
 impl<P> Neg for GenericInteger<P>
 where
@@ -682,3 +1309,36 @@ where
         GenericInteger::<P>::new(ciphertext, self.id)
     }
 }
+
+impl<P> GenericInteger<P>
+where
+    P: IntegerParameter,
+    P::Id: WithGlobalKey<Key = GenericIntegerServerKey<P>>,
+    P::InnerServerKey: for<'a> SmartNegAssign<&'a mut P::InnerCiphertext>,
+{
+    /// Homomorphically negates the integer in place, i.e. computes `self = -self` modulo the
+    /// integer modulus, without creating a new integer.
+    ///
+    /// # Example
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint8};
+    ///
+    /// let config = ConfigBuilder::all_disabled().enable_default_uint8().build();
+    /// let (client_key, server_key) = generate_keys(config);
+    ///
+    /// let mut a = FheUint8::encrypt(42u8, &client_key);
+    ///
+    /// set_server_key(server_key);
+    ///
+    /// a.neg_assign();
+    ///
+    /// let clear_result: u8 = a.decrypt(&client_key);
+    /// assert_eq!(clear_result, 42u8.wrapping_neg());
+    /// ```
+    pub fn neg_assign(&mut self) {
+        let ciphertext = self.ciphertext.get_mut();
+        self.id
+            .with_unwrapped_global(|key| key.inner.smart_neg_assign(ciphertext));
+    }
+}
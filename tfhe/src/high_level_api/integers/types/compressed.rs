@@ -23,7 +23,7 @@ impl From<CompressedRadixCiphertextDyn> for RadixCiphertextDyn {
     }
 }
 
-#[derive(Clone, serde::Serialize, serde::Deserialize)]
+#[derive(Clone)]
 pub struct CompressedGenericInteger<P>
 where
     P: IntegerParameter,
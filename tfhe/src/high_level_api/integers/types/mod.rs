@@ -8,4 +8,5 @@ pub use static_::{
 
 pub(super) mod base;
 pub(super) mod compressed;
+mod serialization;
 pub(super) mod static_;
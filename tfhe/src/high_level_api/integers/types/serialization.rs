@@ -0,0 +1,120 @@
+//! Self-describing serialization of the integer types.
+//!
+//! The serialized ciphertexts start with a header recording the bit width of the type and whether
+//! the ciphertext is compressed, so that deserializing into the wrong type is an error instead of
+//! silently producing a corrupted value.
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::high_level_api::integers::parameters::StaticIntegerParameter;
+use crate::high_level_api::integers::types::base::GenericInteger;
+use crate::high_level_api::integers::types::compressed::{
+    CompressedGenericInteger, CompressedRadixCiphertextDyn,
+};
+use crate::high_level_api::versioned::VersionedFormat;
+
+/// Serialization format of the integer types
+const INTEGER_FORMAT: VersionedFormat = VersionedFormat {
+    name: "integer",
+    description: "an integer ciphertext",
+    magic: *b"TFHI",
+    version: 1,
+};
+
+/// Metadata serialized before the ciphertext, describing the type it was serialized from.
+#[derive(Serialize, Deserialize)]
+struct IntegerMetadata {
+    num_bits: u32,
+    compressed: bool,
+}
+
+impl IntegerMetadata {
+    fn new(num_bits: usize, compressed: bool) -> Self {
+        Self {
+            num_bits: num_bits as u32,
+            compressed,
+        }
+    }
+
+    fn validate(&self, num_bits: usize, compressed: bool) -> Result<(), String> {
+        if self.num_bits as usize != num_bits {
+            return Err(format!(
+                "mismatched integer bit width: the data holds a {} bits integer, \
+                the target type is a {num_bits} bits integer",
+                self.num_bits
+            ));
+        }
+
+        if self.compressed != compressed {
+            let describe = |compressed| if compressed { "compressed" } else { "regular" };
+            return Err(format!(
+                "mismatched integer kind: the data holds a {} integer, \
+                the target type is a {} integer",
+                describe(self.compressed),
+                describe(compressed)
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+impl<P> Serialize for GenericInteger<P>
+where
+    P: StaticIntegerParameter,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let metadata = IntegerMetadata::new(P::MESSAGE_BITS, false);
+        INTEGER_FORMAT.serialize(metadata, &*self.ciphertext.borrow(), serializer)
+    }
+}
+
+impl<'de, P> Deserialize<'de> for GenericInteger<P>
+where
+    P: StaticIntegerParameter,
+    P::Id: Default,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        INTEGER_FORMAT
+            .deserialize(deserializer, |metadata: IntegerMetadata| {
+                metadata.validate(P::MESSAGE_BITS, false)
+            })
+            .map(|ciphertext| Self::new(ciphertext, P::Id::default()))
+    }
+}
+
+impl<P> Serialize for CompressedGenericInteger<P>
+where
+    P: StaticIntegerParameter,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let metadata = IntegerMetadata::new(P::MESSAGE_BITS, true);
+        INTEGER_FORMAT.serialize(metadata, &self.ciphertext, serializer)
+    }
+}
+
+impl<'de, P> Deserialize<'de> for CompressedGenericInteger<P>
+where
+    P: StaticIntegerParameter,
+    P::Id: Default,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        INTEGER_FORMAT
+            .deserialize(deserializer, |metadata: IntegerMetadata| {
+                metadata.validate(P::MESSAGE_BITS, true)
+            })
+            .map(|ciphertext: CompressedRadixCiphertextDyn| Self::new(ciphertext, P::Id::default()))
+    }
+}
@@ -10,12 +10,14 @@ use crate::high_level_api::integers::public_key::GenericIntegerPublicKey;
 use crate::high_level_api::integers::server_key::GenericIntegerServerKey;
 use crate::high_level_api::integers::types::compressed::CompressedGenericInteger;
 use crate::high_level_api::keys::RefKeyFromKeyChain;
-use crate::high_level_api::traits::{FheDecrypt, FheEncrypt};
-use crate::high_level_api::ClientKey;
+use crate::high_level_api::traits::{FheDecrypt, FheEncrypt, FheInteger};
+use crate::high_level_api::{ClientKey, UnsupportedIntegerParameters};
 
 use super::base::GenericInteger;
 use crate::high_level_api::internal_traits::{DecryptionKey, EncryptionKey, ParameterType};
 #[cfg(feature = "internal-keycache")]
+use crate::high_level_api::keys::key_cache_enabled;
+#[cfg(feature = "internal-keycache")]
 use crate::integer::keycache::{KEY_CACHE, KEY_CACHE_WOPBS};
 use crate::integer::wopbs::WopbsKey;
 use paste::paste;
@@ -67,6 +69,35 @@ macro_rules! define_static_integer_parameters {
                         }
                     )
                 }
+
+                /// Creates parameters using custom block parameters, for example with a power of
+                /// two ciphertext modulus.
+                ///
+                /// The number of blocks is chosen for the blocks to hold the bits of the type, an
+                /// error is returned if they cannot hold exactly these bits or if the block
+                /// parameters are not supported.
+                pub fn custom(
+                    block_parameters: crate::shortint::Parameters,
+                ) -> Result<Self, UnsupportedIntegerParameters> {
+                    let block_bits = block_parameters
+                        .message_modulus
+                        .0
+                        .checked_ilog2()
+                        .unwrap_or(0) as usize;
+                    let params = RadixParameters {
+                        block_parameters,
+                        num_block: $num_bits / block_bits.max(1),
+                        wopbs_block_parameters: $wopbs_block_parameters,
+                        pbs_order: crate::shortint::PBSOrder::KeyswitchBootstrap,
+                    };
+                    params
+                        .validate($num_bits)
+                        .map_err(|reason| UnsupportedIntegerParameters {
+                            num_bits: $num_bits,
+                            reason,
+                        })?;
+                    Ok(Self(params))
+                }
             }
 
             impl ParameterType for [<FheUint $num_bits Parameters>] {
@@ -85,6 +116,10 @@ macro_rules! define_static_integer_parameters {
                 fn block_parameters(&self) -> crate::shortint::Parameters {
                     self.0.block_parameters
                 }
+
+                fn validate(&self) -> Result<(), String> {
+                    self.0.validate($num_bits)
+                }
             }
 
             impl From<[<FheUint $num_bits Parameters>]> for RadixParameters {
@@ -313,14 +348,13 @@ where
     fn new(client_key: &C) -> Self {
         #[cfg(feature = "internal-keycache")]
         {
-            KEY_CACHE
-                .get_from_params(client_key.as_ref().parameters())
-                .1
-        }
-        #[cfg(not(feature = "internal-keycache"))]
-        {
-            crate::integer::ServerKey::new(client_key)
+            let parameters = client_key.as_ref().parameters();
+            // Parameters with a custom modulus are not part of the key cache
+            if parameters.ciphertext_modulus.is_native_modulus() && key_cache_enabled() {
+                return KEY_CACHE.get_from_params(parameters).1;
+            }
         }
+        crate::integer::ServerKey::new(client_key)
     }
 
     fn new_wopbs_key(
@@ -328,16 +362,12 @@ where
         server_key: &Self,
         wopbs_block_parameters: crate::shortint::Parameters,
     ) -> WopbsKey {
-        #[cfg(not(feature = "internal-keycache"))]
-        {
-            WopbsKey::new_wopbs_key(client_key.as_ref(), server_key, &wopbs_block_parameters)
-        }
         #[cfg(feature = "internal-keycache")]
-        {
-            let _ = &server_key; // silence warning
-            KEY_CACHE_WOPBS
-                .get_from_params((client_key.as_ref().parameters(), wopbs_block_parameters))
+        if key_cache_enabled() {
+            return KEY_CACHE_WOPBS
+                .get_from_params((client_key.as_ref().parameters(), wopbs_block_parameters));
         }
+        WopbsKey::new_wopbs_key(client_key.as_ref(), server_key, &wopbs_block_parameters)
     }
 }
 
@@ -487,3 +517,38 @@ impl FheEncrypt<u16, ClientKey> for FheUint16 {
         Self::new(ciphertext, id)
     }
 }
+
+macro_rules! impl_fhe_integer {
+    ($($name:ident => $clear_type:ty),* $(,)?) => {
+        $(
+            paste! {
+                impl FheInteger for $name {
+                    type Clear = $clear_type;
+
+                    const NUM_BITS: usize =
+                        <[<$name Parameters>] as StaticIntegerParameter>::MESSAGE_BITS;
+
+                    fn max(&self, other: &Self) -> Self {
+                        GenericInteger::max(self, other)
+                    }
+
+                    fn min(&self, other: &Self) -> Self {
+                        GenericInteger::min(self, other)
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_fhe_integer!(
+    FheUint8 => u8,
+    FheUint10 => u16,
+    FheUint12 => u16,
+    FheUint14 => u16,
+    FheUint16 => u16,
+    FheUint32 => u32,
+    FheUint64 => u64,
+    FheUint128 => u128,
+    FheUint256 => crate::integer::U256,
+);
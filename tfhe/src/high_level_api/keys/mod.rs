@@ -4,12 +4,20 @@ mod client;
 mod public;
 mod server;
 
+#[cfg(feature = "boolean")]
+use crate::boolean::engine::{BooleanEngine, WithThreadLocalEngine};
+use crate::core_crypto::commons::generators::DeterministicSeeder;
+use crate::core_crypto::commons::math::random::{ActivatedRandomGenerator, Seed};
 use crate::high_level_api::config::Config;
+#[cfg(feature = "shortint")]
+use crate::shortint::engine::ShortintEngine;
 pub use client::{ClientKey, RefKeyFromKeyChain};
 pub use public::{
     CompressedPublicKey, PublicKey, RefKeyFromCompressedPublicKeyChain, RefKeyFromPublicKeyChain,
 };
 pub use server::ServerKey;
+#[cfg(feature = "internal-keycache")]
+use std::cell::Cell;
 
 /// Generates keys using the provided config.
 ///
@@ -30,3 +38,92 @@ pub fn generate_keys<C: Into<Config>>(config: C) -> (ClientKey, ServerKey) {
 
     (client_kc, server_kc)
 }
+
+/// Generates keys using the provided config, deriving all the randomness from the given seed.
+///
+/// The same seed and config always yield the same client and server keys, which is useful to
+/// reproduce a key set in tests or benchmarks. The seed must be kept secret like the client key,
+/// as it allows to regenerate it.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "shortint")]
+/// # {
+/// use tfhe::{generate_keys_with_seed, ConfigBuilder};
+///
+/// let config = ConfigBuilder::all_disabled().enable_default_uint3().build();
+/// let (client_key, _server_key) = generate_keys_with_seed(config.clone(), 42);
+/// let (other_client_key, _other_server_key) = generate_keys_with_seed(config, 42);
+///
+/// assert_eq!(
+///     bincode::serialize(&client_key).unwrap(),
+///     bincode::serialize(&other_client_key).unwrap()
+/// );
+/// # }
+/// ```
+pub fn generate_keys_with_seed<C: Into<Config>>(config: C, seed: u128) -> (ClientKey, ServerKey) {
+    let _guard = SeededEnginesGuard::new(seed);
+    generate_keys(config)
+}
+
+#[cfg(feature = "internal-keycache")]
+thread_local! {
+    static KEY_CACHE_BYPASSED: Cell<bool> = Cell::new(false);
+}
+
+/// Returns whether keys may be taken from the key cache instead of being generated.
+///
+/// The key cache ignores the randomness of the thread local engines, so it is bypassed during a
+/// seeded key generation.
+#[cfg(feature = "internal-keycache")]
+pub(crate) fn key_cache_enabled() -> bool {
+    !KEY_CACHE_BYPASSED.with(Cell::get)
+}
+
+/// Replaces the thread local engines by engines seeded with the given seed, as key generation
+/// draws its randomness from them, and restores the previous engines when dropped, even if the
+/// generation panics.
+struct SeededEnginesGuard {
+    #[cfg(feature = "boolean")]
+    boolean_engine: Option<BooleanEngine>,
+    #[cfg(feature = "shortint")]
+    shortint_engine: Option<ShortintEngine>,
+    #[cfg(feature = "internal-keycache")]
+    key_cache_bypassed: bool,
+}
+
+impl SeededEnginesGuard {
+    #[allow(unused_mut, unused_variables)]
+    fn new(seed: u128) -> Self {
+        let mut seeder = DeterministicSeeder::<ActivatedRandomGenerator>::new(Seed(seed));
+
+        Self {
+            #[cfg(feature = "boolean")]
+            boolean_engine: Some(BooleanEngine::with_thread_local_mut(|engine| {
+                std::mem::replace(engine, BooleanEngine::new_from_seeder(&mut seeder))
+            })),
+            #[cfg(feature = "shortint")]
+            shortint_engine: Some(ShortintEngine::with_thread_local_mut(|engine| {
+                std::mem::replace(engine, ShortintEngine::new_from_seeder(&mut seeder))
+            })),
+            #[cfg(feature = "internal-keycache")]
+            key_cache_bypassed: KEY_CACHE_BYPASSED.with(|bypassed| bypassed.replace(true)),
+        }
+    }
+}
+
+impl Drop for SeededEnginesGuard {
+    fn drop(&mut self) {
+        #[cfg(feature = "boolean")]
+        if let Some(boolean_engine) = self.boolean_engine.take() {
+            BooleanEngine::with_thread_local_mut(|engine| *engine = boolean_engine);
+        }
+        #[cfg(feature = "shortint")]
+        if let Some(shortint_engine) = self.shortint_engine.take() {
+            ShortintEngine::with_thread_local_mut(|engine| *engine = shortint_engine);
+        }
+        #[cfg(feature = "internal-keycache")]
+        KEY_CACHE_BYPASSED.with(|bypassed| bypassed.set(self.key_cache_bypassed));
+    }
+}
@@ -9,6 +9,7 @@ use crate::high_level_api::shortints::ShortIntServerKey;
 use std::sync::Arc;
 
 use super::ClientKey;
+use crate::high_level_api::versioned::VersionedFormat;
 
 /// Key of the server
 ///
@@ -42,6 +43,44 @@ impl ServerKey {
             integer_key: Arc::new(IntegerServerKey::new(&keys.integer_key)),
         }
     }
+
+    /// Serializes the key directly into the given writer, using bincode.
+    ///
+    /// Contrary to serializing into a `Vec<u8>` first, this does not require holding
+    /// the whole serialized key in memory, which is useful for large keys written to
+    /// a file or a socket.
+    ///
+    /// The serialized key starts with a header holding magic bytes and a format version,
+    /// deserializing data that does not start with the expected header returns an error.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::{generate_keys, ConfigBuilder, ServerKey};
+    ///
+    /// let config = ConfigBuilder::all_disabled().enable_default_bool().build();
+    /// let (_client_key, server_key) = generate_keys(config);
+    ///
+    /// let mut buffer = std::io::Cursor::new(Vec::new());
+    /// server_key.serialize_into(&mut buffer).unwrap();
+    ///
+    /// buffer.set_position(0);
+    /// let _server_key = ServerKey::deserialize_from(&mut buffer).unwrap();
+    /// ```
+    #[cfg(feature = "bincode")]
+    #[cfg_attr(all(doc, not(doctest)), doc(cfg(feature = "bincode")))]
+    pub fn serialize_into<W: std::io::Write>(&self, writer: W) -> bincode::Result<()> {
+        bincode::serialize_into(writer, self)
+    }
+
+    /// Deserializes a key directly from the given reader, using bincode.
+    ///
+    /// See [`ServerKey::serialize_into`].
+    #[cfg(feature = "bincode")]
+    #[cfg_attr(all(doc, not(doctest)), doc(cfg(feature = "bincode")))]
+    pub fn deserialize_from<R: std::io::Read>(reader: R) -> bincode::Result<Self> {
+        bincode::deserialize_from(reader)
+    }
 }
 
 // By default, serde does not derives Serialize/Deserialize for `Rc` and `Arc` types
@@ -67,20 +106,34 @@ struct SerializableServerKey<'a> {
     pub(crate) integer_key: &'a IntegerServerKey,
 }
 
+/// Serialization format of [ServerKey]
+///
+/// Format versions:
+///
+/// - 2: the WoP-PBS key of the integer types is optional, as it is not generated for non native
+///   ciphertext moduli
+const SERVER_KEY_FORMAT: VersionedFormat = VersionedFormat {
+    name: "ServerKey",
+    description: "a ServerKey",
+    magic: *b"TFHS",
+    version: 2,
+};
+
 impl serde::Serialize for ServerKey {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
-        SerializableServerKey {
+        let keys = SerializableServerKey {
             #[cfg(feature = "boolean")]
             boolean_key: &self.boolean_key,
             #[cfg(feature = "shortint")]
             shortint_key: &self.shortint_key,
             #[cfg(feature = "integer")]
             integer_key: &self.integer_key,
-        }
-        .serialize(serializer)
+        };
+
+        SERVER_KEY_FORMAT.serialize((), &keys, serializer)
     }
 }
 
@@ -99,13 +152,15 @@ impl<'de> serde::Deserialize<'de> for ServerKey {
     where
         D: serde::Deserializer<'de>,
     {
-        DeserializableServerKey::deserialize(deserializer).map(|deserialized| Self {
-            #[cfg(feature = "boolean")]
-            boolean_key: Arc::new(deserialized.boolean_key),
-            #[cfg(feature = "shortint")]
-            shortint_key: Arc::new(deserialized.shortint_key),
-            #[cfg(feature = "integer")]
-            integer_key: Arc::new(deserialized.integer_key),
-        })
+        SERVER_KEY_FORMAT
+            .deserialize(deserializer, |()| Ok(()))
+            .map(|deserialized: DeserializableServerKey| Self {
+                #[cfg(feature = "boolean")]
+                boolean_key: Arc::new(deserialized.boolean_key),
+                #[cfg(feature = "shortint")]
+                shortint_key: Arc::new(deserialized.shortint_key),
+                #[cfg(feature = "integer")]
+                integer_key: Arc::new(deserialized.integer_key),
+            })
     }
 }
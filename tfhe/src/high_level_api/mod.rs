@@ -1,14 +1,18 @@
 #![allow(unused_doc_comments)]
 pub use config::{Config, ConfigBuilder};
-pub use errors::{Error, OutOfRangeError};
+pub use errors::{Error, OutOfRangeError, UnsupportedIntegerParameters};
 pub use global_state::{set_server_key, unset_server_key, with_server_key_as_context};
-pub use keys::{generate_keys, ClientKey, CompressedPublicKey, PublicKey, ServerKey};
+pub use keys::{
+    generate_keys, generate_keys_with_seed, ClientKey, CompressedPublicKey, PublicKey, ServerKey,
+};
 
 #[cfg(test)]
 mod tests;
 
 #[cfg(feature = "boolean")]
-pub use crate::high_level_api::booleans::{CompressedFheBool, FheBool, FheBoolParameters};
+pub use crate::high_level_api::booleans::{
+    if_then_else, CompressedFheBool, FheBool, FheBoolParameters,
+};
 #[cfg(feature = "integer")]
 pub use crate::high_level_api::integers::{
     CompressedFheUint10, CompressedFheUint12, CompressedFheUint128, CompressedFheUint14,
@@ -30,10 +34,13 @@ mod keys;
 mod config;
 mod internal_traits;
 mod traits;
+mod versioned;
 
 #[cfg(feature = "boolean")]
 mod booleans;
 pub mod errors;
+/// The tfhe prelude, extended with the most commonly used types and functions.
+pub mod full_prelude;
 #[cfg(feature = "integer")]
 mod integers;
 /// The tfhe prelude.
@@ -2,6 +2,8 @@ use std::marker::PhantomData;
 
 use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "internal-keycache")]
+use crate::high_level_api::keys::key_cache_enabled;
 #[cfg(feature = "internal-keycache")]
 use crate::shortint::keycache::KEY_CACHE;
 use crate::shortint::ClientKey;
@@ -23,10 +25,14 @@ where
 {
     fn from(parameters: P) -> Self {
         #[cfg(feature = "internal-keycache")]
-        let key = KEY_CACHE
-            .get_from_param(parameters.into())
-            .client_key()
-            .clone();
+        let key = if key_cache_enabled() {
+            KEY_CACHE
+                .get_from_param(parameters.into())
+                .client_key()
+                .clone()
+        } else {
+            ClientKey::new(parameters.into())
+        };
         #[cfg(not(feature = "internal-keycache"))]
         let key = ClientKey::new(parameters.into());
 
@@ -3,6 +3,8 @@ use std::marker::PhantomData;
 
 use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "internal-keycache")]
+use crate::high_level_api::keys::key_cache_enabled;
 #[cfg(feature = "internal-keycache")]
 use crate::shortint::keycache::KEY_CACHE;
 use crate::shortint::ServerKey;
@@ -28,10 +30,14 @@ where
 {
     pub(crate) fn new(client_key: &GenericShortIntClientKey<P>) -> Self {
         #[cfg(feature = "internal-keycache")]
-        let key = KEY_CACHE
-            .get_from_param(client_key.key.parameters)
-            .server_key()
-            .clone();
+        let key = if key_cache_enabled() {
+            KEY_CACHE
+                .get_from_param(client_key.key.parameters)
+                .server_key()
+                .clone()
+        } else {
+            ServerKey::new(&client_key.key)
+        };
         #[cfg(not(feature = "internal-keycache"))]
         let key = ServerKey::new(&client_key.key);
 
@@ -291,6 +297,32 @@ where
         }
     }
 
+    pub(crate) fn not_equal(
+        &self,
+        lhs: &GenericShortInt<P>,
+        rhs: &GenericShortInt<P>,
+    ) -> GenericShortInt<P> {
+        let ciphertext = self
+            .key
+            .not_equal(&lhs.ciphertext.borrow(), &rhs.ciphertext.borrow());
+        GenericShortInt {
+            ciphertext: RefCell::new(ciphertext),
+            id: lhs.id,
+        }
+    }
+
+    pub(crate) fn scalar_not_equal(
+        &self,
+        lhs: &GenericShortInt<P>,
+        scalar: u8,
+    ) -> GenericShortInt<P> {
+        let ciphertext = self.key.scalar_not_equal(&lhs.ciphertext.borrow(), scalar);
+        GenericShortInt {
+            ciphertext: RefCell::new(ciphertext),
+            id: lhs.id,
+        }
+    }
+
     pub(crate) fn scalar_greater_or_equal(
         &self,
         lhs: &GenericShortInt<P>,
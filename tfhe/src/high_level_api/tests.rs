@@ -4,7 +4,9 @@ use crate::high_level_api::FheBool;
 #[cfg(feature = "shortint")]
 use crate::high_level_api::FheUint2;
 #[cfg(any(feature = "boolean", feature = "shortint", feature = "integer"))]
-use crate::high_level_api::{generate_keys, ClientKey, ConfigBuilder, PublicKey};
+use crate::high_level_api::{
+    generate_keys, generate_keys_with_seed, ClientKey, ConfigBuilder, PublicKey,
+};
 #[cfg(feature = "integer")]
 use crate::high_level_api::{FheUint256, FheUint8};
 #[cfg(feature = "integer")]
@@ -137,3 +139,84 @@ fn test_serialize_deserialize_are_implemented() {
     can_be_deserialized(&pks);
     can_be_deserialized(&cpks);
 }
+
+#[test]
+fn test_server_key_serialization_header_is_checked() {
+    let config = ConfigBuilder::all_disabled().build();
+
+    let (_cks, sks) = generate_keys(config);
+
+    let data = bincode::serialize(&sks).unwrap();
+    let _sks: crate::high_level_api::ServerKey = bincode::deserialize(&data).unwrap();
+
+    // Corrupted magic bytes
+    let mut corrupted = data.clone();
+    corrupted[0] ^= 0xFF;
+    let err = bincode::deserialize::<crate::high_level_api::ServerKey>(&corrupted)
+        .err()
+        .unwrap();
+    assert!(err.to_string().contains("invalid ServerKey header"));
+
+    // Unknown format version
+    let mut corrupted = data;
+    corrupted[4] = corrupted[4].wrapping_add(1);
+    let err = bincode::deserialize::<crate::high_level_api::ServerKey>(&corrupted)
+        .err()
+        .unwrap();
+    assert!(err
+        .to_string()
+        .contains("unsupported ServerKey format version"));
+}
+
+#[cfg(all(feature = "boolean", feature = "bincode"))]
+#[test]
+fn test_server_key_streaming_serialization() {
+    let config = ConfigBuilder::all_disabled().enable_default_bool().build();
+
+    let (cks, sks) = generate_keys(config);
+
+    let mut buffer = std::io::Cursor::new(Vec::new());
+    sks.serialize_into(&mut buffer).unwrap();
+    assert_eq!(buffer.get_ref(), &bincode::serialize(&sks).unwrap());
+
+    buffer.set_position(0);
+    let sks = crate::high_level_api::ServerKey::deserialize_from(&mut buffer).unwrap();
+    crate::high_level_api::set_server_key(sks);
+
+    let a = FheBool::encrypt(false, &cks);
+    let b = FheBool::encrypt(true, &cks);
+    let c = a | b;
+    assert!(c.decrypt(&cks));
+}
+
+#[cfg(feature = "shortint")]
+#[test]
+fn test_generate_keys_with_seed() {
+    let config = ConfigBuilder::all_disabled().enable_default_uint2().build();
+
+    let (cks, sks) = generate_keys_with_seed(config.clone(), 0x1234_5678);
+    let (other_cks, other_sks) = generate_keys_with_seed(config.clone(), 0x1234_5678);
+
+    assert_eq!(
+        bincode::serialize(&cks).unwrap(),
+        bincode::serialize(&other_cks).unwrap()
+    );
+    assert_eq!(
+        bincode::serialize(&sks).unwrap(),
+        bincode::serialize(&other_sks).unwrap()
+    );
+
+    let (different_cks, _) = generate_keys_with_seed(config, 0x8765_4321);
+    assert_ne!(
+        bincode::serialize(&cks).unwrap(),
+        bincode::serialize(&different_cks).unwrap()
+    );
+
+    // The keys are usable as any other key
+    crate::high_level_api::set_server_key(sks);
+    let a = FheUint2::try_encrypt(1, &cks).unwrap();
+    let b = FheUint2::try_encrypt(2, &cks).unwrap();
+    let c = a + b;
+    let decrypted: u8 = c.decrypt(&cks);
+    assert_eq!(decrypted, 3);
+}
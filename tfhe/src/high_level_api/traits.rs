@@ -1,3 +1,8 @@
+use std::ops::{
+    Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Mul, MulAssign,
+    Shl, Shr, Sub, SubAssign,
+};
+
 use crate::high_level_api::ClientKey;
 
 /// Trait used to have a generic way of creating a value of a FHE type
@@ -81,6 +86,8 @@ pub trait FheEq<Rhs = Self> {
     type Output;
 
     fn eq(&self, other: Rhs) -> Self::Output;
+
+    fn ne(&self, other: Rhs) -> Self::Output;
 }
 
 /// Trait for fully homomorphic comparisons.
@@ -121,3 +128,98 @@ pub trait FheNumberConstant {
     const MAX: u64;
     const MODULUS: u64;
 }
+
+/// Trait implemented by all the high-level unsigned integer types ([FheUint8], [FheUint16], ...).
+///
+/// It gathers the encryption, decryption and the operations the integer types have in common,
+/// so that code can be written once and be generic over the bit width.
+///
+/// # Example
+///
+/// ```
+/// use tfhe::prelude::*;
+/// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint16, FheUint8};
+///
+/// fn sum_of_squares<T: FheInteger>(values: &[T]) -> T {
+///     let mut iter = values.iter();
+///     let first = iter.next().unwrap();
+///     let mut sum = first.clone() * first;
+///     for value in iter {
+///         sum += value.clone() * value;
+///     }
+///     sum
+/// }
+///
+/// let config = ConfigBuilder::all_disabled()
+///     .enable_default_uint8()
+///     .enable_default_uint16()
+///     .build();
+/// let (client_key, server_key) = generate_keys(config);
+/// set_server_key(server_key);
+///
+/// let values = [3u8, 4, 5]
+///     .iter()
+///     .map(|&v| FheUint8::try_encrypt(v, &client_key).unwrap())
+///     .collect::<Vec<_>>();
+/// let sum: u8 = sum_of_squares(&values).decrypt(&client_key);
+/// assert_eq!(sum, 50);
+///
+/// let values = [300u16, 400]
+///     .iter()
+///     .map(|&v| FheUint16::try_encrypt(v, &client_key).unwrap())
+///     .collect::<Vec<_>>();
+/// let sum: u16 = sum_of_squares(&values).decrypt(&client_key);
+/// assert_eq!(sum, 250_000u32 as u16);
+/// ```
+///
+/// [FheUint8]: crate::high_level_api::FheUint8
+/// [FheUint16]: crate::high_level_api::FheUint16
+pub trait FheInteger:
+    Sized
+    + Clone
+    + FheTryEncrypt<Self::Clear, ClientKey>
+    + FheDecrypt<Self::Clear>
+    + Add<Self, Output = Self>
+    + for<'a> Add<&'a Self, Output = Self>
+    + Sub<Self, Output = Self>
+    + for<'a> Sub<&'a Self, Output = Self>
+    + Mul<Self, Output = Self>
+    + for<'a> Mul<&'a Self, Output = Self>
+    + BitAnd<Self, Output = Self>
+    + for<'a> BitAnd<&'a Self, Output = Self>
+    + BitOr<Self, Output = Self>
+    + for<'a> BitOr<&'a Self, Output = Self>
+    + BitXor<Self, Output = Self>
+    + for<'a> BitXor<&'a Self, Output = Self>
+    + AddAssign<Self>
+    + for<'a> AddAssign<&'a Self>
+    + SubAssign<Self>
+    + for<'a> SubAssign<&'a Self>
+    + MulAssign<Self>
+    + for<'a> MulAssign<&'a Self>
+    + BitAndAssign<Self>
+    + for<'a> BitAndAssign<&'a Self>
+    + BitOrAssign<Self>
+    + for<'a> BitOrAssign<&'a Self>
+    + BitXorAssign<Self>
+    + for<'a> BitXorAssign<&'a Self>
+    + Add<u64, Output = Self>
+    + Sub<u64, Output = Self>
+    + Mul<u64, Output = Self>
+    + Shl<u64, Output = Self>
+    + Shr<u64, Output = Self>
+    + for<'a> FheEq<&'a Self, Output = Self>
+    + for<'a> FheOrd<&'a Self, Output = Self>
+{
+    /// The native type holding the clear values of the integer type.
+    type Clear: Copy;
+
+    /// The number of bits of the integer type.
+    const NUM_BITS: usize;
+
+    /// Returns the maximum of `self` and `other`.
+    fn max(&self, other: &Self) -> Self;
+
+    /// Returns the minimum of `self` and `other`.
+    fn min(&self, other: &Self) -> Self;
+}
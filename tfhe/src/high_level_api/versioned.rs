@@ -0,0 +1,141 @@
+//! Serialization of the high-level types behind a versioned header.
+//!
+//! The serialized data starts with magic bytes and a format version, followed by metadata
+//! specific to the serialized type and then by the actual value, so that data that is not of the
+//! expected type, or that was serialized with an incompatible format, is rejected with a clear
+//! error instead of failing somewhere in the middle of the value.
+
+use std::marker::PhantomData;
+
+use serde::de::{Error, SeqAccess, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Description of a versioned serialization format
+pub(crate) struct VersionedFormat {
+    /// Name of the serialized type, used in error messages
+    pub(crate) name: &'static str,
+    /// Description of the serialized value, used in error messages
+    pub(crate) description: &'static str,
+    /// Magic bytes written at the start of the serialized data
+    pub(crate) magic: [u8; 4],
+    /// Version of the serialization format
+    ///
+    /// It has to be incremented each time the serialized layout of the value changes.
+    pub(crate) version: u32,
+}
+
+#[derive(Serialize, Deserialize)]
+struct VersionedHeader {
+    magic: [u8; 4],
+    version: u32,
+}
+
+impl VersionedFormat {
+    fn header(&self) -> VersionedHeader {
+        VersionedHeader {
+            magic: self.magic,
+            version: self.version,
+        }
+    }
+
+    fn validate<E: Error>(&self, header: &VersionedHeader) -> Result<(), E> {
+        if header.magic != self.magic {
+            return Err(E::custom(format!(
+                "invalid {} header: the data is not {} \
+                or was serialized by a version of tfhe without a format header",
+                self.name, self.description
+            )));
+        }
+
+        if header.version != self.version {
+            return Err(E::custom(format!(
+                "unsupported {} format version {}, this version of tfhe expects version {}",
+                self.name, header.version, self.version
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Serializes the header of the format, followed by `metadata` and `value`.
+    pub(crate) fn serialize<S, Metadata, Value>(
+        &self,
+        metadata: Metadata,
+        value: &Value,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+        Metadata: Serialize,
+        Value: Serialize + ?Sized,
+    {
+        (self.header(), metadata, value).serialize(serializer)
+    }
+
+    /// Deserializes data written by [`serialize`](Self::serialize).
+    ///
+    /// The header is checked first, then the metadata is given to `validate_metadata`, the value
+    /// is only deserialized if both are valid.
+    pub(crate) fn deserialize<'de, D, Metadata, Value, F>(
+        &self,
+        deserializer: D,
+        validate_metadata: F,
+    ) -> Result<Value, D::Error>
+    where
+        D: Deserializer<'de>,
+        Metadata: Deserialize<'de>,
+        Value: Deserialize<'de>,
+        F: Fn(Metadata) -> Result<(), String>,
+    {
+        deserializer.deserialize_tuple(
+            3,
+            VersionedVisitor {
+                format: self,
+                validate_metadata,
+                _marker: PhantomData,
+            },
+        )
+    }
+}
+
+/// Visitor that validates the header and the metadata before deserializing the value
+struct VersionedVisitor<'a, Metadata, Value, F> {
+    format: &'a VersionedFormat,
+    validate_metadata: F,
+    _marker: PhantomData<(Metadata, Value)>,
+}
+
+impl<'de, Metadata, Value, F> Visitor<'de> for VersionedVisitor<'_, Metadata, Value, F>
+where
+    Metadata: Deserialize<'de>,
+    Value: Deserialize<'de>,
+    F: Fn(Metadata) -> Result<(), String>,
+{
+    type Value = Value;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            formatter,
+            "a {} header followed by {}",
+            self.format.name, self.format.description
+        )
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let header: VersionedHeader = seq
+            .next_element()?
+            .ok_or_else(|| Error::invalid_length(0, &self))?;
+        self.format.validate(&header)?;
+
+        let metadata: Metadata = seq
+            .next_element()?
+            .ok_or_else(|| Error::invalid_length(1, &self))?;
+        (self.validate_metadata)(metadata).map_err(Error::custom)?;
+
+        seq.next_element()?
+            .ok_or_else(|| Error::invalid_length(2, &self))
+    }
+}
@@ -0,0 +1,158 @@
+//! Module with the decomposition of clear scalars into radix blocks.
+
+/// Trait for clear scalars that can be decomposed into blocks of a radix representation.
+///
+/// It is implemented for the unsigned integer types, so that the scalar operations of the
+/// [`ServerKey`](crate::integer::ServerKey) accept the clear type matching the width of the
+/// encrypted integer.
+///
+/// Arithmetic and bitwise scalar operations reduce a scalar wider than the ciphertext modulo the
+/// ciphertext modulus, as the wrapping operations of the clear types do. Scalar comparisons
+/// compare the whole scalar instead: a scalar that does not fit in the ciphertext is greater than
+/// any value it can hold.
+pub trait DecomposableInto<T>: Copy {
+    /// Number of bits of the scalar type.
+    const BITS: u32;
+
+    /// Returns the `num_bits` bits of `self` starting at bit `shift`, bits past the end of the
+    /// scalar type are zeros.
+    fn extract_bits(self, shift: u32, num_bits: u32) -> T;
+}
+
+macro_rules! impl_decomposable_into_u8 {
+    ($($scalar_type:ty),*) => {
+        $(
+            impl DecomposableInto<u8> for $scalar_type {
+                const BITS: u32 = <$scalar_type>::BITS;
+
+                fn extract_bits(self, shift: u32, num_bits: u32) -> u8 {
+                    debug_assert!(num_bits <= u8::BITS);
+                    let shifted = self.checked_shr(shift).unwrap_or(0);
+                    let mask = ((1u16 << num_bits) - 1) as $scalar_type;
+                    (shifted & mask) as u8
+                }
+            }
+        )*
+    };
+}
+
+impl_decomposable_into_u8!(u8, u16, u32, u64, u128);
+
+/// Decomposes `scalar` into `num_blocks` blocks of `bits_per_block` bits, from the least
+/// significant to the most significant.
+///
+/// The scalar is reduced modulo `2^(num_blocks * bits_per_block)`, i.e. truncated to the bits the
+/// blocks can hold, blocks past the width of the scalar type are zeros. Use
+/// [`decompose_scalar_checked`] when the scalar has to fit in the blocks.
+pub(crate) fn decompose_scalar_wrapping<T>(
+    scalar: T,
+    bits_per_block: u32,
+    num_blocks: usize,
+) -> Vec<u8>
+where
+    T: DecomposableInto<u8>,
+{
+    (0..num_blocks)
+        .map(|i| {
+            let shift = (i as u32).saturating_mul(bits_per_block);
+            if shift >= T::BITS {
+                0
+            } else {
+                scalar.extract_bits(shift, bits_per_block)
+            }
+        })
+        .collect()
+}
+
+/// Decomposes `scalar` into `num_blocks` blocks of `bits_per_block` bits, from the least
+/// significant to the most significant, or returns `None` if the scalar does not fit in the
+/// blocks.
+pub(crate) fn decompose_scalar_checked<T>(
+    scalar: T,
+    bits_per_block: u32,
+    num_blocks: usize,
+) -> Option<Vec<u8>>
+where
+    T: DecomposableInto<u8>,
+{
+    let num_bits = (num_blocks as u32).saturating_mul(bits_per_block);
+    let fits = (num_bits..T::BITS)
+        .step_by(bits_per_block as usize)
+        .all(|shift| scalar.extract_bits(shift, bits_per_block) == 0);
+
+    fits.then(|| decompose_scalar_wrapping(scalar, bits_per_block, num_blocks))
+}
+
+/// Decomposes `-scalar`, modulo the modulus of `num_blocks` blocks of `bits_per_block` bits, into
+/// blocks from the least significant to the most significant.
+pub(crate) fn decompose_negated_scalar<T>(
+    scalar: T,
+    bits_per_block: u32,
+    num_blocks: usize,
+) -> Vec<u8>
+where
+    T: DecomposableInto<u8>,
+{
+    let block_modulus = 1u16 << bits_per_block;
+
+    // Two's complement in base 2^bits_per_block: complement each block then add one
+    let mut carry = 1u16;
+    let mut blocks = decompose_scalar_wrapping(scalar, bits_per_block, num_blocks);
+    for block in blocks.iter_mut() {
+        let value = (block_modulus - 1 - *block as u16) + carry;
+        *block = (value % block_modulus) as u8;
+        carry = value / block_modulus;
+    }
+    blocks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decompose_scalar_wrapping() {
+        assert_eq!(
+            decompose_scalar_wrapping(0b1110_0100u8, 2, 4),
+            vec![0, 1, 2, 3]
+        );
+        // Blocks past the scalar width are zeros
+        assert_eq!(decompose_scalar_wrapping(0xFFu8, 4, 4), vec![15, 15, 0, 0]);
+        // The scalar is truncated to the blocks
+        assert_eq!(decompose_scalar_wrapping(0x1234u16, 4, 2), vec![4, 3]);
+        // Blocks which do not divide the scalar width
+        assert_eq!(decompose_scalar_wrapping(u128::MAX, 3, 44), {
+            let mut expected = vec![7; 42];
+            expected.extend([3, 0]);
+            expected
+        });
+    }
+
+    #[test]
+    fn test_decompose_scalar_checked() {
+        assert_eq!(
+            decompose_scalar_checked(0b1110_0100u8, 2, 4),
+            Some(vec![0, 1, 2, 3])
+        );
+        assert_eq!(
+            decompose_scalar_checked(0xFFu8, 4, 4),
+            Some(vec![15, 15, 0, 0])
+        );
+        // The scalar does not fit in the blocks
+        assert_eq!(decompose_scalar_checked(0x1234u16, 4, 2), None);
+        assert_eq!(decompose_scalar_checked(0x100u64, 2, 4), None);
+        assert_eq!(decompose_scalar_checked(u128::MAX, 3, 42), None);
+        // Largest value the blocks can hold
+        assert_eq!(decompose_scalar_checked(0xFFu64, 2, 4), Some(vec![3; 4]));
+    }
+
+    #[test]
+    fn test_decompose_negated_scalar() {
+        for scalar in 0..=u8::MAX {
+            let expected = decompose_scalar_wrapping(scalar.wrapping_neg(), 2, 4);
+            assert_eq!(decompose_negated_scalar(scalar, 2, 4), expected);
+        }
+        assert_eq!(decompose_negated_scalar(0u64, 2, 4), vec![0, 0, 0, 0]);
+        assert_eq!(decompose_negated_scalar(1u64, 2, 4), vec![3, 3, 3, 3]);
+    }
+}
@@ -1,8 +1,8 @@
 //! This module implements the ciphertext structures.
 use crate::shortint::ciphertext::{BootstrapKeyswitch, KeyswitchBootstrap};
 use crate::shortint::{
-    CiphertextBase, CiphertextBig, CiphertextSmall, CompressedCiphertextBig,
-    CompressedCiphertextSmall, PBSOrderMarker,
+    CiphertextBase, CiphertextBig, CiphertextSmall, CompressedCiphertextBase,
+    CompressedCiphertextBig, CompressedCiphertextSmall, MessageModulus, PBSOrderMarker,
 };
 use serde::{Deserialize, Serialize};
 
@@ -26,37 +26,311 @@ pub type RadixCiphertext<PBSOder> = BaseRadixCiphertext<CiphertextBase<PBSOder>>
 pub type RadixCiphertextBig = BaseRadixCiphertext<CiphertextBig>;
 pub type RadixCiphertextSmall = BaseRadixCiphertext<CiphertextSmall>;
 
+// Type alias to save some typing in implementation parts
+pub type CompressedRadixCiphertext<PBSOrder> =
+    BaseRadixCiphertext<CompressedCiphertextBase<PBSOrder>>;
+
 /// Structure containing a **compressed** ciphertext in radix decomposition.
 pub type CompressedRadixCiphertextBig = BaseRadixCiphertext<CompressedCiphertextBig>;
 pub type CompressedRadixCiphertextSmall = BaseRadixCiphertext<CompressedCiphertextSmall>;
 
 impl<PBSOrder: PBSOrderMarker> RadixCiphertext<PBSOrder> {
+    /// Creates a radix ciphertext from its blocks, stored from LSB to MSB.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `blocks` is empty, or if the blocks do not all share the same message modulus,
+    /// carry modulus, LWE size and ciphertext modulus.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::{gen_keys_radix, RadixCiphertextBig};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let num_blocks = 4;
+    /// let (cks, _sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let ct = cks.encrypt(227u64);
+    ///
+    /// // Rebuild a ciphertext from the two least significant blocks
+    /// let low_blocks = ct.blocks()[..2].to_vec();
+    /// let low = RadixCiphertextBig::from_blocks(low_blocks);
+    ///
+    /// // The decryption depends on the number of blocks of the ciphertext
+    /// let dec: u64 = cks.decrypt(&low);
+    /// assert_eq!(dec, 227 % 16);
+    /// ```
+    pub fn from_blocks(blocks: Vec<CiphertextBase<PBSOrder>>) -> Self {
+        assert!(
+            !blocks.is_empty(),
+            "Cannot create a radix ciphertext without blocks"
+        );
+
+        let first = &blocks[0];
+        for (i, block) in blocks.iter().enumerate().skip(1) {
+            assert!(
+                block.message_modulus == first.message_modulus
+                    && block.carry_modulus == first.carry_modulus,
+                "Block {i} has message modulus {:?} and carry modulus {:?}, \
+                expected {:?} and {:?} as the first block",
+                block.message_modulus,
+                block.carry_modulus,
+                first.message_modulus,
+                first.carry_modulus,
+            );
+            assert!(
+                block.ct.lwe_size() == first.ct.lwe_size()
+                    && block.ct.ciphertext_modulus() == first.ct.ciphertext_modulus(),
+                "Block {i} has LweSize {:?} and ciphertext modulus {:?}, \
+                expected {:?} and {:?} as the first block",
+                block.ct.lwe_size(),
+                block.ct.ciphertext_modulus(),
+                first.ct.lwe_size(),
+                first.ct.ciphertext_modulus(),
+            );
+        }
+
+        Self { blocks }
+    }
+
+    /// Returns the blocks of the ciphertext, stored from LSB to MSB.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let num_blocks = 4;
+    /// let (cks, _sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let ct = cks.encrypt(227u64);
+    ///
+    /// // 227 = 0b11_10_00_11
+    /// let block_values = ct
+    ///     .blocks()
+    ///     .iter()
+    ///     .map(|block| cks.as_ref().decrypt_one_block(block))
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(block_values, vec![3, 0, 2, 3]);
+    /// ```
+    pub fn blocks(&self) -> &[CiphertextBase<PBSOrder>] {
+        &self.blocks
+    }
+
+    /// Returns the blocks of the ciphertext, stored from LSB to MSB, as a mutable slice.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let num_blocks = 4;
+    /// let (cks, _sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// // 227 = 0b11_10_00_11
+    /// let mut ct = cks.encrypt(227u64);
+    ///
+    /// // Swap the two least significant blocks
+    /// ct.blocks_mut().swap(0, 1);
+    ///
+    /// // 0b11_10_11_00 = 236
+    /// let dec: u64 = cks.decrypt(&ct);
+    /// assert_eq!(dec, 236);
+    /// ```
+    pub fn blocks_mut(&mut self) -> &mut [CiphertextBase<PBSOrder>] {
+        &mut self.blocks
+    }
+
+    /// Returns `true` if the carries of all the blocks are empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let mut ct = cks.encrypt(3u64);
+    /// let ct_2 = cks.encrypt(3u64);
+    /// assert!(ct.block_carries_are_empty());
+    ///
+    /// sks.unchecked_add_assign(&mut ct, &ct_2);
+    /// assert!(!ct.block_carries_are_empty());
+    /// ```
     pub fn block_carries_are_empty(&self) -> bool {
         self.blocks.iter().all(|block| block.carry_is_empty())
     }
+
+    /// Returns the number of blocks of the ciphertext.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let num_blocks = 4;
+    /// let (cks, _sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let ct = cks.encrypt(3u64);
+    /// assert_eq!(ct.num_blocks(), num_blocks);
+    /// ```
+    pub fn num_blocks(&self) -> usize {
+        self.blocks.len()
+    }
+
+    /// Returns the message modulus of the blocks of the ciphertext.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the ciphertext has no blocks.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let num_blocks = 4;
+    /// let (cks, _sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let ct = cks.encrypt(3u64);
+    /// assert_eq!(
+    ///     ct.message_modulus(),
+    ///     PARAM_MESSAGE_2_CARRY_2.message_modulus
+    /// );
+    /// ```
+    pub fn message_modulus(&self) -> MessageModulus {
+        self.blocks[0].message_modulus
+    }
+
+    /// Returns the number of bits of the message space of the ciphertext, i.e. the number of
+    /// blocks times the number of message bits per block.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let num_blocks = 4;
+    /// let (cks, _sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let ct = cks.encrypt(3u64);
+    /// assert_eq!(ct.bit_width(), 8);
+    /// ```
+    pub fn bit_width(&self) -> usize {
+        self.blocks
+            .iter()
+            .map(|block| block.message_modulus.0.ilog2() as usize)
+            .sum()
+    }
 }
 
-impl From<CompressedRadixCiphertextBig> for RadixCiphertextBig {
-    fn from(compressed: CompressedRadixCiphertextBig) -> Self {
-        Self::from(
-            compressed
-                .blocks
+impl<PBSOrder: PBSOrderMarker> CompressedRadixCiphertext<PBSOrder> {
+    /// Decompresses each block, returning a ciphertext on which computations can be done.
+    ///
+    /// Compressed radix ciphertexts are obtained at encryption time, e.g. with
+    /// [`ClientKey::encrypt_radix_compressed`](crate::integer::ClientKey::encrypt_radix_compressed).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::ClientKey;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2);
+    /// let num_block = 4;
+    ///
+    /// let msg = 167_u64;
+    ///
+    /// let compressed_ct = cks.encrypt_radix_compressed(msg, num_block);
+    /// assert_eq!(compressed_ct.num_blocks(), num_block);
+    ///
+    /// let ct = compressed_ct.decompress();
+    ///
+    /// let dec: u64 = cks.decrypt_radix(&ct);
+    /// assert_eq!(msg, dec);
+    /// ```
+    pub fn decompress(self) -> RadixCiphertext<PBSOrder> {
+        RadixCiphertext::from(
+            self.blocks
                 .into_iter()
-                .map(From::from)
+                .map(CompressedCiphertextBase::decompress)
                 .collect::<Vec<_>>(),
         )
     }
+
+    /// Returns the number of blocks of the ciphertext.
+    pub fn num_blocks(&self) -> usize {
+        self.blocks.len()
+    }
 }
 
-impl From<CompressedRadixCiphertextSmall> for RadixCiphertextSmall {
-    fn from(compressed: CompressedRadixCiphertextSmall) -> Self {
-        Self::from(
-            compressed
-                .blocks
-                .into_iter()
-                .map(From::from)
-                .collect::<Vec<_>>(),
-        )
+impl<PBSOrder: PBSOrderMarker> From<CompressedRadixCiphertext<PBSOrder>>
+    for RadixCiphertext<PBSOrder>
+{
+    fn from(compressed: CompressedRadixCiphertext<PBSOrder>) -> Self {
+        compressed.decompress()
+    }
+}
+
+/// Structure containing a radix ciphertext in carry-save form.
+///
+/// Each block holds its message together with a partial carry that has not yet been propagated
+/// to the next block, i.e. the state resolved by
+/// [`ServerKey::full_propagate_parallelized`](crate::integer::ServerKey::full_propagate_parallelized).
+/// The encrypted value is the sum of the block values weighted by their position, carries
+/// included, so several additions can be chained before a single propagation, see
+/// [`ServerKey::carry_save_add_parallelized`](crate::integer::ServerKey::carry_save_add_parallelized)
+/// and
+/// [`ServerKey::resolve_carry_save_parallelized`](crate::integer::ServerKey::resolve_carry_save_parallelized).
+#[derive(Clone)]
+pub struct RadixCiphertextCarrySave<PBSOrder: PBSOrderMarker> {
+    pub(crate) ct: RadixCiphertext<PBSOrder>,
+}
+
+pub type RadixCiphertextCarrySaveBig = RadixCiphertextCarrySave<KeyswitchBootstrap>;
+pub type RadixCiphertextCarrySaveSmall = RadixCiphertextCarrySave<BootstrapKeyswitch>;
+
+impl<PBSOrder: PBSOrderMarker> RadixCiphertextCarrySave<PBSOrder> {
+    /// Returns the blocks of the ciphertext, stored from LSB to MSB, with their pending carries.
+    pub fn blocks(&self) -> &[CiphertextBase<PBSOrder>] {
+        &self.ct.blocks
+    }
+
+    /// Returns the number of blocks of the ciphertext.
+    pub fn num_blocks(&self) -> usize {
+        self.ct.blocks.len()
+    }
+
+    /// Returns `true` if the carries of all the blocks are empty.
+    pub fn block_carries_are_empty(&self) -> bool {
+        self.ct.block_carries_are_empty()
+    }
+}
+
+impl<PBSOrder: PBSOrderMarker> From<RadixCiphertext<PBSOrder>>
+    for RadixCiphertextCarrySave<PBSOrder>
+{
+    fn from(ct: RadixCiphertext<PBSOrder>) -> Self {
+        Self { ct }
+    }
+}
+
+/// Returns the underlying radix ciphertext **without** resolving the pending carries, use
+/// [`ServerKey::resolve_carry_save_parallelized`](crate::integer::ServerKey::resolve_carry_save_parallelized)
+/// to get a ciphertext with empty block carries.
+impl<PBSOrder: PBSOrderMarker> From<RadixCiphertextCarrySave<PBSOrder>>
+    for RadixCiphertext<PBSOrder>
+{
+    fn from(carry_save: RadixCiphertextCarrySave<PBSOrder>) -> Self {
+        carry_save.ct
     }
 }
 
@@ -12,7 +12,10 @@ use crate::integer::ciphertext::{
     RadixCiphertextSmall,
 };
 use crate::integer::client_key::utils::i_crt;
-use crate::integer::encryption::{encrypt_crt, encrypt_words_radix_impl, AsLittleEndianWords};
+use crate::integer::encryption::{
+    encrypt_crt, encrypt_words_radix_impl, encrypt_words_radix_impl_parallelized,
+    AsLittleEndianWords,
+};
 use crate::shortint::parameters::MessageModulus;
 use crate::shortint::{
     CiphertextBase, CiphertextBig, CiphertextSmall, ClientKey as ShortintClientKey, PBSOrderMarker,
@@ -109,6 +112,69 @@ impl ClientKey {
         self.encrypt_words_radix(message, num_blocks, crate::shortint::ClientKey::encrypt)
     }
 
+    /// Encrypts an integer in radix decomposition, the blocks being encrypted in parallel.
+    ///
+    /// The result has the same distribution as the one of [`encrypt_radix`](Self::encrypt_radix),
+    /// but each block is encrypted with the random generator of the thread encrypting it. This
+    /// reduces the latency of the encryption of integers with many blocks.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::{ClientKey, U256};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// // 2 * 128 = 256 bits of message
+    /// let num_block = 128;
+    ///
+    /// let msg = U256::from((u128::MAX - 167, 12_u128));
+    ///
+    /// let ct = cks.encrypt_radix_parallelized(msg, num_block);
+    ///
+    /// // Decryption
+    /// let dec: U256 = cks.decrypt_radix(&ct);
+    /// assert_eq!(msg, dec);
+    /// ```
+    pub fn encrypt_radix_parallelized<T: AsLittleEndianWords>(
+        &self,
+        message: T,
+        num_blocks: usize,
+    ) -> RadixCiphertextBig {
+        encrypt_words_radix_impl_parallelized(
+            &self.key,
+            message,
+            num_blocks,
+            crate::shortint::ClientKey::encrypt,
+        )
+    }
+
+    /// Encrypts an integer in radix decomposition using small ciphertexts, the blocks being
+    /// encrypted in parallel.
+    ///
+    /// See [`encrypt_radix_parallelized`](Self::encrypt_radix_parallelized).
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    pub fn encrypt_radix_small_parallelized<T: AsLittleEndianWords>(
+        &self,
+        message: T,
+        num_blocks: usize,
+    ) -> RadixCiphertextSmall {
+        encrypt_words_radix_impl_parallelized(
+            &self.key,
+            message,
+            num_blocks,
+            crate::shortint::ClientKey::encrypt_small,
+        )
+    }
+
     /// Encrypts an integer in radix decomposition without padding bit
     ///
     /// # Example
@@ -336,6 +402,96 @@ impl ClientKey {
         res
     }
 
+    /// Encrypts a signed integer in radix decomposition, using the two's complement
+    /// representation.
+    ///
+    /// With `num_blocks` blocks of `b` bits of message each, the representable range is
+    /// `[-2^(num_blocks * b - 1), 2^(num_blocks * b - 1) - 1]`. Like [`Self::encrypt_radix`],
+    /// values outside of this range are wrapped around, i.e. taken modulo `2^(num_blocks * b)`.
+    ///
+    /// As the two's complement representation is the same as the unsigned one modulo
+    /// `2^(num_blocks * b)`, operations that wrap around, such as additions, subtractions and
+    /// multiplications, give the correct signed result.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::{ClientKey, ServerKey};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2);
+    /// let sks = ServerKey::new(&cks);
+    ///
+    /// // 2 * 4 = 8 bits of message, values in [-128, 127] are representable
+    /// let num_block = 4;
+    ///
+    /// let ct1 = cks.encrypt_signed_radix(-100, num_block);
+    /// let ct2 = cks.encrypt_signed_radix(42, num_block);
+    ///
+    /// let ct_res = sks.add_parallelized(&ct1, &ct2);
+    ///
+    /// // Decryption
+    /// let dec = cks.decrypt_signed_radix(&ct_res);
+    /// assert_eq!(dec, -58);
+    /// ```
+    pub fn encrypt_signed_radix(&self, message: i64, num_blocks: usize) -> RadixCiphertextBig {
+        let message_modulus = self.key.parameters.message_modulus.0 as u64;
+        let bits_in_block = message_modulus.ilog2() as usize;
+
+        let blocks = (0..num_blocks)
+            .map(|i| {
+                // The arithmetic shift sign extends the message past its 64 bits
+                let shift = (i * bits_in_block).min(63);
+                let block_value = (message >> shift) as u64 % message_modulus;
+                self.key.encrypt(block_value)
+            })
+            .collect::<Vec<_>>();
+
+        RadixCiphertextBig::from(blocks)
+    }
+
+    /// Decrypts a ciphertext encrypting a signed integer in radix decomposition, using the two's
+    /// complement representation.
+    ///
+    /// The most significant bit of the last block is interpreted as the sign. When the radix
+    /// decomposition has more than 64 bits of message, the result is the value wrapped to an
+    /// `i64`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::ClientKey;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2);
+    /// let num_block = 4;
+    ///
+    /// let msg = -37_i64;
+    ///
+    /// // Encryption
+    /// let ct = cks.encrypt_signed_radix(msg, num_block);
+    ///
+    /// // Decryption
+    /// let dec = cks.decrypt_signed_radix(&ct);
+    /// assert_eq!(msg, dec);
+    /// ```
+    pub fn decrypt_signed_radix<PBSOrder: PBSOrderMarker>(
+        &self,
+        ctxt: &RadixCiphertext<PBSOrder>,
+    ) -> i64 {
+        let message_modulus = self.key.parameters.message_modulus.0 as u64;
+        let num_bits = ctxt.blocks.len() * message_modulus.ilog2() as usize;
+
+        let unsigned: u64 = self.decrypt_radix(ctxt);
+        if num_bits >= 64 {
+            unsigned as i64
+        } else {
+            // Move the sign bit to the most significant position to sign extend
+            let unused_bits = 64 - num_bits as u32;
+            ((unsigned << unused_bits) as i64) >> unused_bits
+        }
+    }
+
     /// Decrypts a ciphertext in radix decomposition into 64bits
     ///
     /// The words are assumed to be in little endian order.
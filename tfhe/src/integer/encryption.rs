@@ -1,5 +1,6 @@
 use super::U256;
 use crate::shortint::parameters::MessageModulus;
+use rayon::prelude::*;
 
 pub trait AsLittleEndianWords {
     type Iter<'a>: Iterator<Item = &'a u64>
@@ -221,6 +222,35 @@ where
     RadixCiphertextType::from(blocks)
 }
 
+/// Same as [encrypt_words_radix_impl], with the blocks encrypted in parallel.
+///
+/// Each rayon thread encrypts with its own thread local engine, and therefore its own random
+/// generator, so the blocks are encrypted with independent randomness.
+pub(crate) fn encrypt_words_radix_impl_parallelized<BlockKey, Block, RadixCiphertextType, T, F>(
+    encrypting_key: &BlockKey,
+    message_words: T,
+    num_blocks: usize,
+    encrypt_block: F,
+) -> RadixCiphertextType
+where
+    T: AsLittleEndianWords,
+    BlockKey: BlockEncryptionKey + Sync,
+    Block: Send,
+    F: Fn(&BlockKey, u64) -> Block + Sync,
+    RadixCiphertextType: From<Vec<Block>>,
+{
+    // The decomposition of the message is cheap, only the encryption is worth parallelizing
+    let block_values: Vec<u64> =
+        encrypt_words_radix_impl(encrypting_key, message_words, num_blocks, |_, value| value);
+
+    let blocks = block_values
+        .into_par_iter()
+        .map(|value| encrypt_block(encrypting_key, value))
+        .collect::<Vec<_>>();
+
+    RadixCiphertextType::from(blocks)
+}
+
 pub(crate) fn encrypt_crt<BlockKey, Block, CrtCiphertextType, F>(
     encrypting_key: &BlockKey,
     message: u64,
@@ -52,6 +52,7 @@ extern crate core;
 mod tests;
 pub(crate) mod encryption;
 
+pub mod block_decomposition;
 pub mod ciphertext;
 pub mod client_key;
 #[cfg(any(test, feature = "internal-keycache"))]
@@ -64,13 +65,14 @@ pub mod wopbs;
 
 pub use ciphertext::{
     CompressedRadixCiphertextBig, CompressedRadixCiphertextSmall, CrtCiphertext, IntegerCiphertext,
-    RadixCiphertextBig, RadixCiphertextSmall,
+    RadixCiphertextBig, RadixCiphertextCarrySaveBig, RadixCiphertextCarrySaveSmall,
+    RadixCiphertextSmall,
 };
 pub use client_key::{ClientKey, CrtClientKey, RadixClientKey};
 pub use public_key::{
     CompressedPublicKeyBig, CompressedPublicKeySmall, PublicKeyBig, PublicKeySmall,
 };
-pub use server_key::{CheckError, ServerKey};
+pub use server_key::{CheckError, CompressedServerKey, ServerKey};
 pub use u256::U256;
 
 /// Generate a couple of client and server keys with given parameters
@@ -123,6 +125,30 @@ pub fn gen_keys_radix(
     (RadixClientKey::from((cks, num_blocks)), sks)
 }
 
+/// Generate a couple of client and compressed server keys with given parameters
+///
+/// Contrary to [gen_keys_radix], the server key is directly generated in its compressed form,
+/// which is meant to be sent to the server and decompressed there, see
+/// [CompressedServerKey::decompress].
+///
+/// ```rust
+/// use tfhe::integer::gen_keys_radix_compressed;
+/// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+///
+/// // generate the client key and the compressed server key:
+/// let num_blocks = 4;
+/// let (cks, compressed_sks) = gen_keys_radix_compressed(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+/// ```
+pub fn gen_keys_radix_compressed(
+    parameters_set: &crate::shortint::parameters::Parameters,
+    num_blocks: usize,
+) -> (RadixClientKey, CompressedServerKey) {
+    let cks = ClientKey::new(*parameters_set);
+    let compressed_sks = CompressedServerKey::new(&cks);
+
+    (RadixClientKey::from((cks, num_blocks)), compressed_sks)
+}
+
 /// Generate a couple of client and server keys with given parameters
 ///
 /// Contrary to [gen_keys], this returns a [CrtClientKey]
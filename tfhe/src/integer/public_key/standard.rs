@@ -67,6 +67,31 @@ impl<PBSOrder: PBSOrderMarker> PublicKey<PBSOrder> {
         self.key.parameters
     }
 
+    /// Encrypts an integer in radix decomposition, each block being encrypted with the shortint
+    /// public key.
+    ///
+    /// This allows a client holding only the public key to produce ciphertexts that the
+    /// corresponding client key can decrypt.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::{ClientKey, PublicKeyBig};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2);
+    /// let pk = PublicKeyBig::new(&cks);
+    /// let num_block = 4;
+    ///
+    /// let msg = 167_u64;
+    ///
+    /// // 2 * 4 = 8 bits of message
+    /// let ct = pk.encrypt_radix(msg, num_block);
+    ///
+    /// // Decryption
+    /// let dec: u64 = cks.decrypt_radix(&ct);
+    /// assert_eq!(msg, dec);
+    /// ```
     pub fn encrypt_radix<T: AsLittleEndianWords>(
         &self,
         message: T,
@@ -75,6 +100,8 @@ impl<PBSOrder: PBSOrderMarker> PublicKey<PBSOrder> {
         self.encrypt_words_radix(message, num_blocks, PublicKeyBase::encrypt)
     }
 
+    /// Encrypts an integer in radix decomposition without padding bit, each block being encrypted
+    /// with the shortint public key.
     pub fn encrypt_radix_without_padding(
         &self,
         message: u64,
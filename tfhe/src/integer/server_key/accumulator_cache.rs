@@ -0,0 +1,116 @@
+//! Cache of the accumulators used over and over by the integer operations.
+//!
+//! Operations like the carry propagation apply the same lookup tables to every block, generating
+//! them on each call is a constant overhead that the cache removes.
+
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+
+use crate::integer::ServerKey;
+use crate::shortint::server_key::LookupTableOwned;
+use crate::shortint::{CiphertextBase, PBSOrderMarker};
+
+/// Maximum number of accumulators kept in a cache.
+///
+/// The accumulators are keyed by function and message modulus, so only a handful of them are
+/// used in practice, the bound is there to keep the memory usage under control.
+pub(crate) const ACCUMULATOR_CACHE_CAPACITY: usize = 32;
+
+/// The univariate functions whose accumulators are cached.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub(crate) enum CachedFunction {
+    /// `x % message_modulus`
+    MessageExtract,
+    /// `x / message_modulus`
+    CarryExtract,
+    /// `x != 0`
+    IsNonZero,
+    /// `x == 0`
+    IsZero,
+}
+
+impl CachedFunction {
+    fn evaluate(self, x: u64, message_modulus: u64) -> u64 {
+        match self {
+            Self::MessageExtract => x % message_modulus,
+            Self::CarryExtract => x / message_modulus,
+            Self::IsNonZero => u64::from(x != 0),
+            Self::IsZero => u64::from(x == 0),
+        }
+    }
+}
+
+/// Thread-safe and bounded cache of accumulators, shared between the clones of a [`ServerKey`].
+///
+/// The accumulators only depend on the parameters of the key, so the cache is never serialized
+/// and starts empty when a key is deserialized.
+#[derive(Clone, Default)]
+pub(crate) struct AccumulatorCache {
+    accumulators: Arc<RwLock<HashMap<(CachedFunction, usize), Arc<LookupTableOwned>>>>,
+}
+
+impl AccumulatorCache {
+    #[cfg(test)]
+    pub(crate) fn len(&self) -> usize {
+        self.accumulators.read().unwrap().len()
+    }
+
+    fn get_or_insert_with(
+        &self,
+        key: (CachedFunction, usize),
+        generate: impl FnOnce() -> LookupTableOwned,
+    ) -> Arc<LookupTableOwned> {
+        if let Some(accumulator) = self.accumulators.read().unwrap().get(&key) {
+            return Arc::clone(accumulator);
+        }
+
+        let accumulator = Arc::new(generate());
+
+        let mut accumulators = self.accumulators.write().unwrap();
+        if !accumulators.contains_key(&key) && accumulators.len() >= ACCUMULATOR_CACHE_CAPACITY {
+            // Evicts an arbitrary entry, the cache is not expected to fill up in practice
+            let evicted = *accumulators.keys().next().unwrap();
+            accumulators.remove(&evicted);
+        }
+        Arc::clone(accumulators.entry(key).or_insert(accumulator))
+    }
+}
+
+impl ServerKey {
+    /// Returns the accumulator of `function` for blocks with the given message modulus,
+    /// generating it only the first time it is requested.
+    pub(crate) fn cached_accumulator(
+        &self,
+        function: CachedFunction,
+        message_modulus: usize,
+    ) -> Arc<LookupTableOwned> {
+        self.accumulator_cache
+            .get_or_insert_with((function, message_modulus), || {
+                self.key
+                    .generate_accumulator(|x| function.evaluate(x, message_modulus as u64))
+            })
+    }
+
+    /// Applies the cached accumulator of `function` to the block, using the block message
+    /// modulus.
+    pub(crate) fn apply_cached_function_assign<PBSOrder: PBSOrderMarker>(
+        &self,
+        block: &mut CiphertextBase<PBSOrder>,
+        function: CachedFunction,
+    ) {
+        let accumulator = self.cached_accumulator(function, block.message_modulus.0);
+        self.key.apply_lookup_table_assign(block, &accumulator);
+    }
+
+    /// Returns the result of applying the cached accumulator of `function` to the block, see
+    /// [`Self::apply_cached_function_assign`].
+    pub(crate) fn apply_cached_function<PBSOrder: PBSOrderMarker>(
+        &self,
+        block: &CiphertextBase<PBSOrder>,
+        function: CachedFunction,
+    ) -> CiphertextBase<PBSOrder> {
+        let mut result = block.clone();
+        self.apply_cached_function_assign(&mut result, function);
+        result
+    }
+}
@@ -1,6 +1,7 @@
 use rayon::prelude::*;
 
 use super::ServerKey;
+use crate::integer::block_decomposition::{decompose_scalar_checked, DecomposableInto};
 use crate::integer::ciphertext::RadixCiphertext;
 use crate::shortint::server_key::LookupTableOwned;
 use crate::shortint::{CiphertextBase, PBSOrderMarker};
@@ -275,7 +276,7 @@ impl<'a> Comparator<'a> {
         let num_block = lhs.blocks.len();
         let num_block_is_odd = num_block % 2;
 
-        let mut comparisons = if lhs.blocks[0].carry_modulus.0 < lhs.blocks[0].message_modulus.0 {
+        let comparisons = if lhs.blocks[0].carry_modulus.0 < lhs.blocks[0].message_modulus.0 {
             let mut comparisons = Vec::with_capacity(num_block);
             lhs.blocks
                 .par_iter()
@@ -313,6 +314,17 @@ impl<'a> Comparator<'a> {
             comparisons
         };
 
+        self.reduce_comparisons_parallelized(comparisons)
+    }
+
+    /// Reduces the comparisons of chunks of blocks into a single comparison result
+    ///
+    /// The comparisons are expected to be ordered from the least significant chunk
+    /// to the most significant one
+    fn reduce_comparisons_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        mut comparisons: Vec<CiphertextBase<PBSOrder>>,
+    ) -> CiphertextBase<PBSOrder> {
         let mut comparisons_2 = Vec::with_capacity(comparisons.len() / 2);
         while comparisons.len() != 1 {
             comparisons
@@ -345,6 +357,79 @@ impl<'a> Comparator<'a> {
         selection
     }
 
+    /// Computes the comparison between `lhs` and the clear `scalar`
+    ///
+    /// As the scalar is known in clear, each chunk of blocks of `lhs` is compared to
+    /// the corresponding chunk of the scalar using a single lookup table.
+    ///
+    /// Expects the carry buffers to be empty
+    fn unchecked_scalar_compare_parallelized<PBSOrder, T>(
+        &self,
+        lhs: &RadixCiphertext<PBSOrder>,
+        scalar: T,
+    ) -> CiphertextBase<PBSOrder>
+    where
+        PBSOrder: PBSOrderMarker,
+        T: DecomposableInto<u8>,
+    {
+        let message_modulus = self.server_key.key.message_modulus.0 as u64;
+        let bits_in_block = message_modulus.ilog2();
+        let num_block = lhs.blocks.len();
+
+        let Some(scalar_blocks) = decompose_scalar_checked(scalar, bits_in_block, num_block) else {
+            // The scalar does not fit in the blocks, so it is greater than any value lhs can hold
+            return self.server_key.key.create_trivial(Self::IS_INFERIOR);
+        };
+        let scalar_block = |block_index: usize| u64::from(scalar_blocks[block_index]);
+
+        let compare_to_clear = |block: &CiphertextBase<PBSOrder>, modulus: u64, clear: u64| {
+            let acc =
+                self.server_key
+                    .key
+                    .generate_accumulator(|x| match (x % modulus).cmp(&clear) {
+                        std::cmp::Ordering::Less => Self::IS_INFERIOR,
+                        std::cmp::Ordering::Equal => Self::IS_EQUAL,
+                        std::cmp::Ordering::Greater => Self::IS_SUPERIOR,
+                    });
+            self.server_key.key.apply_lookup_table(block, &acc)
+        };
+
+        let comparisons = if lhs.blocks[0].carry_modulus.0 < lhs.blocks[0].message_modulus.0 {
+            let mut comparisons = Vec::with_capacity(num_block);
+            lhs.blocks
+                .par_iter()
+                .enumerate()
+                .map(|(i, block)| compare_to_clear(block, message_modulus, scalar_block(i)))
+                .collect_into_vec(&mut comparisons);
+            comparisons
+        } else {
+            let num_block_is_odd = num_block % 2;
+            let mut comparisons = Vec::with_capacity((num_block / 2) + num_block_is_odd);
+            lhs.blocks
+                .par_chunks_exact(2)
+                .enumerate()
+                .map(|(i, chunk)| {
+                    let packed = self.pack_block_chunk(chunk);
+                    let packed_scalar =
+                        scalar_block(2 * i) + scalar_block(2 * i + 1) * message_modulus;
+                    compare_to_clear(&packed, message_modulus * message_modulus, packed_scalar)
+                })
+                .collect_into_vec(&mut comparisons);
+
+            if num_block_is_odd == 1 {
+                comparisons.push(compare_to_clear(
+                    &lhs.blocks[num_block - 1],
+                    message_modulus,
+                    scalar_block(num_block - 1),
+                ));
+            }
+
+            comparisons
+        };
+
+        self.reduce_comparisons_parallelized(comparisons)
+    }
+
     fn smart_compare<PBSOrder: PBSOrderMarker>(
         &self,
         lhs: &mut RadixCiphertext<PBSOrder>,
@@ -567,6 +652,45 @@ impl<'a> Comparator<'a> {
         self.map_comparison_result(comparison, sign_result_handler_fn, lhs.blocks.len())
     }
 
+    /// Expects the carry buffers to be empty
+    fn unchecked_scalar_comparison_impl<F, PBSOrder, T>(
+        &self,
+        sign_result_handler_fn: F,
+        lhs: &RadixCiphertext<PBSOrder>,
+        scalar: T,
+    ) -> RadixCiphertext<PBSOrder>
+    where
+        F: Fn(u64) -> u64,
+        PBSOrder: PBSOrderMarker,
+        T: DecomposableInto<u8>,
+    {
+        let comparison = self.unchecked_scalar_compare_parallelized(lhs, scalar);
+        self.map_comparison_result(comparison, sign_result_handler_fn, lhs.blocks.len())
+    }
+
+    fn scalar_comparison_impl<F, PBSOrder, T>(
+        &self,
+        sign_result_handler_fn: F,
+        lhs: &RadixCiphertext<PBSOrder>,
+        scalar: T,
+    ) -> RadixCiphertext<PBSOrder>
+    where
+        F: Fn(u64) -> u64,
+        PBSOrder: PBSOrderMarker,
+        T: DecomposableInto<u8>,
+    {
+        let mut tmp_lhs: RadixCiphertext<PBSOrder>;
+        let lhs = if lhs.block_carries_are_empty() {
+            lhs
+        } else {
+            tmp_lhs = lhs.clone();
+            self.server_key.full_propagate_parallelized(&mut tmp_lhs);
+            &tmp_lhs
+        };
+
+        self.unchecked_scalar_comparison_impl(sign_result_handler_fn, lhs, scalar)
+    }
+
     //======================================
     // Unchecked Single-Threaded operations
     //======================================
@@ -669,6 +793,19 @@ impl<'a> Comparator<'a> {
         )
     }
 
+    pub fn unchecked_ne_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        lhs: &RadixCiphertext<PBSOrder>,
+        rhs: &RadixCiphertext<PBSOrder>,
+    ) -> RadixCiphertext<PBSOrder> {
+        self.unchecked_comparison_impl(
+            Self::unchecked_compare_parallelized,
+            |x| u64::from(x != Self::IS_EQUAL),
+            lhs,
+            rhs,
+        )
+    }
+
     pub fn unchecked_gt_parallelized<PBSOrder: PBSOrderMarker>(
         &self,
         lhs: &RadixCiphertext<PBSOrder>,
@@ -737,6 +874,136 @@ impl<'a> Comparator<'a> {
         self.unchecked_min_or_max_parallelized(lhs, rhs, MinMaxSelector::Min)
     }
 
+    //======================================
+    // Unchecked Multi-Threaded scalar operations
+    //======================================
+
+    pub fn unchecked_scalar_eq_parallelized<PBSOrder, T>(
+        &self,
+        lhs: &RadixCiphertext<PBSOrder>,
+        scalar: T,
+    ) -> RadixCiphertext<PBSOrder>
+    where
+        PBSOrder: PBSOrderMarker,
+        T: DecomposableInto<u8>,
+    {
+        self.unchecked_scalar_comparison_impl(|x| u64::from(x == Self::IS_EQUAL), lhs, scalar)
+    }
+
+    pub fn unchecked_scalar_ne_parallelized<PBSOrder, T>(
+        &self,
+        lhs: &RadixCiphertext<PBSOrder>,
+        scalar: T,
+    ) -> RadixCiphertext<PBSOrder>
+    where
+        PBSOrder: PBSOrderMarker,
+        T: DecomposableInto<u8>,
+    {
+        self.unchecked_scalar_comparison_impl(|x| u64::from(x != Self::IS_EQUAL), lhs, scalar)
+    }
+
+    pub fn unchecked_scalar_gt_parallelized<PBSOrder, T>(
+        &self,
+        lhs: &RadixCiphertext<PBSOrder>,
+        scalar: T,
+    ) -> RadixCiphertext<PBSOrder>
+    where
+        PBSOrder: PBSOrderMarker,
+        T: DecomposableInto<u8>,
+    {
+        self.unchecked_scalar_comparison_impl(|x| u64::from(x == Self::IS_SUPERIOR), lhs, scalar)
+    }
+
+    pub fn unchecked_scalar_ge_parallelized<PBSOrder, T>(
+        &self,
+        lhs: &RadixCiphertext<PBSOrder>,
+        scalar: T,
+    ) -> RadixCiphertext<PBSOrder>
+    where
+        PBSOrder: PBSOrderMarker,
+        T: DecomposableInto<u8>,
+    {
+        self.unchecked_scalar_comparison_impl(
+            |x| u64::from(x == Self::IS_EQUAL || x == Self::IS_SUPERIOR),
+            lhs,
+            scalar,
+        )
+    }
+
+    pub fn unchecked_scalar_lt_parallelized<PBSOrder, T>(
+        &self,
+        lhs: &RadixCiphertext<PBSOrder>,
+        scalar: T,
+    ) -> RadixCiphertext<PBSOrder>
+    where
+        PBSOrder: PBSOrderMarker,
+        T: DecomposableInto<u8>,
+    {
+        self.unchecked_scalar_comparison_impl(|x| u64::from(x == Self::IS_INFERIOR), lhs, scalar)
+    }
+
+    pub fn unchecked_scalar_le_parallelized<PBSOrder, T>(
+        &self,
+        lhs: &RadixCiphertext<PBSOrder>,
+        scalar: T,
+    ) -> RadixCiphertext<PBSOrder>
+    where
+        PBSOrder: PBSOrderMarker,
+        T: DecomposableInto<u8>,
+    {
+        self.unchecked_scalar_comparison_impl(
+            |x| u64::from(x == Self::IS_EQUAL || x == Self::IS_INFERIOR),
+            lhs,
+            scalar,
+        )
+    }
+
+    /// Computes whether `lo <= lhs <= hi`, both bounds being inclusive
+    ///
+    /// The comparisons to both bounds are computed at the same time, and their results
+    /// are combined in a single block before being mapped to the boolean result, so that
+    /// no additional bitwise AND is needed.
+    ///
+    /// Expects the carry buffers to be empty
+    pub fn unchecked_scalar_in_range_parallelized<PBSOrder, T>(
+        &self,
+        lhs: &RadixCiphertext<PBSOrder>,
+        lo: T,
+        hi: T,
+    ) -> RadixCiphertext<PBSOrder>
+    where
+        PBSOrder: PBSOrderMarker,
+        T: DecomposableInto<u8> + PartialOrd + Sync,
+    {
+        if lo > hi {
+            // The range is empty
+            return self.server_key.create_trivial_zero_radix(lhs.blocks.len());
+        }
+
+        let (lo_comparison, mut hi_comparison) = rayon::join(
+            || self.unchecked_scalar_compare_parallelized(lhs, lo),
+            || self.unchecked_scalar_compare_parallelized(lhs, hi),
+        );
+
+        // Both comparisons are in [0, 2], so the packed value is at most 8,
+        // which fits in the 4 bits of space required by the comparator
+        self.server_key
+            .key
+            .unchecked_scalar_mul_assign(&mut hi_comparison, 3);
+        self.server_key
+            .key
+            .unchecked_add_assign(&mut hi_comparison, &lo_comparison);
+
+        self.map_comparison_result(
+            hi_comparison,
+            |x| {
+                let (lo_comparison, hi_comparison) = (x % 3, x / 3);
+                u64::from(lo_comparison != Self::IS_INFERIOR && hi_comparison != Self::IS_SUPERIOR)
+            },
+            lhs.blocks.len(),
+        )
+    }
+
     //======================================
     // Smart Single-Threaded operations
     //======================================
@@ -839,6 +1106,19 @@ impl<'a> Comparator<'a> {
         )
     }
 
+    pub fn smart_ne_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        lhs: &mut RadixCiphertext<PBSOrder>,
+        rhs: &mut RadixCiphertext<PBSOrder>,
+    ) -> RadixCiphertext<PBSOrder> {
+        self.smart_comparison_impl(
+            Self::smart_compare_parallelized,
+            |x| u64::from(x != Self::IS_EQUAL),
+            lhs,
+            rhs,
+        )
+    }
+
     pub fn smart_gt_parallelized<PBSOrder: PBSOrderMarker>(
         &self,
         lhs: &mut RadixCiphertext<PBSOrder>,
@@ -943,6 +1223,39 @@ impl<'a> Comparator<'a> {
         self.unchecked_eq_parallelized(lhs, rhs)
     }
 
+    pub fn ne_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        lhs: &RadixCiphertext<PBSOrder>,
+        rhs: &RadixCiphertext<PBSOrder>,
+    ) -> RadixCiphertext<PBSOrder> {
+        let mut tmp_lhs: RadixCiphertext<PBSOrder>;
+        let mut tmp_rhs: RadixCiphertext<PBSOrder>;
+        let (lhs, rhs) = match (lhs.block_carries_are_empty(), rhs.block_carries_are_empty()) {
+            (true, true) => (lhs, rhs),
+            (true, false) => {
+                tmp_rhs = rhs.clone();
+                self.server_key.full_propagate_parallelized(&mut tmp_rhs);
+                (lhs, &tmp_rhs)
+            }
+            (false, true) => {
+                tmp_lhs = lhs.clone();
+                self.server_key.full_propagate_parallelized(&mut tmp_lhs);
+                (&tmp_lhs, rhs)
+            }
+            (false, false) => {
+                tmp_lhs = lhs.clone();
+                tmp_rhs = rhs.clone();
+                rayon::join(
+                    || self.server_key.full_propagate_parallelized(&mut tmp_lhs),
+                    || self.server_key.full_propagate_parallelized(&mut tmp_rhs),
+                );
+                (&tmp_lhs, &tmp_rhs)
+            }
+        };
+
+        self.unchecked_ne_parallelized(lhs, rhs)
+    }
+
     pub fn gt_parallelized<PBSOrder: PBSOrderMarker>(
         &self,
         lhs: &RadixCiphertext<PBSOrder>,
@@ -1146,6 +1459,112 @@ impl<'a> Comparator<'a> {
         self.server_key.full_propagate_parallelized(&mut res);
         res
     }
+
+    //======================================
+    // "Default" Multi-Threaded scalar operations
+    //======================================
+
+    pub fn scalar_eq_parallelized<PBSOrder, T>(
+        &self,
+        lhs: &RadixCiphertext<PBSOrder>,
+        scalar: T,
+    ) -> RadixCiphertext<PBSOrder>
+    where
+        PBSOrder: PBSOrderMarker,
+        T: DecomposableInto<u8>,
+    {
+        self.scalar_comparison_impl(|x| u64::from(x == Self::IS_EQUAL), lhs, scalar)
+    }
+
+    pub fn scalar_ne_parallelized<PBSOrder, T>(
+        &self,
+        lhs: &RadixCiphertext<PBSOrder>,
+        scalar: T,
+    ) -> RadixCiphertext<PBSOrder>
+    where
+        PBSOrder: PBSOrderMarker,
+        T: DecomposableInto<u8>,
+    {
+        self.scalar_comparison_impl(|x| u64::from(x != Self::IS_EQUAL), lhs, scalar)
+    }
+
+    pub fn scalar_gt_parallelized<PBSOrder, T>(
+        &self,
+        lhs: &RadixCiphertext<PBSOrder>,
+        scalar: T,
+    ) -> RadixCiphertext<PBSOrder>
+    where
+        PBSOrder: PBSOrderMarker,
+        T: DecomposableInto<u8>,
+    {
+        self.scalar_comparison_impl(|x| u64::from(x == Self::IS_SUPERIOR), lhs, scalar)
+    }
+
+    pub fn scalar_ge_parallelized<PBSOrder, T>(
+        &self,
+        lhs: &RadixCiphertext<PBSOrder>,
+        scalar: T,
+    ) -> RadixCiphertext<PBSOrder>
+    where
+        PBSOrder: PBSOrderMarker,
+        T: DecomposableInto<u8>,
+    {
+        self.scalar_comparison_impl(
+            |x| u64::from(x == Self::IS_EQUAL || x == Self::IS_SUPERIOR),
+            lhs,
+            scalar,
+        )
+    }
+
+    pub fn scalar_lt_parallelized<PBSOrder, T>(
+        &self,
+        lhs: &RadixCiphertext<PBSOrder>,
+        scalar: T,
+    ) -> RadixCiphertext<PBSOrder>
+    where
+        PBSOrder: PBSOrderMarker,
+        T: DecomposableInto<u8>,
+    {
+        self.scalar_comparison_impl(|x| u64::from(x == Self::IS_INFERIOR), lhs, scalar)
+    }
+
+    pub fn scalar_le_parallelized<PBSOrder, T>(
+        &self,
+        lhs: &RadixCiphertext<PBSOrder>,
+        scalar: T,
+    ) -> RadixCiphertext<PBSOrder>
+    where
+        PBSOrder: PBSOrderMarker,
+        T: DecomposableInto<u8>,
+    {
+        self.scalar_comparison_impl(
+            |x| u64::from(x == Self::IS_EQUAL || x == Self::IS_INFERIOR),
+            lhs,
+            scalar,
+        )
+    }
+
+    pub fn scalar_in_range_parallelized<PBSOrder, T>(
+        &self,
+        lhs: &RadixCiphertext<PBSOrder>,
+        lo: T,
+        hi: T,
+    ) -> RadixCiphertext<PBSOrder>
+    where
+        PBSOrder: PBSOrderMarker,
+        T: DecomposableInto<u8> + PartialOrd + Sync,
+    {
+        let mut tmp_lhs: RadixCiphertext<PBSOrder>;
+        let lhs = if lhs.block_carries_are_empty() {
+            lhs
+        } else {
+            tmp_lhs = lhs.clone();
+            self.server_key.full_propagate_parallelized(&mut tmp_lhs);
+            &tmp_lhs
+        };
+
+        self.unchecked_scalar_in_range_parallelized(lhs, lo, hi)
+    }
 }
 
 #[cfg(test)]
@@ -1352,6 +1771,47 @@ mod tests {
         }
     }
 
+    /// Function to test a scalar comparator function.
+    ///
+    /// This calls the `scalar_comparator_method` with 32 bits ciphertexts and
+    /// scalars that are either equal, of the same size or wider than the ciphertext,
+    /// and compares that it gives the same results as the `clear_fn`.
+    fn test_scalar_function<ScalarFn, ClearF>(
+        param: Parameters,
+        num_test: usize,
+        scalar_comparator_method: ScalarFn,
+        clear_fn: ClearF,
+    ) where
+        ScalarFn:
+            for<'a, 'b> Fn(&'a Comparator<'b>, &'a RadixCiphertextBig, u64) -> RadixCiphertextBig,
+        ClearF: Fn(u64, u64) -> u64,
+    {
+        let mut rng = rand::thread_rng();
+
+        let num_block = (32f64 / (param.message_modulus.0 as f64).log(2.0)).ceil() as usize;
+
+        let (cks, sks) = gen_keys(&param);
+        let comparator = Comparator::new(&sks);
+
+        for _ in 0..num_test {
+            let clear = rng.gen::<u32>() as u64;
+            let scalar = match rng.gen_range(0..3) {
+                0 => clear,
+                1 => rng.gen::<u32>() as u64,
+                _ => rng.gen::<u64>(),
+            };
+
+            let ct = cks.encrypt_radix(clear, num_block);
+
+            let result = scalar_comparator_method(&comparator, &ct, scalar);
+            let decrypted: u64 = cks.decrypt_radix(&result);
+
+            let expected_result = clear_fn(clear, scalar);
+
+            assert_eq!(decrypted, expected_result);
+        }
+    }
+
     fn test_unchecked_min_256_bits(params: crate::shortint::Parameters, num_tests: usize) {
         test_unchecked_function(
             params,
@@ -1535,6 +1995,49 @@ mod tests {
         };
     }
 
+    /// This macro generates the tests for a given scalar comparison fn
+    ///
+    /// All our scalar comparison function have 2 variants:
+    /// - unchecked_scalar_$comparison_name_parallelized
+    /// - scalar_$comparison_name_parallelized
+    macro_rules! define_scalar_comparison_test_functions {
+        ($comparison_name:ident) => {
+            paste::paste!{
+                fn [<unchecked_scalar_ $comparison_name _parallelized_32_bits>](params: crate::shortint::Parameters) {
+                    let num_tests = 10;
+                    test_scalar_function(
+                        params,
+                        num_tests,
+                        |comparator, lhs, rhs| comparator.[<unchecked_scalar_ $comparison_name _parallelized>](lhs, rhs),
+                        |lhs, rhs| u64::from(<u64>::$comparison_name(&lhs, &rhs)),
+                    )
+                }
+
+                fn [<scalar_ $comparison_name _parallelized_32_bits>](params: crate::shortint::Parameters) {
+                    let num_tests = 10;
+                    test_scalar_function(
+                        params,
+                        num_tests,
+                        |comparator, lhs, rhs| comparator.[<scalar_ $comparison_name _parallelized>](lhs, rhs),
+                        |lhs, rhs| u64::from(<u64>::$comparison_name(&lhs, &rhs)),
+                    )
+                }
+
+                create_parametrized_test!([<unchecked_scalar_ $comparison_name _parallelized_32_bits>]
+                {
+                    PARAM_MESSAGE_2_CARRY_2,
+                    PARAM_MESSAGE_4_CARRY_4
+                });
+
+                create_parametrized_test!([<scalar_ $comparison_name _parallelized_32_bits>]
+                {
+                    PARAM_MESSAGE_2_CARRY_2,
+                    PARAM_MESSAGE_4_CARRY_4
+                });
+            }
+        };
+    }
+
     use crate::shortint::parameters::{
         PARAM_MESSAGE_2_CARRY_2,
         // PARAM_MESSAGE_3_CARRY_3
@@ -1547,6 +2050,141 @@ mod tests {
     define_comparison_test_functions!(gt);
     define_comparison_test_functions!(ge);
 
+    // ne only has parallelized variants, so its tests are not generated by
+    // define_comparison_test_functions
+    fn unchecked_ne_parallelized_256_bits(params: crate::shortint::Parameters) {
+        test_unchecked_function(
+            params,
+            1,
+            |comparator, lhs, rhs| comparator.unchecked_ne_parallelized(lhs, rhs),
+            |lhs, rhs| U256::from((lhs != rhs) as u128),
+        )
+    }
+
+    fn smart_ne_parallelized_256_bits(params: crate::shortint::Parameters) {
+        test_smart_function(
+            params,
+            1,
+            |comparator, lhs, rhs| comparator.smart_ne_parallelized(lhs, rhs),
+            |lhs, rhs| U256::from((lhs != rhs) as u128),
+        )
+    }
+
+    fn ne_parallelized_256_bits(params: crate::shortint::Parameters) {
+        test_default_function(
+            params,
+            1,
+            |comparator, lhs, rhs| comparator.ne_parallelized(lhs, rhs),
+            |lhs, rhs| U256::from((lhs != rhs) as u128),
+        )
+    }
+
+    /// Random 256 bits values are almost never equal, so this checks the equal case explicitly
+    fn ne_parallelized_equal_inputs_256_bits(params: crate::shortint::Parameters) {
+        let (cks, sks) = gen_keys(&params);
+        let num_block = (256f64 / (params.message_modulus.0 as f64).log(2.0)).ceil() as usize;
+        let comparator = Comparator::new(&sks);
+
+        let clear = rand::thread_rng().gen::<U256>();
+        let ct_0 = cks.encrypt_radix(clear, num_block);
+        let ct_1 = cks.encrypt_radix(clear, num_block);
+
+        let mut decrypted = U256::default();
+        let result = comparator.unchecked_ne_parallelized(&ct_0, &ct_1);
+        cks.decrypt_radix_into(&result, &mut decrypted);
+        assert_eq!(decrypted, U256::from(0u128));
+
+        let result = comparator.ne_parallelized(&ct_0, &ct_1);
+        cks.decrypt_radix_into(&result, &mut decrypted);
+        assert_eq!(decrypted, U256::from(0u128));
+    }
+
+    create_parametrized_test!(unchecked_ne_parallelized_256_bits {
+        PARAM_MESSAGE_2_CARRY_2,
+        PARAM_MESSAGE_4_CARRY_4
+    });
+    create_parametrized_test!(smart_ne_parallelized_256_bits {
+        PARAM_MESSAGE_2_CARRY_2,
+        PARAM_MESSAGE_4_CARRY_4
+    });
+    create_parametrized_test!(ne_parallelized_256_bits {
+        PARAM_MESSAGE_2_CARRY_2,
+        PARAM_MESSAGE_4_CARRY_4
+    });
+    create_parametrized_test!(ne_parallelized_equal_inputs_256_bits {
+        PARAM_MESSAGE_2_CARRY_2,
+        PARAM_MESSAGE_4_CARRY_4
+    });
+
+    define_scalar_comparison_test_functions!(eq);
+    define_scalar_comparison_test_functions!(ne);
+    define_scalar_comparison_test_functions!(lt);
+    define_scalar_comparison_test_functions!(le);
+    define_scalar_comparison_test_functions!(gt);
+    define_scalar_comparison_test_functions!(ge);
+
+    /// Function to test a scalar range comparator function.
+    ///
+    /// The bounds are picked so that the clear value is below, inside or above the range,
+    /// with some bounds equal to the clear value and some empty ranges.
+    fn test_scalar_in_range_function<ScalarFn>(
+        param: Parameters,
+        num_test: usize,
+        scalar_in_range_method: ScalarFn,
+    ) where
+        ScalarFn: for<'a, 'b> Fn(
+            &'a Comparator<'b>,
+            &'a RadixCiphertextBig,
+            u64,
+            u64,
+        ) -> RadixCiphertextBig,
+    {
+        let mut rng = rand::thread_rng();
+
+        let num_block = (32f64 / (param.message_modulus.0 as f64).log(2.0)).ceil() as usize;
+
+        let (cks, sks) = gen_keys(&param);
+        let comparator = Comparator::new(&sks);
+
+        for _ in 0..num_test {
+            let clear = rng.gen::<u32>() as u64;
+            let mut random_bound = || match rng.gen_range(0..3) {
+                0 => clear,
+                1 => rng.gen::<u32>() as u64,
+                _ => rng.gen::<u64>(),
+            };
+            let (lo, hi) = (random_bound(), random_bound());
+
+            let ct = cks.encrypt_radix(clear, num_block);
+
+            let result = scalar_in_range_method(&comparator, &ct, lo, hi);
+            let decrypted: u64 = cks.decrypt_radix(&result);
+
+            assert_eq!(decrypted, u64::from((lo..=hi).contains(&clear)));
+        }
+    }
+
+    fn unchecked_scalar_in_range_parallelized_32_bits(params: crate::shortint::Parameters) {
+        test_scalar_in_range_function(params, 10, |comparator, lhs, lo, hi| {
+            comparator.unchecked_scalar_in_range_parallelized(lhs, lo, hi)
+        })
+    }
+
+    fn scalar_in_range_parallelized_32_bits(params: crate::shortint::Parameters) {
+        test_scalar_in_range_function(params, 10, |comparator, lhs, lo, hi| {
+            comparator.scalar_in_range_parallelized(lhs, lo, hi)
+        })
+    }
+
+    create_parametrized_test!(unchecked_scalar_in_range_parallelized_32_bits {
+        PARAM_MESSAGE_2_CARRY_2,
+        PARAM_MESSAGE_4_CARRY_4
+    });
+    create_parametrized_test!(scalar_in_range_parallelized_32_bits {
+        PARAM_MESSAGE_2_CARRY_2,
+        PARAM_MESSAGE_4_CARRY_4
+    });
+
     //================
     // Min
     //================
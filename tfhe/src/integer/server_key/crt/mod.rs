@@ -1,4 +1,5 @@
 use crate::integer::ciphertext::CrtCiphertext;
+use crate::integer::server_key::accumulator_cache::CachedFunction;
 use crate::integer::ServerKey;
 
 #[cfg(test)]
@@ -42,7 +43,7 @@ impl ServerKey {
     /// ```
     pub fn full_extract_message_assign(&self, ctxt: &mut CrtCiphertext) {
         for ct_i in ctxt.blocks.iter_mut() {
-            self.key.message_extract_assign(ct_i);
+            self.apply_cached_function_assign(ct_i, CachedFunction::MessageExtract);
         }
     }
 
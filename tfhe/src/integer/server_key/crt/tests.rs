@@ -14,6 +14,8 @@ create_parametrized_test!(integer_smart_crt_scalar_mul);
 create_parametrized_test!(integer_smart_crt_scalar_sub);
 create_parametrized_test!(integer_smart_crt_sub);
 
+create_parametrized_test!(integer_crt_radix_conversion);
+
 /// Number of loop iteration within randomized tests
 const NB_TEST: usize = 30;
 
@@ -271,3 +273,76 @@ fn integer_smart_crt_sub(param: Parameters) {
         assert_eq!(clear_0, dec_res);
     }
 }
+
+fn integer_crt_radix_conversion(param: Parameters) {
+    // Define CRT basis, and global modulus
+    let basis = make_basis(param.message_modulus.0);
+    let modulus = basis.iter().product::<u64>();
+
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    // Enough radix blocks to hold any CRT value, plus one so that radix values can exceed the
+    // CRT modulus
+    let message_modulus = param.message_modulus.0 as u64;
+    let mut num_blocks = 1;
+    while message_modulus.pow(num_blocks) < modulus {
+        num_blocks += 1;
+    }
+    num_blocks += 1;
+    let radix_modulus = message_modulus.pow(num_blocks);
+
+    for _ in 0..NB_TEST_SMALLER {
+        let clear = rng.gen::<u64>() % radix_modulus;
+
+        // radix to CRT reduces the value modulo the CRT modulus
+        let ct_radix = cks.encrypt_radix(clear, num_blocks as usize);
+        let ct_crt = sks.radix_to_crt_parallelized(&ct_radix, basis.clone());
+        let dec_res = cks.decrypt_crt(&ct_crt);
+        assert_eq!(dec_res, clear % modulus);
+
+        // CRT to radix is exact when the radix ciphertext is wide enough
+        let ct_res = sks.crt_to_radix_parallelized(&ct_crt, num_blocks as usize);
+        let dec_res: u64 = cks.decrypt_radix(&ct_res);
+        assert_eq!(dec_res, clear % modulus);
+
+        // and truncating otherwise
+        let ct_res = sks.crt_to_radix_parallelized(&ct_crt, 1);
+        let dec_res: u64 = cks.decrypt_radix(&ct_res);
+        assert_eq!(dec_res, clear % modulus % message_modulus);
+    }
+}
+
+#[test]
+fn integer_radix_to_crt_largest_modulus() {
+    let param = PARAM_MESSAGE_2_CARRY_2;
+    // Largest modulus for which the sum of two residues fits in a block
+    let basis = vec![7, 8];
+    let modulus = basis.iter().product::<u64>();
+
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    let num_blocks = 4;
+    for _ in 0..NB_TEST_SMALLER {
+        let clear = rng.gen::<u64>() % 256;
+
+        let ct_radix = cks.encrypt_radix(clear, num_blocks);
+        let ct_crt = sks.radix_to_crt_parallelized(&ct_radix, basis.clone());
+        let dec_res = cks.decrypt_crt(&ct_crt);
+        assert_eq!(dec_res, clear % modulus);
+    }
+}
+
+#[test]
+#[should_panic(expected = "The moduli of the basis must be in [2, 8]")]
+fn integer_radix_to_crt_rejects_modulus_above_half_the_block_modulus() {
+    let (cks, sks) = KEY_CACHE.get_from_params(PARAM_MESSAGE_2_CARRY_2);
+
+    let ct_radix = cks.encrypt_radix(97, 4);
+    let _ = sks.radix_to_crt_parallelized(&ct_radix, vec![11]);
+}
@@ -1,12 +1,18 @@
 mod add_crt;
 mod mul_crt;
 mod neg_crt;
+mod radix_conversion;
 mod scalar_add_crt;
+mod scalar_div_crt;
 mod scalar_mul_crt;
 mod scalar_sub_crt;
 mod sub_crt;
 
+#[cfg(test)]
+mod tests;
+
 use crate::integer::ciphertext::CrtCiphertext;
+use crate::integer::server_key::accumulator_cache::CachedFunction;
 use crate::integer::ServerKey;
 use rayon::prelude::*;
 
@@ -40,7 +46,7 @@ impl ServerKey {
     /// ```
     pub fn full_extract_message_assign_parallelized(&self, ctxt: &mut CrtCiphertext) {
         ctxt.blocks.par_iter_mut().for_each(|ct_i| {
-            self.key.message_extract_assign(ct_i);
+            self.apply_cached_function_assign(ct_i, CachedFunction::MessageExtract);
         });
     }
 
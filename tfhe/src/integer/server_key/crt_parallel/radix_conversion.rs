@@ -0,0 +1,308 @@
+use super::scalar_div_crt::modular_inverse;
+use crate::integer::ciphertext::{CrtCiphertext, RadixCiphertextBig};
+use crate::integer::ServerKey;
+use crate::shortint::parameters::{CarryModulus, MessageModulus};
+use crate::shortint::CiphertextBig;
+use rayon::prelude::*;
+
+impl ServerKey {
+    /// Homomorphically converts a radix ciphertext to the CRT representation with the given
+    /// `basis`.
+    ///
+    /// The result encrypts `ct mod M`, where `M` is the product of the moduli of the basis, the
+    /// conversion is therefore exact if `ct < M` and truncating otherwise. The basis moduli must
+    /// be pairwise coprime for the result to be a meaningful CRT value, and each modulus must be
+    /// in `[2, (message_modulus * carry_modulus - 1) / 2 + 1]`, so that the sum of two residues
+    /// fits in a block.
+    ///
+    /// Writing `ct = sum_j d_j * B^j` with `B` the radix message modulus, the residue modulo
+    /// `m_i` is `sum_j (d_j * (B^j mod m_i)) mod m_i`: each term is computed with one
+    /// programmable bootstrapping on the digit `d_j`, the terms are then summed in groups as
+    /// large as the carry space allows, each group sum being reduced modulo `m_i`, until a single
+    /// residue is left.
+    ///
+    /// This function computes the operation without checking that the input block carries are
+    /// empty, which is required for the result to be correct. The output block carries are
+    /// always empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a modulus of the basis is not in
+    /// `[2, (message_modulus * carry_modulus - 1) / 2 + 1]`.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    pub fn unchecked_radix_to_crt_parallelized(
+        &self,
+        ct: &RadixCiphertextBig,
+        basis: Vec<u64>,
+    ) -> CrtCiphertext {
+        let message_modulus = self.key.message_modulus.0 as u64;
+        let total_modulus = message_modulus * self.key.carry_modulus.0 as u64;
+        // Two terms must fit in a block for the sum to be reduced
+        let max_modulus = (total_modulus - 1) / 2 + 1;
+        assert!(
+            basis
+                .iter()
+                .all(|&modulus| (2..=max_modulus).contains(&modulus)),
+            "The moduli of the basis must be in [2, {max_modulus}]"
+        );
+
+        let blocks = basis
+            .par_iter()
+            .map(|&modulus| {
+                // Terms of the sum, reduced modulo the basis modulus
+                let terms = ct
+                    .blocks
+                    .par_iter()
+                    .enumerate()
+                    .map(|(j, block)| {
+                        let weight = (0..j).fold(1, |weight, _| weight * message_modulus % modulus);
+                        let acc = self
+                            .key
+                            .generate_accumulator(|x| (x % message_modulus) * weight % modulus);
+                        self.key.apply_lookup_table(block, &acc)
+                    })
+                    .collect::<Vec<_>>();
+
+                let reduce_acc = self.key.generate_accumulator(|x| x % modulus);
+                let sum = self.unchecked_sum_blocks_in_groups_parallelized(
+                    terms,
+                    modulus - 1,
+                    &reduce_acc,
+                    modulus - 1,
+                );
+
+                let mut residue = self.key.apply_lookup_table(&sum, &reduce_acc);
+                // Same metadata as blocks encrypted with this modulus
+                residue.message_modulus = MessageModulus(modulus as usize);
+                residue.carry_modulus = CarryModulus((total_modulus / modulus) as usize);
+                residue
+            })
+            .collect::<Vec<_>>();
+
+        CrtCiphertext::from((blocks, basis))
+    }
+
+    /// Homomorphically converts a radix ciphertext to the CRT representation with the given
+    /// `basis`.
+    ///
+    /// The result encrypts `ct mod M`, where `M` is the product of the moduli of the basis, the
+    /// conversion is therefore exact if `ct < M` and truncating otherwise. The basis moduli must
+    /// be pairwise coprime for the result to be a meaningful CRT value, and each modulus must be
+    /// in `[2, (message_modulus * carry_modulus - 1) / 2 + 1]`, so that the sum of two residues
+    /// fits in a block.
+    ///
+    /// See [`unchecked_radix_to_crt_parallelized`](Self::unchecked_radix_to_crt_parallelized)
+    /// for details on the implementation.
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertexts block carries are empty and clears them if it's not the
+    /// case and the operation requires it. It outputs a ciphertext whose block carries are always
+    /// empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a modulus of the basis is not in
+    /// `[2, (message_modulus * carry_modulus - 1) / 2 + 1]`.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let (cks, sks) = gen_keys(&PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let msg = 97u64;
+    /// let ct = cks.encrypt_radix(msg, num_blocks);
+    ///
+    /// let basis = vec![2, 3, 5, 7];
+    /// let ct_res = sks.radix_to_crt_parallelized(&ct, basis);
+    ///
+    /// // Decrypt:
+    /// let dec_result = cks.decrypt_crt(&ct_res);
+    /// assert_eq!(dec_result, msg % 210);
+    /// ```
+    pub fn radix_to_crt_parallelized(
+        &self,
+        ct: &RadixCiphertextBig,
+        basis: Vec<u64>,
+    ) -> CrtCiphertext {
+        if ct.block_carries_are_empty() {
+            self.unchecked_radix_to_crt_parallelized(ct, basis)
+        } else {
+            let mut ct = ct.clone();
+            self.full_propagate_parallelized(&mut ct);
+            self.unchecked_radix_to_crt_parallelized(&ct, basis)
+        }
+    }
+
+    /// Homomorphically converts a CRT ciphertext to a radix ciphertext with `num_blocks` blocks.
+    ///
+    /// The CRT value `v`, in `[0, M)` where `M` is the product of the moduli of the basis, is
+    /// reconstructed in radix form, the result encrypts `v mod message_modulus^num_blocks`: the
+    /// conversion is therefore exact if the radix ciphertext has enough blocks to hold `M - 1` and
+    /// truncating otherwise. The basis moduli must be pairwise coprime and their product must fit
+    /// in a `u64`.
+    ///
+    /// The reconstruction is `v = (sum_i (r_i * c_i mod M)) mod M`, with `r_i` the residues and
+    /// `c_i` the CRT coefficients. The digits of each `r_i * c_i mod M` are computed with one
+    /// programmable bootstrapping per digit on the residue `r_i`, the resulting radix ciphertexts
+    /// are summed and the sum is reduced modulo `M` with
+    /// [`scalar_mod_parallelized`](Self::scalar_mod_parallelized). This is a slow operation.
+    ///
+    /// This function computes the operation without checking that the input block carries are
+    /// empty, which is required for the result to be correct. The output block carries are
+    /// always empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the basis moduli are not pairwise coprime or if their product does not fit in a
+    /// `u64`.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    pub fn unchecked_crt_to_radix_parallelized(
+        &self,
+        ct: &CrtCiphertext,
+        num_blocks: usize,
+    ) -> RadixCiphertextBig {
+        let message_modulus = self.key.message_modulus.0 as u128;
+        let whole_modulus = ct
+            .moduli
+            .iter()
+            .try_fold(1u64, |product, &modulus| product.checked_mul(modulus))
+            .expect("The product of the moduli of the basis must fit in a u64")
+            as u128;
+
+        // CRT coefficients, c_i = 1 mod m_i and c_i = 0 mod m_j for j != i
+        let coefficients = ct
+            .moduli
+            .iter()
+            .map(|&modulus| {
+                let other_moduli = whole_modulus / modulus as u128;
+                let inverse = modular_inverse((other_moduli % modulus as u128) as u64, modulus)
+                    .expect("The moduli of the basis must be pairwise coprime");
+                other_moduli * inverse as u128 % whole_modulus
+            })
+            .collect::<Vec<_>>();
+
+        // The sum holds at most one value below whole_modulus per residue
+        let max_sum = (whole_modulus - 1) * ct.moduli.len() as u128;
+        let mut sum_num_blocks = 0;
+        let mut remaining = max_sum;
+        while remaining > 0 {
+            remaining /= message_modulus;
+            sum_num_blocks += 1;
+        }
+        let sum_num_blocks = sum_num_blocks.max(1);
+
+        let terms = ct
+            .blocks
+            .par_iter()
+            .zip(ct.moduli.par_iter())
+            .zip(coefficients.par_iter())
+            .map(|((residue, &modulus), &coefficient)| {
+                let term_value = |x: u64| (x % modulus) as u128 * coefficient % whole_modulus;
+                let blocks = (0..sum_num_blocks)
+                    .into_par_iter()
+                    .map(|j| {
+                        let acc = self.key.generate_accumulator(|x| {
+                            (term_value(x) / message_modulus.pow(j as u32) % message_modulus) as u64
+                        });
+                        let mut block: CiphertextBig = self.key.apply_lookup_table(residue, &acc);
+                        // Same metadata as radix blocks
+                        block.message_modulus = self.key.message_modulus;
+                        block.carry_modulus = self.key.carry_modulus;
+                        block
+                    })
+                    .collect::<Vec<_>>();
+                RadixCiphertextBig::from(blocks)
+            })
+            .collect::<Vec<_>>();
+
+        let sum = self
+            .default_binary_op_seq_parallelized(&terms, ServerKey::add_parallelized)
+            .unwrap();
+        let mut result = self.unchecked_scalar_mod_parallelized(&sum, whole_modulus as u64);
+
+        // Truncates, or extends with empty blocks, the reconstructed value
+        result
+            .blocks
+            .resize_with(num_blocks, || self.key.create_trivial(0));
+        result
+    }
+
+    /// Homomorphically converts a CRT ciphertext to a radix ciphertext with `num_blocks` blocks.
+    ///
+    /// The result encrypts `v mod message_modulus^num_blocks`, where `v` is the CRT value: the
+    /// conversion is therefore exact if the radix ciphertext has enough blocks to hold `M - 1`,
+    /// `M` being the product of the moduli of the basis, and truncating otherwise. The basis
+    /// moduli must be pairwise coprime and their product must fit in a `u64`.
+    ///
+    /// See [`unchecked_crt_to_radix_parallelized`](Self::unchecked_crt_to_radix_parallelized)
+    /// for details on the implementation.
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertexts block carries are empty and clears them if it's not the
+    /// case and the operation requires it. It outputs a ciphertext whose block carries are always
+    /// empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the basis moduli are not pairwise coprime or if their product does not fit in a
+    /// `u64`.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let (cks, sks) = gen_keys(&PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let msg = 97u64;
+    /// let basis = vec![2, 3, 5, 7];
+    /// let ct = cks.encrypt_crt(msg, basis);
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let ct_res = sks.crt_to_radix_parallelized(&ct, num_blocks);
+    ///
+    /// // Decrypt:
+    /// let dec_result: u64 = cks.decrypt_radix(&ct_res);
+    /// assert_eq!(dec_result, msg);
+    /// ```
+    pub fn crt_to_radix_parallelized(
+        &self,
+        ct: &CrtCiphertext,
+        num_blocks: usize,
+    ) -> RadixCiphertextBig {
+        let carries_are_empty = ct
+            .blocks
+            .iter()
+            .zip(ct.moduli.iter())
+            .all(|(block, &modulus)| (block.degree.0 as u64) < modulus);
+
+        if carries_are_empty {
+            self.unchecked_crt_to_radix_parallelized(ct, num_blocks)
+        } else {
+            let mut ct = ct.clone();
+            self.full_extract_message_assign_parallelized(&mut ct);
+            self.unchecked_crt_to_radix_parallelized(&ct, num_blocks)
+        }
+    }
+}
@@ -0,0 +1,193 @@
+use crate::integer::client_key::utils::extended_euclid;
+use crate::integer::server_key::CheckError;
+use crate::integer::server_key::CheckError::CarryFull;
+use crate::integer::{CrtCiphertext, ServerKey};
+use rayon::prelude::*;
+
+/// Return the inverse of `scalar` modulo `modulus` if it exists, i.e. if `scalar` and `modulus`
+/// are coprime.
+pub(super) fn modular_inverse(scalar: u64, modulus: u64) -> Option<u64> {
+    let (l, r, s, _, _) = extended_euclid((scalar % modulus) as i64, modulus as i64);
+    if r[l] != 1 {
+        return None;
+    }
+    Some(s[l].rem_euclid(modulus as i64) as u64)
+}
+
+impl ServerKey {
+    /// Computes homomorphically a division of a ciphertext by a scalar.
+    ///
+    /// Each residue is multiplied by the inverse of `scalar` modulo its own modulus `m_i`.
+    ///
+    /// If `scalar` is coprime with every modulus of the basis, the result encrypts the
+    /// quotient modulo `M`, the product of the moduli, i.e. the value `x` such that
+    /// `x * scalar = ct (mod M)`.
+    ///
+    /// If `gcd(scalar, m_i) != 1` for some modulus, `scalar` has no inverse modulo `m_i` and
+    /// the corresponding residue is left untouched. The other residues are still divided, so the
+    /// result does not decrypt to a meaningful quotient anymore: callers must make sure the
+    /// scalar is invertible modulo each `m_i` if they need the full CRT value.
+    ///
+    /// This function computes the operation without checking if it exceeds the capacity of the
+    /// ciphertext, see [`Self::checked_crt_scalar_div_parallelized`] for the checked version.
+    ///
+    /// The result is returned as a new ciphertext.
+    ///
+    /// # Example
+    ///
+    ///```rust
+    /// use tfhe::integer::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(&PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let clear_1 = 14;
+    /// let clear_2 = 7;
+    /// let basis = vec![2, 3, 5];
+    /// // Encrypt a message
+    /// let ctxt_1 = cks.encrypt_crt(clear_1, basis);
+    ///
+    /// let ctxt = sks.unchecked_crt_scalar_div_parallelized(&ctxt_1, clear_2);
+    ///
+    /// // Decrypt
+    /// let res = cks.decrypt_crt(&ctxt);
+    /// assert_eq!((res * clear_2) % 30, clear_1);
+    /// assert_eq!(res, 2);
+    /// ```
+    pub fn unchecked_crt_scalar_div_parallelized(
+        &self,
+        ctxt: &CrtCiphertext,
+        scalar: u64,
+    ) -> CrtCiphertext {
+        let mut ct_result = ctxt.clone();
+        self.unchecked_crt_scalar_div_assign_parallelized(&mut ct_result, scalar);
+        ct_result
+    }
+
+    /// Computes homomorphically a division of a ciphertext by a scalar.
+    ///
+    /// The result is assigned to the input ciphertext.
+    ///
+    /// See [`Self::unchecked_crt_scalar_div_parallelized`] for the behavior when `scalar` is not
+    /// invertible modulo one of the moduli.
+    pub fn unchecked_crt_scalar_div_assign_parallelized(
+        &self,
+        ctxt: &mut CrtCiphertext,
+        scalar: u64,
+    ) {
+        ctxt.blocks
+            .par_iter_mut()
+            .zip(ctxt.moduli.par_iter())
+            .for_each(|(ct_i, mod_i)| {
+                if let Some(inverse) = modular_inverse(scalar, *mod_i) {
+                    self.key.unchecked_scalar_mul_assign(ct_i, inverse as u8);
+                }
+            });
+    }
+
+    /// Verifies if a ciphertext can be divided by a scalar, i.e. if multiplying each residue by
+    /// the inverse of `scalar` modulo its modulus does not exceed the carry space.
+    pub fn is_crt_scalar_div_possible(&self, ctxt: &CrtCiphertext, scalar: u64) -> bool {
+        ctxt.blocks
+            .iter()
+            .zip(ctxt.moduli.iter())
+            .all(|(ct_i, mod_i)| {
+                modular_inverse(scalar, *mod_i).map_or(true, |inverse| {
+                    self.key.is_scalar_mul_possible(ct_i, inverse as u8)
+                })
+            })
+    }
+
+    /// Computes homomorphically a division of a ciphertext by a scalar.
+    ///
+    /// If the operation can be performed, the result is returned in a new ciphertext.
+    /// Otherwise [CheckError::CarryFull] is returned.
+    ///
+    /// See [`Self::unchecked_crt_scalar_div_parallelized`] for the behavior when `scalar` is not
+    /// invertible modulo one of the moduli.
+    ///
+    /// # Example
+    ///
+    ///```rust
+    /// use tfhe::integer::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(&PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let clear_1 = 14;
+    /// let clear_2 = 7;
+    /// let basis = vec![2, 3, 5];
+    /// // Encrypt a message
+    /// let ctxt_1 = cks.encrypt_crt(clear_1, basis);
+    ///
+    /// let ctxt = sks
+    ///     .checked_crt_scalar_div_parallelized(&ctxt_1, clear_2)
+    ///     .unwrap();
+    ///
+    /// // Decrypt
+    /// let res = cks.decrypt_crt(&ctxt);
+    /// assert_eq!(res, 2);
+    /// ```
+    pub fn checked_crt_scalar_div_parallelized(
+        &self,
+        ct: &CrtCiphertext,
+        scalar: u64,
+    ) -> Result<CrtCiphertext, CheckError> {
+        // If the ciphertext cannot be divided without exceeding the capacity of a ciphertext
+        if self.is_crt_scalar_div_possible(ct, scalar) {
+            Ok(self.unchecked_crt_scalar_div_parallelized(ct, scalar))
+        } else {
+            Err(CarryFull)
+        }
+    }
+
+    /// Computes homomorphically a division of a ciphertext by a scalar.
+    ///
+    /// If the operation can be performed, the result is assigned to the input ciphertext.
+    /// Otherwise [CheckError::CarryFull] is returned, and the ciphertext is not modified.
+    pub fn checked_crt_scalar_div_assign_parallelized(
+        &self,
+        ct: &mut CrtCiphertext,
+        scalar: u64,
+    ) -> Result<(), CheckError> {
+        // If the ciphertext cannot be divided without exceeding the capacity of a ciphertext
+        if self.is_crt_scalar_div_possible(ct, scalar) {
+            self.unchecked_crt_scalar_div_assign_parallelized(ct, scalar);
+            Ok(())
+        } else {
+            Err(CarryFull)
+        }
+    }
+
+    /// Computes homomorphically a division of a ciphertext by a scalar.
+    ///
+    /// The carries of the residues are cleared first if the division would exceed the capacity
+    /// of the ciphertext.
+    ///
+    /// The result is returned as a new ciphertext.
+    pub fn smart_crt_scalar_div_parallelized(
+        &self,
+        ctxt: &mut CrtCiphertext,
+        scalar: u64,
+    ) -> CrtCiphertext {
+        if !self.is_crt_scalar_div_possible(ctxt, scalar) {
+            self.full_extract_message_assign_parallelized(ctxt);
+        }
+        self.unchecked_crt_scalar_div_parallelized(ctxt, scalar)
+    }
+
+    /// Computes homomorphically a division of a ciphertext by a scalar.
+    ///
+    /// The carries of the residues are cleared first if the division would exceed the capacity
+    /// of the ciphertext.
+    ///
+    /// The result is assigned to the input ciphertext.
+    pub fn smart_crt_scalar_div_assign_parallelized(&self, ctxt: &mut CrtCiphertext, scalar: u64) {
+        if !self.is_crt_scalar_div_possible(ctxt, scalar) {
+            self.full_extract_message_assign_parallelized(ctxt);
+        }
+        self.unchecked_crt_scalar_div_assign_parallelized(ctxt, scalar);
+    }
+}
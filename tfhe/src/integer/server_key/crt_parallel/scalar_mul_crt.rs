@@ -90,13 +90,9 @@ impl ServerKey {
         ct: &CrtCiphertext,
         scalar: u64,
     ) -> Result<CrtCiphertext, CheckError> {
-        let mut ct_result = ct.clone();
-
         // If the ciphertext cannot be multiplied without exceeding the capacity of a ciphertext
         if self.is_crt_scalar_mul_possible(ct, scalar) {
-            ct_result = self.unchecked_crt_scalar_mul(&ct_result, scalar);
-
-            Ok(ct_result)
+            Ok(self.unchecked_crt_scalar_mul_parallelized(ct, scalar))
         } else {
             Err(CarryFull)
         }
@@ -158,7 +154,7 @@ impl ServerKey {
         if !self.is_crt_scalar_mul_possible(ctxt, scalar) {
             self.full_extract_message_assign_parallelized(ctxt);
         }
-        self.unchecked_crt_scalar_mul(ctxt, scalar)
+        self.unchecked_crt_scalar_mul_parallelized(ctxt, scalar)
     }
 
     /// Computes homomorphically a multiplication between a scalar and a ciphertext.
@@ -191,7 +187,7 @@ impl ServerKey {
     /// assert_eq!((clear_1 * clear_2) % 30, res);
     /// ```
     pub fn smart_crt_scalar_mul_assign_parallelized(&self, ctxt: &mut CrtCiphertext, scalar: u64) {
-        if !self.is_crt_small_scalar_mul_possible(ctxt, scalar) {
+        if !self.is_crt_scalar_mul_possible(ctxt, scalar) {
             self.full_extract_message_assign_parallelized(ctxt);
         }
         self.unchecked_crt_scalar_mul_assign_parallelized(ctxt, scalar);
@@ -0,0 +1,134 @@
+use crate::integer::keycache::KEY_CACHE;
+use crate::integer::server_key::CheckError;
+use crate::shortint::parameters::*;
+use crate::shortint::Parameters;
+use rand::Rng;
+
+create_parametrized_test!(integer_unchecked_crt_scalar_div_parallelized);
+create_parametrized_test!(integer_smart_crt_scalar_div_parallelized);
+create_parametrized_test!(integer_checked_crt_scalar_div_parallelized);
+
+/// Number of loop iteration within randomized tests
+const NB_TEST: usize = 30;
+
+fn make_basis(message_modulus: usize) -> Vec<u64> {
+    match message_modulus {
+        2 => vec![2],
+        3 => vec![2],
+        n if n < 8 => vec![2, 3],
+        n if n < 16 => vec![2, 5, 7],
+        _ => vec![3, 7, 13],
+    }
+}
+
+/// Returns a random scalar that is invertible modulo each modulus of the basis
+fn random_invertible_scalar(rng: &mut impl Rng, basis: &[u64]) -> u64 {
+    loop {
+        let scalar = rng.gen::<u64>() % basis.iter().product::<u64>();
+        if basis
+            .iter()
+            .all(|modulus| super::scalar_div_crt::modular_inverse(scalar, *modulus).is_some())
+        {
+            return scalar;
+        }
+    }
+}
+
+fn integer_unchecked_crt_scalar_div_parallelized(param: Parameters) {
+    // Define CRT basis, and global modulus
+    let basis = make_basis(param.message_modulus.0);
+    let modulus = basis.iter().product::<u64>();
+
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..NB_TEST {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = random_invertible_scalar(&mut rng, &basis);
+
+        // encryption of an integer
+        let ct_zero = cks.encrypt_crt(clear_0, basis.clone());
+
+        // divide the ciphertext by the scalar
+        let ct_res = sks.unchecked_crt_scalar_div_parallelized(&ct_zero, clear_1);
+
+        // decryption of ct_res
+        let dec_res = cks.decrypt_crt(&ct_res);
+
+        // assert
+        assert_eq!((dec_res * clear_1) % modulus, clear_0);
+    }
+}
+
+fn integer_smart_crt_scalar_div_parallelized(param: Parameters) {
+    // Define CRT basis, and global modulus
+    let basis = make_basis(param.message_modulus.0);
+    let modulus = basis.iter().product::<u64>();
+
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..NB_TEST {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+        let clear_2 = random_invertible_scalar(&mut rng, &basis);
+
+        // encryption of an integer, with non empty carries
+        let mut ct_zero = cks.encrypt_crt(clear_0, basis.clone());
+        let ct_one = cks.encrypt_crt(clear_1, basis.clone());
+        sks.unchecked_crt_add_assign(&mut ct_zero, &ct_one);
+
+        // divide the ciphertext by the scalar
+        sks.smart_crt_scalar_div_assign_parallelized(&mut ct_zero, clear_2);
+
+        // decryption of ct_res
+        let dec_res = cks.decrypt_crt(&ct_zero);
+
+        // assert
+        assert_eq!((dec_res * clear_2) % modulus, (clear_0 + clear_1) % modulus);
+    }
+}
+
+fn integer_checked_crt_scalar_div_parallelized(param: Parameters) {
+    // Define CRT basis, and global modulus
+    let basis = make_basis(param.message_modulus.0);
+    let modulus = basis.iter().product::<u64>();
+
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..NB_TEST {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = random_invertible_scalar(&mut rng, &basis);
+
+        // encryption of an integer
+        let mut ct_zero = cks.encrypt_crt(clear_0, basis.clone());
+
+        let res = sks.checked_crt_scalar_div_parallelized(&ct_zero, clear_1);
+        assert!(res.is_ok());
+        let dec_res = cks.decrypt_crt(&res.unwrap());
+        assert_eq!((dec_res * clear_1) % modulus, clear_0);
+
+        // Saturate the carries, so that multiplying a residue by an inverse other than 1
+        // exceeds the capacity of the ciphertext
+        let max_degree = sks.key.max_degree.0;
+        for block in ct_zero.blocks.iter_mut() {
+            block.degree.0 = max_degree;
+        }
+        let has_non_trivial_inverse = basis.iter().any(|modulus| {
+            super::scalar_div_crt::modular_inverse(clear_1, *modulus).map_or(false, |inv| inv > 1)
+        });
+        let res = sks.checked_crt_scalar_div_assign_parallelized(&mut ct_zero, clear_1);
+        if has_non_trivial_inverse {
+            assert!(matches!(res, Err(CheckError::CarryFull)));
+        } else {
+            assert!(res.is_ok());
+        }
+    }
+}
@@ -2,6 +2,7 @@
 //!
 //! This module implements the generation of the server public key, together with all the
 //! available homomorphic integer operations.
+mod accumulator_cache;
 pub mod comparator;
 mod crt;
 mod crt_parallel;
@@ -9,6 +10,7 @@ mod radix;
 mod radix_parallel;
 
 use crate::integer::client_key::ClientKey;
+use crate::integer::server_key::accumulator_cache::AccumulatorCache;
 use crate::shortint::server_key::MaxDegree;
 use serde::{Deserialize, Serialize};
 
@@ -22,6 +24,8 @@ pub use crate::shortint::CheckError;
 #[derive(Serialize, Deserialize, Clone)]
 pub struct ServerKey {
     pub(crate) key: crate::shortint::ServerKey,
+    #[serde(skip)]
+    pub(crate) accumulator_cache: AccumulatorCache,
 }
 
 impl From<ServerKey> for crate::shortint::ServerKey {
@@ -60,38 +64,129 @@ impl ServerKey {
             MaxDegree(max),
         );
 
-        ServerKey { key: sks }
+        ServerKey {
+            key: sks,
+            accumulator_cache: AccumulatorCache::default(),
+        }
     }
 
     /// Creates a ServerKey from an already generated shortint::ServerKey.
     ///
+    /// This avoids generating a new key when the shortint keys for the same parameters are
+    /// already available.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the parameters of `key` do not match the parameters of `cks`.
+    ///
     /// # Example
     ///
     /// ```rust
     /// use tfhe::integer::{ClientKey, ServerKey};
     /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
     ///
-    /// let size = 4;
+    /// // Generate the shortint keys:
+    /// let (shortint_cks, shortint_sks) = tfhe::shortint::gen_keys(PARAM_MESSAGE_2_CARRY_2);
     ///
-    /// // Generate the client key:
-    /// let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2);
+    /// // Wrap them into integer keys:
+    /// let cks = ClientKey::from(shortint_cks);
+    /// let sks = ServerKey::from_shortint(&cks, shortint_sks);
     ///
-    /// // Generate the server key:
-    /// let sks = ServerKey::new(&cks);
+    /// let num_blocks = 4;
+    /// let msg = 97u64;
+    ///
+    /// let ct = cks.encrypt_radix(msg, num_blocks);
+    /// let ct_res = sks.scalar_add_parallelized(&ct, 14u64);
+    ///
+    /// let dec: u64 = cks.decrypt_radix(&ct_res);
+    /// assert_eq!(dec, msg + 14);
     /// ```
     pub fn from_shortint(
         cks: &ClientKey,
         mut key: crate::shortint::server_key::ServerKey,
     ) -> ServerKey {
+        let parameters = cks.key.parameters;
+        assert_eq!(
+            key.message_modulus, parameters.message_modulus,
+            "the shortint server key and the client key have different message moduli"
+        );
+        assert_eq!(
+            key.carry_modulus, parameters.carry_modulus,
+            "the shortint server key and the client key have different carry moduli"
+        );
+        assert_eq!(
+            key.bootstrapping_key.input_lwe_dimension(),
+            parameters.lwe_dimension,
+            "the shortint server key and the client key have different LWE dimensions"
+        );
+        assert_eq!(
+            key.bootstrapping_key.polynomial_size(),
+            parameters.polynomial_size,
+            "the shortint server key and the client key have different polynomial sizes"
+        );
+
         // It should remain just enough space add a carry
         let max =
             (cks.key.parameters.message_modulus.0 - 1) * cks.key.parameters.carry_modulus.0 - 1;
 
         key.max_degree = MaxDegree(max);
-        ServerKey { key }
+        ServerKey {
+            key,
+            accumulator_cache: AccumulatorCache::default(),
+        }
+    }
+
+    /// Runs `op` inside the given rayon [`ThreadPool`](rayon::ThreadPool).
+    ///
+    /// All the `_parallelized` operations use the global rayon thread pool by default. Calling
+    /// them from `op` confines all the work they spawn to `pool`, which allows bounding the CPU
+    /// usage of a computation, e.g. when several independent computations share the same
+    /// machine.
+    ///
+    /// This is a thin wrapper around [`rayon::ThreadPool::install`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let msg1 = 14;
+    /// let msg2 = 97;
+    ///
+    /// let ct1 = cks.encrypt(msg1);
+    /// let ct2 = cks.encrypt(msg2);
+    ///
+    /// // Only use two threads for this computation
+    /// let pool = rayon::ThreadPoolBuilder::new()
+    ///     .num_threads(2)
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let ct_res = sks.install_in_thread_pool(&pool, |sks| sks.add_parallelized(&ct1, &ct2));
+    ///
+    /// // Decrypt:
+    /// let dec_result: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec_result, msg1 + msg2);
+    /// ```
+    pub fn install_in_thread_pool<OP, R>(&self, pool: &rayon::ThreadPool, op: OP) -> R
+    where
+        OP: FnOnce(&Self) -> R + Send,
+        R: Send,
+    {
+        pool.install(|| op(self))
     }
 }
 
+/// A structure containing a **compressed** server public key.
+///
+/// It is smaller than a [`ServerKey`], which makes it suited for transport, and has to be
+/// decompressed to compute on ciphertexts.
+#[derive(Serialize, Deserialize, Clone)]
 pub struct CompressedServerKey {
     pub(crate) key: crate::shortint::CompressedServerKey,
 }
@@ -101,11 +196,43 @@ impl CompressedServerKey {
         let key = crate::shortint::CompressedServerKey::new(&client_key.key);
         Self { key }
     }
+
+    /// Decompresses the key, returning a [`ServerKey`] which can be used to compute on
+    /// ciphertexts.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::{gen_keys_radix_compressed, CompressedServerKey};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let num_blocks = 4;
+    /// let (cks, compressed_sks) = gen_keys_radix_compressed(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// // The compressed key is what gets sent to the server
+    /// let serialized = bincode::serialize(&compressed_sks).unwrap();
+    /// let compressed_sks: CompressedServerKey = bincode::deserialize(&serialized).unwrap();
+    ///
+    /// let sks = compressed_sks.decompress();
+    ///
+    /// let ct1 = cks.encrypt(14u64);
+    /// let ct2 = cks.encrypt(97u64);
+    /// let ct_res = sks.add_parallelized(&ct1, &ct2);
+    ///
+    /// let dec: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, 14 + 97);
+    /// ```
+    pub fn decompress(self) -> ServerKey {
+        ServerKey::from(self)
+    }
 }
 
 impl From<CompressedServerKey> for ServerKey {
     fn from(compressed: CompressedServerKey) -> Self {
         let key = compressed.key.into();
-        Self { key }
+        Self {
+            key,
+            accumulator_cache: AccumulatorCache::default(),
+        }
     }
 }
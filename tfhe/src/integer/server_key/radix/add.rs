@@ -255,4 +255,82 @@ impl ServerKey {
         }
         self.unchecked_add_assign(ct_left, ct_right);
     }
+
+    /// Computes homomorphically an addition between two ciphertexts encrypting integer values,
+    /// without propagating the carries of the result.
+    ///
+    /// Each block of the result holds the sum of the corresponding input blocks in its carry
+    /// space, the carries are only resolved when [`Self::full_propagate`] (or
+    /// [`Self::full_propagate_parallelized`]) is called. This allows to sum many ciphertexts and
+    /// pay for the carry propagation once at the end.
+    ///
+    /// Starting from ciphertexts with empty carries, up to
+    /// `(message_modulus * carry_modulus - 1) / (message_modulus - 1)` ciphertexts can be summed
+    /// before a propagation is needed, i.e. `sum_count - 1` calls to this function (5 ciphertexts
+    /// with `PARAM_MESSAGE_2_CARRY_2`). [`Self::is_add_possible`] tells whether another addition
+    /// can be done.
+    ///
+    /// The result is returned as a new ciphertext.
+    ///
+    /// # Panics
+    ///
+    /// Contrary to [`Self::unchecked_add`], this function panics if the addition would exceed the
+    /// capacity of the carry space, instead of silently returning an incorrect result.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let msgs = [14u64, 97, 3, 42, 55];
+    /// let cts = msgs.iter().map(|&m| cks.encrypt(m)).collect::<Vec<_>>();
+    ///
+    /// // 5 ciphertexts can be summed before propagating with these parameters
+    /// let mut ct_res = cts[0].clone();
+    /// for ct in &cts[1..] {
+    ///     ct_res = sks.add_no_propagate(&ct_res, ct);
+    /// }
+    /// assert!(!sks.is_add_possible(&ct_res, &cts[0]));
+    ///
+    /// sks.full_propagate_parallelized(&mut ct_res);
+    ///
+    /// // Decrypt:
+    /// let dec_result: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec_result, msgs.iter().sum::<u64>());
+    /// ```
+    pub fn add_no_propagate<PBSOrder: PBSOrderMarker>(
+        &self,
+        ct_left: &RadixCiphertext<PBSOrder>,
+        ct_right: &RadixCiphertext<PBSOrder>,
+    ) -> RadixCiphertext<PBSOrder> {
+        let mut result = ct_left.clone();
+        self.add_assign_no_propagate(&mut result, ct_right);
+        result
+    }
+
+    /// Computes homomorphically an addition between two ciphertexts encrypting integer values,
+    /// without propagating the carries of the result.
+    ///
+    /// The result is assigned to the `ct_left` ciphertext, see [`Self::add_no_propagate`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the addition would exceed the capacity of the carry space.
+    pub fn add_assign_no_propagate<PBSOrder: PBSOrderMarker>(
+        &self,
+        ct_left: &mut RadixCiphertext<PBSOrder>,
+        ct_right: &RadixCiphertext<PBSOrder>,
+    ) {
+        assert!(
+            self.is_add_possible(ct_left, ct_right),
+            "The addition would exceed the carry space capacity, \
+            the carries must be propagated first"
+        );
+        self.unchecked_add_assign(ct_left, ct_right);
+    }
 }
@@ -12,6 +12,7 @@ mod sub;
 use super::ServerKey;
 
 use crate::integer::ciphertext::RadixCiphertext;
+use crate::integer::server_key::accumulator_cache::CachedFunction;
 use crate::shortint::PBSOrderMarker;
 
 #[cfg(test)]
@@ -80,9 +81,9 @@ impl ServerKey {
         ctxt: &mut RadixCiphertext<PBSOrder>,
         index: usize,
     ) {
-        let carry = self.key.carry_extract(&ctxt.blocks[index]);
+        let carry = self.apply_cached_function(&ctxt.blocks[index], CachedFunction::CarryExtract);
 
-        ctxt.blocks[index] = self.key.message_extract(&ctxt.blocks[index]);
+        self.apply_cached_function_assign(&mut ctxt.blocks[index], CachedFunction::MessageExtract);
 
         //add the carry to the next block
         if index < ctxt.blocks.len() - 1 {
@@ -123,4 +124,200 @@ impl ServerKey {
             self.propagate(ctxt, i);
         }
     }
+    /// Concatenates the blocks of several ciphertexts into a single ciphertext.
+    ///
+    /// The parts are given from the least significant to the most significant, so that the result
+    /// encrypts `sum(part_i * modulus^i)` where `modulus` is the modulus of a part. All the parts
+    /// must have the same number of blocks and be encrypted under the same key.
+    ///
+    /// The carries of each part are propagated first, so that they do not leak into the next
+    /// part.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(&PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// // Two 4 bits parts
+    /// let low = cks.encrypt_radix(3u64, 2);
+    /// let high = cks.encrypt_radix(10u64, 2);
+    ///
+    /// let ct_res = sks.concat_radix(&[low, high]);
+    /// assert_eq!(ct_res.num_blocks(), 4);
+    ///
+    /// // Decrypt:
+    /// let res: u64 = cks.decrypt_radix(&ct_res);
+    /// assert_eq!(res, 3 + (10 << 4));
+    /// ```
+    pub fn concat_radix<PBSOrder: PBSOrderMarker>(
+        &self,
+        parts: &[RadixCiphertext<PBSOrder>],
+    ) -> RadixCiphertext<PBSOrder> {
+        let num_blocks_per_part = parts.first().map_or(0, |part| part.blocks.len());
+        assert!(
+            parts
+                .iter()
+                .all(|part| part.blocks.len() == num_blocks_per_part),
+            "All the parts must have the same number of blocks"
+        );
+
+        let mut blocks = Vec::with_capacity(parts.len() * num_blocks_per_part);
+        for part in parts {
+            if part.block_carries_are_empty() {
+                blocks.extend_from_slice(&part.blocks);
+            } else {
+                let mut part = part.clone();
+                self.full_propagate(&mut part);
+                blocks.extend(part.blocks);
+            }
+        }
+
+        RadixCiphertext::from(blocks)
+    }
+
+    /// Splits the blocks of a ciphertext into several ciphertexts of `num_blocks_per_part` blocks.
+    ///
+    /// This is the inverse of [`Self::concat_radix`], the parts are returned from the least
+    /// significant to the most significant. The carries are propagated first.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of blocks of the ciphertext is not a multiple of
+    /// `num_blocks_per_part`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(&PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let ct = cks.encrypt_radix(3u64 + (10 << 4), 4);
+    ///
+    /// let parts = sks.split_radix(&ct, 2);
+    /// assert_eq!(parts.len(), 2);
+    ///
+    /// // Decrypt:
+    /// let low: u64 = cks.decrypt_radix(&parts[0]);
+    /// let high: u64 = cks.decrypt_radix(&parts[1]);
+    /// assert_eq!(low, 3);
+    /// assert_eq!(high, 10);
+    /// ```
+    pub fn split_radix<PBSOrder: PBSOrderMarker>(
+        &self,
+        ctxt: &RadixCiphertext<PBSOrder>,
+        num_blocks_per_part: usize,
+    ) -> Vec<RadixCiphertext<PBSOrder>> {
+        assert!(
+            num_blocks_per_part != 0 && ctxt.blocks.len() % num_blocks_per_part == 0,
+            "The number of blocks ({}) must be a multiple of the number of blocks per part ({})",
+            ctxt.blocks.len(),
+            num_blocks_per_part
+        );
+
+        let mut tmp_ctxt;
+        let ctxt = if ctxt.block_carries_are_empty() {
+            ctxt
+        } else {
+            tmp_ctxt = ctxt.clone();
+            self.full_propagate(&mut tmp_ctxt);
+            &tmp_ctxt
+        };
+
+        ctxt.blocks
+            .chunks(num_blocks_per_part)
+            .map(|blocks| RadixCiphertext::from(blocks.to_vec()))
+            .collect()
+    }
+
+    /// Appends `additional_blocks` trivially encrypted zero blocks to the most significant end of
+    /// the ciphertext, so that it can be used alongside wider ciphertexts.
+    ///
+    /// The encrypted value is preserved. If the block carries are not empty, they are propagated
+    /// first, so that the carry of the most significant block is discarded as it would have been
+    /// before the extension instead of leaking into the new blocks.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(&PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// // 8 bits value
+    /// let mut ct = cks.encrypt_radix(213u64, 4);
+    ///
+    /// // Widen it to 16 bits
+    /// sks.extend_radix_with_trivial_zeros(&mut ct, 4);
+    /// assert_eq!(ct.num_blocks(), 8);
+    ///
+    /// // Decrypt:
+    /// let res: u64 = cks.decrypt_radix(&ct);
+    /// assert_eq!(res, 213);
+    /// ```
+    pub fn extend_radix_with_trivial_zeros<PBSOrder: PBSOrderMarker>(
+        &self,
+        ctxt: &mut RadixCiphertext<PBSOrder>,
+        additional_blocks: usize,
+    ) {
+        if !ctxt.block_carries_are_empty() {
+            self.full_propagate(ctxt);
+        }
+
+        ctxt.blocks
+            .extend((0..additional_blocks).map(|_| self.key.create_trivial(0)));
+    }
+
+    /// Removes the `num_blocks` most significant blocks of the ciphertext.
+    ///
+    /// This is a truncation: the removed blocks are dropped whatever they encrypt, so the result
+    /// encrypts the original value modulo the modulus of the remaining blocks, like an `as` cast
+    /// to a narrower integer type in Rust.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `num_blocks` is not smaller than the number of blocks of the ciphertext.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(&PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// // 16 bits value
+    /// let mut ct = cks.encrypt_radix(0x1234u64, 8);
+    ///
+    /// // Truncate it to 8 bits
+    /// sks.trim_radix_blocks(&mut ct, 4);
+    /// assert_eq!(ct.num_blocks(), 4);
+    ///
+    /// // Decrypt:
+    /// let res: u64 = cks.decrypt_radix(&ct);
+    /// assert_eq!(res, 0x34);
+    /// ```
+    pub fn trim_radix_blocks<PBSOrder: PBSOrderMarker>(
+        &self,
+        ctxt: &mut RadixCiphertext<PBSOrder>,
+        num_blocks: usize,
+    ) {
+        assert!(
+            num_blocks < ctxt.blocks.len(),
+            "Cannot remove {} blocks from a ciphertext with {} blocks",
+            num_blocks,
+            ctxt.blocks.len()
+        );
+
+        ctxt.blocks.truncate(ctxt.blocks.len() - num_blocks);
+    }
 }
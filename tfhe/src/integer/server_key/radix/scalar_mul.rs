@@ -1,3 +1,4 @@
+use crate::integer::block_decomposition::{decompose_scalar_wrapping, DecomposableInto};
 use crate::integer::ciphertext::RadixCiphertext;
 use crate::integer::server_key::CheckError;
 use crate::integer::server_key::CheckError::CarryFull;
@@ -323,7 +324,7 @@ impl ServerKey {
     /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
     ///
     /// let msg = 230;
-    /// let scalar = 376;
+    /// let scalar = 376u64;
     ///
     /// let mut ct = cks.encrypt(msg);
     ///
@@ -334,12 +335,16 @@ impl ServerKey {
     /// let clear: u64 = cks.decrypt(&ct_res);
     /// assert_eq!(msg * scalar % modulus, clear);
     /// ```
-    pub fn smart_scalar_mul<PBSOrder: PBSOrderMarker>(
+    pub fn smart_scalar_mul<PBSOrder, T>(
         &self,
         ctxt: &mut RadixCiphertext<PBSOrder>,
-        scalar: u64,
-    ) -> RadixCiphertext<PBSOrder> {
-        let mask = (self.key.message_modulus.0 - 1) as u64;
+        scalar: T,
+    ) -> RadixCiphertext<PBSOrder>
+    where
+        PBSOrder: PBSOrderMarker,
+        T: DecomposableInto<u8>,
+    {
+        let bits_in_block = self.key.message_modulus.0.ilog2();
 
         //Propagate the carries before doing the multiplications
         self.full_propagate(ctxt);
@@ -351,16 +356,12 @@ impl ServerKey {
 
         let mut tmp;
 
-        let mut b_i = 1_u64;
-        for i in 0..ctxt.blocks.len() {
-            //lambda = sum u_ib^i
-            let u_ib_i = scalar & (mask * b_i);
-            let u_i = u_ib_i / b_i;
+        //lambda = sum u_ib^i
+        let decomposed_scalar = decompose_scalar_wrapping(scalar, bits_in_block, ctxt.blocks.len());
+        for (i, u_i) in decomposed_scalar.into_iter().enumerate() {
+            let u_i = u64::from(u_i);
 
             if u_i == 0 {
-                //update the power b^{i+1}
-                let Some(new_power) = b_i.checked_mul(self.key.message_modulus.0 as u64) else {break};
-                b_i = new_power;
                 continue;
             } else if u_i == 1 {
                 // tmp = ctxt * 1 * b^i
@@ -377,20 +378,19 @@ impl ServerKey {
 
             //update the result
             result = self.smart_add(&mut result, &mut tmp);
-
-            //update the power b^{i+1}
-            let Some(new_power) = b_i.checked_mul(self.key.message_modulus.0 as u64) else {break};
-            b_i = new_power;
         }
 
         result
     }
 
-    pub fn smart_scalar_mul_assign<PBSOrder: PBSOrderMarker>(
+    pub fn smart_scalar_mul_assign<PBSOrder, T>(
         &self,
         ctxt: &mut RadixCiphertext<PBSOrder>,
-        scalar: u64,
-    ) {
+        scalar: T,
+    ) where
+        PBSOrder: PBSOrderMarker,
+        T: DecomposableInto<u8>,
+    {
         *ctxt = self.smart_scalar_mul(ctxt, scalar);
     }
 }
@@ -1,3 +1,4 @@
+use crate::integer::block_decomposition::{decompose_negated_scalar, DecomposableInto};
 use crate::integer::ciphertext::RadixCiphertext;
 use crate::integer::server_key::CheckError;
 use crate::integer::server_key::CheckError::CarryFull;
@@ -23,7 +24,7 @@ impl ServerKey {
     /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
     ///
     /// let msg = 40;
-    /// let scalar = 3;
+    /// let scalar = 3u64;
     ///
     /// let ct = cks.encrypt(msg);
     ///
@@ -34,44 +35,34 @@ impl ServerKey {
     /// let dec: u64 = cks.decrypt(&ct_res);
     /// assert_eq!(msg - scalar, dec);
     /// ```
-    pub fn unchecked_scalar_sub<PBSOrder: PBSOrderMarker>(
+    pub fn unchecked_scalar_sub<PBSOrder, T>(
         &self,
         ct: &RadixCiphertext<PBSOrder>,
-        scalar: u64,
-    ) -> RadixCiphertext<PBSOrder> {
+        scalar: T,
+    ) -> RadixCiphertext<PBSOrder>
+    where
+        PBSOrder: PBSOrderMarker,
+        T: DecomposableInto<u8>,
+    {
         let mut result = ct.clone();
         self.unchecked_scalar_sub_assign(&mut result, scalar);
         result
     }
 
-    pub fn unchecked_scalar_sub_assign<PBSOrder: PBSOrderMarker>(
+    pub fn unchecked_scalar_sub_assign<PBSOrder, T>(
         &self,
         ct: &mut RadixCiphertext<PBSOrder>,
-        scalar: u64,
-    ) {
-        // Widen to 128 bits to avoid overflow when doing wrapped negation.
-        let scalar = scalar as u128;
-        //Bits of message put to 1
-        let mask = (self.key.message_modulus.0 - 1) as u128;
+        scalar: T,
+    ) where
+        PBSOrder: PBSOrderMarker,
+        T: DecomposableInto<u8>,
+    {
+        let bits_in_block = self.key.message_modulus.0.ilog2();
+        let decomposed_neg_scalar =
+            decompose_negated_scalar(scalar, bits_in_block, ct.blocks.len());
 
-        let modulus = (self.key.message_modulus.0 as u128).checked_pow(ct.blocks.len() as u32);
-
-        let neg_scalar = match modulus {
-            Some(modul) => scalar.wrapping_neg() % modul,
-            None => scalar.wrapping_neg(),
-        };
-
-        let mut power = 1_u128;
-        //Put each decomposition into a new ciphertext
-        for ct_i in ct.blocks.iter_mut() {
-            let mut decomp = neg_scalar & (mask * power);
-            decomp /= power;
-
-            self.key.unchecked_scalar_add_assign(ct_i, decomp as u8);
-
-            //modulus to the power i
-            let Some(new_power) = power.checked_mul(self.key.message_modulus.0 as u128) else {break};
-            power = new_power;
+        for (ct_i, scalar_block) in ct.blocks.iter_mut().zip(decomposed_neg_scalar) {
+            self.key.unchecked_scalar_add_assign(ct_i, scalar_block);
         }
     }
 
@@ -88,7 +79,7 @@ impl ServerKey {
     /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
     ///
     /// let msg = 40u64;
-    /// let scalar = 2;
+    /// let scalar = 2u64;
     ///
     /// let ct1 = cks.encrypt(msg);
     ///
@@ -97,37 +88,23 @@ impl ServerKey {
     ///
     /// assert_eq!(true, res);
     /// ```
-    pub fn is_scalar_sub_possible<PBSOrder: PBSOrderMarker>(
+    pub fn is_scalar_sub_possible<PBSOrder, T>(
         &self,
         ct: &RadixCiphertext<PBSOrder>,
-        scalar: u64,
-    ) -> bool {
-        //Bits of message put to 1
-        let mask = (self.key.message_modulus.0 - 1) as u64;
-
-        let modulus = self
-            .key
-            .message_modulus
-            .0
-            .saturating_pow(ct.blocks.len() as u32) as u64;
+        scalar: T,
+    ) -> bool
+    where
+        PBSOrder: PBSOrderMarker,
+        T: DecomposableInto<u8>,
+    {
+        let bits_in_block = self.key.message_modulus.0.ilog2();
+        let decomposed_neg_scalar =
+            decompose_negated_scalar(scalar, bits_in_block, ct.blocks.len());
 
-        let neg_scalar = scalar.wrapping_neg() % modulus;
-
-        let mut power = 1_u64;
-
-        for ct_i in ct.blocks.iter() {
-            let mut decomp = neg_scalar & (mask * power);
-            decomp /= power;
-
-            if !self.key.is_scalar_add_possible(ct_i, decomp as u8) {
-                return false;
-            }
-
-            //modulus to the power i
-            let Some(new_power) = power.checked_mul(self.key.message_modulus.0 as u64) else {break};
-            power = new_power;
-        }
-        true
+        ct.blocks
+            .iter()
+            .zip(decomposed_neg_scalar)
+            .all(|(ct_i, scalar_block)| self.key.is_scalar_add_possible(ct_i, scalar_block))
     }
 
     /// Computes homomorphically a subtraction of a ciphertext by a scalar.
@@ -147,7 +124,7 @@ impl ServerKey {
     /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
     ///
     /// let msg = 40;
-    /// let scalar = 4;
+    /// let scalar = 4u64;
     ///
     /// let ct = cks.encrypt(msg);
     ///
@@ -160,11 +137,15 @@ impl ServerKey {
     /// # Ok(())
     /// # }
     /// ```
-    pub fn checked_scalar_sub<PBSOrder: PBSOrderMarker>(
+    pub fn checked_scalar_sub<PBSOrder, T>(
         &self,
         ct: &RadixCiphertext<PBSOrder>,
-        scalar: u64,
-    ) -> Result<RadixCiphertext<PBSOrder>, CheckError> {
+        scalar: T,
+    ) -> Result<RadixCiphertext<PBSOrder>, CheckError>
+    where
+        PBSOrder: PBSOrderMarker,
+        T: DecomposableInto<u8>,
+    {
         if self.is_scalar_sub_possible(ct, scalar) {
             Ok(self.unchecked_scalar_sub(ct, scalar))
         } else {
@@ -189,7 +170,7 @@ impl ServerKey {
     /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
     ///
     /// let msg = 232;
-    /// let scalar = 83;
+    /// let scalar = 83u64;
     ///
     /// let mut ct = cks.encrypt(msg);
     ///
@@ -202,11 +183,15 @@ impl ServerKey {
     /// # Ok(())
     /// # }
     /// ```
-    pub fn checked_scalar_sub_assign<PBSOrder: PBSOrderMarker>(
+    pub fn checked_scalar_sub_assign<PBSOrder, T>(
         &self,
         ct: &mut RadixCiphertext<PBSOrder>,
-        scalar: u64,
-    ) -> Result<(), CheckError> {
+        scalar: T,
+    ) -> Result<(), CheckError>
+    where
+        PBSOrder: PBSOrderMarker,
+        T: DecomposableInto<u8>,
+    {
         if self.is_scalar_sub_possible(ct, scalar) {
             self.unchecked_scalar_sub_assign(ct, scalar);
             Ok(())
@@ -228,7 +213,7 @@ impl ServerKey {
     /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
     ///
     /// let msg = 165;
-    /// let scalar = 112;
+    /// let scalar = 112u64;
     ///
     /// let mut ct = cks.encrypt(msg);
     ///
@@ -239,11 +224,15 @@ impl ServerKey {
     /// let dec: u64 = cks.decrypt(&ct_res);
     /// assert_eq!(msg - scalar, dec);
     /// ```
-    pub fn smart_scalar_sub<PBSOrder: PBSOrderMarker>(
+    pub fn smart_scalar_sub<PBSOrder, T>(
         &self,
         ct: &mut RadixCiphertext<PBSOrder>,
-        scalar: u64,
-    ) -> RadixCiphertext<PBSOrder> {
+        scalar: T,
+    ) -> RadixCiphertext<PBSOrder>
+    where
+        PBSOrder: PBSOrderMarker,
+        T: DecomposableInto<u8>,
+    {
         if !self.is_scalar_sub_possible(ct, scalar) {
             self.full_propagate(ct);
         }
@@ -251,11 +240,14 @@ impl ServerKey {
         self.unchecked_scalar_sub(ct, scalar)
     }
 
-    pub fn smart_scalar_sub_assign<PBSOrder: PBSOrderMarker>(
+    pub fn smart_scalar_sub_assign<PBSOrder, T>(
         &self,
         ct: &mut RadixCiphertext<PBSOrder>,
-        scalar: u64,
-    ) {
+        scalar: T,
+    ) where
+        PBSOrder: PBSOrderMarker,
+        T: DecomposableInto<u8>,
+    {
         if !self.is_scalar_sub_possible(ct, scalar) {
             self.full_propagate(ct);
         }
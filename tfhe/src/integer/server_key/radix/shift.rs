@@ -38,12 +38,7 @@ impl ServerKey {
     ) -> RadixCiphertext<PBSOrder> {
         let mut result = self.create_trivial_zero_radix(ctxt.blocks.len());
 
-        let limit = result.blocks.len() - shift;
-
-        for (res_i, c_i) in result.blocks[..limit]
-            .iter_mut()
-            .zip(ctxt.blocks[shift..].iter())
-        {
+        for (res_i, c_i) in result.blocks.iter_mut().zip(ctxt.blocks.iter().skip(shift)) {
             *res_i = c_i.clone();
         }
 
@@ -230,7 +225,13 @@ impl ServerKey {
         ct: &mut RadixCiphertext<PBSOrder>,
         shift: usize,
     ) {
-        let tmp = 1_u64 << shift;
+        //number of bits of message
+        let nb_bits = self.key.message_modulus.0.ilog2() as usize;
+
+        // 2^u = 2^{p*q+r}, shifting by q blocks or more clears all the blocks
+        let quotient = (shift / nb_bits).min(ct.blocks.len());
+        let tmp = 1_u64 << (shift % nb_bits);
         self.smart_scalar_mul_assign(ct, tmp);
+        *ct = self.blockshift(ct, quotient);
     }
 }
@@ -16,8 +16,12 @@ create_parametrized_test!(integer_encrypt_decrypt_128_bits);
 create_parametrized_test!(integer_encrypt_decrypt_128_bits_specific_values);
 create_parametrized_test!(integer_encrypt_decrypt_256_bits_specific_values);
 create_parametrized_test!(integer_encrypt_decrypt_256_bits);
+create_parametrized_test!(integer_encrypt_decrypt_256_bits_parallelized);
+create_parametrized_test!(integer_concat_split_radix);
+create_parametrized_test!(integer_extend_trim_radix);
 create_parametrized_test!(integer_unchecked_add);
 create_parametrized_test!(integer_smart_add);
+create_parametrized_test!(integer_add_no_propagate);
 create_parametrized_test! {
     integer_smart_add_128_bits {
         // Skip the 1_1 params for the smart add 128 bits which proved to be the slowest test in our test
@@ -79,6 +83,75 @@ fn integer_encrypt_decrypt(param: Parameters) {
     }
 }
 
+fn integer_concat_split_radix(param: Parameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+
+    // RNG
+    let mut rng = rand::thread_rng();
+
+    let num_parts = 2;
+    let num_blocks_per_part = NB_CTXT / num_parts;
+
+    // message_modulus^vec_length
+    let part_modulus = param.message_modulus.0.pow(num_blocks_per_part as u32) as u64;
+
+    for _ in 0..NB_TEST {
+        let clear_low = rng.gen::<u64>() % part_modulus;
+        let clear_high = rng.gen::<u64>() % part_modulus;
+
+        let ct_low = cks.encrypt_radix(clear_low, num_blocks_per_part);
+        let ct_high = cks.encrypt_radix(clear_high, num_blocks_per_part);
+
+        let ct_res = sks.concat_radix(&[ct_low, ct_high]);
+        assert_eq!(ct_res.blocks.len(), NB_CTXT);
+
+        let dec: u64 = cks.decrypt_radix(&ct_res);
+        assert_eq!(clear_low + clear_high * part_modulus, dec);
+
+        let parts = sks.split_radix(&ct_res, num_blocks_per_part);
+        assert_eq!(parts.len(), num_parts);
+
+        let dec_low: u64 = cks.decrypt_radix(&parts[0]);
+        let dec_high: u64 = cks.decrypt_radix(&parts[1]);
+        assert_eq!(clear_low, dec_low);
+        assert_eq!(clear_high, dec_high);
+    }
+}
+
+fn integer_extend_trim_radix(param: Parameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+
+    // RNG
+    let mut rng = rand::thread_rng();
+
+    let bits_in_block = param.message_modulus.0.ilog2() as usize;
+    let num_blocks_8_bits = (8 + bits_in_block - 1) / bits_in_block;
+    let num_blocks_16_bits = (16 + bits_in_block - 1) / bits_in_block;
+
+    for _ in 0..NB_TEST {
+        let clear = rng.gen::<u8>();
+
+        let mut ct = cks.encrypt_radix(clear as u64, num_blocks_8_bits);
+
+        sks.extend_radix_with_trivial_zeros(&mut ct, num_blocks_16_bits - num_blocks_8_bits);
+        assert_eq!(ct.blocks.len(), num_blocks_16_bits);
+
+        let dec: u64 = cks.decrypt_radix(&ct);
+        assert_eq!(clear as u64, dec);
+
+        // The widened value can be used in 16 bits arithmetic
+        let ct_res = sks.smart_scalar_add(&mut ct, 256u64);
+        let dec: u64 = cks.decrypt_radix(&ct_res);
+        assert_eq!(clear as u64 + 256, dec);
+
+        sks.trim_radix_blocks(&mut ct, num_blocks_16_bits - num_blocks_8_bits);
+        assert_eq!(ct.blocks.len(), num_blocks_8_bits);
+
+        let dec: u64 = cks.decrypt_radix(&ct);
+        assert_eq!(clear as u64, dec);
+    }
+}
+
 fn integer_encrypt_decrypt_128_bits(param: Parameters) {
     let (cks, _) = KEY_CACHE.get_from_params(param);
 
@@ -209,6 +282,36 @@ fn integer_encrypt_decrypt_256_bits(param: Parameters) {
     }
 }
 
+fn integer_encrypt_decrypt_256_bits_parallelized(param: Parameters) {
+    let (cks, _) = KEY_CACHE.get_from_params(param);
+
+    // RNG
+    let mut rng = rand::thread_rng();
+    let num_block = (256f64 / (param.message_modulus.0 as f64).log(2.0)).ceil() as usize;
+
+    for _ in 0..10 {
+        let clear0 = rng.gen::<u128>();
+        let clear1 = rng.gen::<u128>();
+
+        let clear = crate::integer::U256::from((clear0, clear1));
+
+        //encryption
+        let ct = cks.encrypt_radix_parallelized(clear, num_block);
+        let ct_small = cks.encrypt_radix_small_parallelized(clear, num_block);
+        assert_eq!(ct.blocks().len(), num_block);
+
+        // decryption
+        let mut dec = crate::integer::U256::default();
+        cks.decrypt_radix_into(&ct, &mut dec);
+        let mut dec_small = crate::integer::U256::default();
+        cks.decrypt_radix_into(&ct_small, &mut dec_small);
+
+        // assert
+        assert_eq!(clear, dec);
+        assert_eq!(clear, dec_small);
+    }
+}
+
 fn integer_smart_add_128_bits(param: Parameters) {
     let (cks, sks) = KEY_CACHE.get_from_params(param);
 
@@ -1231,3 +1334,39 @@ fn integer_smart_scalar_mul_decomposition_overflow() {
 
     assert_eq!((clear_0 * scalar as u128), dec_res);
 }
+
+fn integer_add_no_propagate(param: Parameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+
+    // RNG
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = param.message_modulus.0.pow(NB_CTXT as u32) as u64;
+
+    let total_modulus = param.message_modulus.0 * param.carry_modulus.0;
+    let max_sum_size = (total_modulus - 1) / (param.message_modulus.0 - 1);
+
+    for _ in 0..NB_TEST_SMALLER {
+        let clears = (0..max_sum_size)
+            .map(|_| rng.gen::<u64>() % modulus)
+            .collect::<Vec<_>>();
+        let cts = clears
+            .iter()
+            .map(|&clear| cks.encrypt_radix(clear, NB_CTXT))
+            .collect::<Vec<_>>();
+
+        let mut ct_res = cts[0].clone();
+        for ct in &cts[1..] {
+            assert!(sks.is_add_possible(&ct_res, ct));
+            sks.add_assign_no_propagate(&mut ct_res, ct);
+        }
+        assert!(!sks.is_add_possible(&ct_res, &cts[0]));
+
+        sks.full_propagate(&mut ct_res);
+        assert!(ct_res.block_carries_are_empty());
+
+        let dec_res: u64 = cks.decrypt_radix(&ct_res);
+        assert_eq!(clears.iter().sum::<u64>() % modulus, dec_res);
+    }
+}
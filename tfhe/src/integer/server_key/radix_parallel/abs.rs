@@ -0,0 +1,111 @@
+use crate::integer::ciphertext::RadixCiphertext;
+use crate::integer::ServerKey;
+use crate::shortint::PBSOrderMarker;
+use rayon::prelude::*;
+
+impl ServerKey {
+    /// Homomorphically computes the absolute value of a ciphertext, interpreting the encrypted
+    /// integer as a signed value in two's complement, the sign being the most significant bit of
+    /// the last block.
+    ///
+    /// The absolute value is computed by conditionally complementing every block with the sign
+    /// and adding the sign back, i.e. `(ct ^ mask) + sign` with `mask` being all ones for a
+    /// negative input.
+    ///
+    /// Like Rust's `wrapping_abs`, the absolute value of the most negative value wraps around and
+    /// returns the most negative value itself.
+    ///
+    /// This function computes the operation without checking that the input block carries are
+    /// empty, which is required for the result to be correct. The output block carries are
+    /// always empty.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    pub fn unchecked_abs_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        ct: &RadixCiphertext<PBSOrder>,
+    ) -> RadixCiphertext<PBSOrder> {
+        let message_modulus = self.key.message_modulus.0 as u64;
+        let sign_bit_pos = message_modulus.ilog2() - 1;
+
+        let sign_acc = self
+            .key
+            .generate_accumulator(|x| ((x % message_modulus) >> sign_bit_pos) & 1);
+        let sign = self
+            .key
+            .apply_lookup_table(ct.blocks.last().unwrap(), &sign_acc);
+
+        let mut result = ct.clone();
+        result.blocks.par_iter_mut().for_each(|block| {
+            self.key
+                .unchecked_evaluate_bivariate_function_assign(block, &sign, |x, sign| {
+                    if sign == 1 {
+                        (message_modulus - 1) - (x % message_modulus)
+                    } else {
+                        x % message_modulus
+                    }
+                });
+        });
+
+        self.key.unchecked_add_assign(&mut result.blocks[0], &sign);
+        self.full_propagate_parallelized(&mut result);
+        result
+    }
+
+    /// Homomorphically computes the absolute value of a ciphertext, interpreting the encrypted
+    /// integer as a signed value in two's complement, the sign being the most significant bit of
+    /// the last block.
+    ///
+    /// Like Rust's `wrapping_abs`, the absolute value of the most negative value wraps around and
+    /// returns the most negative value itself.
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertexts block carries are empty and clears them if it's not the
+    /// case and the operation requires it. It outputs a ciphertext whose block carries are always
+    /// empty.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// // -5 in two's complement on 8 bits
+    /// let msg = (-5i8) as u8 as u64;
+    ///
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// let ct_res = sks.abs_parallelized(&ct);
+    ///
+    /// // Decrypt:
+    /// let dec_result: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec_result, 5);
+    ///
+    /// // The most negative value wraps around
+    /// let ct = cks.encrypt(128);
+    /// let ct_res = sks.abs_parallelized(&ct);
+    /// let dec_result: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec_result, 128);
+    /// ```
+    pub fn abs_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        ct: &RadixCiphertext<PBSOrder>,
+    ) -> RadixCiphertext<PBSOrder> {
+        if ct.block_carries_are_empty() {
+            self.unchecked_abs_parallelized(ct)
+        } else {
+            let mut ct = ct.clone();
+            self.full_propagate_parallelized(&mut ct);
+            self.unchecked_abs_parallelized(&ct)
+        }
+    }
+}
@@ -1,8 +1,7 @@
-use std::sync::Mutex;
-
 use crate::integer::ciphertext::RadixCiphertext;
 use crate::integer::ServerKey;
-use crate::shortint::PBSOrderMarker;
+use crate::shortint::{CiphertextBase, PBSOrderMarker};
+use rayon::prelude::*;
 
 impl ServerKey {
     /// Computes homomorphically an addition between two ciphertexts encrypting integer values.
@@ -145,7 +144,217 @@ impl ServerKey {
         self.full_propagate_parallelized(lhs);
     }
 
+    /// Computes homomorphically an addition between two ciphertexts encrypting integer values,
+    /// and whether the addition overflowed.
+    ///
+    /// Returns the sum, wrapped around the modulus of the input ciphertexts, and an encrypted
+    /// boolean (`1` in case of overflow, `0` otherwise), like Rust's `overflowing_add`. The flag is
+    /// the carry out of the most significant block, obtained by computing the sum on one extra
+    /// block.
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertexts block carries are empty and clears them if it's not the
+    /// case and the operation requires it. It outputs a ciphertext whose block carries are always
+    /// empty.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let msg1 = 200u8;
+    /// let msg2 = 97u8;
+    ///
+    /// let ct1 = cks.encrypt(msg1 as u64);
+    /// let ct2 = cks.encrypt(msg2 as u64);
+    ///
+    /// let (ct_res, overflowed) = sks.overflowing_add_parallelized(&ct1, &ct2);
+    ///
+    /// // Decrypt:
+    /// let dec_result: u64 = cks.decrypt(&ct_res);
+    /// let (expected, expected_overflow) = msg1.overflowing_add(msg2);
+    /// assert_eq!(dec_result, expected as u64);
+    /// assert_eq!(
+    ///     cks.decrypt_one_block(&overflowed),
+    ///     u64::from(expected_overflow)
+    /// );
+    /// ```
+    pub fn overflowing_add_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        ct_left: &RadixCiphertext<PBSOrder>,
+        ct_right: &RadixCiphertext<PBSOrder>,
+    ) -> (RadixCiphertext<PBSOrder>, CiphertextBase<PBSOrder>) {
+        let mut lhs = ct_left.clone();
+        let mut rhs = ct_right.clone();
+        // Input carries must be propagated first, they would otherwise spill into the extra block
+        rayon::join(
+            || self.maybe_full_propagate_parallelized(&mut lhs),
+            || self.maybe_full_propagate_parallelized(&mut rhs),
+        );
+        lhs.blocks.push(self.key.create_trivial(0));
+        rhs.blocks.push(self.key.create_trivial(0));
+
+        self.add_assign_parallelized(&mut lhs, &rhs);
+
+        // The extra block holds the carry out of the input blocks
+        let overflowed = lhs.blocks.pop().unwrap();
+        (lhs, overflowed)
+    }
+
+    /// Computes homomorphically the addition of two ciphertexts encrypting integer values and of
+    /// an incoming carry, and the outgoing carry.
+    ///
+    /// `carry_in` must be a shortint ciphertext encrypting `0` or `1` with empty carries, like the
+    /// returned carry. Returns the sum, wrapped around the modulus of the input ciphertexts, and
+    /// an encrypted boolean (`1` if the sum of the inputs and of the carry overflowed, `0`
+    /// otherwise). This allows chaining additions of integers split in several radix
+    /// ciphertexts, from the least significant one to the most significant one.
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertexts block carries are empty and clears them if it's not the
+    /// case and the operation requires it. It outputs a ciphertext whose block carries are always
+    /// empty.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message per radix ciphertext
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// // 16 bits values, split in two radix ciphertexts of 8 bits
+    /// let msg1 = 0x12c8u16;
+    /// let msg2 = 0x3461u16;
+    ///
+    /// let ct1_low = cks.encrypt((msg1 & 0xff) as u64);
+    /// let ct1_high = cks.encrypt((msg1 >> 8) as u64);
+    /// let ct2_low = cks.encrypt((msg2 & 0xff) as u64);
+    /// let ct2_high = cks.encrypt((msg2 >> 8) as u64);
+    ///
+    /// let carry = cks.encrypt_one_block(0);
+    /// let (ct_res_low, carry) = sks.add_with_carry_parallelized(&ct1_low, &ct2_low, &carry);
+    /// let (ct_res_high, carry) = sks.add_with_carry_parallelized(&ct1_high, &ct2_high, &carry);
+    ///
+    /// // Decrypt:
+    /// let dec_low: u64 = cks.decrypt(&ct_res_low);
+    /// let dec_high: u64 = cks.decrypt(&ct_res_high);
+    /// let (expected, expected_carry) = msg1.overflowing_add(msg2);
+    /// assert_eq!(dec_low | (dec_high << 8), expected as u64);
+    /// assert_eq!(cks.decrypt_one_block(&carry), u64::from(expected_carry));
+    /// ```
+    pub fn add_with_carry_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        ct_left: &RadixCiphertext<PBSOrder>,
+        ct_right: &RadixCiphertext<PBSOrder>,
+        carry_in: &CiphertextBase<PBSOrder>,
+    ) -> (RadixCiphertext<PBSOrder>, CiphertextBase<PBSOrder>) {
+        let mut lhs = ct_left.clone();
+        let mut rhs = ct_right.clone();
+        rayon::join(
+            || {
+                if !lhs.block_carries_are_empty() {
+                    self.full_propagate_parallelized(&mut lhs);
+                }
+            },
+            || {
+                if !rhs.block_carries_are_empty() {
+                    self.full_propagate_parallelized(&mut rhs);
+                }
+            },
+        );
+        lhs.blocks.push(self.key.create_trivial(0));
+        rhs.blocks.push(self.key.create_trivial(0));
+
+        // The least significant block holds at most 2 * (message_modulus - 1) + 1, which fits in
+        // its carry space
+        self.unchecked_add_assign(&mut lhs, &rhs);
+        self.key.unchecked_add_assign(&mut lhs.blocks[0], carry_in);
+        self.full_propagate_parallelized(&mut lhs);
+
+        // The extra block holds the carry out of the input blocks
+        let carry_out = lhs.blocks.pop().unwrap();
+        (lhs, carry_out)
+    }
+
+    /// Computes homomorphically an addition between two ciphertexts encrypting integer values,
+    /// saturating at the maximum value the ciphertexts can encrypt instead of overflowing, like
+    /// Rust's `saturating_add`.
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertexts block carries are empty and clears them if it's not the
+    /// case and the operation requires it. It outputs a ciphertext whose block carries are always
+    /// empty.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let msg1 = 200u8;
+    /// let msg2 = 97u8;
+    ///
+    /// let ct1 = cks.encrypt(msg1 as u64);
+    /// let ct2 = cks.encrypt(msg2 as u64);
+    ///
+    /// let ct_res = sks.saturating_add_parallelized(&ct1, &ct2);
+    ///
+    /// // Decrypt:
+    /// let dec_result: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec_result, msg1.saturating_add(msg2) as u64);
+    /// ```
+    pub fn saturating_add_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        ct_left: &RadixCiphertext<PBSOrder>,
+        ct_right: &RadixCiphertext<PBSOrder>,
+    ) -> RadixCiphertext<PBSOrder> {
+        let (mut result, overflowed) = self.overflowing_add_parallelized(ct_left, ct_right);
+
+        let message_max = self.key.message_modulus.0 as u64 - 1;
+        result.blocks.par_iter_mut().for_each(|block| {
+            self.key.unchecked_evaluate_bivariate_function_assign(
+                block,
+                &overflowed,
+                |x, overflowed| {
+                    if overflowed == 1 {
+                        message_max
+                    } else {
+                        x
+                    }
+                },
+            );
+        });
+        result
+    }
+
     /// op must be associative and commutative
+    ///
+    /// The terms are always combined in the same order, independently of the thread scheduling,
+    /// so the same inputs yield bit-identical output ciphertexts.
     pub fn smart_binary_op_seq_parallelized<'this, 'item, PBSOrder: PBSOrderMarker + 'item>(
         &'this self,
         ct_seq: impl IntoIterator<Item = &'item mut RadixCiphertext<PBSOrder>>,
@@ -197,23 +406,22 @@ impl ServerKey {
                 // we repeatedly divide the number of terms by two by iteratively reducing
                 // consecutive terms in the array
                 while ct_seq.len() > 1 {
-                    let results = Mutex::new(Vec::<RadixCiphertext<PBSOrder>>::with_capacity(
-                        ct_seq.len() / 2,
-                    ));
-
                     // if the number of elements is odd, we skip the first element
                     let untouched_prefix = ct_seq.len() % 2;
                     let ct_seq_slice = &mut ct_seq[untouched_prefix..];
 
-                    ct_seq_slice.par_chunks_mut(2).for_each(|chunk| {
-                        let (first, second) = chunk.split_at_mut(1);
-                        let first = &mut first[0];
-                        let second = &mut second[0];
-                        let result = op(sks, first.as_mut(), second.as_mut());
-                        results.lock().unwrap().push(result);
-                    });
+                    // the results are collected in the order of the terms, so the ciphertexts
+                    // are combined the same way whatever the thread scheduling is
+                    let results = ct_seq_slice
+                        .par_chunks_mut(2)
+                        .map(|chunk| {
+                            let (first, second) = chunk.split_at_mut(1);
+                            let first = &mut first[0];
+                            let second = &mut second[0];
+                            op(sks, first.as_mut(), second.as_mut())
+                        })
+                        .collect::<Vec<_>>();
 
-                    let results = results.into_inner().unwrap();
                     ct_seq.truncate(untouched_prefix);
                     ct_seq.extend(results.into_iter().map(CiphertextCow::Owned));
                 }
@@ -231,6 +439,9 @@ impl ServerKey {
     }
 
     /// op must be associative and commutative
+    ///
+    /// The terms are always combined in the same order, independently of the thread scheduling,
+    /// so the same inputs yield bit-identical output ciphertexts.
     pub fn default_binary_op_seq_parallelized<'this, 'item, PBSOrder: PBSOrderMarker + 'item>(
         &'this self,
         ct_seq: impl IntoIterator<Item = &'item RadixCiphertext<PBSOrder>>,
@@ -282,23 +493,22 @@ impl ServerKey {
                 // we repeatedly divide the number of terms by two by iteratively reducing
                 // consecutive terms in the array
                 while ct_seq.len() > 1 {
-                    let results = Mutex::new(Vec::<RadixCiphertext<PBSOrder>>::with_capacity(
-                        ct_seq.len() / 2,
-                    ));
-
                     // if the number of elements is odd, we skip the first element
                     let untouched_prefix = ct_seq.len() % 2;
                     let ct_seq_slice = &mut ct_seq[untouched_prefix..];
 
-                    ct_seq_slice.par_chunks(2).for_each(|chunk| {
-                        let (first, second) = chunk.split_at(1);
-                        let first = &first[0];
-                        let second = &second[0];
-                        let result = op(sks, first.as_ref(), second.as_ref());
-                        results.lock().unwrap().push(result);
-                    });
+                    // the results are collected in the order of the terms, so the ciphertexts
+                    // are combined the same way whatever the thread scheduling is
+                    let results = ct_seq_slice
+                        .par_chunks(2)
+                        .map(|chunk| {
+                            let (first, second) = chunk.split_at(1);
+                            let first = &first[0];
+                            let second = &second[0];
+                            op(sks, first.as_ref(), second.as_ref())
+                        })
+                        .collect::<Vec<_>>();
 
-                    let results = results.into_inner().unwrap();
                     ct_seq.truncate(untouched_prefix);
                     ct_seq.extend(results.into_iter().map(CiphertextCow::Owned));
                 }
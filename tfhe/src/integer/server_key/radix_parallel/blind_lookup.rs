@@ -0,0 +1,169 @@
+use crate::integer::ciphertext::RadixCiphertext;
+use crate::integer::server_key::accumulator_cache::CachedFunction;
+use crate::integer::ServerKey;
+use crate::shortint::PBSOrderMarker;
+use rayon::prelude::*;
+
+impl ServerKey {
+    /// Homomorphically looks up `table[index]`, where `index` is encrypted and `table` is clear.
+    ///
+    /// The result has the same number of blocks as `index`, so table values are taken modulo
+    /// the modulus of the `index` radix decomposition. Indices that are out of range of the table
+    /// yield an encryption of 0.
+    ///
+    /// When `index` is made of a single block, the lookup is done with one programmable
+    /// bootstrapping. Otherwise, an encrypted equality indicator is computed for
+    /// each table entry, and the result is the sum of the entries weighted by their indicator.
+    ///
+    /// This function computes the operation without checking that the input block carries are
+    /// empty, which is required for the result to be correct.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    pub fn unchecked_blind_lookup_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        index: &RadixCiphertext<PBSOrder>,
+        table: &[u64],
+    ) -> RadixCiphertext<PBSOrder> {
+        let message_modulus = self.key.message_modulus.0 as u64;
+        let bits_in_block = message_modulus.ilog2();
+        let num_blocks = index.blocks.len();
+
+        let block_of = |value: u64, block_index: usize| {
+            value
+                .checked_shr(block_index as u32 * bits_in_block)
+                .unwrap_or(0)
+                % message_modulus
+        };
+
+        if num_blocks == 1 {
+            let acc = self.key.generate_accumulator(|x| {
+                table
+                    .get((x % message_modulus) as usize)
+                    .map_or(0, |&value| value % message_modulus)
+            });
+            let block = self.key.apply_lookup_table(&index.blocks[0], &acc);
+            return RadixCiphertext::from(vec![block]);
+        }
+
+        // Entries past this bound cannot be reached by the index
+        let reachable_entries = message_modulus
+            .checked_pow(num_blocks as u32)
+            .map_or(table.len(), |modulus| table.len().min(modulus as usize));
+
+        // For each reachable entry, the blocks of value * (index == entry_index)
+        let terms = table[..reachable_entries]
+            .par_iter()
+            .enumerate()
+            .map(|(entry_index, &value)| {
+                let mut indicators = index
+                    .blocks
+                    .par_iter()
+                    .enumerate()
+                    .map(|(i, block)| {
+                        let expected = block_of(entry_index as u64, i);
+                        let acc = self
+                            .key
+                            .generate_accumulator(|x| u64::from(x % message_modulus == expected));
+                        self.key.apply_lookup_table(block, &acc)
+                    })
+                    .collect::<Vec<_>>();
+
+                // The block indicators are combined pairwise in a fixed order, so that the
+                // result does not depend on the thread scheduling
+                while indicators.len() > 1 {
+                    indicators = indicators
+                        .par_chunks(2)
+                        .map(|chunk| match chunk {
+                            [lhs, rhs] => self.key.unchecked_bitand(lhs, rhs),
+                            [single] => single.clone(),
+                            _ => unreachable!(),
+                        })
+                        .collect();
+                }
+                let indicator = indicators.pop().unwrap();
+
+                (0..num_blocks)
+                    .map(|i| {
+                        let value_block = block_of(value, i);
+                        (value_block != 0)
+                            .then(|| self.key.unchecked_scalar_mul(&indicator, value_block as u8))
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        let mut result = self.create_trivial_zero_radix(num_blocks);
+        result
+            .blocks
+            .par_iter_mut()
+            .enumerate()
+            .for_each(|(i, block)| {
+                for mut term in terms
+                    .iter()
+                    .filter_map(|entry_terms| entry_terms[i].clone())
+                {
+                    self.key.smart_add_assign(block, &mut term);
+                }
+                // At most one term is non zero, so only the degree needs to be cleaned
+                if block.degree.0 >= self.key.message_modulus.0 {
+                    self.apply_cached_function_assign(block, CachedFunction::MessageExtract);
+                }
+            });
+
+        result
+    }
+
+    /// Homomorphically looks up `table[index]`, where `index` is encrypted and `table` is clear.
+    ///
+    /// The result has the same number of blocks as `index`, so table values are taken modulo
+    /// the modulus of the `index` radix decomposition. Indices that are out of range of the table
+    /// yield an encryption of 0.
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertexts block carries are empty and clears them if it's not the
+    /// case and the operation requires it. It outputs a ciphertext whose block carries are always
+    /// empty.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let num_blocks = 2;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let table = [3, 1, 4, 1, 5, 9];
+    ///
+    /// let ct = cks.encrypt(4);
+    /// let ct_res = sks.blind_lookup_parallelized(&ct, &table);
+    /// let dec_result: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec_result, 5);
+    ///
+    /// // Out of range indices give 0
+    /// let ct = cks.encrypt(12);
+    /// let ct_res = sks.blind_lookup_parallelized(&ct, &table);
+    /// let dec_result: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec_result, 0);
+    /// ```
+    pub fn blind_lookup_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        index: &RadixCiphertext<PBSOrder>,
+        table: &[u64],
+    ) -> RadixCiphertext<PBSOrder> {
+        if index.block_carries_are_empty() {
+            self.unchecked_blind_lookup_parallelized(index, table)
+        } else {
+            let mut index = index.clone();
+            self.full_propagate_parallelized(&mut index);
+            self.unchecked_blind_lookup_parallelized(&index, table)
+        }
+    }
+}
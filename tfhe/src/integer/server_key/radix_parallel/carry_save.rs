@@ -0,0 +1,136 @@
+use crate::integer::ciphertext::{RadixCiphertext, RadixCiphertextCarrySave};
+use crate::integer::server_key::accumulator_cache::CachedFunction;
+use crate::integer::ServerKey;
+use crate::shortint::PBSOrderMarker;
+use rayon::prelude::*;
+
+impl ServerKey {
+    /// Computes homomorphically an addition between two ciphertexts in carry-save form, keeping
+    /// the carries of the blocks pending.
+    ///
+    /// This function computes the addition without checking that the carries of the blocks have
+    /// enough room to hold the result, which is required for the result to be correct. It does not
+    /// perform any PBS.
+    pub fn unchecked_carry_save_add<PBSOrder: PBSOrderMarker>(
+        &self,
+        ct_left: &RadixCiphertextCarrySave<PBSOrder>,
+        ct_right: &RadixCiphertextCarrySave<PBSOrder>,
+    ) -> RadixCiphertextCarrySave<PBSOrder> {
+        RadixCiphertextCarrySave::from(self.unchecked_add(&ct_left.ct, &ct_right.ct))
+    }
+
+    /// Moves the pending carry of each block into the next block, all the blocks being processed
+    /// at the same time.
+    ///
+    /// Contrary to a full propagation, the carry of a block is not propagated any further, so the
+    /// blocks may still have non empty carries afterwards, but their degree is bounded by
+    /// `(message_modulus - 1) + max_carry`, which frees room for the next additions. The carry of
+    /// the most significant block is discarded, as in a full propagation.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    pub fn compress_carry_save_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        ct: &mut RadixCiphertextCarrySave<PBSOrder>,
+    ) {
+        let (carries, messages): (Vec<_>, Vec<_>) = ct
+            .ct
+            .blocks
+            .par_iter()
+            .map(|block| {
+                rayon::join(
+                    || self.apply_cached_function(block, CachedFunction::CarryExtract),
+                    || self.apply_cached_function(block, CachedFunction::MessageExtract),
+                )
+            })
+            .unzip();
+
+        ct.ct.blocks = messages;
+        for (block, carry) in ct.ct.blocks[1..].iter_mut().zip(carries.iter()) {
+            self.key.unchecked_add_assign(block, carry);
+        }
+    }
+
+    /// Computes homomorphically an addition between two ciphertexts in carry-save form, keeping
+    /// the carries of the blocks pending.
+    ///
+    /// When the carries of the blocks do not have enough room for the addition, the inputs are
+    /// first compressed with
+    /// [`compress_carry_save_parallelized`](Self::compress_carry_save_parallelized), so that many
+    /// additions can be chained with a single propagation, done by
+    /// [`resolve_carry_save_parallelized`](Self::resolve_carry_save_parallelized), at the end.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::{gen_keys_radix, RadixCiphertextCarrySaveBig};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let msgs = [14u64, 97, 42, 3, 55];
+    ///
+    /// let mut acc = RadixCiphertextCarrySaveBig::from(cks.encrypt(msgs[0]));
+    /// for &msg in &msgs[1..] {
+    ///     let ct = RadixCiphertextCarrySaveBig::from(cks.encrypt(msg));
+    ///     acc = sks.carry_save_add_parallelized(&acc, &ct);
+    /// }
+    ///
+    /// // A single propagation for all the additions
+    /// let ct_res = sks.resolve_carry_save_parallelized(acc);
+    /// assert!(ct_res.block_carries_are_empty());
+    ///
+    /// // Decrypt:
+    /// let dec_result: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec_result, msgs.iter().sum::<u64>() % 256);
+    /// ```
+    pub fn carry_save_add_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        ct_left: &RadixCiphertextCarrySave<PBSOrder>,
+        ct_right: &RadixCiphertextCarrySave<PBSOrder>,
+    ) -> RadixCiphertextCarrySave<PBSOrder> {
+        if self.is_add_possible(&ct_left.ct, &ct_right.ct) {
+            return self.unchecked_carry_save_add(ct_left, ct_right);
+        }
+
+        let mut ct_left = ct_left.clone();
+        let mut ct_right = ct_right.clone();
+        rayon::join(
+            || self.compress_carry_save_parallelized(&mut ct_left),
+            || self.compress_carry_save_parallelized(&mut ct_right),
+        );
+
+        // With small carry moduli a compression may not free enough room, fall back to
+        // propagating the carries
+        if !self.is_add_possible(&ct_left.ct, &ct_right.ct) {
+            rayon::join(
+                || self.full_propagate_parallelized(&mut ct_left.ct),
+                || self.full_propagate_parallelized(&mut ct_right.ct),
+            );
+        }
+
+        self.unchecked_carry_save_add(&ct_left, &ct_right)
+    }
+
+    /// Propagates all the pending carries of a ciphertext in carry-save form, returning a radix
+    /// ciphertext whose block carries are empty.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    pub fn resolve_carry_save_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        ct: RadixCiphertextCarrySave<PBSOrder>,
+    ) -> RadixCiphertext<PBSOrder> {
+        let mut result = ct.ct;
+        self.maybe_full_propagate_parallelized(&mut result);
+        result
+    }
+}
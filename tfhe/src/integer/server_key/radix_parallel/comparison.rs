@@ -2,7 +2,8 @@ use super::ServerKey;
 
 use crate::integer::ciphertext::RadixCiphertext;
 use crate::integer::server_key::comparator::Comparator;
-use crate::shortint::PBSOrderMarker;
+use crate::shortint::{CiphertextBase, PBSOrderMarker};
+use rayon::prelude::*;
 
 impl ServerKey {
     pub fn unchecked_eq_parallelized<PBSOrder: PBSOrderMarker>(
@@ -13,6 +14,14 @@ impl ServerKey {
         Comparator::new(self).unchecked_eq_parallelized(lhs, rhs)
     }
 
+    pub fn unchecked_ne_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        lhs: &RadixCiphertext<PBSOrder>,
+        rhs: &RadixCiphertext<PBSOrder>,
+    ) -> RadixCiphertext<PBSOrder> {
+        Comparator::new(self).unchecked_ne_parallelized(lhs, rhs)
+    }
+
     pub fn unchecked_gt_parallelized<PBSOrder: PBSOrderMarker>(
         &self,
         lhs: &RadixCiphertext<PBSOrder>,
@@ -69,6 +78,14 @@ impl ServerKey {
         Comparator::new(self).smart_eq_parallelized(lhs, rhs)
     }
 
+    pub fn smart_ne_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        lhs: &mut RadixCiphertext<PBSOrder>,
+        rhs: &mut RadixCiphertext<PBSOrder>,
+    ) -> RadixCiphertext<PBSOrder> {
+        Comparator::new(self).smart_ne_parallelized(lhs, rhs)
+    }
+
     pub fn smart_gt_parallelized<PBSOrder: PBSOrderMarker>(
         &self,
         lhs: &mut RadixCiphertext<PBSOrder>,
@@ -125,6 +142,14 @@ impl ServerKey {
         Comparator::new(self).eq_parallelized(lhs, rhs)
     }
 
+    pub fn ne_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        lhs: &RadixCiphertext<PBSOrder>,
+        rhs: &RadixCiphertext<PBSOrder>,
+    ) -> RadixCiphertext<PBSOrder> {
+        Comparator::new(self).ne_parallelized(lhs, rhs)
+    }
+
     pub fn gt_parallelized<PBSOrder: PBSOrderMarker>(
         &self,
         lhs: &RadixCiphertext<PBSOrder>,
@@ -172,4 +197,603 @@ impl ServerKey {
     ) -> RadixCiphertext<PBSOrder> {
         Comparator::new(self).min_parallelized(lhs, rhs)
     }
+
+    /// Homomorphically computes the maximum of all the ciphertexts in the slice.
+    ///
+    /// The ciphertexts are reduced pairwise in a balanced tournament, so the computation has a
+    /// depth logarithmic in the number of ciphertexts. A slice with a single ciphertext returns
+    /// a clone of it.
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertexts block carries are empty and clears them if it's not the
+    /// case and the operation requires it. It outputs a ciphertext whose block carries are always
+    /// empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `cts` is empty.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let cts = [97u64, 3, 164, 42, 12]
+    ///     .iter()
+    ///     .map(|&msg| cks.encrypt(msg))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// let ct_res = sks.max_of_many_parallelized(&cts);
+    ///
+    /// // Decrypt:
+    /// let dec_result: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec_result, 164);
+    /// ```
+    pub fn max_of_many_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        cts: &[RadixCiphertext<PBSOrder>],
+    ) -> RadixCiphertext<PBSOrder> {
+        assert!(
+            !cts.is_empty(),
+            "Cannot compute the maximum of an empty slice of ciphertexts"
+        );
+        // Ciphertexts not taking part in a pair still need clean carries
+        let cts = self.clean_clones_parallelized(cts);
+        tournament_reduce_parallelized(cts, |lhs, rhs| self.max_parallelized(lhs, rhs))
+    }
+
+    /// Homomorphically computes the minimum of all the ciphertexts in the slice.
+    ///
+    /// The ciphertexts are reduced pairwise in a balanced tournament, so the computation has a
+    /// depth logarithmic in the number of ciphertexts. A slice with a single ciphertext returns
+    /// a clone of it.
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertexts block carries are empty and clears them if it's not the
+    /// case and the operation requires it. It outputs a ciphertext whose block carries are always
+    /// empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `cts` is empty.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let cts = [97u64, 3, 164, 42, 12]
+    ///     .iter()
+    ///     .map(|&msg| cks.encrypt(msg))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// let ct_res = sks.min_of_many_parallelized(&cts);
+    ///
+    /// // Decrypt:
+    /// let dec_result: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec_result, 3);
+    /// ```
+    pub fn min_of_many_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        cts: &[RadixCiphertext<PBSOrder>],
+    ) -> RadixCiphertext<PBSOrder> {
+        assert!(
+            !cts.is_empty(),
+            "Cannot compute the minimum of an empty slice of ciphertexts"
+        );
+        // Ciphertexts not taking part in a pair still need clean carries
+        let cts = self.clean_clones_parallelized(cts);
+        tournament_reduce_parallelized(cts, |lhs, rhs| self.min_parallelized(lhs, rhs))
+    }
+
+    /// Homomorphically computes the maximum of all the ciphertexts in the slice, along with the
+    /// index of the ciphertext holding it, i.e. an encrypted argmax.
+    ///
+    /// When the maximum is held by several ciphertexts, the lowest index is returned.
+    ///
+    /// The returned index has the same number of blocks as the input ciphertexts. The
+    /// ciphertexts are reduced pairwise in a balanced tournament, where the index of the winner
+    /// of each pair is selected with the result of the comparison, so the computation has a
+    /// depth logarithmic in the number of ciphertexts.
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertexts block carries are empty and clears them if it's not the
+    /// case and the operation requires it. It outputs ciphertexts whose block carries are always
+    /// empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `cts` is empty, or if it has more elements than what the ciphertexts can
+    /// represent, as the indices would not fit.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let cts = [97u64, 164, 3, 164, 42]
+    ///     .iter()
+    ///     .map(|&msg| cks.encrypt(msg))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// let (ct_max, ct_index) = sks.max_index_of_parallelized(&cts);
+    ///
+    /// // Decrypt:
+    /// let max: u64 = cks.decrypt(&ct_max);
+    /// let index: u64 = cks.decrypt(&ct_index);
+    /// assert_eq!(max, 164);
+    /// // Ties resolve to the lowest index
+    /// assert_eq!(index, 1);
+    /// ```
+    pub fn max_index_of_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        cts: &[RadixCiphertext<PBSOrder>],
+    ) -> (RadixCiphertext<PBSOrder>, RadixCiphertext<PBSOrder>) {
+        assert!(
+            !cts.is_empty(),
+            "Cannot compute the maximum of an empty slice of ciphertexts"
+        );
+        self.tournament_reduce_with_index_parallelized(cts, |lhs, rhs| {
+            self.gt_parallelized(rhs, lhs)
+        })
+    }
+
+    /// Homomorphically computes the minimum of all the ciphertexts in the slice, along with the
+    /// index of the ciphertext holding it, i.e. an encrypted argmin.
+    ///
+    /// When the minimum is held by several ciphertexts, the lowest index is returned.
+    ///
+    /// See [`max_index_of_parallelized`](Self::max_index_of_parallelized) for the details.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `cts` is empty, or if it has more elements than what the ciphertexts can
+    /// represent, as the indices would not fit.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let cts = [97u64, 12, 164, 42, 12]
+    ///     .iter()
+    ///     .map(|&msg| cks.encrypt(msg))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// let (ct_min, ct_index) = sks.min_index_of_parallelized(&cts);
+    ///
+    /// // Decrypt:
+    /// let min: u64 = cks.decrypt(&ct_min);
+    /// let index: u64 = cks.decrypt(&ct_index);
+    /// assert_eq!(min, 12);
+    /// assert_eq!(index, 1);
+    /// ```
+    pub fn min_index_of_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        cts: &[RadixCiphertext<PBSOrder>],
+    ) -> (RadixCiphertext<PBSOrder>, RadixCiphertext<PBSOrder>) {
+        assert!(
+            !cts.is_empty(),
+            "Cannot compute the minimum of an empty slice of ciphertexts"
+        );
+        self.tournament_reduce_with_index_parallelized(cts, |lhs, rhs| {
+            self.lt_parallelized(rhs, lhs)
+        })
+    }
+
+    /// Homomorphically swaps `lhs` and `rhs` if the encrypted boolean `condition` is 1, leaves
+    /// them untouched if it is 0.
+    ///
+    /// `condition` must be a block encrypting either 0 or 1, such as the first block of the
+    /// result of a comparison.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `lhs` and `rhs` do not have the same number of blocks.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let mut ct_a = cks.encrypt(97u64);
+    /// let mut ct_b = cks.encrypt(164u64);
+    ///
+    /// let condition = cks.encrypt_one_block(1);
+    /// sks.conditional_swap_parallelized(&condition, &mut ct_a, &mut ct_b);
+    ///
+    /// // Decrypt:
+    /// let a: u64 = cks.decrypt(&ct_a);
+    /// let b: u64 = cks.decrypt(&ct_b);
+    /// assert_eq!(a, 164);
+    /// assert_eq!(b, 97);
+    /// ```
+    pub fn conditional_swap_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        condition: &CiphertextBase<PBSOrder>,
+        lhs: &mut RadixCiphertext<PBSOrder>,
+        rhs: &mut RadixCiphertext<PBSOrder>,
+    ) {
+        assert_eq!(
+            lhs.blocks.len(),
+            rhs.blocks.len(),
+            "lhs and rhs must have the same number of blocks"
+        );
+        rayon::join(
+            || self.maybe_full_propagate_parallelized(lhs),
+            || self.maybe_full_propagate_parallelized(rhs),
+        );
+
+        let (new_lhs, new_rhs) = rayon::join(
+            || self.cmux_parallelized(condition, rhs, lhs),
+            || self.cmux_parallelized(condition, lhs, rhs),
+        );
+        *lhs = new_lhs;
+        *rhs = new_rhs;
+    }
+
+    /// Homomorphically orders `lhs` and `rhs`: after the call, `lhs` holds the minimum and `rhs`
+    /// the maximum of the two input values.
+    ///
+    /// This is the compare-and-swap building block of sorting networks, it swaps the ciphertexts
+    /// when `lhs > rhs`, see
+    /// [`conditional_swap_parallelized`](Self::conditional_swap_parallelized).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `lhs` and `rhs` do not have the same number of blocks.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let mut ct_a = cks.encrypt(164u64);
+    /// let mut ct_b = cks.encrypt(97u64);
+    ///
+    /// sks.compare_and_swap_parallelized(&mut ct_a, &mut ct_b);
+    ///
+    /// // Decrypt:
+    /// let a: u64 = cks.decrypt(&ct_a);
+    /// let b: u64 = cks.decrypt(&ct_b);
+    /// assert_eq!(a, 97);
+    /// assert_eq!(b, 164);
+    /// ```
+    pub fn compare_and_swap_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        lhs: &mut RadixCiphertext<PBSOrder>,
+        rhs: &mut RadixCiphertext<PBSOrder>,
+    ) {
+        assert_eq!(
+            lhs.blocks.len(),
+            rhs.blocks.len(),
+            "lhs and rhs must have the same number of blocks"
+        );
+        rayon::join(
+            || self.maybe_full_propagate_parallelized(lhs),
+            || self.maybe_full_propagate_parallelized(rhs),
+        );
+
+        let condition = self.unchecked_gt_parallelized(lhs, rhs);
+        self.conditional_swap_parallelized(&condition.blocks[0], lhs, rhs);
+    }
+
+    /// Homomorphically selects `options[index]`, where `index` is encrypted.
+    ///
+    /// The selection is a tree of cmuxes driven by the bits of `index`, starting from the least
+    /// significant one: each level halves the number of candidates. An `index` that is out of
+    /// range of `options` yields an encryption of 0.
+    ///
+    /// The result has the same number of blocks as the options, `index` can have any number of
+    /// blocks.
+    ///
+    /// This function computes the operation without checking that the input block carries are
+    /// empty, which is required for the result to be correct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `options` is empty or if the options do not all have the same number of blocks.
+    ///
+    /// Also panics if `index` has more bits than needed to index `options` and
+    /// `message_modulus * carry_modulus < 16`, as checking that the index is in range relies on a
+    /// comparison.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    pub fn unchecked_select_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        index: &RadixCiphertext<PBSOrder>,
+        options: &[RadixCiphertext<PBSOrder>],
+    ) -> RadixCiphertext<PBSOrder> {
+        assert!(
+            !options.is_empty(),
+            "Cannot select among an empty slice of options"
+        );
+        let num_blocks = options[0].blocks.len();
+        assert!(
+            options
+                .iter()
+                .all(|option| option.blocks.len() == num_blocks),
+            "All the options must have the same number of blocks"
+        );
+
+        let message_modulus = self.key.message_modulus.0 as u64;
+        let bits_in_block = message_modulus.ilog2() as usize;
+        let index_capacity_bits = index.blocks.len() * bits_in_block;
+
+        // Options past this bound cannot be reached by the index
+        let reachable_options = 1usize
+            .checked_shl(index_capacity_bits as u32)
+            .map_or(options.len(), |capacity| capacity.min(options.len()));
+        let options = &options[..reachable_options];
+        let num_index_bits = options.len().next_power_of_two().ilog2() as usize;
+
+        let index_bits = (0..num_index_bits)
+            .into_par_iter()
+            .map(|i| {
+                let shift = i % bits_in_block;
+                let acc = self
+                    .key
+                    .generate_accumulator(|x| ((x % message_modulus) >> shift) & 1);
+                self.key
+                    .apply_lookup_table(&index.blocks[i / bits_in_block], &acc)
+            })
+            .collect::<Vec<_>>();
+
+        let zero = self.create_trivial_zero_radix(num_blocks);
+        let select_level = |bit: &CiphertextBase<PBSOrder>, level: &[RadixCiphertext<PBSOrder>]| {
+            level
+                .par_chunks(2)
+                .map(|pair| match pair {
+                    [even, odd] => self.cmux_parallelized(bit, odd, even),
+                    // The missing odd option is an out of range index
+                    [even] => self.cmux_parallelized(bit, &zero, even),
+                    _ => unreachable!(),
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let mut index_bits_iter = index_bits.iter();
+        let selected = match index_bits_iter.next() {
+            Some(bit) => {
+                let mut level = select_level(bit, options);
+                for bit in index_bits_iter {
+                    level = select_level(bit, &level);
+                }
+                level.pop().unwrap()
+            }
+            None => options[0].clone(),
+        };
+
+        if num_index_bits >= index_capacity_bits {
+            // All the bits of the index drove the cmux tree
+            return selected;
+        }
+
+        // The index bits that did not drive the cmux tree must all be zero
+        let in_range = self.unchecked_scalar_lt_parallelized(index, 1u64 << num_index_bits);
+        self.cmux_parallelized(&in_range.blocks[0], &selected, &zero)
+    }
+
+    /// Homomorphically selects `options[index]`, where `index` is encrypted.
+    ///
+    /// An `index` that is out of range of `options` yields an encryption of 0, see
+    /// [`unchecked_select_parallelized`](Self::unchecked_select_parallelized) for details on the
+    /// implementation.
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertexts block carries are empty and clears them if it's not the
+    /// case and the operation requires it. It outputs a ciphertext whose block carries are always
+    /// empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `options` is empty or if the options do not all have the same number of blocks.
+    ///
+    /// Also panics if `index` has more bits than needed to index `options` and
+    /// `message_modulus * carry_modulus < 16`, as checking that the index is in range relies on a
+    /// comparison.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let options = [97u64, 164, 42]
+    ///     .iter()
+    ///     .map(|&value| cks.encrypt(value))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// let index = cks.encrypt(1u64);
+    /// let ct_res = sks.select_parallelized(&index, &options);
+    /// let dec_result: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec_result, 164);
+    ///
+    /// // Out of range indices give 0
+    /// let index = cks.encrypt(3u64);
+    /// let ct_res = sks.select_parallelized(&index, &options);
+    /// let dec_result: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec_result, 0);
+    /// ```
+    pub fn select_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        index: &RadixCiphertext<PBSOrder>,
+        options: &[RadixCiphertext<PBSOrder>],
+    ) -> RadixCiphertext<PBSOrder> {
+        let clean_clone = |ct: &RadixCiphertext<PBSOrder>| {
+            let mut ct = ct.clone();
+            self.maybe_full_propagate_parallelized(&mut ct);
+            ct
+        };
+
+        let (index, options) = rayon::join(
+            || clean_clone(index),
+            || options.par_iter().map(clean_clone).collect::<Vec<_>>(),
+        );
+
+        self.unchecked_select_parallelized(&index, &options)
+    }
+
+    /// Reduces the non-empty slice of ciphertexts paired with their encrypted index, in a
+    /// balanced tournament. `takes_rhs` computes, for two ciphertexts with clean carries, the
+    /// encrypted boolean (in the first block) telling whether the pair with the highest index
+    /// wins. As the pairs keep the slice order, a false condition resolves ties to the lowest
+    /// index.
+    fn tournament_reduce_with_index_parallelized<PBSOrder, F>(
+        &self,
+        cts: &[RadixCiphertext<PBSOrder>],
+        takes_rhs: F,
+    ) -> (RadixCiphertext<PBSOrder>, RadixCiphertext<PBSOrder>)
+    where
+        PBSOrder: PBSOrderMarker,
+        F: Fn(&RadixCiphertext<PBSOrder>, &RadixCiphertext<PBSOrder>) -> RadixCiphertext<PBSOrder>
+            + Sync,
+    {
+        let num_blocks = cts[0].blocks.len();
+        let index_bits = num_blocks as u32 * self.key.message_modulus.0.ilog2();
+        assert!(
+            index_bits >= u64::BITS || (cts.len() as u64 - 1) >> index_bits == 0,
+            "Cannot index {} ciphertexts with {num_blocks} blocks",
+            cts.len()
+        );
+
+        // The selection works on the blocks, the carries must be clean
+        let pairs = self
+            .clean_clones_parallelized(cts)
+            .into_iter()
+            .enumerate()
+            .map(|(i, ct)| {
+                let mut index = self.create_trivial_zero_radix(num_blocks);
+                self.unchecked_scalar_add_assign(&mut index, i as u64);
+                (ct, index)
+            })
+            .collect::<Vec<_>>();
+
+        tournament_reduce_parallelized(pairs, |(lhs, lhs_index), (rhs, rhs_index)| {
+            let condition = takes_rhs(lhs, rhs);
+            let condition = &condition.blocks[0];
+            rayon::join(
+                || self.cmux_parallelized(condition, rhs, lhs),
+                || self.cmux_parallelized(condition, rhs_index, lhs_index),
+            )
+        })
+    }
+
+    /// Returns `if_true` if the encrypted boolean `condition` is 1, `if_false` otherwise, i.e. a
+    /// cmux. All the blocks must have clean carries.
+    fn cmux_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        condition: &CiphertextBase<PBSOrder>,
+        if_true: &RadixCiphertext<PBSOrder>,
+        if_false: &RadixCiphertext<PBSOrder>,
+    ) -> RadixCiphertext<PBSOrder> {
+        let blocks = if_true
+            .blocks
+            .par_iter()
+            .zip(if_false.blocks.par_iter())
+            .map(|(true_block, false_block)| {
+                self.block_cmux_parallelized(condition, true_block, false_block)
+            })
+            .collect::<Vec<_>>();
+
+        RadixCiphertext::from(blocks)
+    }
+
+    /// Returns clones of the ciphertexts, with their carries cleaned.
+    fn clean_clones_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        cts: &[RadixCiphertext<PBSOrder>],
+    ) -> Vec<RadixCiphertext<PBSOrder>> {
+        cts.par_iter()
+            .map(|ct| {
+                let mut ct = ct.clone();
+                self.maybe_full_propagate_parallelized(&mut ct);
+                ct
+            })
+            .collect()
+    }
+}
+
+/// Reduces the non-empty vector by applying `op` to pairs of items, each round halving the number
+/// of items, an odd one out being carried over to the next round.
+fn tournament_reduce_parallelized<T, F>(mut items: Vec<T>, op: F) -> T
+where
+    T: Clone + Send + Sync,
+    F: Fn(&T, &T) -> T + Sync,
+{
+    while items.len() > 1 {
+        items = items
+            .par_chunks(2)
+            .map(|pair| match pair {
+                [lhs, rhs] => op(lhs, rhs),
+                [single] => single.clone(),
+                _ => unreachable!(),
+            })
+            .collect();
+    }
+    items.pop().unwrap()
 }
@@ -0,0 +1,176 @@
+use crate::integer::ciphertext::{RadixCiphertext, RadixCiphertextCarrySave};
+use crate::integer::server_key::accumulator_cache::CachedFunction;
+use crate::integer::ServerKey;
+use crate::shortint::PBSOrderMarker;
+use rayon::prelude::*;
+
+impl ServerKey {
+    /// Homomorphically computes the base 2 logarithm of a ciphertext, rounded down, i.e. the
+    /// position of its most significant set bit.
+    ///
+    /// The logarithm of 0 is not defined, this function returns 0 for an input of 0, the same
+    /// result as for an input of 1. [`is_zero_parallelized`](Self::is_zero_parallelized) can be
+    /// used to tell these two inputs apart.
+    ///
+    /// The result has the same number of blocks as the input, which is always enough to hold it.
+    ///
+    /// The logarithm is the number of powers of two, from `2^1` to the largest one the ciphertext
+    /// can hold, that are less or equal to the input. These are counted per block: a block
+    /// contributes all its powers of two when a block of higher significance is not zero,
+    /// otherwise it contributes as many as its value is greater or equal to. The per block
+    /// counts are then summed.
+    ///
+    /// This function computes the operation without checking that the input block carries are
+    /// empty, which is required for the result to be correct. The output block carries are
+    /// always empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the message modulus is not a power of two.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    pub fn unchecked_ilog2_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        ct: &RadixCiphertext<PBSOrder>,
+    ) -> RadixCiphertext<PBSOrder> {
+        let message_modulus = self.key.message_modulus.0 as u64;
+        assert!(
+            message_modulus.is_power_of_two(),
+            "the logarithm requires a power of two message modulus"
+        );
+        let block_bits = message_modulus.ilog2() as u64;
+        let num_blocks = ct.blocks.len();
+
+        if num_blocks == 0 {
+            return ct.clone();
+        }
+
+        // Inclusive suffix OR of the non zero indicators of the blocks, computed as a parallel
+        // scan: after the round with stride d, each entry covers the blocks [i, i + 2d)
+        let mut any_non_zero = ct
+            .blocks
+            .par_iter()
+            .map(|block| self.apply_cached_function(block, CachedFunction::IsNonZero))
+            .collect::<Vec<_>>();
+        let mut stride = 1;
+        while stride < num_blocks {
+            any_non_zero = (0..num_blocks)
+                .into_par_iter()
+                .map(|i| match any_non_zero.get(i + stride) {
+                    Some(higher) => self.key.unchecked_bitor(&any_non_zero[i], higher),
+                    None => any_non_zero[i].clone(),
+                })
+                .collect();
+            stride *= 2;
+        }
+
+        // Number of the powers of two held by the block, that are counted in the logarithm
+        let counted_powers = |block_index: usize| {
+            if block_index == 0 {
+                block_bits - 1
+            } else {
+                block_bits
+            }
+        };
+
+        let counts = ct
+            .blocks
+            .par_iter()
+            .enumerate()
+            .map(|(i, block)| {
+                let offset = counted_powers(i) + 1 - block_bits;
+                let acc = self
+                    .key
+                    .generate_accumulator_bivariate(|higher_is_non_zero, x| {
+                        let x = x % message_modulus;
+                        if higher_is_non_zero != 0 {
+                            counted_powers(i)
+                        } else if x == 0 {
+                            0
+                        } else {
+                            x.ilog2() as u64 + offset
+                        }
+                    });
+
+                let higher_is_non_zero = any_non_zero
+                    .get(i + 1)
+                    .cloned()
+                    .unwrap_or_else(|| self.key.create_trivial(0));
+                self.key
+                    .unchecked_apply_lookup_table_bivariate(&higher_is_non_zero, block, &acc)
+            })
+            .collect::<Vec<_>>();
+
+        // Each count is smaller than the message modulus, the sum is accumulated without
+        // propagating the carries in between
+        let mut terms = counts.into_iter().map(|count| {
+            let mut term = self.create_trivial_zero_radix(num_blocks);
+            term.blocks[0] = count;
+            RadixCiphertextCarrySave::from(term)
+        });
+        let first = terms.next().unwrap();
+        let sum = terms.fold(first, |sum, term| {
+            self.carry_save_add_parallelized(&sum, &term)
+        });
+        self.resolve_carry_save_parallelized(sum)
+    }
+
+    /// Homomorphically computes the base 2 logarithm of a ciphertext, rounded down, i.e. the
+    /// position of its most significant set bit.
+    ///
+    /// The logarithm of 0 is not defined, this function returns 0 for an input of 0, see
+    /// [`unchecked_ilog2_parallelized`](Self::unchecked_ilog2_parallelized).
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertexts block carries are empty and clears them if it's not the
+    /// case and the operation requires it. It outputs a ciphertext whose block carries are always
+    /// empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the message modulus is not a power of two.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let msg = 0b0001_1011u64;
+    ///
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// let ct_res = sks.ilog2_parallelized(&ct);
+    ///
+    /// // Decrypt:
+    /// let dec_result: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec_result, 4);
+    ///
+    /// // The logarithm of 0 is defined as 0
+    /// let ct_res = sks.ilog2_parallelized(&cks.encrypt(0u64));
+    /// let dec_result: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec_result, 0);
+    /// ```
+    pub fn ilog2_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        ct: &RadixCiphertext<PBSOrder>,
+    ) -> RadixCiphertext<PBSOrder> {
+        if ct.block_carries_are_empty() {
+            self.unchecked_ilog2_parallelized(ct)
+        } else {
+            let mut ct = ct.clone();
+            self.full_propagate_parallelized(&mut ct);
+            self.unchecked_ilog2_parallelized(&ct)
+        }
+    }
+}
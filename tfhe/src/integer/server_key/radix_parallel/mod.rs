@@ -1,9 +1,19 @@
+mod abs;
 mod add;
 mod bitwise_op;
+mod blind_lookup;
+mod carry_save;
 mod comparison;
+mod ilog2;
 mod mul;
 mod neg;
+mod parity;
+mod reverse_bits;
 mod scalar_add;
+mod scalar_bitwise_op;
+mod scalar_comparison;
+mod scalar_div;
+mod scalar_mod;
 mod scalar_mul;
 mod scalar_sub;
 mod shift;
@@ -14,7 +24,11 @@ mod tests;
 
 use super::ServerKey;
 use crate::integer::ciphertext::RadixCiphertext;
-use crate::shortint::PBSOrderMarker;
+use crate::integer::server_key::accumulator_cache::CachedFunction;
+use crate::shortint::ciphertext::Degree;
+use crate::shortint::server_key::LookupTableOwned;
+use crate::shortint::{CiphertextBase, PBSOrderMarker};
+use rayon::prelude::*;
 
 // parallelized versions
 impl ServerKey {
@@ -49,8 +63,8 @@ impl ServerKey {
         index: usize,
     ) {
         let (carry, message) = rayon::join(
-            || self.key.carry_extract(&ctxt.blocks[index]),
-            || self.key.message_extract(&ctxt.blocks[index]),
+            || self.apply_cached_function(&ctxt.blocks[index], CachedFunction::CarryExtract),
+            || self.apply_cached_function(&ctxt.blocks[index], CachedFunction::MessageExtract),
         );
         ctxt.blocks[index] = message;
 
@@ -95,4 +109,137 @@ impl ServerKey {
             self.propagate_parallelized(ctxt, i);
         }
     }
+
+    /// Propagate all the carries if some blocks have non empty carries.
+    ///
+    /// Returns `true` if the carries were propagated, and `false` if all the block carries were
+    /// already empty, in which case the ciphertext is left untouched.
+    ///
+    /// # Example
+    ///
+    ///```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let msg = 10u64;
+    ///
+    /// let mut ct1 = cks.encrypt(msg);
+    /// let mut ct2 = cks.encrypt(msg);
+    ///
+    /// // Freshly encrypted ciphertexts have empty carries
+    /// assert!(!sks.maybe_full_propagate_parallelized(&mut ct1));
+    ///
+    /// // Compute homomorphically an addition:
+    /// let mut ct_res = sks.unchecked_add(&mut ct1, &mut ct2);
+    /// assert!(sks.maybe_full_propagate_parallelized(&mut ct_res));
+    ///
+    /// // Decrypt:
+    /// let res: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(msg + msg, res);
+    /// ```
+    pub fn maybe_full_propagate_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        ctxt: &mut RadixCiphertext<PBSOrder>,
+    ) -> bool {
+        if ctxt.block_carries_are_empty() {
+            return false;
+        }
+        self.full_propagate_parallelized(ctxt);
+        true
+    }
+
+    /// Returns `if_true` if the encrypted boolean `condition` is 1, `if_false` otherwise.
+    ///
+    /// Each of the two blocks is zeroed or kept depending on `condition` with one programmable
+    /// bootstrapping, both being computed in parallel, and the two results are summed. The input
+    /// blocks must have clean carries, and so does the result.
+    pub(crate) fn block_cmux_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        condition: &CiphertextBase<PBSOrder>,
+        if_true: &CiphertextBase<PBSOrder>,
+        if_false: &CiphertextBase<PBSOrder>,
+    ) -> CiphertextBase<PBSOrder> {
+        let (mut result, if_false) = rayon::join(
+            || {
+                let keep_if_true = |x: u64, c: u64| if c == 1 { x } else { 0 };
+                self.key
+                    .unchecked_evaluate_bivariate_function(if_true, condition, keep_if_true)
+            },
+            || {
+                let keep_if_false = |x: u64, c: u64| if c == 1 { 0 } else { x };
+                self.key
+                    .unchecked_evaluate_bivariate_function(if_false, condition, keep_if_false)
+            },
+        );
+        self.key.unchecked_add_assign(&mut result, &if_false);
+        // One of the two terms is always zero, so the sum fits in the message space
+        result.degree = Degree(self.key.message_modulus.0 - 1);
+        result
+    }
+
+    /// Sums `blocks` in groups as large as the carry space allows, until a single group is left.
+    ///
+    /// The input blocks hold values in `[0, max_value]`. Each group sum is reduced with one
+    /// programmable bootstrapping through `lookup_table`, whose outputs must be in
+    /// `[0, lookup_table_max_value]`, and the reduced blocks are summed again the same way, as a
+    /// balanced tree. The sum of the last group is returned without being reduced, so that the
+    /// caller can apply its own final lookup table. A trivial zero is returned if `blocks` is
+    /// empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_value` does not fit in a block, or if the carry space cannot hold the sum of
+    /// two outputs of `lookup_table`, as the number of blocks would then never decrease.
+    pub(crate) fn unchecked_sum_blocks_in_groups_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        mut blocks: Vec<CiphertextBase<PBSOrder>>,
+        max_value: u64,
+        lookup_table: &LookupTableOwned,
+        lookup_table_max_value: u64,
+    ) -> CiphertextBase<PBSOrder> {
+        let max_block_value = (self.key.message_modulus.0 * self.key.carry_modulus.0) as u64 - 1;
+        // Number of blocks holding values up to max_value that can be summed without exceeding
+        // the carry space
+        let group_size = |max_value: u64| (max_block_value / max_value.max(1)) as usize;
+        assert!(
+            max_value <= max_block_value,
+            "The blocks to sum must hold values in [0, {max_block_value}], got {max_value}"
+        );
+        assert!(
+            group_size(lookup_table_max_value) >= 2,
+            "The carry space must hold the sum of two lookup table outputs, \
+            got outputs up to {lookup_table_max_value} for blocks holding up to {max_block_value}"
+        );
+
+        let mut max_value = max_value;
+        loop {
+            let group_size = group_size(max_value);
+            if blocks.len() <= group_size {
+                let mut blocks = blocks.into_iter();
+                let Some(mut sum) = blocks.next() else {
+                    return self.key.create_trivial(0);
+                };
+                for block in blocks {
+                    self.key.unchecked_add_assign(&mut sum, &block);
+                }
+                return sum;
+            }
+
+            blocks = blocks
+                .par_chunks(group_size)
+                .map(|group| {
+                    let mut sum = group[0].clone();
+                    for block in &group[1..] {
+                        self.key.unchecked_add_assign(&mut sum, block);
+                    }
+                    self.key.apply_lookup_table(&sum, lookup_table)
+                })
+                .collect();
+            max_value = lookup_table_max_value;
+        }
+    }
 }
@@ -1,8 +1,7 @@
-use std::sync::Mutex;
-
 use crate::integer::ciphertext::RadixCiphertext;
+use crate::integer::server_key::accumulator_cache::CachedFunction;
 use crate::integer::ServerKey;
-use crate::shortint::PBSOrderMarker;
+use crate::shortint::{CiphertextBase, PBSOrderMarker};
 use rayon::prelude::*;
 
 impl ServerKey {
@@ -335,14 +334,12 @@ impl ServerKey {
     ) -> RadixCiphertext<PBSOrder> {
         let mut result = self.create_trivial_zero_radix(ct1.blocks.len());
 
-        let terms = Mutex::new(Vec::new());
-
-        ct2.blocks.par_iter().enumerate().for_each(|(i, ct2_i)| {
-            let term = self.unchecked_block_mul_parallelized(ct1, ct2_i, i);
-            terms.lock().unwrap().push(term);
-        });
-
-        let terms = terms.into_inner().unwrap();
+        let terms = ct2
+            .blocks
+            .par_iter()
+            .enumerate()
+            .map(|(i, ct2_i)| self.unchecked_block_mul_parallelized(ct1, ct2_i, i))
+            .collect::<Vec<_>>();
 
         for term in terms {
             self.unchecked_add_assign(&mut result, &term);
@@ -407,12 +404,12 @@ impl ServerKey {
             || self.full_propagate_parallelized(ct2),
         );
 
-        let terms = Mutex::new(Vec::new());
-        ct2.blocks.par_iter().enumerate().for_each(|(i, ct2_i)| {
-            let term = self.unchecked_block_mul_parallelized(ct1, ct2_i, i);
-            terms.lock().unwrap().push(term);
-        });
-        let mut terms = terms.into_inner().unwrap();
+        let mut terms = ct2
+            .blocks
+            .par_iter()
+            .enumerate()
+            .map(|(i, ct2_i)| self.unchecked_block_mul_parallelized(ct1, ct2_i, i))
+            .collect::<Vec<_>>();
 
         self.smart_binary_op_seq_parallelized(&mut terms, ServerKey::smart_add_parallelized)
             .unwrap_or_else(|| self.create_trivial_zero_radix(ct1.blocks.len()))
@@ -498,4 +495,90 @@ impl ServerKey {
         self.unchecked_mul_assign_parallelized(lhs, rhs);
         self.full_propagate_parallelized(lhs);
     }
+
+    /// Computes homomorphically a multiplication between two ciphertexts encrypting integer
+    /// values, and whether the multiplication overflowed.
+    ///
+    /// Returns the product, wrapped around the modulus of the input ciphertexts, and an encrypted
+    /// boolean (`1` in case of overflow, `0` otherwise). This is the encrypted counterpart of
+    /// Rust's `overflowing_mul`: as the result of a homomorphic operation cannot be inspected,
+    /// the overflow is signaled by the flag instead of returning `None` like `checked_mul`.
+    ///
+    /// The product is computed on twice the number of blocks of `ct1`, so that the high partial
+    /// products are kept, the flag encrypts whether these high blocks are non-zero. This makes
+    /// the operation more expensive than [`Self::mul_parallelized`].
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertexts block carries are empty and clears them if it's not the
+    /// case and the operation requires it. It outputs a ciphertext whose block carries are always
+    /// empty.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let clear_1 = 17u64;
+    /// let clear_2 = 6u64;
+    ///
+    /// let ctxt_1 = cks.encrypt(clear_1);
+    /// let ctxt_2 = cks.encrypt(clear_2);
+    ///
+    /// // 17 * 6 fits in 8 bits
+    /// let (ct_res, overflowed) = sks.checked_mul_parallelized(&ctxt_1, &ctxt_2);
+    /// let res: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(res, clear_1 * clear_2);
+    /// assert_eq!(cks.decrypt_one_block(&overflowed), 0);
+    ///
+    /// // 17 * 17 does not
+    /// let (ct_res, overflowed) = sks.checked_mul_parallelized(&ctxt_1, &ctxt_1);
+    /// let res: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(res, (clear_1 * clear_1) % 256);
+    /// assert_eq!(cks.decrypt_one_block(&overflowed), 1);
+    /// ```
+    pub fn checked_mul_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        ct1: &RadixCiphertext<PBSOrder>,
+        ct2: &RadixCiphertext<PBSOrder>,
+    ) -> (RadixCiphertext<PBSOrder>, CiphertextBase<PBSOrder>) {
+        let num_blocks = ct1.blocks.len();
+
+        let mut lhs = ct1.clone();
+        let mut rhs = ct2.clone();
+        rayon::join(
+            || {
+                if !lhs.block_carries_are_empty() {
+                    self.full_propagate_parallelized(&mut lhs)
+                }
+            },
+            || {
+                if !rhs.block_carries_are_empty() {
+                    self.full_propagate_parallelized(&mut rhs)
+                }
+            },
+        );
+
+        // Keep the high partial products by computing the product on twice the number of blocks
+        lhs.blocks
+            .extend((0..num_blocks).map(|_| self.key.create_trivial(0)));
+        let mut product = self.unchecked_mul_parallelized(&mut lhs, &rhs);
+        self.full_propagate_parallelized(&mut product);
+
+        let high_blocks = RadixCiphertext::from(product.blocks.split_off(num_blocks));
+        let high_blocks_are_zero = self.unchecked_is_zero_parallelized(&high_blocks);
+
+        let overflowed =
+            self.apply_cached_function(&high_blocks_are_zero.blocks[0], CachedFunction::IsZero);
+
+        (product, overflowed)
+    }
 }
@@ -39,6 +39,42 @@ impl ServerKey {
         self.unchecked_neg(ctxt)
     }
 
+    /// Homomorphically computes the opposite of a ciphertext encrypting an integer message.
+    ///
+    /// The result is assigned to the input ciphertext.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg = 1u64;
+    ///
+    /// // Encrypt a message:
+    /// let mut ctxt = cks.encrypt(msg);
+    ///
+    /// // Compute homomorphically a negation
+    /// sks.smart_neg_assign_parallelized(&mut ctxt);
+    ///
+    /// // Decrypt
+    /// let dec: u64 = cks.decrypt(&ctxt);
+    /// assert_eq!(255, dec);
+    /// ```
+    pub fn smart_neg_assign_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        ctxt: &mut RadixCiphertext<PBSOrder>,
+    ) {
+        if !self.is_neg_possible(ctxt) {
+            self.full_propagate_parallelized(ctxt);
+        }
+        self.unchecked_neg_assign(ctxt);
+    }
+
     /// Homomorphically computes the opposite of a ciphertext encrypting an integer message.
     ///
     /// The result is returned as a new ciphertext.
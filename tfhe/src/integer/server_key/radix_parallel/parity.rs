@@ -0,0 +1,107 @@
+use crate::integer::ciphertext::RadixCiphertext;
+use crate::integer::ServerKey;
+use crate::shortint::PBSOrderMarker;
+use rayon::prelude::*;
+
+impl ServerKey {
+    /// Homomorphically computes the parity of a ciphertext, i.e. the XOR of all its bits.
+    ///
+    /// The result is an encryption of 1 if `ct` has an odd number of set bits and 0 otherwise,
+    /// stored in the first block of a ciphertext with as many blocks as `ct`.
+    ///
+    /// The parity of each block is computed with one programmable bootstrapping. Block parities
+    /// are then summed together in groups as large as the carry space allows, each group sum
+    /// being reduced to its parity with one programmable bootstrapping, until a single block is
+    /// left.
+    ///
+    /// This function computes the operation without checking that the input block carries are
+    /// empty, which is required for the result to be correct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a block cannot hold the value 2, i.e. if `message_modulus * carry_modulus < 3`.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    pub fn unchecked_parity_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        ct: &RadixCiphertext<PBSOrder>,
+    ) -> RadixCiphertext<PBSOrder> {
+        let message_modulus = self.key.message_modulus.0 as u64;
+        let num_blocks = ct.blocks.len();
+
+        let mut result = self.create_trivial_zero_radix(num_blocks);
+        if num_blocks == 0 {
+            return result;
+        }
+
+        let block_parity_acc = self
+            .key
+            .generate_accumulator(|x| u64::from((x % message_modulus).count_ones()) % 2);
+        let parity_acc = self.key.generate_accumulator(|x| x % 2);
+
+        let parities = ct
+            .blocks
+            .par_iter()
+            .map(|block| self.key.apply_lookup_table(block, &block_parity_acc))
+            .collect::<Vec<_>>();
+
+        let sum = self.unchecked_sum_blocks_in_groups_parallelized(parities, 1, &parity_acc, 1);
+        result.blocks[0] = self.key.apply_lookup_table(&sum, &parity_acc);
+        result
+    }
+
+    /// Homomorphically computes the parity of a ciphertext, i.e. the XOR of all its bits.
+    ///
+    /// The result is an encryption of 1 if `ct` has an odd number of set bits and 0 otherwise,
+    /// stored in the first block of a ciphertext with as many blocks as `ct`.
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertexts block carries are empty and clears them if it's not the
+    /// case and the operation requires it. It outputs a ciphertext whose block carries are always
+    /// empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a block cannot hold the value 2, i.e. if `message_modulus * carry_modulus < 3`.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// // 4 set bits
+    /// let ct = cks.encrypt(0b0001_1011u64);
+    /// let ct_res = sks.parity_parallelized(&ct);
+    /// let dec_result: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec_result, 0);
+    ///
+    /// // 5 set bits
+    /// let ct = cks.encrypt(0b0101_1011u64);
+    /// let ct_res = sks.parity_parallelized(&ct);
+    /// let dec_result: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec_result, 1);
+    /// ```
+    pub fn parity_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        ct: &RadixCiphertext<PBSOrder>,
+    ) -> RadixCiphertext<PBSOrder> {
+        if ct.block_carries_are_empty() {
+            self.unchecked_parity_parallelized(ct)
+        } else {
+            let mut ct = ct.clone();
+            self.full_propagate_parallelized(&mut ct);
+            self.unchecked_parity_parallelized(&ct)
+        }
+    }
+}
@@ -0,0 +1,103 @@
+use crate::integer::ciphertext::RadixCiphertext;
+use crate::integer::ServerKey;
+use crate::shortint::PBSOrderMarker;
+use rayon::prelude::*;
+
+impl ServerKey {
+    /// Homomorphically reverses the order of the bits of a ciphertext.
+    ///
+    /// The bits are reversed within the full width of the radix ciphertext, i.e.
+    /// `num_blocks * log2(message_modulus)` bits: the least significant bit of the input becomes
+    /// the most significant bit of the last block.
+    ///
+    /// The bits of each block are reversed with one PBS, then the order of the blocks is
+    /// reversed.
+    ///
+    /// This function computes the operation without checking that the input block carries are
+    /// empty, which is required for the result to be correct. The output block carries are
+    /// always empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the message modulus is not a power of two.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    pub fn unchecked_reverse_bits_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        ct: &RadixCiphertext<PBSOrder>,
+    ) -> RadixCiphertext<PBSOrder> {
+        let message_modulus = self.key.message_modulus.0 as u64;
+        assert!(
+            message_modulus.is_power_of_two(),
+            "reversing the bits requires a power of two message modulus"
+        );
+        let block_bits = message_modulus.ilog2();
+
+        let acc = self.key.generate_accumulator(|x| {
+            // The block bits end up in the most significant bits of the reversed u64
+            (x % message_modulus).reverse_bits() >> (u64::BITS - block_bits)
+        });
+
+        let mut result = ct.clone();
+        result
+            .blocks
+            .par_iter_mut()
+            .for_each(|block| self.key.apply_lookup_table_assign(block, &acc));
+        result.blocks.reverse();
+        result
+    }
+
+    /// Homomorphically reverses the order of the bits of a ciphertext.
+    ///
+    /// The bits are reversed within the full width of the radix ciphertext, i.e.
+    /// `num_blocks * log2(message_modulus)` bits, see
+    /// [`unchecked_reverse_bits_parallelized`](Self::unchecked_reverse_bits_parallelized).
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertexts block carries are empty and clears them if it's not the
+    /// case and the operation requires it. It outputs a ciphertext whose block carries are always
+    /// empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the message modulus is not a power of two.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let msg = 0b0001_1011u64;
+    ///
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// let ct_res = sks.reverse_bits_parallelized(&ct);
+    ///
+    /// // Decrypt:
+    /// let dec_result: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec_result, 0b1101_1000);
+    /// ```
+    pub fn reverse_bits_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        ct: &RadixCiphertext<PBSOrder>,
+    ) -> RadixCiphertext<PBSOrder> {
+        if ct.block_carries_are_empty() {
+            self.unchecked_reverse_bits_parallelized(ct)
+        } else {
+            let mut ct = ct.clone();
+            self.full_propagate_parallelized(&mut ct);
+            self.unchecked_reverse_bits_parallelized(&ct)
+        }
+    }
+}
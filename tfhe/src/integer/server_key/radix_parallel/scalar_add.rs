@@ -1,3 +1,4 @@
+use crate::integer::block_decomposition::DecomposableInto;
 use crate::integer::ciphertext::RadixCiphertext;
 use crate::integer::ServerKey;
 use crate::shortint::PBSOrderMarker;
@@ -18,7 +19,7 @@ impl ServerKey {
     /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
     ///
     /// let msg = 4;
-    /// let scalar = 40;
+    /// let scalar = 40u64;
     ///
     /// let mut ct = cks.encrypt(msg);
     ///
@@ -29,11 +30,15 @@ impl ServerKey {
     /// let dec: u64 = cks.decrypt(&ct_res);
     /// assert_eq!(msg + scalar, dec);
     /// ```
-    pub fn smart_scalar_add_parallelized<PBSOrder: PBSOrderMarker>(
+    pub fn smart_scalar_add_parallelized<PBSOrder, T>(
         &self,
         ct: &mut RadixCiphertext<PBSOrder>,
-        scalar: u64,
-    ) -> RadixCiphertext<PBSOrder> {
+        scalar: T,
+    ) -> RadixCiphertext<PBSOrder>
+    where
+        PBSOrder: PBSOrderMarker,
+        T: DecomposableInto<u8>,
+    {
         if !self.is_scalar_add_possible(ct, scalar) {
             self.full_propagate_parallelized(ct);
         }
@@ -55,7 +60,7 @@ impl ServerKey {
     /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
     ///
     /// let msg = 129;
-    /// let scalar = 40;
+    /// let scalar = 40u64;
     ///
     /// let mut ct = cks.encrypt(msg);
     ///
@@ -66,11 +71,14 @@ impl ServerKey {
     /// let dec: u64 = cks.decrypt(&ct);
     /// assert_eq!(msg + scalar, dec);
     /// ```
-    pub fn smart_scalar_add_assign_parallelized<PBSOrder: PBSOrderMarker>(
+    pub fn smart_scalar_add_assign_parallelized<PBSOrder, T>(
         &self,
         ct: &mut RadixCiphertext<PBSOrder>,
-        scalar: u64,
-    ) {
+        scalar: T,
+    ) where
+        PBSOrder: PBSOrderMarker,
+        T: DecomposableInto<u8>,
+    {
         if !self.is_scalar_add_possible(ct, scalar) {
             self.full_propagate_parallelized(ct);
         }
@@ -101,7 +109,7 @@ impl ServerKey {
     /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
     ///
     /// let msg = 4;
-    /// let scalar = 40;
+    /// let scalar = 40u64;
     ///
     /// let ct = cks.encrypt(msg);
     ///
@@ -112,11 +120,15 @@ impl ServerKey {
     /// let dec: u64 = cks.decrypt(&ct_res);
     /// assert_eq!(msg + scalar, dec);
     /// ```
-    pub fn scalar_add_parallelized<PBSOrder: PBSOrderMarker>(
+    pub fn scalar_add_parallelized<PBSOrder, T>(
         &self,
         ct: &RadixCiphertext<PBSOrder>,
-        scalar: u64,
-    ) -> RadixCiphertext<PBSOrder> {
+        scalar: T,
+    ) -> RadixCiphertext<PBSOrder>
+    where
+        PBSOrder: PBSOrderMarker,
+        T: DecomposableInto<u8>,
+    {
         let mut ct_res = ct.clone();
         self.scalar_add_assign_parallelized(&mut ct_res, scalar);
         ct_res
@@ -146,7 +158,7 @@ impl ServerKey {
     /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
     ///
     /// let msg = 129;
-    /// let scalar = 40;
+    /// let scalar = 40u64;
     ///
     /// let mut ct = cks.encrypt(msg);
     ///
@@ -157,14 +169,15 @@ impl ServerKey {
     /// let dec: u64 = cks.decrypt(&ct);
     /// assert_eq!(msg + scalar, dec);
     /// ```
-    pub fn scalar_add_assign_parallelized<PBSOrder: PBSOrderMarker>(
+    pub fn scalar_add_assign_parallelized<PBSOrder, T>(
         &self,
         ct: &mut RadixCiphertext<PBSOrder>,
-        scalar: u64,
-    ) {
-        if !ct.block_carries_are_empty() {
-            self.full_propagate_parallelized(ct);
-        };
+        scalar: T,
+    ) where
+        PBSOrder: PBSOrderMarker,
+        T: DecomposableInto<u8>,
+    {
+        self.maybe_full_propagate_parallelized(ct);
         self.unchecked_scalar_add_assign(ct, scalar);
         self.full_propagate_parallelized(ct);
     }
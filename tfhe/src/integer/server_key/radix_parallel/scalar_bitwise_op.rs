@@ -0,0 +1,411 @@
+use crate::integer::block_decomposition::{decompose_scalar_wrapping, DecomposableInto};
+use crate::integer::ciphertext::RadixCiphertext;
+use crate::integer::ServerKey;
+use crate::shortint::PBSOrderMarker;
+use rayon::prelude::*;
+
+impl ServerKey {
+    /// Applies `op` between each block of the ciphertext and the corresponding block of the
+    /// radix decomposition of the scalar, using a programmable bootstrapping per block.
+    ///
+    /// The blocks carries are expected to be empty.
+    fn unchecked_scalar_bitop_assign_parallelized<PBSOrder, T, F>(
+        &self,
+        ct: &mut RadixCiphertext<PBSOrder>,
+        scalar: T,
+        op: F,
+    ) where
+        PBSOrder: PBSOrderMarker,
+        T: DecomposableInto<u8>,
+        F: Fn(u64, u64) -> u64 + Sync,
+    {
+        let message_modulus = self.key.message_modulus.0 as u64;
+        let bits_in_block = message_modulus.ilog2();
+        let decomposed_scalar = decompose_scalar_wrapping(scalar, bits_in_block, ct.blocks.len());
+
+        ct.blocks
+            .par_iter_mut()
+            .zip(decomposed_scalar)
+            .for_each(|(block, scalar_block)| {
+                let scalar_block = u64::from(scalar_block);
+                let acc = self
+                    .key
+                    .generate_accumulator(|x| op(x % message_modulus, scalar_block));
+                self.key.apply_lookup_table_assign(block, &acc);
+            });
+    }
+
+    /// Computes homomorphically a bitand between a ciphertext and a clear scalar.
+    ///
+    /// This function computes the operation without checking that the input block carries are
+    /// empty, which is required for the result to be correct.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    pub fn unchecked_scalar_bitand_parallelized<PBSOrder, T>(
+        &self,
+        ct: &RadixCiphertext<PBSOrder>,
+        scalar: T,
+    ) -> RadixCiphertext<PBSOrder>
+    where
+        PBSOrder: PBSOrderMarker,
+        T: DecomposableInto<u8>,
+    {
+        let mut result = ct.clone();
+        self.unchecked_scalar_bitand_assign_parallelized(&mut result, scalar);
+        result
+    }
+
+    pub fn unchecked_scalar_bitand_assign_parallelized<PBSOrder, T>(
+        &self,
+        ct: &mut RadixCiphertext<PBSOrder>,
+        scalar: T,
+    ) where
+        PBSOrder: PBSOrderMarker,
+        T: DecomposableInto<u8>,
+    {
+        self.unchecked_scalar_bitop_assign_parallelized(ct, scalar, |x, y| x & y);
+    }
+
+    /// Computes homomorphically a bitand between a ciphertext and a clear scalar.
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertexts block carries are empty and clears them if it's not the
+    /// case and the operation requires it. It outputs a ciphertext whose block carries are always
+    /// empty.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let msg = 14;
+    /// let scalar = 97u64;
+    ///
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// let ct_res = sks.scalar_bitand_parallelized(&ct, scalar);
+    ///
+    /// // Decrypt:
+    /// let dec_result: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec_result, msg & scalar);
+    /// ```
+    pub fn scalar_bitand_parallelized<PBSOrder, T>(
+        &self,
+        ct: &RadixCiphertext<PBSOrder>,
+        scalar: T,
+    ) -> RadixCiphertext<PBSOrder>
+    where
+        PBSOrder: PBSOrderMarker,
+        T: DecomposableInto<u8>,
+    {
+        let mut ct_res = ct.clone();
+        self.scalar_bitand_assign_parallelized(&mut ct_res, scalar);
+        ct_res
+    }
+
+    pub fn scalar_bitand_assign_parallelized<PBSOrder, T>(
+        &self,
+        ct: &mut RadixCiphertext<PBSOrder>,
+        scalar: T,
+    ) where
+        PBSOrder: PBSOrderMarker,
+        T: DecomposableInto<u8>,
+    {
+        self.maybe_full_propagate_parallelized(ct);
+        self.unchecked_scalar_bitand_assign_parallelized(ct, scalar);
+    }
+
+    /// Computes homomorphically a bitor between a ciphertext and a clear scalar.
+    ///
+    /// This function computes the operation without checking that the input block carries are
+    /// empty, which is required for the result to be correct.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    pub fn unchecked_scalar_bitor_parallelized<PBSOrder, T>(
+        &self,
+        ct: &RadixCiphertext<PBSOrder>,
+        scalar: T,
+    ) -> RadixCiphertext<PBSOrder>
+    where
+        PBSOrder: PBSOrderMarker,
+        T: DecomposableInto<u8>,
+    {
+        let mut result = ct.clone();
+        self.unchecked_scalar_bitor_assign_parallelized(&mut result, scalar);
+        result
+    }
+
+    pub fn unchecked_scalar_bitor_assign_parallelized<PBSOrder, T>(
+        &self,
+        ct: &mut RadixCiphertext<PBSOrder>,
+        scalar: T,
+    ) where
+        PBSOrder: PBSOrderMarker,
+        T: DecomposableInto<u8>,
+    {
+        self.unchecked_scalar_bitop_assign_parallelized(ct, scalar, |x, y| x | y);
+    }
+
+    /// Computes homomorphically a bitor between a ciphertext and a clear scalar.
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertexts block carries are empty and clears them if it's not the
+    /// case and the operation requires it. It outputs a ciphertext whose block carries are always
+    /// empty.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let msg = 14;
+    /// let scalar = 97u64;
+    ///
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// let ct_res = sks.scalar_bitor_parallelized(&ct, scalar);
+    ///
+    /// // Decrypt:
+    /// let dec_result: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec_result, msg | scalar);
+    /// ```
+    pub fn scalar_bitor_parallelized<PBSOrder, T>(
+        &self,
+        ct: &RadixCiphertext<PBSOrder>,
+        scalar: T,
+    ) -> RadixCiphertext<PBSOrder>
+    where
+        PBSOrder: PBSOrderMarker,
+        T: DecomposableInto<u8>,
+    {
+        let mut ct_res = ct.clone();
+        self.scalar_bitor_assign_parallelized(&mut ct_res, scalar);
+        ct_res
+    }
+
+    pub fn scalar_bitor_assign_parallelized<PBSOrder, T>(
+        &self,
+        ct: &mut RadixCiphertext<PBSOrder>,
+        scalar: T,
+    ) where
+        PBSOrder: PBSOrderMarker,
+        T: DecomposableInto<u8>,
+    {
+        self.maybe_full_propagate_parallelized(ct);
+        self.unchecked_scalar_bitor_assign_parallelized(ct, scalar);
+    }
+
+    /// Computes homomorphically a bitxor between a ciphertext and a clear scalar.
+    ///
+    /// This function computes the operation without checking that the input block carries are
+    /// empty, which is required for the result to be correct.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    pub fn unchecked_scalar_bitxor_parallelized<PBSOrder, T>(
+        &self,
+        ct: &RadixCiphertext<PBSOrder>,
+        scalar: T,
+    ) -> RadixCiphertext<PBSOrder>
+    where
+        PBSOrder: PBSOrderMarker,
+        T: DecomposableInto<u8>,
+    {
+        let mut result = ct.clone();
+        self.unchecked_scalar_bitxor_assign_parallelized(&mut result, scalar);
+        result
+    }
+
+    pub fn unchecked_scalar_bitxor_assign_parallelized<PBSOrder, T>(
+        &self,
+        ct: &mut RadixCiphertext<PBSOrder>,
+        scalar: T,
+    ) where
+        PBSOrder: PBSOrderMarker,
+        T: DecomposableInto<u8>,
+    {
+        self.unchecked_scalar_bitop_assign_parallelized(ct, scalar, |x, y| x ^ y);
+    }
+
+    /// Computes homomorphically a bitxor between a ciphertext and a clear scalar.
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertexts block carries are empty and clears them if it's not the
+    /// case and the operation requires it. It outputs a ciphertext whose block carries are always
+    /// empty.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let msg = 14;
+    /// let scalar = 97u64;
+    ///
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// let ct_res = sks.scalar_bitxor_parallelized(&ct, scalar);
+    ///
+    /// // Decrypt:
+    /// let dec_result: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec_result, msg ^ scalar);
+    /// ```
+    pub fn scalar_bitxor_parallelized<PBSOrder, T>(
+        &self,
+        ct: &RadixCiphertext<PBSOrder>,
+        scalar: T,
+    ) -> RadixCiphertext<PBSOrder>
+    where
+        PBSOrder: PBSOrderMarker,
+        T: DecomposableInto<u8>,
+    {
+        let mut ct_res = ct.clone();
+        self.scalar_bitxor_assign_parallelized(&mut ct_res, scalar);
+        ct_res
+    }
+
+    pub fn scalar_bitxor_assign_parallelized<PBSOrder, T>(
+        &self,
+        ct: &mut RadixCiphertext<PBSOrder>,
+        scalar: T,
+    ) where
+        PBSOrder: PBSOrderMarker,
+        T: DecomposableInto<u8>,
+    {
+        self.maybe_full_propagate_parallelized(ct);
+        self.unchecked_scalar_bitxor_assign_parallelized(ct, scalar);
+    }
+
+    /// Homomorphically keeps the `bits` least significant bits of a ciphertext and clears the
+    /// others, i.e. computes `ct % 2^bits`, updating it in-place.
+    ///
+    /// The blocks above the boundary are replaced by trivial zeros, and the block holding the
+    /// boundary, if any, is masked with one programmable bootstrapping. The number of blocks is
+    /// unchanged, unlike [`trim_radix_blocks`](Self::trim_radix_blocks), and `bits` does not
+    /// have to be a multiple of the number of bits per block.
+    ///
+    /// This function computes the operation without checking that the input block carries are
+    /// empty, which is required for the result to be correct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the message modulus is not a power of two.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    pub fn unchecked_wrap_to_bits_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        ct: &mut RadixCiphertext<PBSOrder>,
+        bits: usize,
+    ) {
+        let message_modulus = self.key.message_modulus.0 as u64;
+        assert!(
+            message_modulus.is_power_of_two(),
+            "wrapping to a number of bits requires a power of two message modulus"
+        );
+        let block_bits = message_modulus.ilog2() as usize;
+
+        // Index of the block holding the boundary, and number of its bits that are kept
+        let boundary_block = bits / block_bits;
+        let boundary_bits = bits % block_bits;
+
+        let mask = (1 << boundary_bits) - 1;
+        let acc = self.key.generate_accumulator(|x| x & mask);
+
+        ct.blocks
+            .par_iter_mut()
+            .enumerate()
+            .skip(boundary_block)
+            .for_each(|(i, block)| {
+                if i == boundary_block && boundary_bits != 0 {
+                    self.key.apply_lookup_table_assign(block, &acc);
+                } else {
+                    *block = self.key.create_trivial(0);
+                }
+            });
+    }
+
+    /// Homomorphically keeps the `bits` least significant bits of a ciphertext and clears the
+    /// others, i.e. computes `ct % 2^bits`, updating it in-place.
+    ///
+    /// This emulates the wrapping arithmetic of a `bits` wide integer on a wider ciphertext. The
+    /// number of blocks is unchanged, unlike [`trim_radix_blocks`](Self::trim_radix_blocks), and
+    /// `bits` does not have to be a multiple of the number of bits per block.
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertexts block carries are empty and clears them if it's not the
+    /// case and the operation requires it. It outputs a ciphertext whose block carries are always
+    /// empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the message modulus is not a power of two.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let msg = 0b1011_0110u64;
+    ///
+    /// let mut ct = cks.encrypt(msg);
+    ///
+    /// // Keep 5 bits, which cuts the third block
+    /// sks.wrap_to_bits_parallelized(&mut ct, 5);
+    /// assert_eq!(ct.num_blocks(), num_blocks);
+    ///
+    /// // Decrypt:
+    /// let dec_result: u64 = cks.decrypt(&ct);
+    /// assert_eq!(dec_result, msg % (1 << 5));
+    /// ```
+    pub fn wrap_to_bits_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        ct: &mut RadixCiphertext<PBSOrder>,
+        bits: usize,
+    ) {
+        self.maybe_full_propagate_parallelized(ct);
+        self.unchecked_wrap_to_bits_parallelized(ct, bits);
+    }
+}
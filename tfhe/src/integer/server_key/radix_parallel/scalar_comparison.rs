@@ -0,0 +1,603 @@
+use super::ServerKey;
+
+use crate::integer::block_decomposition::DecomposableInto;
+use crate::integer::ciphertext::RadixCiphertext;
+use crate::integer::server_key::accumulator_cache::CachedFunction;
+use crate::integer::server_key::comparator::Comparator;
+use crate::shortint::PBSOrderMarker;
+
+impl ServerKey {
+    /// Homomorphically computes whether `lhs` is equal to the clear `scalar`.
+    ///
+    /// The result is an encryption of 1 if the comparison holds and 0 otherwise, stored in the
+    /// first block of a ciphertext with as many blocks as `lhs`.
+    ///
+    /// The scalar is not encrypted: each block of `lhs` is compared to the corresponding clear
+    /// block of the scalar. A scalar that does not fit in the blocks of `lhs` is greater than any
+    /// value `lhs` can encrypt.
+    ///
+    /// This function computes the operation without checking that the input block carries are
+    /// empty, which is required for the result to be correct.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    pub fn unchecked_scalar_eq_parallelized<PBSOrder, T>(
+        &self,
+        lhs: &RadixCiphertext<PBSOrder>,
+        scalar: T,
+    ) -> RadixCiphertext<PBSOrder>
+    where
+        PBSOrder: PBSOrderMarker,
+        T: DecomposableInto<u8>,
+    {
+        Comparator::new(self).unchecked_scalar_eq_parallelized(lhs, scalar)
+    }
+
+    /// Homomorphically computes whether `lhs` is not equal to the clear `scalar`.
+    ///
+    /// See [`Self::unchecked_scalar_eq_parallelized`] for the layout of the result and how the
+    /// clear scalar is compared.
+    ///
+    /// This function computes the operation without checking that the input block carries are
+    /// empty, which is required for the result to be correct.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    pub fn unchecked_scalar_ne_parallelized<PBSOrder, T>(
+        &self,
+        lhs: &RadixCiphertext<PBSOrder>,
+        scalar: T,
+    ) -> RadixCiphertext<PBSOrder>
+    where
+        PBSOrder: PBSOrderMarker,
+        T: DecomposableInto<u8>,
+    {
+        Comparator::new(self).unchecked_scalar_ne_parallelized(lhs, scalar)
+    }
+
+    /// Homomorphically computes whether `lhs` is greater than the clear `scalar`.
+    ///
+    /// See [`Self::unchecked_scalar_eq_parallelized`] for the layout of the result and how the
+    /// clear scalar is compared.
+    ///
+    /// This function computes the operation without checking that the input block carries are
+    /// empty, which is required for the result to be correct.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    pub fn unchecked_scalar_gt_parallelized<PBSOrder, T>(
+        &self,
+        lhs: &RadixCiphertext<PBSOrder>,
+        scalar: T,
+    ) -> RadixCiphertext<PBSOrder>
+    where
+        PBSOrder: PBSOrderMarker,
+        T: DecomposableInto<u8>,
+    {
+        Comparator::new(self).unchecked_scalar_gt_parallelized(lhs, scalar)
+    }
+
+    /// Homomorphically computes whether `lhs` is greater than or equal to the clear `scalar`.
+    ///
+    /// See [`Self::unchecked_scalar_eq_parallelized`] for the layout of the result and how the
+    /// clear scalar is compared.
+    ///
+    /// This function computes the operation without checking that the input block carries are
+    /// empty, which is required for the result to be correct.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    pub fn unchecked_scalar_ge_parallelized<PBSOrder, T>(
+        &self,
+        lhs: &RadixCiphertext<PBSOrder>,
+        scalar: T,
+    ) -> RadixCiphertext<PBSOrder>
+    where
+        PBSOrder: PBSOrderMarker,
+        T: DecomposableInto<u8>,
+    {
+        Comparator::new(self).unchecked_scalar_ge_parallelized(lhs, scalar)
+    }
+
+    /// Homomorphically computes whether `lhs` is less than the clear `scalar`.
+    ///
+    /// See [`Self::unchecked_scalar_eq_parallelized`] for the layout of the result and how the
+    /// clear scalar is compared.
+    ///
+    /// This function computes the operation without checking that the input block carries are
+    /// empty, which is required for the result to be correct.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    pub fn unchecked_scalar_lt_parallelized<PBSOrder, T>(
+        &self,
+        lhs: &RadixCiphertext<PBSOrder>,
+        scalar: T,
+    ) -> RadixCiphertext<PBSOrder>
+    where
+        PBSOrder: PBSOrderMarker,
+        T: DecomposableInto<u8>,
+    {
+        Comparator::new(self).unchecked_scalar_lt_parallelized(lhs, scalar)
+    }
+
+    /// Homomorphically computes whether `lhs` is less than or equal to the clear `scalar`.
+    ///
+    /// See [`Self::unchecked_scalar_eq_parallelized`] for the layout of the result and how the
+    /// clear scalar is compared.
+    ///
+    /// This function computes the operation without checking that the input block carries are
+    /// empty, which is required for the result to be correct.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    pub fn unchecked_scalar_le_parallelized<PBSOrder, T>(
+        &self,
+        lhs: &RadixCiphertext<PBSOrder>,
+        scalar: T,
+    ) -> RadixCiphertext<PBSOrder>
+    where
+        PBSOrder: PBSOrderMarker,
+        T: DecomposableInto<u8>,
+    {
+        Comparator::new(self).unchecked_scalar_le_parallelized(lhs, scalar)
+    }
+
+    /// Homomorphically computes whether `lhs` is equal to the clear `scalar`.
+    ///
+    /// See [`Self::unchecked_scalar_eq_parallelized`] for the layout of the result and how the
+    /// clear scalar is compared.
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertexts block carries are empty and clears them if it's not the
+    /// case and the operation requires it. It outputs a ciphertext whose block carries are always
+    /// empty.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let msg = 97u64;
+    /// let scalar = 42u64;
+    ///
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// let ct_res = sks.scalar_eq_parallelized(&ct, scalar);
+    ///
+    /// // Decrypt:
+    /// let dec_result: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec_result, u64::from(msg == scalar));
+    /// ```
+    pub fn scalar_eq_parallelized<PBSOrder, T>(
+        &self,
+        lhs: &RadixCiphertext<PBSOrder>,
+        scalar: T,
+    ) -> RadixCiphertext<PBSOrder>
+    where
+        PBSOrder: PBSOrderMarker,
+        T: DecomposableInto<u8>,
+    {
+        Comparator::new(self).scalar_eq_parallelized(lhs, scalar)
+    }
+
+    /// Homomorphically computes whether `lhs` is not equal to the clear `scalar`.
+    ///
+    /// See [`Self::unchecked_scalar_eq_parallelized`] for the layout of the result and how the
+    /// clear scalar is compared.
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertexts block carries are empty and clears them if it's not the
+    /// case and the operation requires it. It outputs a ciphertext whose block carries are always
+    /// empty.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let msg = 97u64;
+    /// let scalar = 42u64;
+    ///
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// let ct_res = sks.scalar_ne_parallelized(&ct, scalar);
+    ///
+    /// // Decrypt:
+    /// let dec_result: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec_result, u64::from(msg != scalar));
+    /// ```
+    pub fn scalar_ne_parallelized<PBSOrder, T>(
+        &self,
+        lhs: &RadixCiphertext<PBSOrder>,
+        scalar: T,
+    ) -> RadixCiphertext<PBSOrder>
+    where
+        PBSOrder: PBSOrderMarker,
+        T: DecomposableInto<u8>,
+    {
+        Comparator::new(self).scalar_ne_parallelized(lhs, scalar)
+    }
+
+    /// Homomorphically computes whether `lhs` is greater than the clear `scalar`.
+    ///
+    /// See [`Self::unchecked_scalar_eq_parallelized`] for the layout of the result and how the
+    /// clear scalar is compared.
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertexts block carries are empty and clears them if it's not the
+    /// case and the operation requires it. It outputs a ciphertext whose block carries are always
+    /// empty.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let msg = 97u64;
+    /// let scalar = 42u64;
+    ///
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// let ct_res = sks.scalar_gt_parallelized(&ct, scalar);
+    ///
+    /// // Decrypt:
+    /// let dec_result: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec_result, u64::from(msg > scalar));
+    /// ```
+    pub fn scalar_gt_parallelized<PBSOrder, T>(
+        &self,
+        lhs: &RadixCiphertext<PBSOrder>,
+        scalar: T,
+    ) -> RadixCiphertext<PBSOrder>
+    where
+        PBSOrder: PBSOrderMarker,
+        T: DecomposableInto<u8>,
+    {
+        Comparator::new(self).scalar_gt_parallelized(lhs, scalar)
+    }
+
+    /// Homomorphically computes whether `lhs` is greater than or equal to the clear `scalar`.
+    ///
+    /// See [`Self::unchecked_scalar_eq_parallelized`] for the layout of the result and how the
+    /// clear scalar is compared.
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertexts block carries are empty and clears them if it's not the
+    /// case and the operation requires it. It outputs a ciphertext whose block carries are always
+    /// empty.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let msg = 97u64;
+    /// let scalar = 42u64;
+    ///
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// let ct_res = sks.scalar_ge_parallelized(&ct, scalar);
+    ///
+    /// // Decrypt:
+    /// let dec_result: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec_result, u64::from(msg >= scalar));
+    /// ```
+    pub fn scalar_ge_parallelized<PBSOrder, T>(
+        &self,
+        lhs: &RadixCiphertext<PBSOrder>,
+        scalar: T,
+    ) -> RadixCiphertext<PBSOrder>
+    where
+        PBSOrder: PBSOrderMarker,
+        T: DecomposableInto<u8>,
+    {
+        Comparator::new(self).scalar_ge_parallelized(lhs, scalar)
+    }
+
+    /// Homomorphically computes whether `lhs` is less than the clear `scalar`.
+    ///
+    /// See [`Self::unchecked_scalar_eq_parallelized`] for the layout of the result and how the
+    /// clear scalar is compared.
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertexts block carries are empty and clears them if it's not the
+    /// case and the operation requires it. It outputs a ciphertext whose block carries are always
+    /// empty.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let msg = 97u64;
+    /// let scalar = 42u64;
+    ///
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// let ct_res = sks.scalar_lt_parallelized(&ct, scalar);
+    ///
+    /// // Decrypt:
+    /// let dec_result: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec_result, u64::from(msg < scalar));
+    /// ```
+    pub fn scalar_lt_parallelized<PBSOrder, T>(
+        &self,
+        lhs: &RadixCiphertext<PBSOrder>,
+        scalar: T,
+    ) -> RadixCiphertext<PBSOrder>
+    where
+        PBSOrder: PBSOrderMarker,
+        T: DecomposableInto<u8>,
+    {
+        Comparator::new(self).scalar_lt_parallelized(lhs, scalar)
+    }
+
+    /// Homomorphically computes whether `lhs` is less than or equal to the clear `scalar`.
+    ///
+    /// See [`Self::unchecked_scalar_eq_parallelized`] for the layout of the result and how the
+    /// clear scalar is compared.
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertexts block carries are empty and clears them if it's not the
+    /// case and the operation requires it. It outputs a ciphertext whose block carries are always
+    /// empty.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let msg = 97u64;
+    /// let scalar = 42u64;
+    ///
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// let ct_res = sks.scalar_le_parallelized(&ct, scalar);
+    ///
+    /// // Decrypt:
+    /// let dec_result: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec_result, u64::from(msg <= scalar));
+    /// ```
+    pub fn scalar_le_parallelized<PBSOrder, T>(
+        &self,
+        lhs: &RadixCiphertext<PBSOrder>,
+        scalar: T,
+    ) -> RadixCiphertext<PBSOrder>
+    where
+        PBSOrder: PBSOrderMarker,
+        T: DecomposableInto<u8>,
+    {
+        Comparator::new(self).scalar_le_parallelized(lhs, scalar)
+    }
+
+    /// Homomorphically computes whether `lhs` is in the range `[lo, hi]`, i.e. whether
+    /// `lo <= lhs <= hi`, both bounds being inclusive.
+    ///
+    /// The result is an encryption of 1 if `lhs` is in the range and 0 otherwise, stored in the
+    /// first block of a ciphertext with as many blocks as `lhs`. An empty range, i.e. `lo > hi`,
+    /// always gives an encryption of 0.
+    ///
+    /// The comparisons to both bounds are computed in parallel and combined in a single block
+    /// with one programmable bootstrapping, which is cheaper than computing the two scalar
+    /// comparisons followed by a bitwise AND.
+    ///
+    /// This function computes the operation without checking that the input block carries are
+    /// empty, which is required for the result to be correct.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    pub fn unchecked_scalar_in_range_parallelized<PBSOrder, T>(
+        &self,
+        lhs: &RadixCiphertext<PBSOrder>,
+        lo: T,
+        hi: T,
+    ) -> RadixCiphertext<PBSOrder>
+    where
+        PBSOrder: PBSOrderMarker,
+        T: DecomposableInto<u8> + PartialOrd + Sync,
+    {
+        Comparator::new(self).unchecked_scalar_in_range_parallelized(lhs, lo, hi)
+    }
+
+    /// Homomorphically computes whether `lhs` is in the range `[lo, hi]`, i.e. whether
+    /// `lo <= lhs <= hi`, both bounds being inclusive.
+    ///
+    /// The result is an encryption of 1 if `lhs` is in the range and 0 otherwise, stored in the
+    /// first block of a ciphertext with as many blocks as `lhs`. An empty range, i.e. `lo > hi`,
+    /// always gives an encryption of 0.
+    ///
+    /// See [`unchecked_scalar_in_range_parallelized`](Self::unchecked_scalar_in_range_parallelized)
+    /// for details on the implementation.
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertexts block carries are empty and clears them if it's not the
+    /// case and the operation requires it. It outputs a ciphertext whose block carries are always
+    /// empty.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let msg = 97u64;
+    /// let (lo, hi) = (42u64, 97u64);
+    ///
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// let ct_res = sks.scalar_in_range_parallelized(&ct, lo, hi);
+    ///
+    /// // Decrypt:
+    /// let dec_result: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec_result, u64::from(lo <= msg && msg <= hi));
+    /// ```
+    pub fn scalar_in_range_parallelized<PBSOrder, T>(
+        &self,
+        lhs: &RadixCiphertext<PBSOrder>,
+        lo: T,
+        hi: T,
+    ) -> RadixCiphertext<PBSOrder>
+    where
+        PBSOrder: PBSOrderMarker,
+        T: DecomposableInto<u8> + PartialOrd + Sync,
+    {
+        Comparator::new(self).scalar_in_range_parallelized(lhs, lo, hi)
+    }
+
+    /// Homomorphically computes whether `ct` encrypts 0.
+    ///
+    /// The result is an encryption of 1 if `ct` is zero and 0 otherwise, stored in the first block
+    /// of a ciphertext with as many blocks as `ct`.
+    ///
+    /// Instead of comparing each block to the corresponding block of a clear scalar, as
+    /// [`unchecked_scalar_eq_parallelized`](Self::unchecked_scalar_eq_parallelized) does, blocks
+    /// are summed together in groups as large as the carry space allows, each group sum being
+    /// reduced to a single non-zero indicator with one programmable bootstrapping. Indicators are
+    /// then summed and reduced the same way, as a balanced tree, until a single block is left.
+    ///
+    /// This function computes the operation without checking that the input block carries are
+    /// empty, which is required for the result to be correct.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    pub fn unchecked_is_zero_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        ct: &RadixCiphertext<PBSOrder>,
+    ) -> RadixCiphertext<PBSOrder> {
+        let is_non_zero_acc =
+            self.cached_accumulator(CachedFunction::IsNonZero, self.key.message_modulus.0);
+
+        let sum = self.unchecked_sum_blocks_in_groups_parallelized(
+            ct.blocks.clone(),
+            self.key.message_modulus.0 as u64 - 1,
+            &is_non_zero_acc,
+            1,
+        );
+
+        let mut result = self.create_trivial_zero_radix(ct.blocks.len());
+        result.blocks[0] = self.apply_cached_function(&sum, CachedFunction::IsZero);
+        result
+    }
+
+    /// Homomorphically computes whether `ct` encrypts 0.
+    ///
+    /// The result is an encryption of 1 if `ct` is zero and 0 otherwise, stored in the first block
+    /// of a ciphertext with as many blocks as `ct`.
+    ///
+    /// This is cheaper than [`scalar_eq_parallelized`](Self::scalar_eq_parallelized) with a scalar
+    /// of 0, as blocks are summed together in groups before being tested, so that far fewer
+    /// programmable bootstrappings are needed.
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertexts block carries are empty and clears them if it's not the
+    /// case and the operation requires it. It outputs a ciphertext whose block carries are always
+    /// empty.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let ct = cks.encrypt(97);
+    /// let ct_res = sks.is_zero_parallelized(&ct);
+    /// let dec_result: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec_result, 0);
+    ///
+    /// let ct = cks.encrypt(0);
+    /// let ct_res = sks.is_zero_parallelized(&ct);
+    /// let dec_result: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec_result, 1);
+    /// ```
+    pub fn is_zero_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        ct: &RadixCiphertext<PBSOrder>,
+    ) -> RadixCiphertext<PBSOrder> {
+        if ct.block_carries_are_empty() {
+            self.unchecked_is_zero_parallelized(ct)
+        } else {
+            let mut ct = ct.clone();
+            self.full_propagate_parallelized(&mut ct);
+            self.unchecked_is_zero_parallelized(&ct)
+        }
+    }
+}
@@ -0,0 +1,213 @@
+use crate::integer::ciphertext::RadixCiphertext;
+use crate::integer::ServerKey;
+use crate::shortint::ciphertext::Degree;
+use crate::shortint::PBSOrderMarker;
+use rayon::prelude::*;
+
+impl ServerKey {
+    /// Returns whether the value of a radix ciphertext with `num_blocks` blocks fits in the
+    /// message and carry space of a single block.
+    fn radix_fits_in_one_block(&self, num_blocks: usize) -> bool {
+        let message_modulus = self.key.message_modulus.0 as u64;
+        let total_modulus = message_modulus * self.key.carry_modulus.0 as u64;
+        message_modulus
+            .checked_pow(num_blocks as u32)
+            .map_or(false, |modulus| modulus <= total_modulus)
+    }
+
+    /// Homomorphically computes the quotient of the division of a ciphertext by a clear constant.
+    ///
+    /// The division is computed with a lookup table when the encrypted value fits in a single
+    /// block, i.e. when `message_modulus^num_blocks <= message_modulus * carry_modulus`, e.g. up to
+    /// 2 blocks with
+    /// [`PARAM_MESSAGE_2_CARRY_2`](crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2).
+    /// The blocks are then packed in a single block, and each block of the quotient is read from
+    /// the packed value with one programmable bootstrapping, all of them being done in parallel.
+    ///
+    /// Otherwise, a binary long division is computed: the bits of the numerator are shifted one at
+    /// a time in the remainder, from which the divisor is subtracted when it is smaller or equal.
+    /// This requires a comparison and a subtraction per bit of the numerator, and is therefore much
+    /// slower.
+    ///
+    /// This function computes the operation without checking that the input block carries are
+    /// empty, which is required for the result to be correct. The output block carries are
+    /// always empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `divisor` is 0, or if the encrypted value does not fit in a single block and
+    /// either the message modulus is not a power of two or `message_modulus * carry_modulus < 16`,
+    /// as the long division relies on comparisons.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    pub fn unchecked_scalar_div_by_const_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        ct: &RadixCiphertext<PBSOrder>,
+        divisor: u64,
+    ) -> RadixCiphertext<PBSOrder> {
+        assert_ne!(divisor, 0, "attempt to divide by zero");
+
+        let message_modulus = self.key.message_modulus.0 as u64;
+        let num_blocks = ct.blocks.len();
+
+        if num_blocks == 0 || divisor == 1 {
+            return ct.clone();
+        }
+
+        if !self.radix_fits_in_one_block(num_blocks) {
+            return self.unchecked_scalar_long_div_parallelized(ct, divisor);
+        }
+
+        // Horner evaluation of the packed value, the most significant block is multiplied by
+        // message_modulus^(num_blocks - 1)
+        let mut packed = ct.blocks[num_blocks - 1].clone();
+        for block in ct.blocks[..num_blocks - 1].iter().rev() {
+            self.key
+                .unchecked_scalar_mul_assign(&mut packed, message_modulus as u8);
+            self.key.unchecked_add_assign(&mut packed, block);
+        }
+
+        let blocks = (0..num_blocks)
+            .into_par_iter()
+            .map(|i| {
+                let block_weight = message_modulus.pow(i as u32);
+                let acc = self
+                    .key
+                    .generate_accumulator(|x| (x / divisor / block_weight) % message_modulus);
+                self.key.apply_lookup_table(&packed, &acc)
+            })
+            .collect::<Vec<_>>();
+
+        RadixCiphertext::from(blocks)
+    }
+
+    /// Binary long division of a ciphertext by a clear divisor, see
+    /// [`unchecked_scalar_div_by_const_parallelized`](Self::unchecked_scalar_div_by_const_parallelized).
+    fn unchecked_scalar_long_div_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        ct: &RadixCiphertext<PBSOrder>,
+        divisor: u64,
+    ) -> RadixCiphertext<PBSOrder> {
+        let message_modulus = self.key.message_modulus.0 as u64;
+        assert!(
+            message_modulus.is_power_of_two(),
+            "the long division requires a power of two message modulus"
+        );
+        let block_bits = message_modulus.ilog2() as usize;
+        let num_blocks = ct.blocks.len();
+        let num_bits = num_blocks * block_bits;
+
+        // The divisor is greater than any value the ciphertext can encrypt
+        if divisor.ilog2() as usize >= num_bits {
+            return self.create_trivial_zero_radix(num_blocks);
+        }
+
+        // Bits of the numerator, the least significant first
+        let bit_accs = (0..block_bits)
+            .map(|shift| self.key.generate_accumulator(|x| (x >> shift) & 1))
+            .collect::<Vec<_>>();
+        let bits = (0..num_bits)
+            .into_par_iter()
+            .map(|i| {
+                let block = &ct.blocks[i / block_bits];
+                self.key
+                    .apply_lookup_table(block, &bit_accs[i % block_bits])
+            })
+            .collect::<Vec<_>>();
+
+        // The remainder holds values up to twice the divisor, which needs one more block than the
+        // numerator in the worst case
+        let divisor_blocks = (0..=num_blocks)
+            .map(|i| divisor.checked_shr((i * block_bits) as u32).unwrap_or(0) % message_modulus)
+            .collect::<Vec<_>>();
+        let mut remainder = self.create_trivial_zero_radix(num_blocks + 1);
+        let mut quotient = self.create_trivial_zero_radix(num_blocks);
+
+        for i in (0..num_bits).rev() {
+            // The least significant bit of the shifted remainder is free for the next bit
+            remainder = self.scalar_left_shift_parallelized(&remainder, 1);
+            self.key
+                .unchecked_add_assign(&mut remainder.blocks[0], &bits[i]);
+            remainder.blocks[0].degree = Degree(message_modulus as usize - 1);
+
+            let is_ge = self.unchecked_scalar_ge_parallelized(&remainder, divisor);
+            let is_ge = &is_ge.blocks[0];
+
+            // The divisor if it is smaller or equal to the remainder, 0 otherwise
+            let masked_divisor = divisor_blocks
+                .iter()
+                .map(|&divisor_block| self.key.unchecked_scalar_mul(is_ge, divisor_block as u8))
+                .collect::<Vec<_>>();
+            remainder = self.sub_parallelized(&remainder, &RadixCiphertext::from(masked_divisor));
+
+            let quotient_bit = self.key.unchecked_scalar_mul(is_ge, 1 << (i % block_bits));
+            self.key
+                .unchecked_add_assign(&mut quotient.blocks[i / block_bits], &quotient_bit);
+        }
+
+        // Each bit of the quotient is set once, so the blocks do not overflow the message space
+        for block in quotient.blocks.iter_mut() {
+            block.degree = Degree(message_modulus as usize - 1);
+        }
+        quotient
+    }
+
+    /// Homomorphically computes the quotient of the division of a ciphertext by a clear constant.
+    ///
+    /// The division is computed with a lookup table when the encrypted value fits in a single
+    /// block, i.e. when `message_modulus^num_blocks <= message_modulus * carry_modulus`, and with a
+    /// much slower binary long division otherwise, see
+    /// [`unchecked_scalar_div_by_const_parallelized`](Self::unchecked_scalar_div_by_const_parallelized).
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertexts block carries are empty and clears them if it's not the
+    /// case and the operation requires it. It outputs a ciphertext whose block carries are always
+    /// empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `divisor` is 0, or if the encrypted value does not fit in a single block and
+    /// either the message modulus is not a power of two or `message_modulus * carry_modulus < 16`,
+    /// as the long division relies on comparisons.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 2 * 2 = 4 bits of message, which fit in a single block
+    /// let num_blocks = 2;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let msg = 14u64;
+    /// let divisor = 3u64;
+    ///
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// let ct_res = sks.scalar_div_by_const_parallelized(&ct, divisor);
+    ///
+    /// // Decrypt:
+    /// let dec_result: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec_result, msg / divisor);
+    /// ```
+    pub fn scalar_div_by_const_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        ct: &RadixCiphertext<PBSOrder>,
+        divisor: u64,
+    ) -> RadixCiphertext<PBSOrder> {
+        if ct.block_carries_are_empty() {
+            self.unchecked_scalar_div_by_const_parallelized(ct, divisor)
+        } else {
+            let mut ct = ct.clone();
+            self.full_propagate_parallelized(&mut ct);
+            self.unchecked_scalar_div_by_const_parallelized(&ct, divisor)
+        }
+    }
+}
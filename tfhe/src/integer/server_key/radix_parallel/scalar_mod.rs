@@ -0,0 +1,161 @@
+use crate::integer::ciphertext::RadixCiphertext;
+use crate::integer::server_key::accumulator_cache::CachedFunction;
+use crate::integer::ServerKey;
+use crate::shortint::PBSOrderMarker;
+use rayon::prelude::*;
+
+impl ServerKey {
+    /// Homomorphically computes the remainder of the division of `ct` by the clear `modulus`,
+    /// i.e. `ct mod modulus`.
+    ///
+    /// Power of two moduli are computed with a mask of the low bits. Other moduli are computed
+    /// with a schoolbook long division on the blocks of `ct`, starting from the most significant
+    /// one: each block is shifted into a remainder smaller than `modulus * message_modulus`,
+    /// which is then reduced below `modulus` by conditional subtractions of the shifted modulus.
+    ///
+    /// This function computes the operation without checking that the input block carries are
+    /// empty, which is required for the result to be correct. The output block carries are
+    /// always empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `modulus` is 0.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    pub fn unchecked_scalar_mod_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        ct: &RadixCiphertext<PBSOrder>,
+        modulus: u64,
+    ) -> RadixCiphertext<PBSOrder> {
+        assert_ne!(
+            modulus, 0,
+            "attempt to calculate the remainder with a divisor of zero"
+        );
+
+        if modulus.is_power_of_two() {
+            return self.unchecked_scalar_bitand_parallelized(ct, modulus - 1);
+        }
+
+        let bit_width = ct.bit_width();
+        if bit_width < u64::BITS as usize && modulus >= 1 << bit_width {
+            return ct.clone();
+        }
+
+        let message_modulus = self.key.message_modulus.0 as u128;
+        let bits_per_block = message_modulus.ilog2();
+        let modulus = modulus as u128;
+
+        // The remainder must hold any value below modulus * message_modulus
+        let mut rem_num_blocks = 0;
+        let mut max_rem = modulus * message_modulus - 1;
+        while max_rem > 0 {
+            max_rem /= message_modulus;
+            rem_num_blocks += 1;
+        }
+
+        let mut rem = self.create_trivial_zero_radix(rem_num_blocks);
+        for block in ct.blocks.iter().rev() {
+            // rem = rem * message_modulus + block, as rem < modulus its most significant block is
+            // empty and the shift does not lose any information
+            rem.blocks.pop();
+            rem.blocks.insert(0, block.clone());
+
+            // rem < modulus << bits_per_block, so subtracting the shifted modulus when possible,
+            // for each shift in decreasing order, brings it below modulus
+            for shift in (0..bits_per_block).rev() {
+                self.scalar_sub_if_ge_parallelized(&mut rem, modulus << shift);
+            }
+        }
+
+        let num_blocks = ct.blocks.len();
+        if rem.blocks.len() > num_blocks {
+            // rem < modulus and rem <= ct, so the extra blocks are empty
+            rem.blocks.truncate(num_blocks);
+        } else {
+            rem.blocks
+                .resize_with(num_blocks, || self.key.create_trivial(0));
+        }
+        rem
+    }
+
+    /// Homomorphically computes the remainder of the division of `ct` by the clear `modulus`,
+    /// i.e. `ct mod modulus`.
+    ///
+    /// Power of two moduli are computed with a cheap mask of the low bits. This makes it suited to
+    /// reduce encrypted values into a range, e.g. to index an encrypted hash table or to convert
+    /// a radix integer to a residue number system.
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertexts block carries are empty and clears them if it's not the
+    /// case and the operation requires it. It outputs a ciphertext whose block carries are always
+    /// empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `modulus` is 0.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let msg = 230u64;
+    ///
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// let ct_res = sks.scalar_mod_parallelized(&ct, 7);
+    /// let dec_result: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec_result, msg % 7);
+    ///
+    /// // Power of two moduli are a mask
+    /// let ct_res = sks.scalar_mod_parallelized(&ct, 16);
+    /// let dec_result: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec_result, msg % 16);
+    /// ```
+    pub fn scalar_mod_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        ct: &RadixCiphertext<PBSOrder>,
+        modulus: u64,
+    ) -> RadixCiphertext<PBSOrder> {
+        if ct.block_carries_are_empty() {
+            self.unchecked_scalar_mod_parallelized(ct, modulus)
+        } else {
+            let mut ct = ct.clone();
+            self.full_propagate_parallelized(&mut ct);
+            self.unchecked_scalar_mod_parallelized(&ct, modulus)
+        }
+    }
+
+    /// Replaces `ct` by `ct - scalar` if `ct >= scalar`, `scalar` must fit in the blocks of `ct`.
+    fn scalar_sub_if_ge_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        ct: &mut RadixCiphertext<PBSOrder>,
+        scalar: u128,
+    ) {
+        // With an extra block, the subtraction wraps around to a value with a non empty most
+        // significant block iff ct < scalar
+        let mut extended = ct.clone();
+        extended.blocks.push(self.key.create_trivial(0));
+        let diff = self.scalar_sub_parallelized(&extended, scalar);
+
+        let is_ge = self.apply_cached_function(diff.blocks.last().unwrap(), CachedFunction::IsZero);
+
+        ct.blocks
+            .par_iter_mut()
+            .zip(diff.blocks.par_iter())
+            .for_each(|(block, diff_block)| {
+                *block = self.block_cmux_parallelized(&is_ge, diff_block, block);
+            });
+    }
+}
@@ -1,11 +1,11 @@
+use crate::integer::block_decomposition::{decompose_scalar_wrapping, DecomposableInto};
 use crate::integer::ciphertext::RadixCiphertext;
 use crate::integer::server_key::CheckError;
 use crate::integer::server_key::CheckError::CarryFull;
 use crate::integer::ServerKey;
 use crate::shortint::PBSOrderMarker;
 use rayon::prelude::*;
-use std::collections::HashMap;
-use std::sync::Mutex;
+use std::collections::BTreeMap;
 
 impl ServerKey {
     /// Computes homomorphically a multiplication between a scalar and a ciphertext.
@@ -320,9 +320,7 @@ impl ServerKey {
         ctxt: &mut RadixCiphertext<PBSOrder>,
         scalar: u64,
     ) {
-        if !ctxt.block_carries_are_empty() {
-            self.full_propagate_parallelized(ctxt);
-        }
+        self.maybe_full_propagate_parallelized(ctxt);
         self.unchecked_small_scalar_mul_assign_parallelized(ctxt, scalar);
         self.full_propagate_parallelized(ctxt);
     }
@@ -342,7 +340,7 @@ impl ServerKey {
     /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
     ///
     /// let msg = 230;
-    /// let scalar = 376;
+    /// let scalar = 376u64;
     ///
     /// let mut ct = cks.encrypt(msg);
     ///
@@ -353,70 +351,69 @@ impl ServerKey {
     /// let clear: u64 = cks.decrypt(&ct_res);
     /// assert_eq!(msg * scalar % modulus, clear);
     /// ```
-    pub fn smart_scalar_mul_parallelized<PBSOrder: PBSOrderMarker>(
+    pub fn smart_scalar_mul_parallelized<PBSOrder, T>(
         &self,
         ct: &mut RadixCiphertext<PBSOrder>,
-        scalar: u64,
-    ) -> RadixCiphertext<PBSOrder> {
-        let zero = self.create_trivial_zero_radix(ct.blocks.len());
-        if scalar == 0 || ct.blocks.is_empty() {
+        scalar: T,
+    ) -> RadixCiphertext<PBSOrder>
+    where
+        PBSOrder: PBSOrderMarker,
+        T: DecomposableInto<u8>,
+    {
+        let bits_in_block = self.key.message_modulus.0.ilog2();
+        let n = ct.blocks.len();
+
+        let zero = self.create_trivial_zero_radix(n);
+        let decomposed_scalar = decompose_scalar_wrapping(scalar, bits_in_block, n);
+        if decomposed_scalar.iter().all(|&u_i| u_i == 0) {
             return zero;
         }
 
-        let b = self.key.message_modulus.0 as u64;
-        let n = ct.blocks.len();
-
         //Propagate the carries before doing the multiplications
-        self.full_propagate_parallelized(ct);
+        self.maybe_full_propagate_parallelized(ct);
         let ct = &*ct;
 
         // key is the small scalar we multiply by
         // value is the vector of blockshifts
-        let mut task_map = HashMap::<u64, Vec<usize>>::new();
-
-        // Divide scalar progressively towards zero
-        let mut scalar_i = scalar;
-        for i in 0..n {
-            let u_i = scalar_i % b;
+        let mut task_map = BTreeMap::<u8, Vec<usize>>::new();
+        for (i, u_i) in decomposed_scalar.into_iter().enumerate() {
             task_map.entry(u_i).or_insert_with(Vec::new).push(i);
-            scalar_i /= b;
-            if scalar_i == 0 {
-                break;
-            }
         }
 
-        let terms = Mutex::new(Vec::<RadixCiphertext<PBSOrder>>::new());
-        task_map.par_iter().for_each(|(&u_i, blockshifts)| {
-            if u_i == 0 {
-                return;
-            }
+        // The map is ordered and the terms are collected in order, so that the terms are always
+        // summed the same way
+        let mut terms = task_map
+            .par_iter()
+            .filter(|&(&u_i, _)| u_i != 0)
+            .flat_map(|(&u_i, blockshifts)| {
+                let blockshifts = &**blockshifts;
+                let min_blockshift = *blockshifts.iter().min().unwrap();
 
-            let blockshifts = &**blockshifts;
-            let min_blockshift = *blockshifts.iter().min().unwrap();
+                let mut tmp = ct.clone();
+                if u_i != 1 {
+                    tmp.blocks[0..n - min_blockshift]
+                        .par_iter_mut()
+                        .for_each(|ct_i| self.key.unchecked_scalar_mul_assign(ct_i, u_i));
+                }
 
-            let mut tmp = ct.clone();
-            if u_i != 1 {
-                tmp.blocks[0..n - min_blockshift]
-                    .par_iter_mut()
-                    .for_each(|ct_i| self.key.unchecked_scalar_mul_assign(ct_i, u_i as u8));
-            }
-
-            let tmp = &tmp;
-            blockshifts.par_iter().for_each(|&shift| {
-                let term = self.blockshift(tmp, shift);
-                terms.lock().unwrap().push(term);
-            });
-        });
-        let mut terms = terms.into_inner().unwrap();
+                blockshifts
+                    .par_iter()
+                    .map(|&shift| self.blockshift(&tmp, shift))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<RadixCiphertext<PBSOrder>>>();
         self.smart_binary_op_seq_parallelized(&mut terms, ServerKey::smart_add_parallelized)
             .unwrap_or(zero)
     }
 
-    pub fn smart_scalar_mul_assign_parallelized<PBSOrder: PBSOrderMarker>(
+    pub fn smart_scalar_mul_assign_parallelized<PBSOrder, T>(
         &self,
         ctxt: &mut RadixCiphertext<PBSOrder>,
-        scalar: u64,
-    ) {
+        scalar: T,
+    ) where
+        PBSOrder: PBSOrderMarker,
+        T: DecomposableInto<u8>,
+    {
         *ctxt = self.smart_scalar_mul_parallelized(ctxt, scalar);
     }
 
@@ -431,6 +428,19 @@ impl ServerKey {
     /// example) has always the same performance characteristics from one call to another and
     /// guarantees correctness by pre-emptively clearing carries of output ciphertexts.
     ///
+    /// # Complexity
+    ///
+    /// The scalar is decomposed in base `message_modulus`, i.e. in windows of the bit width of a
+    /// block. The ciphertext blocks are multiplied by each distinct non-zero digit with a clear
+    /// multiplication, which does not compute any PBS, and shifted by whole blocks, which is free.
+    /// The shifted terms, one per non-zero digit, are then summed, carries being propagated only
+    /// when the block carry spaces are full. The number of PBS therefore depends on the number of
+    /// non-zero digits of the scalar and not on its value.
+    ///
+    /// In particular, multiplying a ciphertext with empty carries by a power of `message_modulus`
+    /// is a block shift which computes no PBS, and multiplying it by any other power of two only
+    /// costs one carry propagation.
+    ///
     /// # Example
     ///
     /// ```rust
@@ -443,7 +453,7 @@ impl ServerKey {
     /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
     ///
     /// let msg = 230;
-    /// let scalar = 376;
+    /// let scalar = 376u64;
     ///
     /// let mut ct = cks.encrypt(msg);
     ///
@@ -454,73 +464,32 @@ impl ServerKey {
     /// let clear: u64 = cks.decrypt(&ct_res);
     /// assert_eq!(msg * scalar % modulus, clear);
     /// ```
-    pub fn scalar_mul_parallelized<PBSOrder: PBSOrderMarker>(
+    pub fn scalar_mul_parallelized<PBSOrder, T>(
         &self,
         ct: &RadixCiphertext<PBSOrder>,
-        scalar: u64,
-    ) -> RadixCiphertext<PBSOrder> {
+        scalar: T,
+    ) -> RadixCiphertext<PBSOrder>
+    where
+        PBSOrder: PBSOrderMarker,
+        T: DecomposableInto<u8>,
+    {
         let mut ct_res = ct.clone();
         self.scalar_mul_assign_parallelized(&mut ct_res, scalar);
         ct_res
     }
 
-    pub fn scalar_mul_assign_parallelized<PBSOrder: PBSOrderMarker>(
+    pub fn scalar_mul_assign_parallelized<PBSOrder, T>(
         &self,
         ct: &mut RadixCiphertext<PBSOrder>,
-        scalar: u64,
-    ) {
-        let zero = self.create_trivial_zero_radix(ct.blocks.len());
-        if scalar == 0 || ct.blocks.is_empty() {
-            *ct = zero;
-            return;
-        }
-
-        let b = self.key.message_modulus.0 as u64;
-        let n = ct.blocks.len();
-
-        //Propagate the carries before doing the multiplications
-        self.full_propagate_parallelized(ct);
-
-        // key is the small scalar we multiply by
-        // value is the vector of blockshifts
-        let mut task_map = HashMap::<u64, Vec<usize>>::new();
-
-        let mut scalar_i = scalar;
-        for i in 0..n {
-            let u_i = scalar_i % b;
-            task_map.entry(u_i).or_insert_with(Vec::new).push(i);
-            scalar_i /= b;
-            if scalar_i == 0 {
-                break;
-            }
-        }
-
-        let terms = Mutex::new(Vec::<RadixCiphertext<PBSOrder>>::new());
-        task_map.par_iter().for_each(|(&u_i, blockshifts)| {
-            if u_i == 0 {
-                return;
-            }
-
-            let blockshifts = &**blockshifts;
-            let min_blockshift = *blockshifts.iter().min().unwrap();
-
-            let mut tmp = ct.clone();
-            if u_i != 1 {
-                tmp.blocks[0..n - min_blockshift]
-                    .par_iter_mut()
-                    .for_each(|ct_i| self.key.unchecked_scalar_mul_assign(ct_i, u_i as u8));
-            }
-
-            let tmp = &tmp;
-            blockshifts.par_iter().for_each(|&shift| {
-                let term = self.blockshift(tmp, shift);
-                terms.lock().unwrap().push(term);
-            });
-        });
-        let terms = terms.into_inner().unwrap();
-        *ct = self
-            .default_binary_op_seq_parallelized(&terms, ServerKey::add_parallelized)
-            .unwrap_or(zero);
-        self.full_propagate_parallelized(ct);
+        scalar: T,
+    ) where
+        PBSOrder: PBSOrderMarker,
+        T: DecomposableInto<u8>,
+    {
+        // The input carries are cleaned so that the smart operation does not need to propagate
+        // them, the terms are then only propagated when adding them requires it
+        self.maybe_full_propagate_parallelized(ct);
+        *ct = self.smart_scalar_mul_parallelized(ct, scalar);
+        self.maybe_full_propagate_parallelized(ct);
     }
 }
@@ -1,3 +1,4 @@
+use crate::integer::block_decomposition::DecomposableInto;
 use crate::integer::ciphertext::RadixCiphertext;
 use crate::integer::ServerKey;
 use crate::shortint::PBSOrderMarker;
@@ -16,7 +17,7 @@ impl ServerKey {
     /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
     ///
     /// let msg = 165;
-    /// let scalar = 112;
+    /// let scalar = 112u64;
     ///
     /// let mut ct = cks.encrypt(msg);
     ///
@@ -27,22 +28,29 @@ impl ServerKey {
     /// let dec: u64 = cks.decrypt(&ct_res);
     /// assert_eq!(msg - scalar, dec);
     /// ```
-    pub fn smart_scalar_sub_parallelized<PBSOrder: PBSOrderMarker>(
+    pub fn smart_scalar_sub_parallelized<PBSOrder, T>(
         &self,
         ct: &mut RadixCiphertext<PBSOrder>,
-        scalar: u64,
-    ) -> RadixCiphertext<PBSOrder> {
+        scalar: T,
+    ) -> RadixCiphertext<PBSOrder>
+    where
+        PBSOrder: PBSOrderMarker,
+        T: DecomposableInto<u8>,
+    {
         if !self.is_scalar_sub_possible(ct, scalar) {
             self.full_propagate_parallelized(ct);
         }
         self.unchecked_scalar_sub(ct, scalar)
     }
 
-    pub fn smart_scalar_sub_assign_parallelized<PBSOrder: PBSOrderMarker>(
+    pub fn smart_scalar_sub_assign_parallelized<PBSOrder, T>(
         &self,
         ct: &mut RadixCiphertext<PBSOrder>,
-        scalar: u64,
-    ) {
+        scalar: T,
+    ) where
+        PBSOrder: PBSOrderMarker,
+        T: DecomposableInto<u8>,
+    {
         if !self.is_scalar_sub_possible(ct, scalar) {
             self.full_propagate_parallelized(ct);
         }
@@ -71,7 +79,7 @@ impl ServerKey {
     /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
     ///
     /// let msg = 165;
-    /// let scalar = 112;
+    /// let scalar = 112u64;
     ///
     /// let ct = cks.encrypt(msg);
     ///
@@ -81,25 +89,36 @@ impl ServerKey {
     /// // Decrypt:
     /// let dec: u64 = cks.decrypt(&ct_res);
     /// assert_eq!(msg - scalar, dec);
+    ///
+    /// // When the scalar is greater than the encrypted value, the result wraps around
+    /// let ct = cks.encrypt(5u64);
+    /// let ct_res = sks.scalar_sub_parallelized(&ct, 10u64);
+    /// let dec: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, 251);
     /// ```
-    pub fn scalar_sub_parallelized<PBSOrder: PBSOrderMarker>(
+    pub fn scalar_sub_parallelized<PBSOrder, T>(
         &self,
         ct: &RadixCiphertext<PBSOrder>,
-        scalar: u64,
-    ) -> RadixCiphertext<PBSOrder> {
+        scalar: T,
+    ) -> RadixCiphertext<PBSOrder>
+    where
+        PBSOrder: PBSOrderMarker,
+        T: DecomposableInto<u8>,
+    {
         let mut ct_res = ct.clone();
         self.scalar_sub_assign_parallelized(&mut ct_res, scalar);
         ct_res
     }
 
-    pub fn scalar_sub_assign_parallelized<PBSOrder: PBSOrderMarker>(
+    pub fn scalar_sub_assign_parallelized<PBSOrder, T>(
         &self,
         ct: &mut RadixCiphertext<PBSOrder>,
-        scalar: u64,
-    ) {
-        if !ct.block_carries_are_empty() {
-            self.full_propagate_parallelized(ct);
-        };
+        scalar: T,
+    ) where
+        PBSOrder: PBSOrderMarker,
+        T: DecomposableInto<u8>,
+    {
+        self.maybe_full_propagate_parallelized(ct);
         self.unchecked_scalar_sub_assign(ct, scalar);
         self.full_propagate_parallelized(ct);
     }
@@ -184,9 +184,7 @@ impl ServerKey {
         ct: &mut RadixCiphertext<PBSOrder>,
         shift: usize,
     ) {
-        if !ct.block_carries_are_empty() {
-            self.full_propagate_parallelized(ct);
-        }
+        self.maybe_full_propagate_parallelized(ct);
         self.unchecked_scalar_right_shift_assign_parallelized(ct, shift);
         self.full_propagate_parallelized(ct);
     }
@@ -270,8 +268,14 @@ impl ServerKey {
         ct: &mut RadixCiphertext<PBSOrder>,
         shift: usize,
     ) {
-        let tmp = 1_u64 << shift;
+        //number of bits of message
+        let nb_bits = self.key.message_modulus.0.ilog2() as usize;
+
+        // 2^u = 2^{p*q+r}, shifting by q blocks or more clears all the blocks
+        let quotient = (shift / nb_bits).min(ct.blocks.len());
+        let tmp = 1_u64 << (shift % nb_bits);
         self.smart_scalar_mul_assign_parallelized(ct, tmp);
+        *ct = self.blockshift(ct, quotient);
     }
 
     /// Computes homomorphically a left shift by a scalar.
@@ -359,9 +363,7 @@ impl ServerKey {
         ct: &mut RadixCiphertext<PBSOrder>,
         shift: usize,
     ) {
-        if !ct.block_carries_are_empty() {
-            self.full_propagate_parallelized(ct);
-        }
+        self.maybe_full_propagate_parallelized(ct);
         self.unchecked_scalar_left_shift_assign_parallelized(ct, shift);
         self.full_propagate_parallelized(ct);
     }
@@ -1,6 +1,8 @@
 use crate::integer::ciphertext::RadixCiphertext;
+use crate::integer::server_key::accumulator_cache::CachedFunction;
 use crate::integer::ServerKey;
-use crate::shortint::PBSOrderMarker;
+use crate::shortint::{CiphertextBase, PBSOrderMarker};
+use rayon::prelude::*;
 
 impl ServerKey {
     /// Computes homomorphically the subtraction between ct_left and ct_right.
@@ -213,4 +215,202 @@ impl ServerKey {
         self.unchecked_sub_assign(lhs, rhs);
         self.full_propagate_parallelized(lhs);
     }
+
+    /// Computes homomorphically the subtraction between ct_left and ct_right, and whether the
+    /// subtraction underflowed.
+    ///
+    /// Returns the difference, wrapped around the modulus of the input ciphertexts, and an
+    /// encrypted boolean (`1` if ct_left < ct_right, `0` otherwise), like Rust's
+    /// `overflowing_sub`. The flag is the borrow out of the most significant block, obtained by
+    /// computing the difference on one extra block.
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertexts block carries are empty and clears them if it's not the
+    /// case and the operation requires it. It outputs a ciphertext whose block carries are always
+    /// empty.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg_1 = 120u8;
+    /// let msg_2 = 181u8;
+    ///
+    /// let ctxt_1 = cks.encrypt(msg_1 as u64);
+    /// let ctxt_2 = cks.encrypt(msg_2 as u64);
+    ///
+    /// let (ct_res, underflowed) = sks.overflowing_sub_parallelized(&ctxt_1, &ctxt_2);
+    ///
+    /// // Decrypt:
+    /// let res: u64 = cks.decrypt(&ct_res);
+    /// let (expected, expected_underflow) = msg_1.overflowing_sub(msg_2);
+    /// assert_eq!(res, expected as u64);
+    /// assert_eq!(
+    ///     cks.decrypt_one_block(&underflowed),
+    ///     u64::from(expected_underflow)
+    /// );
+    /// ```
+    pub fn overflowing_sub_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        ctxt_left: &RadixCiphertext<PBSOrder>,
+        ctxt_right: &RadixCiphertext<PBSOrder>,
+    ) -> (RadixCiphertext<PBSOrder>, CiphertextBase<PBSOrder>) {
+        let mut lhs = ctxt_left.clone();
+        let mut rhs = ctxt_right.clone();
+        // Input carries must be propagated first, they would otherwise spill into the extra block
+        rayon::join(
+            || self.maybe_full_propagate_parallelized(&mut lhs),
+            || self.maybe_full_propagate_parallelized(&mut rhs),
+        );
+        lhs.blocks.push(self.key.create_trivial(0));
+        rhs.blocks.push(self.key.create_trivial(0));
+
+        self.sub_assign_parallelized(&mut lhs, &rhs);
+
+        // The extra block is empty unless the subtraction wrapped around
+        let borrow_block = lhs.blocks.pop().unwrap();
+        let underflowed = self.apply_cached_function(&borrow_block, CachedFunction::IsNonZero);
+        (lhs, underflowed)
+    }
+
+    /// Computes homomorphically the subtraction of ct_right and of an incoming borrow from
+    /// ct_left, and the outgoing borrow.
+    ///
+    /// `borrow_in` must be a shortint ciphertext encrypting `0` or `1` with empty carries, like
+    /// the returned borrow. Returns the difference, wrapped around the modulus of the input
+    /// ciphertexts, and an encrypted boolean (`1` if ct_left < ct_right + borrow_in, `0`
+    /// otherwise). This allows chaining subtractions of integers split in several radix
+    /// ciphertexts, from the least significant one to the most significant one.
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertexts block carries are empty and clears them if it's not the
+    /// case and the operation requires it. It outputs a ciphertext whose block carries are always
+    /// empty.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message per radix ciphertext
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// // 16 bits values, split in two radix ciphertexts of 8 bits
+    /// let msg_1 = 0x3461u16;
+    /// let msg_2 = 0x12c8u16;
+    ///
+    /// let ctxt_1_low = cks.encrypt((msg_1 & 0xff) as u64);
+    /// let ctxt_1_high = cks.encrypt((msg_1 >> 8) as u64);
+    /// let ctxt_2_low = cks.encrypt((msg_2 & 0xff) as u64);
+    /// let ctxt_2_high = cks.encrypt((msg_2 >> 8) as u64);
+    ///
+    /// let borrow = cks.encrypt_one_block(0);
+    /// let (ct_res_low, borrow) = sks.sub_with_borrow_parallelized(&ctxt_1_low, &ctxt_2_low, &borrow);
+    /// let (ct_res_high, borrow) =
+    ///     sks.sub_with_borrow_parallelized(&ctxt_1_high, &ctxt_2_high, &borrow);
+    ///
+    /// // Decrypt:
+    /// let res_low: u64 = cks.decrypt(&ct_res_low);
+    /// let res_high: u64 = cks.decrypt(&ct_res_high);
+    /// let (expected, expected_borrow) = msg_1.overflowing_sub(msg_2);
+    /// assert_eq!(res_low | (res_high << 8), expected as u64);
+    /// assert_eq!(cks.decrypt_one_block(&borrow), u64::from(expected_borrow));
+    /// ```
+    pub fn sub_with_borrow_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        ctxt_left: &RadixCiphertext<PBSOrder>,
+        ctxt_right: &RadixCiphertext<PBSOrder>,
+        borrow_in: &CiphertextBase<PBSOrder>,
+    ) -> (RadixCiphertext<PBSOrder>, CiphertextBase<PBSOrder>) {
+        let mut lhs = ctxt_left.clone();
+        let mut rhs = ctxt_right.clone();
+        if !rhs.block_carries_are_empty() {
+            self.full_propagate_parallelized(&mut rhs);
+        }
+        lhs.blocks.push(self.key.create_trivial(0));
+        rhs.blocks.push(self.key.create_trivial(0));
+
+        // ct_right + borrow_in always fits in the extra block, so subtracting it from ct_left on
+        // one more block gives the borrow in the extra block
+        self.key.unchecked_add_assign(&mut rhs.blocks[0], borrow_in);
+        self.sub_assign_parallelized(&mut lhs, &rhs);
+
+        // The extra block is empty unless the subtraction wrapped around
+        let borrow_block = lhs.blocks.pop().unwrap();
+        let borrow_out = self.apply_cached_function(&borrow_block, CachedFunction::IsNonZero);
+        (lhs, borrow_out)
+    }
+
+    /// Computes homomorphically the subtraction between ct_left and ct_right, saturating at zero
+    /// instead of underflowing, like Rust's `saturating_sub`.
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertexts block carries are empty and clears them if it's not the
+    /// case and the operation requires it. It outputs a ciphertext whose block carries are always
+    /// empty.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg_1 = 120u8;
+    /// let msg_2 = 181u8;
+    ///
+    /// let ctxt_1 = cks.encrypt(msg_1 as u64);
+    /// let ctxt_2 = cks.encrypt(msg_2 as u64);
+    ///
+    /// let ct_res = sks.saturating_sub_parallelized(&ctxt_1, &ctxt_2);
+    ///
+    /// // Decrypt:
+    /// let res: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(res, msg_1.saturating_sub(msg_2) as u64);
+    /// ```
+    pub fn saturating_sub_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        ctxt_left: &RadixCiphertext<PBSOrder>,
+        ctxt_right: &RadixCiphertext<PBSOrder>,
+    ) -> RadixCiphertext<PBSOrder> {
+        let (mut result, underflowed) = self.overflowing_sub_parallelized(ctxt_left, ctxt_right);
+
+        result.blocks.par_iter_mut().for_each(|block| {
+            self.key.unchecked_evaluate_bivariate_function_assign(
+                block,
+                &underflowed,
+                |x, underflowed| {
+                    if underflowed == 1 {
+                        0
+                    } else {
+                        x
+                    }
+                },
+            );
+        });
+        result
+    }
 }
@@ -1,3 +1,4 @@
+use crate::integer::ciphertext::{RadixCiphertext, RadixCiphertextBig, RadixCiphertextCarrySave};
 use crate::integer::keycache::KEY_CACHE;
 use crate::integer::{RadixClientKey, ServerKey};
 use crate::shortint::parameters::*;
@@ -45,23 +46,32 @@ create_parametrized_test!(integer_default_add_sequence_multi_thread);
 fn test_integer_default_add_sequence_single_thread_param_message_2_carry_2() {
     integer_default_add_sequence_single_thread(PARAM_MESSAGE_2_CARRY_2)
 }
+#[test]
+fn test_integer_default_add_in_thread_pool_param_message_2_carry_2() {
+    integer_default_add_in_thread_pool(PARAM_MESSAGE_2_CARRY_2)
+}
 create_parametrized_test!(integer_smart_bitand);
 create_parametrized_test!(integer_smart_bitor);
 create_parametrized_test!(integer_smart_bitxor);
 create_parametrized_test!(integer_default_bitand);
 create_parametrized_test!(integer_default_bitor);
 create_parametrized_test!(integer_default_bitxor);
+create_parametrized_test!(integer_default_scalar_bitand);
+create_parametrized_test!(integer_default_scalar_bitor);
+create_parametrized_test!(integer_default_scalar_bitxor);
 create_parametrized_test!(integer_unchecked_small_scalar_mul);
 create_parametrized_test!(integer_smart_small_scalar_mul);
 create_parametrized_test!(integer_default_small_scalar_mul);
 create_parametrized_test!(integer_smart_scalar_mul);
 create_parametrized_test!(integer_default_scalar_mul);
+create_parametrized_test!(integer_default_scalar_mul_power_of_two);
 create_parametrized_test!(integer_unchecked_scalar_left_shift);
 create_parametrized_test!(integer_default_scalar_left_shift);
 create_parametrized_test!(integer_unchecked_scalar_right_shift);
 create_parametrized_test!(integer_default_scalar_right_shift);
 create_parametrized_test!(integer_smart_neg);
 create_parametrized_test!(integer_default_neg);
+create_parametrized_test!(integer_default_abs);
 create_parametrized_test!(integer_smart_sub);
 create_parametrized_test!(integer_default_sub);
 create_parametrized_test!(integer_unchecked_block_mul);
@@ -69,10 +79,54 @@ create_parametrized_test!(integer_smart_block_mul);
 create_parametrized_test!(integer_default_block_mul);
 create_parametrized_test!(integer_smart_mul);
 create_parametrized_test!(integer_default_mul);
+create_parametrized_test!(integer_default_checked_mul);
+create_parametrized_test!(integer_carry_save_add);
+create_parametrized_test!(integer_default_scalar_mod);
+create_parametrized_test!(integer_default_overflowing_add_sub);
+create_parametrized_test!(integer_default_saturating_add_sub);
+create_parametrized_test!(integer_default_overflowing_saturating_add_sub_dirty_inputs);
+create_parametrized_test!(integer_default_scalar_sub_underflow);
+create_parametrized_test!(integer_default_max_min_index_of {
+    PARAM_MESSAGE_2_CARRY_2,
+    PARAM_MESSAGE_3_CARRY_3,
+    PARAM_MESSAGE_4_CARRY_4
+});
+create_parametrized_test!(integer_default_conditional_swap {
+    PARAM_MESSAGE_2_CARRY_2,
+    PARAM_MESSAGE_3_CARRY_3,
+    PARAM_MESSAGE_4_CARRY_4
+});
+create_parametrized_test!(integer_accumulator_cache);
+create_parametrized_test!(integer_default_reverse_bits);
+create_parametrized_test!(integer_default_select {
+    PARAM_MESSAGE_2_CARRY_2,
+    PARAM_MESSAGE_3_CARRY_3,
+    PARAM_MESSAGE_4_CARRY_4
+});
+create_parametrized_test!(integer_parallelized_ops_are_deterministic);
+create_parametrized_test!(integer_default_ilog2);
+create_parametrized_test!(integer_default_parity);
+create_parametrized_test!(integer_default_scalar_div_by_const {
+    PARAM_MESSAGE_2_CARRY_2,
+    PARAM_MESSAGE_3_CARRY_3,
+    PARAM_MESSAGE_4_CARRY_4
+});
+create_parametrized_test!(integer_default_add_sub_with_carry);
+create_parametrized_test!(integer_default_wrap_to_bits);
 create_parametrized_test!(integer_smart_scalar_sub);
 create_parametrized_test!(integer_default_scalar_sub);
 create_parametrized_test!(integer_smart_scalar_add);
 create_parametrized_test!(integer_default_scalar_add);
+create_parametrized_test!(integer_default_scalar_ops_clear_types);
+create_parametrized_test!(integer_default_blind_lookup);
+create_parametrized_test!(integer_default_max_min_of_many {
+    PARAM_MESSAGE_2_CARRY_2,
+    PARAM_MESSAGE_3_CARRY_3,
+    PARAM_MESSAGE_4_CARRY_4
+});
+create_parametrized_test!(integer_default_signed_add);
+create_parametrized_test!(integer_maybe_full_propagate);
+create_parametrized_test!(integer_default_is_zero);
 
 fn integer_smart_add(param: Parameters) {
     let (cks, sks) = KEY_CACHE.get_from_params(param);
@@ -240,6 +294,39 @@ fn integer_default_add(param: Parameters) {
     }
 }
 
+fn integer_default_add_in_thread_pool(param: Parameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = param.message_modulus.0.pow(NB_CTXT as u32) as u64;
+
+    let threadpool = rayon::ThreadPoolBuilder::new()
+        .num_threads(2)
+        .build()
+        .unwrap();
+
+    for _ in 0..NB_TEST_SMALLER {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+
+        let ctxt_0 = cks.encrypt(clear_0);
+        let ctxt_1 = cks.encrypt(clear_1);
+
+        let ct_res = sks.install_in_thread_pool(&threadpool, |sks| {
+            assert_eq!(rayon::current_num_threads(), 2);
+            sks.add_parallelized(&ctxt_0, &ctxt_1)
+        });
+        assert!(ct_res.block_carries_are_empty());
+
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!((clear_0 + clear_1) % modulus, dec_res);
+    }
+}
+
 fn integer_default_add_sequence_multi_thread(param: Parameters) {
     let (cks, sks) = KEY_CACHE.get_from_params(param);
     let cks = RadixClientKey::from((cks, NB_CTXT));
@@ -600,6 +687,90 @@ fn integer_default_bitxor(param: Parameters) {
     }
 }
 
+fn integer_default_scalar_bitand(param: Parameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = param.message_modulus.0.pow(NB_CTXT as u32) as u64;
+
+    for _ in 0..NB_TEST_SMALLER {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+
+        // encryption of an integer
+        let ctxt_0 = cks.encrypt(clear_0);
+
+        let ct_res = sks.scalar_bitand_parallelized(&ctxt_0, clear_1);
+        assert!(ct_res.block_carries_are_empty());
+
+        // decryption of ct_res
+        let dec_res: u64 = cks.decrypt(&ct_res);
+
+        // assert
+        assert_eq!(clear_0 & clear_1, dec_res);
+    }
+}
+
+fn integer_default_scalar_bitor(param: Parameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = param.message_modulus.0.pow(NB_CTXT as u32) as u64;
+
+    for _ in 0..NB_TEST_SMALLER {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+
+        // encryption of an integer
+        let ctxt_0 = cks.encrypt(clear_0);
+
+        let ct_res = sks.scalar_bitor_parallelized(&ctxt_0, clear_1);
+        assert!(ct_res.block_carries_are_empty());
+
+        // decryption of ct_res
+        let dec_res: u64 = cks.decrypt(&ct_res);
+
+        // assert
+        assert_eq!(clear_0 | clear_1, dec_res);
+    }
+}
+
+fn integer_default_scalar_bitxor(param: Parameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = param.message_modulus.0.pow(NB_CTXT as u32) as u64;
+
+    for _ in 0..NB_TEST_SMALLER {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+
+        // encryption of an integer
+        let ctxt_0 = cks.encrypt(clear_0);
+
+        let ct_res = sks.scalar_bitxor_parallelized(&ctxt_0, clear_1);
+        assert!(ct_res.block_carries_are_empty());
+
+        // decryption of ct_res
+        let dec_res: u64 = cks.decrypt(&ct_res);
+
+        // assert
+        assert_eq!(clear_0 ^ clear_1, dec_res);
+    }
+}
+
 fn integer_unchecked_small_scalar_mul(param: Parameters) {
     let (cks, sks) = KEY_CACHE.get_from_params(param);
     let cks = RadixClientKey::from((cks, NB_CTXT));
@@ -768,6 +939,32 @@ fn integer_default_scalar_mul(param: Parameters) {
     }
 }
 
+fn integer_default_scalar_mul_power_of_two(param: Parameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = param.message_modulus.0.pow(NB_CTXT as u32) as u64;
+    let num_bits = modulus.ilog2();
+
+    // Exercises both block aligned and unaligned shifts
+    for power in 0..num_bits {
+        let clear = rng.gen::<u64>() % modulus;
+        let scalar = 1u64 << power;
+
+        let ct = cks.encrypt(clear);
+
+        let ct_res = sks.scalar_mul_parallelized(&ct, scalar);
+        assert!(ct_res.block_carries_are_empty());
+
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!((clear * scalar) % modulus, dec_res);
+    }
+}
+
 fn integer_unchecked_scalar_left_shift(param: Parameters) {
     let (cks, sks) = KEY_CACHE.get_from_params(param);
     let cks = RadixClientKey::from((cks, NB_CTXT));
@@ -963,6 +1160,37 @@ fn integer_default_neg(param: Parameters) {
     }
 }
 
+fn integer_default_abs(param: Parameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = param.message_modulus.0.pow(NB_CTXT as u32) as u64;
+
+    for _ in 0..NB_TEST_SMALLER {
+        let clear = rng.gen::<u64>() % modulus;
+
+        let ctxt = cks.encrypt(clear);
+
+        let ct_res = sks.abs_parallelized(&ctxt);
+        assert!(ct_res.block_carries_are_empty());
+
+        let dec: u64 = cks.decrypt(&ct_res);
+
+        // Values with the most significant bit set are negative in two's complement
+        let clear_result = if clear >= modulus / 2 {
+            clear.wrapping_neg() % modulus
+        } else {
+            clear
+        };
+
+        assert_eq!(clear_result, dec);
+    }
+}
+
 fn integer_smart_sub(param: Parameters) {
     let (cks, sks) = KEY_CACHE.get_from_params(param);
     let cks = RadixClientKey::from((cks, NB_CTXT));
@@ -1395,3 +1623,879 @@ fn integer_default_scalar_sub(param: Parameters) {
         }
     }
 }
+
+fn integer_default_blind_lookup(param: Parameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = param.message_modulus.0.pow(NB_CTXT as u32) as u64;
+
+    let table = (0..8).map(|_| rng.gen::<u64>()).collect::<Vec<_>>();
+
+    for _ in 0..NB_TEST_SMALLER {
+        // Also exercises out of range indices
+        let clear = rng.gen::<u64>() % (2 * table.len() as u64).min(modulus);
+
+        let ctxt = cks.encrypt(clear);
+
+        let ct_res = sks.blind_lookup_parallelized(&ctxt, &table);
+        assert!(ct_res.block_carries_are_empty());
+
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        let expected = table.get(clear as usize).map_or(0, |value| value % modulus);
+        assert_eq!(expected, dec_res);
+    }
+}
+
+fn integer_default_max_min_of_many(param: Parameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = param.message_modulus.0.pow(NB_CTXT as u32) as u64;
+
+    // Exercises a single ciphertext as well as even and odd tournament sizes
+    for num_cts in [1, 2, 5, 8] {
+        let clears = (0..num_cts)
+            .map(|_| rng.gen::<u64>() % modulus)
+            .collect::<Vec<_>>();
+        let ctxts = clears
+            .iter()
+            .map(|&clear| cks.encrypt(clear))
+            .collect::<Vec<_>>();
+
+        let ct_res = sks.max_of_many_parallelized(&ctxts);
+        assert!(ct_res.block_carries_are_empty());
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(*clears.iter().max().unwrap(), dec_res);
+
+        let ct_res = sks.min_of_many_parallelized(&ctxts);
+        assert!(ct_res.block_carries_are_empty());
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(*clears.iter().min().unwrap(), dec_res);
+    }
+}
+
+fn integer_default_signed_add(param: Parameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    let num_bits = NB_CTXT as u32 * param.message_modulus.0.ilog2();
+    let max = (1i64 << (num_bits - 1)) - 1;
+    let min = -(1i64 << (num_bits - 1));
+
+    for _ in 0..NB_TEST_SMALLER {
+        let clear_0 = rng.gen_range(min..=max);
+        let clear_1 = rng.gen_range(min..=max);
+
+        let ctxt_0 = cks.encrypt_signed_radix(clear_0, NB_CTXT);
+        let ctxt_1 = cks.encrypt_signed_radix(clear_1, NB_CTXT);
+
+        let dec: i64 = cks.decrypt_signed_radix(&ctxt_0);
+        assert_eq!(clear_0, dec);
+
+        let ct_res = sks.add_parallelized(&ctxt_0, &ctxt_1);
+        let dec_res: i64 = cks.decrypt_signed_radix(&ct_res);
+
+        // Wrap the clear result the same way the two's complement encryption does
+        let unused_bits = 64 - num_bits;
+        let expected = (clear_0 + clear_1) << unused_bits >> unused_bits;
+        assert_eq!(expected, dec_res);
+    }
+}
+
+fn integer_maybe_full_propagate(param: Parameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = param.message_modulus.0.pow(NB_CTXT as u32) as u64;
+
+    for _ in 0..NB_TEST_SMALLER {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+
+        let mut ctxt_0 = cks.encrypt(clear_0);
+        let ctxt_1 = cks.encrypt(clear_1);
+
+        // Nothing to propagate on a fresh ciphertext
+        assert!(!sks.maybe_full_propagate_parallelized(&mut ctxt_0));
+
+        let mut ct_res = sks.unchecked_add(&ctxt_0, &ctxt_1);
+        let needed_propagation = !ct_res.block_carries_are_empty();
+        assert_eq!(
+            sks.maybe_full_propagate_parallelized(&mut ct_res),
+            needed_propagation
+        );
+        assert!(ct_res.block_carries_are_empty());
+
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!((clear_0 + clear_1) % modulus, dec_res);
+    }
+}
+
+fn integer_default_is_zero(param: Parameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = param.message_modulus.0.pow(NB_CTXT as u32) as u64;
+
+    let ctxt = cks.encrypt(0);
+    let ct_res = sks.is_zero_parallelized(&ctxt);
+    let dec_res: u64 = cks.decrypt(&ct_res);
+    assert_eq!(dec_res, 1);
+
+    for _ in 0..NB_TEST_SMALLER {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+
+        let ctxt_0 = cks.encrypt(clear_0);
+        let ctxt_1 = cks.encrypt(clear_1);
+
+        let ct_res = sks.is_zero_parallelized(&ctxt_0);
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(dec_res, u64::from(clear_0 == 0));
+
+        // Input with non empty carries, which is zero half of the time
+        let (ctxt_1, clear_sum) = if rng.gen::<bool>() {
+            let ct = sks.unchecked_add(&ctxt_0, &ctxt_1);
+            (ct, (clear_0 + clear_1) % modulus)
+        } else {
+            let neg = sks.neg_parallelized(&ctxt_0);
+            (sks.unchecked_add(&ctxt_0, &neg), 0)
+        };
+        let ct_res = sks.is_zero_parallelized(&ctxt_1);
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(dec_res, u64::from(clear_sum == 0));
+    }
+}
+
+fn integer_default_scalar_ops_clear_types(param: Parameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = param.message_modulus.0.pow(NB_CTXT as u32) as u64;
+
+    for _ in 0..NB_TEST_SMALLER {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let ctxt_0 = cks.encrypt(clear_0);
+
+        // Scalars narrower than the encrypted integer
+        let scalar = rng.gen::<u8>();
+
+        let ct_res = sks.scalar_add_parallelized(&ctxt_0, scalar);
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(dec_res, (clear_0 + scalar as u64) % modulus);
+
+        let ct_res = sks.scalar_sub_parallelized(&ctxt_0, scalar);
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(dec_res, clear_0.wrapping_sub(scalar as u64) % modulus);
+
+        let ct_res = sks.scalar_bitand_parallelized(&ctxt_0, scalar);
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(dec_res, clear_0 & scalar as u64);
+
+        let ct_res = sks.scalar_mul_parallelized(&ctxt_0, scalar);
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(dec_res, clear_0 * scalar as u64 % modulus);
+
+        let ct_res = sks.scalar_le_parallelized(&ctxt_0, scalar);
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(dec_res, u64::from(clear_0 <= scalar as u64));
+
+        // Arithmetic and bitwise operations reduce scalars wider than the encrypted integer
+        // modulo its modulus
+        let scalar = rng.gen::<u128>();
+        let truncated = (scalar % modulus as u128) as u64;
+
+        let ct_res = sks.scalar_add_parallelized(&ctxt_0, scalar);
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(dec_res, (clear_0 + truncated) % modulus);
+
+        let ct_res = sks.scalar_sub_parallelized(&ctxt_0, scalar);
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(dec_res, (clear_0 + modulus - truncated) % modulus);
+
+        let ct_res = sks.scalar_bitxor_parallelized(&ctxt_0, scalar);
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(dec_res, clear_0 ^ truncated);
+
+        let ct_res = sks.scalar_mul_parallelized(&ctxt_0, scalar);
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(dec_res, clear_0 * truncated % modulus);
+
+        // while comparisons compare the whole scalar
+        let scalar = modulus as u128 + truncated as u128;
+
+        let ct_res = sks.scalar_eq_parallelized(&ctxt_0, scalar);
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(dec_res, 0);
+
+        let ct_res = sks.scalar_lt_parallelized(&ctxt_0, scalar);
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(dec_res, 1);
+
+        let ct_res = sks.scalar_in_range_parallelized(&ctxt_0, truncated as u128, scalar);
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(dec_res, u64::from(clear_0 >= truncated));
+    }
+}
+
+fn integer_default_checked_mul(param: Parameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = param.message_modulus.0.pow(NB_CTXT as u32) as u64;
+
+    for _ in 0..NB_TEST_SMALLER {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        // Half of the time, use an operand small enough for the product not to overflow
+        let clear_1 = if rng.gen::<bool>() {
+            rng.gen::<u64>() % (modulus / clear_0.max(1))
+        } else {
+            rng.gen::<u64>() % modulus
+        };
+
+        let ctxt_0 = cks.encrypt(clear_0);
+        let ctxt_1 = cks.encrypt(clear_1);
+
+        let (ct_res, overflowed) = sks.checked_mul_parallelized(&ctxt_0, &ctxt_1);
+        assert!(ct_res.block_carries_are_empty());
+        assert_eq!(ct_res.blocks.len(), NB_CTXT);
+
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(dec_res, (clear_0 * clear_1) % modulus);
+
+        let dec_overflowed = cks.decrypt_one_block(&overflowed);
+        assert_eq!(dec_overflowed, u64::from(clear_0 * clear_1 >= modulus));
+    }
+}
+
+fn integer_carry_save_add(param: Parameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = param.message_modulus.0.pow(NB_CTXT as u32) as u64;
+
+    let nb_operands = 6;
+
+    for _ in 0..NB_TEST_SMALLER {
+        let clears = (0..nb_operands)
+            .map(|_| rng.gen::<u64>() % modulus)
+            .collect::<Vec<_>>();
+
+        let mut acc = RadixCiphertextCarrySave::from(cks.encrypt(clears[0]));
+        let mut clear_acc = clears[0];
+        for &clear in &clears[1..] {
+            let ct = RadixCiphertextCarrySave::from(cks.encrypt(clear));
+            acc = sks.carry_save_add_parallelized(&acc, &ct);
+            clear_acc = (clear_acc + clear) % modulus;
+
+            // The pending carries are part of the encrypted value
+            let dec_acc: u64 = cks.decrypt(&RadixCiphertext::from(acc.clone()));
+            assert_eq!(dec_acc, clear_acc);
+        }
+
+        let ct_res = sks.resolve_carry_save_parallelized(acc);
+        assert!(ct_res.block_carries_are_empty());
+
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(dec_res, clears.iter().sum::<u64>() % modulus);
+    }
+}
+
+fn integer_default_scalar_mod(param: Parameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = param.message_modulus.0.pow(NB_CTXT as u32) as u64;
+
+    for _ in 0..NB_TEST_SMALLER {
+        let clear = rng.gen::<u64>() % modulus;
+        let ctxt = cks.encrypt(clear);
+
+        // Power of two moduli, non power of two moduli and moduli larger than the message space
+        let scalar_moduli = [
+            1 << rng.gen_range(0..=NB_CTXT as u32 * param.message_modulus.0.ilog2()),
+            rng.gen_range(1..modulus),
+            modulus + rng.gen_range(1..modulus),
+        ];
+
+        for scalar_modulus in scalar_moduli {
+            let ct_res = sks.scalar_mod_parallelized(&ctxt, scalar_modulus);
+            assert!(ct_res.block_carries_are_empty());
+            assert_eq!(ct_res.blocks.len(), NB_CTXT);
+
+            let dec_res: u64 = cks.decrypt(&ct_res);
+            assert_eq!(dec_res, clear % scalar_modulus);
+        }
+    }
+}
+
+fn integer_default_overflowing_add_sub(param: Parameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = param.message_modulus.0.pow(NB_CTXT as u32) as u64;
+
+    for _ in 0..NB_TEST_SMALLER {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+
+        let ctxt_0 = cks.encrypt(clear_0);
+        let ctxt_1 = cks.encrypt(clear_1);
+
+        let (ct_res, overflowed) = sks.overflowing_add_parallelized(&ctxt_0, &ctxt_1);
+        assert!(ct_res.block_carries_are_empty());
+        assert_eq!(ct_res.blocks.len(), NB_CTXT);
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(dec_res, (clear_0 + clear_1) % modulus);
+        let dec_overflowed = cks.decrypt_one_block(&overflowed);
+        assert_eq!(dec_overflowed, u64::from(clear_0 + clear_1 >= modulus));
+
+        let (ct_res, underflowed) = sks.overflowing_sub_parallelized(&ctxt_0, &ctxt_1);
+        assert!(ct_res.block_carries_are_empty());
+        assert_eq!(ct_res.blocks.len(), NB_CTXT);
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(dec_res, (clear_0 + modulus - clear_1) % modulus);
+        let dec_underflowed = cks.decrypt_one_block(&underflowed);
+        assert_eq!(dec_underflowed, u64::from(clear_0 < clear_1));
+    }
+}
+
+fn integer_default_saturating_add_sub(param: Parameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = param.message_modulus.0.pow(NB_CTXT as u32) as u64;
+
+    // Boundary values and random ones
+    let mut clears = vec![
+        (0, 0),
+        (modulus - 1, 0),
+        (modulus - 1, 1),
+        (modulus - 1, modulus - 1),
+        (0, 1),
+        (1, 1),
+    ];
+    clears.extend(
+        (0..NB_TEST_SMALLER).map(|_| (rng.gen::<u64>() % modulus, rng.gen::<u64>() % modulus)),
+    );
+
+    for (clear_0, clear_1) in clears {
+        let ctxt_0 = cks.encrypt(clear_0);
+        let ctxt_1 = cks.encrypt(clear_1);
+
+        let ct_res = sks.saturating_add_parallelized(&ctxt_0, &ctxt_1);
+        assert!(ct_res.block_carries_are_empty());
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(dec_res, (clear_0 + clear_1).min(modulus - 1));
+
+        let ct_res = sks.saturating_sub_parallelized(&ctxt_0, &ctxt_1);
+        assert!(ct_res.block_carries_are_empty());
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(dec_res, clear_0.saturating_sub(clear_1));
+    }
+}
+
+fn integer_default_overflowing_saturating_add_sub_dirty_inputs(param: Parameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = param.message_modulus.0.pow(NB_CTXT as u32) as u64;
+
+    for _ in 0..NB_TEST_SMALLER {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+        let clear_2 = rng.gen::<u64>() % modulus;
+
+        // The input carries are not empty, they must not be mistaken for an overflow
+        let ctxt_0 = sks.unchecked_add(&cks.encrypt(clear_0), &cks.encrypt(clear_1));
+        assert!(!ctxt_0.block_carries_are_empty());
+        let clear_0 = (clear_0 + clear_1) % modulus;
+        let ctxt_1 = sks.unchecked_add(&cks.encrypt(clear_2), &cks.encrypt(0));
+        let clear_1 = clear_2;
+
+        let (ct_res, overflowed) = sks.overflowing_add_parallelized(&ctxt_0, &ctxt_1);
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(dec_res, (clear_0 + clear_1) % modulus);
+        let dec_overflowed = cks.decrypt_one_block(&overflowed);
+        assert_eq!(dec_overflowed, u64::from(clear_0 + clear_1 >= modulus));
+
+        let (ct_res, underflowed) = sks.overflowing_sub_parallelized(&ctxt_0, &ctxt_1);
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(dec_res, (clear_0 + modulus - clear_1) % modulus);
+        let dec_underflowed = cks.decrypt_one_block(&underflowed);
+        assert_eq!(dec_underflowed, u64::from(clear_0 < clear_1));
+
+        let ct_res = sks.saturating_add_parallelized(&ctxt_0, &ctxt_1);
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(dec_res, (clear_0 + clear_1).min(modulus - 1));
+
+        let ct_res = sks.saturating_sub_parallelized(&ctxt_0, &ctxt_1);
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(dec_res, clear_0.saturating_sub(clear_1));
+    }
+}
+
+fn integer_default_scalar_sub_underflow(param: Parameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    // message_modulus^vec_length
+    let modulus = param.message_modulus.0.pow(NB_CTXT as u32) as u64;
+
+    // The result must wrap around in the modular space of the whole radix integer, the borrow
+    // going through all the blocks
+    for (clear_0, clear_1) in [
+        (5, 10),
+        (0, 1),
+        (0, modulus - 1),
+        (1, modulus - 1),
+        (modulus - 2, modulus - 1),
+    ] {
+        let clear_0 = clear_0 % modulus;
+        let ctxt_0 = cks.encrypt(clear_0);
+
+        let ct_res = sks.scalar_sub_parallelized(&ctxt_0, clear_1);
+        assert!(ct_res.block_carries_are_empty());
+
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(dec_res, (clear_0 + modulus - clear_1 % modulus) % modulus);
+    }
+}
+
+fn integer_default_max_min_index_of(param: Parameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = param.message_modulus.0.pow(NB_CTXT as u32) as u64;
+
+    // Exercises a single ciphertext as well as even and odd tournament sizes, the values are
+    // drawn from a small range to exercise ties
+    for num_cts in [1, 2, 5, 8] {
+        let clears = (0..num_cts)
+            .map(|_| rng.gen::<u64>() % 4 % modulus)
+            .collect::<Vec<_>>();
+        let ctxts = clears
+            .iter()
+            .map(|&clear| cks.encrypt(clear))
+            .collect::<Vec<_>>();
+
+        let clear_max = *clears.iter().max().unwrap();
+        let clear_max_index = clears.iter().position(|&x| x == clear_max).unwrap() as u64;
+        let (ct_max, ct_index) = sks.max_index_of_parallelized(&ctxts);
+        assert!(ct_max.block_carries_are_empty());
+        assert!(ct_index.block_carries_are_empty());
+        let dec_max: u64 = cks.decrypt(&ct_max);
+        let dec_index: u64 = cks.decrypt(&ct_index);
+        assert_eq!(clear_max, dec_max);
+        assert_eq!(clear_max_index, dec_index);
+
+        let clear_min = *clears.iter().min().unwrap();
+        let clear_min_index = clears.iter().position(|&x| x == clear_min).unwrap() as u64;
+        let (ct_min, ct_index) = sks.min_index_of_parallelized(&ctxts);
+        assert!(ct_min.block_carries_are_empty());
+        assert!(ct_index.block_carries_are_empty());
+        let dec_min: u64 = cks.decrypt(&ct_min);
+        let dec_index: u64 = cks.decrypt(&ct_index);
+        assert_eq!(clear_min, dec_min);
+        assert_eq!(clear_min_index, dec_index);
+    }
+
+    let result = std::panic::catch_unwind(|| {
+        sks.max_index_of_parallelized::<crate::shortint::ciphertext::KeyswitchBootstrap>(&[])
+    });
+    assert!(result.is_err());
+}
+
+fn integer_default_conditional_swap(param: Parameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = param.message_modulus.0.pow(NB_CTXT as u32) as u64;
+
+    for _ in 0..NB_TEST_SMALLER {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+        let clear_condition = rng.gen::<u64>() % 2;
+
+        let mut ctxt_0 = cks.encrypt(clear_0);
+        let mut ctxt_1 = cks.encrypt(clear_1);
+        let ctxt_condition = cks.encrypt_one_block(clear_condition);
+
+        sks.conditional_swap_parallelized(&ctxt_condition, &mut ctxt_0, &mut ctxt_1);
+        assert!(ctxt_0.block_carries_are_empty());
+        assert!(ctxt_1.block_carries_are_empty());
+
+        let dec_0: u64 = cks.decrypt(&ctxt_0);
+        let dec_1: u64 = cks.decrypt(&ctxt_1);
+        if clear_condition == 1 {
+            assert_eq!((dec_0, dec_1), (clear_1, clear_0));
+        } else {
+            assert_eq!((dec_0, dec_1), (clear_0, clear_1));
+        }
+
+        sks.compare_and_swap_parallelized(&mut ctxt_0, &mut ctxt_1);
+        let dec_0: u64 = cks.decrypt(&ctxt_0);
+        let dec_1: u64 = cks.decrypt(&ctxt_1);
+        assert_eq!(dec_0, clear_0.min(clear_1));
+        assert_eq!(dec_1, clear_0.max(clear_1));
+    }
+}
+
+fn integer_accumulator_cache(param: Parameters) {
+    use crate::integer::server_key::accumulator_cache::{
+        CachedFunction, ACCUMULATOR_CACHE_CAPACITY,
+    };
+
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    // message_modulus^vec_length
+    let modulus = param.message_modulus.0.pow(NB_CTXT as u32) as u64;
+
+    let clear_0 = (modulus - 1) / 2;
+    let clear_1 = modulus / 2;
+    let ctxt_0 = cks.encrypt(clear_0);
+    let ctxt_1 = cks.encrypt(clear_1);
+
+    // The carries are propagated twice, the second time with the cached accumulators
+    assert_eq!(sks.accumulator_cache.len(), 0);
+    for _ in 0..2 {
+        let mut ct_res = sks.unchecked_add(&ctxt_0, &ctxt_1);
+        sks.full_propagate_parallelized(&mut ct_res);
+        assert_eq!(sks.accumulator_cache.len(), 2);
+
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(dec_res, (clear_0 + clear_1) % modulus);
+    }
+
+    // Clones share the cache
+    let sks_clone = sks.clone();
+    let _ = sks_clone.cached_accumulator(CachedFunction::IsZero, param.message_modulus.0);
+    assert_eq!(sks.accumulator_cache.len(), 3);
+
+    // The cache is bounded
+    for message_modulus in 1..=2 * ACCUMULATOR_CACHE_CAPACITY {
+        let _ = sks.cached_accumulator(CachedFunction::MessageExtract, message_modulus);
+        assert!(sks.accumulator_cache.len() <= ACCUMULATOR_CACHE_CAPACITY);
+    }
+}
+
+fn integer_default_reverse_bits(param: Parameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = param.message_modulus.0.pow(NB_CTXT as u32) as u64;
+    let num_bits = modulus.ilog2();
+
+    for _ in 0..NB_TEST_SMALLER {
+        let clear = rng.gen::<u64>() % modulus;
+        let ctxt = cks.encrypt(clear);
+
+        let ct_res = sks.reverse_bits_parallelized(&ctxt);
+        assert!(ct_res.block_carries_are_empty());
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(dec_res, clear.reverse_bits() >> (u64::BITS - num_bits));
+
+        let ct_res = sks.reverse_bits_parallelized(&ct_res);
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(dec_res, clear);
+    }
+}
+
+fn integer_default_select(param: Parameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = param.message_modulus.0.pow(NB_CTXT as u32) as u64;
+
+    // Not a power of two, so that the cmux tree is unbalanced
+    let clear_options = (0..5)
+        .map(|_| rng.gen::<u64>() % modulus)
+        .collect::<Vec<_>>();
+    let options = clear_options
+        .iter()
+        .map(|&clear| cks.encrypt(clear))
+        .collect::<Vec<_>>();
+
+    for _ in 0..NB_TEST_SMALLER {
+        // Some indices are out of range
+        let clear_index = rng.gen_range(0..clear_options.len() as u64 + 2);
+        let ctxt_index = cks.encrypt(clear_index);
+
+        let ct_res = sks.select_parallelized(&ctxt_index, &options);
+        assert!(ct_res.block_carries_are_empty());
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        let expected = clear_options
+            .get(clear_index as usize)
+            .copied()
+            .unwrap_or(0);
+        assert_eq!(dec_res, expected);
+    }
+}
+
+fn integer_parallelized_ops_are_deterministic(param: Parameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = param.message_modulus.0.pow(NB_CTXT as u32) as u64;
+
+    let serialize = |ct: &RadixCiphertextBig| bincode::serialize(ct).unwrap();
+
+    for _ in 0..NB_TEST_SMALLER {
+        let ctxts = (0..17)
+            .map(|_| cks.encrypt(rng.gen::<u64>() % modulus))
+            .collect::<Vec<_>>();
+        let scalar = rng.gen::<u64>() % modulus;
+
+        // Running the same operation twice on the same inputs yields the same ciphertext
+        let sum = || {
+            sks.default_binary_op_seq_parallelized(&ctxts, ServerKey::add_parallelized)
+                .unwrap()
+        };
+        assert_eq!(serialize(&sum()), serialize(&sum()));
+
+        let mul = || sks.mul_parallelized(&ctxts[0], &ctxts[1]);
+        assert_eq!(serialize(&mul()), serialize(&mul()));
+
+        let scalar_mul = || sks.scalar_mul_parallelized(&ctxts[0], scalar);
+        assert_eq!(serialize(&scalar_mul()), serialize(&scalar_mul()));
+    }
+}
+
+fn integer_default_ilog2(param: Parameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = param.message_modulus.0.pow(NB_CTXT as u32) as u64;
+
+    // Zero, every power of two and its predecessor, and random values
+    let clears = [0, modulus - 1]
+        .into_iter()
+        .chain((0..modulus.ilog2()).flat_map(|i| [1 << i, (1 << i) - 1]))
+        .chain((0..NB_TEST_SMALLER).map(|_| rng.gen::<u64>() % modulus));
+
+    for clear in clears {
+        let ctxt = cks.encrypt(clear);
+
+        let ct_res = sks.ilog2_parallelized(&ctxt);
+        assert!(ct_res.block_carries_are_empty());
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        // The logarithm of 0 is defined as 0
+        let expected = clear.checked_ilog2().unwrap_or(0) as u64;
+        assert_eq!(dec_res, expected, "wrong ilog2 of {clear}");
+    }
+}
+
+fn integer_default_parity(param: Parameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = param.message_modulus.0.pow(NB_CTXT as u32) as u64;
+
+    for _ in 0..NB_TEST_SMALLER {
+        let clear = rng.gen::<u64>() % modulus;
+        let ctxt = cks.encrypt(clear);
+
+        let ct_res = sks.parity_parallelized(&ctxt);
+        assert!(ct_res.block_carries_are_empty());
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(dec_res, u64::from(clear.count_ones() & 1));
+    }
+}
+
+fn integer_default_scalar_div_by_const(param: Parameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    // A single block always goes through the lookup table, NB_CTXT blocks go through the long
+    // division for most parameters
+    for num_blocks in [1, 2, NB_CTXT] {
+        // message_modulus^vec_length
+        let modulus = param.message_modulus.0.pow(num_blocks as u32) as u64;
+
+        for _ in 0..NB_TEST_SMALLER {
+            let clear = rng.gen::<u64>() % modulus;
+            // Divisors greater than the encrypted values are allowed
+            let divisor = rng.gen_range(1..=modulus + 1);
+
+            let ctxt = cks.encrypt_radix(clear, num_blocks);
+
+            let ct_res = sks.scalar_div_by_const_parallelized(&ctxt, divisor);
+            assert!(ct_res.block_carries_are_empty());
+            let dec_res: u64 = cks.decrypt_radix(&ct_res);
+            assert_eq!(dec_res, clear / divisor, "{clear} / {divisor}");
+        }
+    }
+}
+
+fn integer_default_add_sub_with_carry(param: Parameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = param.message_modulus.0.pow(NB_CTXT as u32) as u64;
+
+    for _ in 0..NB_TEST_SMALLER {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+        let clear_carry = rng.gen::<u64>() % 2;
+
+        let ctxt_0 = cks.encrypt(clear_0);
+        let ctxt_1 = cks.encrypt(clear_1);
+        let ctxt_carry = cks.encrypt_one_block(clear_carry);
+
+        let (ct_res, carry) = sks.add_with_carry_parallelized(&ctxt_0, &ctxt_1, &ctxt_carry);
+        assert!(ct_res.block_carries_are_empty());
+        assert_eq!(ct_res.blocks.len(), NB_CTXT);
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(dec_res, (clear_0 + clear_1 + clear_carry) % modulus);
+        let dec_carry = cks.decrypt_one_block(&carry);
+        assert_eq!(
+            dec_carry,
+            u64::from(clear_0 + clear_1 + clear_carry >= modulus)
+        );
+
+        // The returned carry can be chained
+        let previous_carry = dec_carry;
+        let (ct_res, carry) = sks.add_with_carry_parallelized(&ctxt_0, &ctxt_0, &carry);
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(dec_res, (2 * clear_0 + previous_carry) % modulus);
+        let dec_carry = cks.decrypt_one_block(&carry);
+        assert_eq!(
+            dec_carry,
+            u64::from(2 * clear_0 + previous_carry >= modulus)
+        );
+
+        let (ct_res, borrow) = sks.sub_with_borrow_parallelized(&ctxt_0, &ctxt_1, &ctxt_carry);
+        assert!(ct_res.block_carries_are_empty());
+        assert_eq!(ct_res.blocks.len(), NB_CTXT);
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(
+            dec_res,
+            (clear_0 + 2 * modulus - clear_1 - clear_carry) % modulus
+        );
+        let dec_borrow = cks.decrypt_one_block(&borrow);
+        assert_eq!(dec_borrow, u64::from(clear_0 < clear_1 + clear_carry));
+
+        // The returned borrow can be chained
+        let previous_borrow = dec_borrow;
+        let (ct_res, borrow) = sks.sub_with_borrow_parallelized(&ctxt_1, &ctxt_0, &borrow);
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(
+            dec_res,
+            (clear_1 + 2 * modulus - clear_0 - previous_borrow) % modulus
+        );
+        let dec_borrow = cks.decrypt_one_block(&borrow);
+        assert_eq!(dec_borrow, u64::from(clear_1 < clear_0 + previous_borrow));
+    }
+}
+
+fn integer_default_wrap_to_bits(param: Parameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = param.message_modulus.0.pow(NB_CTXT as u32) as u64;
+    let num_bits = modulus.ilog2() as usize;
+
+    for _ in 0..NB_TEST_SMALLER {
+        let clear = rng.gen::<u64>() % modulus;
+        // Widths beyond the ciphertext are allowed and leave it unchanged
+        let bits = rng.gen_range(0..=num_bits + 1);
+
+        let mut ct = cks.encrypt(clear);
+
+        sks.wrap_to_bits_parallelized(&mut ct, bits);
+        assert!(ct.block_carries_are_empty());
+        assert_eq!(ct.blocks.len(), NB_CTXT);
+        let dec_res: u64 = cks.decrypt(&ct);
+        assert_eq!(
+            dec_res,
+            clear % (1 << bits),
+            "{clear} wrapped to {bits} bits"
+        );
+    }
+}
@@ -260,6 +260,7 @@ impl WopbsKey {
                 degree: Degree(block.message_modulus.0 - 1),
                 message_modulus: block.message_modulus,
                 carry_modulus: block.carry_modulus,
+                has_padding_bit: block.has_padding_bit,
                 _order_marker: Default::default(),
             });
         }
@@ -344,6 +345,7 @@ impl WopbsKey {
                 degree: Degree(block.message_modulus.0 - 1),
                 message_modulus: block.message_modulus,
                 carry_modulus: block.carry_modulus,
+                has_padding_bit: block.has_padding_bit,
                 _order_marker: Default::default(),
             });
         }
@@ -1045,6 +1047,7 @@ impl WopbsKey {
                 degree: Degree(block.message_modulus.0 - 1),
                 message_modulus: block.message_modulus,
                 carry_modulus: block.carry_modulus,
+                has_padding_bit: block.has_padding_bit,
                 _order_marker: Default::default(),
             });
         }
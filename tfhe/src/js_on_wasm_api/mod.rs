@@ -36,3 +36,78 @@ pub(self) mod js_wasm_seeder {
         }
     }
 }
+
+#[cfg(feature = "shortint-client-js-wasm-api")]
+pub(self) mod progress {
+    use std::io::Read;
+    use wasm_bindgen::prelude::*;
+
+    /// Reader over a serialized buffer that reports the percentage of the buffer read so far to
+    /// a JS callback, each time it increases.
+    struct ProgressReader<'a> {
+        remaining: &'a [u8],
+        total: usize,
+        last_reported: Option<u32>,
+        callback: &'a js_sys::Function,
+    }
+
+    impl<'a> ProgressReader<'a> {
+        fn new(buffer: &'a [u8], callback: &'a js_sys::Function) -> Self {
+            Self {
+                remaining: buffer,
+                total: buffer.len(),
+                last_reported: None,
+                callback,
+            }
+        }
+
+        fn report(&mut self) -> std::io::Result<()> {
+            let read = self.total - self.remaining.len();
+            // Computed on 64 bits, as usize is 32 bits on wasm32 and the product would overflow
+            // for buffers of a few dozen MB
+            let percentage = if self.total == 0 {
+                100
+            } else {
+                (read as u64 * 100 / self.total as u64) as u32
+            };
+
+            if self.last_reported.map_or(true, |last| percentage > last) {
+                self.last_reported = Some(percentage);
+                self.callback
+                    .call1(&JsValue::NULL, &JsValue::from(percentage))
+                    .map_err(|e| {
+                        std::io::Error::new(
+                            std::io::ErrorKind::Other,
+                            format!("progress callback failed: {e:?}"),
+                        )
+                    })?;
+            }
+            Ok(())
+        }
+    }
+
+    impl<'a> Read for ProgressReader<'a> {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            let count = self.remaining.read(buf)?;
+            self.report()?;
+            Ok(count)
+        }
+    }
+
+    /// Deserializes `buffer` with bincode, calling `callback` with the percentage (from 0 to 100)
+    /// of the buffer deserialized so far each time it increases, e.g. to drive a progress bar.
+    pub fn deserialize_with_progress<T: serde::de::DeserializeOwned>(
+        buffer: &[u8],
+        callback: &js_sys::Function,
+    ) -> Result<T, JsError> {
+        let mut reader = ProgressReader::new(buffer, callback);
+        let value = bincode::deserialize_from(&mut reader)
+            .map_err(|e| wasm_bindgen::JsError::new(format!("{e:?}").as_str()))?;
+        // Trailing bytes are ignored by bincode, the deserialization is nonetheless complete
+        reader.remaining = &[];
+        reader
+            .report()
+            .map_err(|e| wasm_bindgen::JsError::new(format!("{e:?}").as_str()))?;
+        Ok(value)
+    }
+}
@@ -2,6 +2,7 @@ use bincode;
 use wasm_bindgen::prelude::*;
 
 use super::js_wasm_seeder;
+use super::progress::deserialize_with_progress;
 
 use std::panic::set_hook;
 
@@ -53,6 +54,34 @@ pub struct Shortint {}
 #[wasm_bindgen]
 pub struct ShortintParameters(pub(crate) crate::shortint::Parameters);
 
+#[wasm_bindgen]
+impl ShortintParameters {
+    #[wasm_bindgen]
+    pub fn message_modulus(&self) -> usize {
+        self.0.message_modulus.0
+    }
+
+    #[wasm_bindgen]
+    pub fn carry_modulus(&self) -> usize {
+        self.0.carry_modulus.0
+    }
+
+    #[wasm_bindgen]
+    pub fn lwe_dimension(&self) -> usize {
+        self.0.lwe_dimension.0
+    }
+
+    #[wasm_bindgen]
+    pub fn glwe_dimension(&self) -> usize {
+        self.0.glwe_dimension.0
+    }
+
+    #[wasm_bindgen]
+    pub fn polynomial_size(&self) -> usize {
+        self.0.polynomial_size.0
+    }
+}
+
 pub const SHORTINT_NATIVE_MODULUS: u64 = 0;
 
 #[wasm_bindgen]
@@ -131,6 +160,12 @@ impl Shortint {
         .map(ShortintParameters)
     }
 
+    #[wasm_bindgen]
+    pub fn parameter_name(parameters: &ShortintParameters) -> Option<String> {
+        set_hook(Box::new(console_error_panic_hook::hook));
+        crate::shortint::parameters::parameters_name(&parameters.0).map(str::to_string)
+    }
+
     #[wasm_bindgen]
     #[allow(clippy::too_many_arguments)]
     pub fn new_parameters(
@@ -189,6 +224,11 @@ impl Shortint {
         let seed_low_bytes: u128 = seed_low_bytes.into();
         let seed: u128 = (seed_high_bytes << 64) | seed_low_bytes;
 
+        parameters
+            .0
+            .validate()
+            .map_err(|e| wasm_bindgen::JsError::new(e.as_str()))?;
+
         let mut constant_seeder = Box::new(js_wasm_seeder::ConstantSeeder::new(
             crate::core_crypto::commons::math::random::Seed(seed),
         ));
@@ -203,11 +243,16 @@ impl Shortint {
     }
 
     #[wasm_bindgen]
-    pub fn new_client_key(parameters: &ShortintParameters) -> ShortintClientKey {
+    pub fn new_client_key(parameters: &ShortintParameters) -> Result<ShortintClientKey, JsError> {
         set_hook(Box::new(console_error_panic_hook::hook));
 
-        ShortintClientKey(crate::shortint::client_key::ClientKey::new(
-            parameters.0.to_owned(),
+        parameters
+            .0
+            .validate()
+            .map_err(|e| wasm_bindgen::JsError::new(e.as_str()))?;
+
+        Ok(ShortintClientKey(
+            crate::shortint::client_key::ClientKey::new(parameters.0.to_owned()),
         ))
     }
 
@@ -375,6 +420,23 @@ impl Shortint {
             .map(ShortintCiphertext)
     }
 
+    /// Deserializes a shortint ciphertext serialized on its own (e.g. a `CiphertextBig` or a
+    /// `CiphertextSmall` serialized with bincode by a Rust server), without knowing its PBS order
+    /// in advance.
+    ///
+    /// The serialized data of a shortint ciphertext records its PBS order, which is used to
+    /// return the ciphertext with the right kind.
+    #[wasm_bindgen]
+    pub fn deserialize_ciphertext_auto(buffer: &[u8]) -> Result<ShortintCiphertext, JsError> {
+        set_hook(Box::new(console_error_panic_hook::hook));
+        // Deserializing with the wrong PBS order fails, so at most one of the two succeeds
+        bincode::deserialize(buffer)
+            .map(ShortintCiphertextInner::Big)
+            .or_else(|_| bincode::deserialize(buffer).map(ShortintCiphertextInner::Small))
+            .map_err(|e| wasm_bindgen::JsError::new(format!("{e:?}").as_str()))
+            .map(ShortintCiphertext)
+    }
+
     #[wasm_bindgen]
     pub fn serialize_compressed_ciphertext(
         ciphertext: &ShortintCompressedCiphertext,
@@ -409,6 +471,17 @@ impl Shortint {
             .map(ShortintClientKey)
     }
 
+    /// Deserializes a client key, calling `callback` with the percentage (from 0 to 100) of the
+    /// buffer deserialized so far each time it increases, e.g. to drive a progress bar.
+    #[wasm_bindgen]
+    pub fn deserialize_client_key_with_progress(
+        buffer: &[u8],
+        callback: &js_sys::Function,
+    ) -> Result<ShortintClientKey, JsError> {
+        set_hook(Box::new(console_error_panic_hook::hook));
+        deserialize_with_progress(buffer, callback).map(ShortintClientKey)
+    }
+
     #[wasm_bindgen]
     pub fn serialize_public_key(public_key: &ShortintPublicKey) -> Result<Vec<u8>, JsError> {
         set_hook(Box::new(console_error_panic_hook::hook));
@@ -424,6 +497,17 @@ impl Shortint {
             .map(ShortintPublicKey)
     }
 
+    /// Deserializes a public key, calling `callback` with the percentage (from 0 to 100) of the
+    /// buffer deserialized so far each time it increases, e.g. to drive a progress bar.
+    #[wasm_bindgen]
+    pub fn deserialize_public_key_with_progress(
+        buffer: &[u8],
+        callback: &js_sys::Function,
+    ) -> Result<ShortintPublicKey, JsError> {
+        set_hook(Box::new(console_error_panic_hook::hook));
+        deserialize_with_progress(buffer, callback).map(ShortintPublicKey)
+    }
+
     #[wasm_bindgen]
     pub fn serialize_compressed_public_key(
         public_key: &ShortintCompressedPublicKey,
@@ -443,6 +527,17 @@ impl Shortint {
             .map(ShortintCompressedPublicKey)
     }
 
+    /// Deserializes a compressed public key, calling `callback` with the percentage (from 0 to 100)
+    /// of the buffer deserialized so far each time it increases, e.g. to drive a progress bar.
+    #[wasm_bindgen]
+    pub fn deserialize_compressed_public_key_with_progress(
+        buffer: &[u8],
+        callback: &js_sys::Function,
+    ) -> Result<ShortintCompressedPublicKey, JsError> {
+        set_hook(Box::new(console_error_panic_hook::hook));
+        deserialize_with_progress(buffer, callback).map(ShortintCompressedPublicKey)
+    }
+
     #[wasm_bindgen]
     pub fn serialize_compressed_server_key(
         server_key: &ShortintCompressedServerKey,
@@ -461,4 +556,15 @@ impl Shortint {
             .map_err(|e| wasm_bindgen::JsError::new(format!("{e:?}").as_str()))
             .map(ShortintCompressedServerKey)
     }
+
+    /// Deserializes a compressed server key, calling `callback` with the percentage (from 0 to 100)
+    /// of the buffer deserialized so far each time it increases, e.g. to drive a progress bar.
+    #[wasm_bindgen]
+    pub fn deserialize_compressed_server_key_with_progress(
+        buffer: &[u8],
+        callback: &js_sys::Function,
+    ) -> Result<ShortintCompressedServerKey, JsError> {
+        set_hook(Box::new(console_error_panic_hook::hook));
+        deserialize_with_progress(buffer, callback).map(ShortintCompressedServerKey)
+    }
 }
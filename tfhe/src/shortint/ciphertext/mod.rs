@@ -1,6 +1,7 @@
 //! Module with the definition of the Ciphertext.
 use crate::core_crypto::entities::*;
 use crate::shortint::parameters::{CarryModulus, MessageModulus};
+use rayon::prelude::*;
 use serde::{Deserialize, Serialize};
 use std::cmp;
 use std::fmt::Debug;
@@ -125,6 +126,7 @@ pub struct CiphertextBase<OpOrder: PBSOrderMarker> {
     pub degree: Degree,
     pub message_modulus: MessageModulus,
     pub carry_modulus: CarryModulus,
+    pub(crate) has_padding_bit: Option<bool>,
     pub _order_marker: PhantomData<OpOrder>,
 }
 
@@ -135,6 +137,150 @@ impl<OpOrder: PBSOrderMarker> CiphertextBase<OpOrder> {
     pub fn carry_is_empty(&self) -> bool {
         self.degree.0 < self.message_modulus.0
     }
+
+    /// Returns whether the message is encoded with a padding bit.
+    ///
+    /// Ciphertexts encrypted with `encrypt` carry a padding bit, whereas ciphertexts encrypted
+    /// with `encrypt_without_padding` or `encrypt_native_crt` use the whole torus to encode the
+    /// message and carries.
+    ///
+    /// Returns `None` for ciphertexts deserialized from data written before the encoding was
+    /// recorded, for which it is unknown.
+    pub fn carries_padding_bit(&self) -> Option<bool> {
+        self.has_padding_bit
+    }
+
+    /// Returns the degree of the ciphertext, i.e. the maximum value the encrypted message and
+    /// carry can take given the operations done so far.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::ciphertext::Degree;
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let ct_1 = cks.encrypt(1);
+    /// let ct_2 = cks.encrypt(2);
+    /// assert_eq!(ct_1.degree(), Degree(3));
+    ///
+    /// let ct_res = sks.unchecked_add(&ct_1, &ct_2);
+    /// assert_eq!(ct_res.degree(), Degree(6));
+    /// ```
+    pub fn degree(&self) -> Degree {
+        self.degree
+    }
+
+    /// Returns the message modulus of the ciphertext.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::{CarryModulus, MessageModulus, PARAM_MESSAGE_2_CARRY_2};
+    ///
+    /// let (cks, _sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let ct = cks.encrypt(1);
+    /// assert_eq!(ct.message_modulus(), MessageModulus(4));
+    /// assert_eq!(ct.carry_modulus(), CarryModulus(4));
+    /// ```
+    pub fn message_modulus(&self) -> MessageModulus {
+        self.message_modulus
+    }
+
+    /// Returns the carry modulus of the ciphertext.
+    ///
+    /// See [`message_modulus`](Self::message_modulus) for usage.
+    pub fn carry_modulus(&self) -> CarryModulus {
+        self.carry_modulus
+    }
+
+    /// Returns a view of the underlying [`LWE ciphertext`](`LweCiphertext`), to be used with the
+    /// [`core_crypto`](crate::core_crypto) algorithms.
+    pub fn as_lwe(&self) -> LweCiphertextView<'_, u64> {
+        self.ct.as_view()
+    }
+
+    /// Returns a mutable view of the underlying [`LWE ciphertext`](`LweCiphertext`), to be used
+    /// with the [`core_crypto`](crate::core_crypto) algorithms.
+    ///
+    /// # Warning
+    ///
+    /// The metadata of the ciphertext, and in particular its [`Degree`], is not updated by
+    /// modifications made through the returned view. Keeping it consistent with the encrypted
+    /// value, as well as keeping the noise within the bounds supported by the parameters, is the
+    /// caller's responsibility: shortint operations rely on the degree to decide when carries
+    /// must be cleaned, an incorrect degree leads to incorrect results.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::prelude::*;
+    /// use tfhe::shortint::ciphertext::Degree;
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, _sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let msg = 3;
+    /// let mut ct = cks.encrypt(msg);
+    ///
+    /// // Multiply the message by 2 directly on the LWE ciphertext
+    /// lwe_ciphertext_cleartext_mul_assign(&mut ct.as_lwe_mut(), Cleartext(2));
+    /// // The degree has to be updated manually
+    /// ct.degree = Degree(ct.degree.0 * 2);
+    ///
+    /// let dec = cks.decrypt_message_and_carry(&ct);
+    /// assert_eq!(dec, msg * 2);
+    /// ```
+    pub fn as_lwe_mut(&mut self) -> LweCiphertextMutView<'_, u64> {
+        self.ct.as_mut_view()
+    }
+}
+
+/// Serialized PBS order of a ciphertext, along with the metadata added to the format since.
+///
+/// The first variants have the layout of the serialized [`PBSOrder`] used before the padding bit
+/// was recorded, so that older ciphertexts can still be deserialized.
+#[derive(Serialize, Deserialize)]
+enum VersionedPBSOrder {
+    KeyswitchBootstrap,
+    BootstrapKeyswitch,
+    V1 {
+        op_order: PBSOrder,
+        has_padding_bit: bool,
+    },
+}
+
+impl VersionedPBSOrder {
+    fn new(op_order: PBSOrder, has_padding_bit: Option<bool>) -> Self {
+        match has_padding_bit {
+            Some(has_padding_bit) => Self::V1 {
+                op_order,
+                has_padding_bit,
+            },
+            // The encoding is still unknown, keep the older layout
+            None => match op_order {
+                PBSOrder::KeyswitchBootstrap => Self::KeyswitchBootstrap,
+                PBSOrder::BootstrapKeyswitch => Self::BootstrapKeyswitch,
+            },
+        }
+    }
+
+    fn into_parts(self) -> (PBSOrder, Option<bool>) {
+        match self {
+            Self::KeyswitchBootstrap => (PBSOrder::KeyswitchBootstrap, None),
+            Self::BootstrapKeyswitch => (PBSOrder::BootstrapKeyswitch, None),
+            Self::V1 {
+                op_order,
+                has_padding_bit,
+            } => (op_order, Some(has_padding_bit)),
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize)]
@@ -143,7 +289,7 @@ struct SerialiazableCiphertextBase {
     pub degree: Degree,
     pub message_modulus: MessageModulus,
     pub carry_modulus: CarryModulus,
-    pub op_order: PBSOrder,
+    pub op_order: VersionedPBSOrder,
 }
 
 // Manual impl to be able to carry the OpOrder information
@@ -157,7 +303,7 @@ impl<OpOrder: PBSOrderMarker> Serialize for CiphertextBase<OpOrder> {
             degree: self.degree,
             message_modulus: self.message_modulus,
             carry_modulus: self.carry_modulus,
-            op_order: OpOrder::pbs_order(),
+            op_order: VersionedPBSOrder::new(OpOrder::pbs_order(), self.has_padding_bit),
         }
         .serialize(serializer)
     }
@@ -170,12 +316,13 @@ impl<'de, OpOrder: PBSOrderMarker> Deserialize<'de> for CiphertextBase<OpOrder>
         D: serde::Deserializer<'de>,
     {
         let intermediate = SerialiazableCiphertextBase::deserialize(deserializer)?;
-        if intermediate.op_order != OpOrder::pbs_order() {
+        let (op_order, has_padding_bit) = intermediate.op_order.into_parts();
+        if op_order != OpOrder::pbs_order() {
             return Err(serde::de::Error::custom(format!(
                 "Expected PBSOrder: {:?}, got {:?}, \
                 did you mix CiphertextBig ({:?}) and CiphertextSmall ({:?})?",
                 OpOrder::pbs_order(),
-                intermediate.op_order,
+                op_order,
                 PBSOrder::KeyswitchBootstrap,
                 PBSOrder::BootstrapKeyswitch
             )));
@@ -186,6 +333,7 @@ impl<'de, OpOrder: PBSOrderMarker> Deserialize<'de> for CiphertextBase<OpOrder>
             degree: intermediate.degree,
             message_modulus: intermediate.message_modulus,
             carry_modulus: intermediate.carry_modulus,
+            has_padding_bit,
             _order_marker: Default::default(),
         })
     }
@@ -200,6 +348,7 @@ pub struct CompressedCiphertextBase<OpOrder: PBSOrderMarker> {
     pub degree: Degree,
     pub message_modulus: MessageModulus,
     pub carry_modulus: CarryModulus,
+    pub(crate) has_padding_bit: Option<bool>,
     pub _order_marker: PhantomData<OpOrder>,
 }
 
@@ -212,7 +361,7 @@ struct SerialiazableCompressedCiphertextBase {
     pub degree: Degree,
     pub message_modulus: MessageModulus,
     pub carry_modulus: CarryModulus,
-    pub op_order: PBSOrder,
+    pub op_order: VersionedPBSOrder,
 }
 
 // Manual impl to be able to carry the OpOrder information
@@ -226,7 +375,7 @@ impl<OpOrder: PBSOrderMarker> Serialize for CompressedCiphertextBase<OpOrder> {
             degree: self.degree,
             message_modulus: self.message_modulus,
             carry_modulus: self.carry_modulus,
-            op_order: OpOrder::pbs_order(),
+            op_order: VersionedPBSOrder::new(OpOrder::pbs_order(), self.has_padding_bit),
         }
         .serialize(serializer)
     }
@@ -239,12 +388,13 @@ impl<'de, OpOrder: PBSOrderMarker> Deserialize<'de> for CompressedCiphertextBase
         D: serde::Deserializer<'de>,
     {
         let intermediate = SerialiazableCompressedCiphertextBase::deserialize(deserializer)?;
-        if intermediate.op_order != OpOrder::pbs_order() {
+        let (op_order, has_padding_bit) = intermediate.op_order.into_parts();
+        if op_order != OpOrder::pbs_order() {
             return Err(serde::de::Error::custom(format!(
                 "Expected PBSOrder: {:?}, got {:?}, \
                     did you mix CompressedCiphertextBig ({:?}) and CompressedCiphertextSmall ({:?})?",
                 OpOrder::pbs_order(),
-                intermediate.op_order,
+                op_order,
                 PBSOrder::KeyswitchBootstrap,
                 PBSOrder::BootstrapKeyswitch
             )));
@@ -255,18 +405,27 @@ impl<'de, OpOrder: PBSOrderMarker> Deserialize<'de> for CompressedCiphertextBase
             degree: intermediate.degree,
             message_modulus: intermediate.message_modulus,
             carry_modulus: intermediate.carry_modulus,
+            has_padding_bit,
             _order_marker: Default::default(),
         })
     }
 }
 
 impl<OpOrder: PBSOrderMarker> CompressedCiphertextBase<OpOrder> {
+    /// Returns whether the message is encoded with a padding bit.
+    ///
+    /// See [`CiphertextBase::carries_padding_bit`].
+    pub fn carries_padding_bit(&self) -> Option<bool> {
+        self.has_padding_bit
+    }
+
     pub fn decompress(self) -> CiphertextBase<OpOrder> {
         let CompressedCiphertextBase {
             ct,
             degree,
             message_modulus,
             carry_modulus,
+            has_padding_bit,
             _order_marker,
         } = self;
 
@@ -275,6 +434,7 @@ impl<OpOrder: PBSOrderMarker> CompressedCiphertextBase<OpOrder> {
             degree,
             message_modulus,
             carry_modulus,
+            has_padding_bit,
             _order_marker,
         }
     }
@@ -285,3 +445,37 @@ impl<OpOrder: PBSOrderMarker> From<CompressedCiphertextBase<OpOrder>> for Cipher
         value.decompress()
     }
 }
+
+/// Decompresses a slice of compressed ciphertexts, the decompressions being computed in parallel.
+///
+/// The result is identical to decompressing each ciphertext sequentially with
+/// [`CompressedCiphertextBase::decompress`].
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+/// use tfhe::shortint::{decompress_many_parallelized, ClientKey};
+///
+/// let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2);
+///
+/// let msgs = [0, 1, 2, 3];
+/// let compressed: Vec<_> = msgs
+///     .iter()
+///     .map(|&msg| cks.encrypt_compressed(msg))
+///     .collect();
+///
+/// let cts = decompress_many_parallelized(&compressed);
+///
+/// for (msg, ct) in msgs.iter().zip(cts.iter()) {
+///     assert_eq!(*msg, cks.decrypt(ct));
+/// }
+/// ```
+pub fn decompress_many_parallelized<OpOrder: PBSOrderMarker>(
+    compressed_ciphertexts: &[CompressedCiphertextBase<OpOrder>],
+) -> Vec<CiphertextBase<OpOrder>> {
+    compressed_ciphertexts
+        .par_iter()
+        .map(|compressed_ciphertext| compressed_ciphertext.clone().decompress())
+        .collect()
+}
@@ -6,7 +6,7 @@ use crate::shortint::ciphertext::{
     CompressedCiphertextSmall, PBSOrderMarker,
 };
 use crate::shortint::engine::ShortintEngine;
-use crate::shortint::parameters::{MessageModulus, Parameters};
+use crate::shortint::parameters::{MessageModulus, Parameters, StandardDev};
 use serde::{Deserialize, Serialize};
 use std::fmt::Debug;
 
@@ -28,6 +28,28 @@ pub struct ClientKey {
     pub parameters: Parameters,
 }
 
+/// The result of a checked decryption, see [`ClientKey::decrypt_message_and_carry_checked`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CheckedDecryption {
+    /// The decrypted message and carry, as returned by [`ClientKey::decrypt_message_and_carry`].
+    pub message_and_carry: u64,
+    /// The fraction of the decoding bound that was left unused by the noise, in `[0, 1]`.
+    ///
+    /// A value of `1` means the decrypted plaintext was exactly on an encoding, a value close to
+    /// `0` means the noise was close to half the distance between two encodings and that the
+    /// decryption was borderline.
+    pub noise_margin: f64,
+}
+
+#[cfg(feature = "zeroize-secret-keys")]
+impl Drop for ClientKey {
+    fn drop(&mut self) {
+        self.large_lwe_secret_key.zeroize();
+        self.glwe_secret_key.zeroize();
+        self.small_lwe_secret_key.zeroize();
+    }
+}
+
 impl ClientKey {
     /// Generate a client key.
     ///
@@ -75,6 +97,70 @@ impl ClientKey {
         ShortintEngine::with_thread_local_mut(|engine| engine.encrypt(self, message).unwrap())
     }
 
+    /// Encrypt a small integer message using the client key, with the given standard deviation
+    /// for the encryption noise instead of the one of the parameters.
+    ///
+    /// The standard deviation replaces the one the parameters use for this kind of ciphertext,
+    /// i.e. `glwe_modular_std_dev` for a [`CiphertextBig`] encrypted under the large LWE key.
+    /// This is meant for testing, e.g. to build ciphertexts close to the decryption threshold and
+    /// check how operations behave on them. Encrypting with a standard deviation smaller than the
+    /// one of the parameters is not secure.
+    ///
+    /// The input message is reduced to the encrypted message space modulus
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::{StandardDev, PARAM_MESSAGE_2_CARRY_2};
+    /// use tfhe::shortint::ClientKey;
+    ///
+    /// let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let msg = 3;
+    ///
+    /// // Noiseless encryption, the decrypted plaintext is exactly on the encoding
+    /// let ct = cks.encrypt_with_std_dev(msg, StandardDev(0.0));
+    /// let dec = cks.decrypt_message_and_carry_checked(&ct);
+    /// assert_eq!(msg, dec.message_and_carry);
+    /// assert_eq!(dec.noise_margin, 1.0);
+    /// ```
+    pub fn encrypt_with_std_dev(&self, message: u64, std_dev: StandardDev) -> CiphertextBig {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine.encrypt_with_std_dev(self, message, std_dev).unwrap()
+        })
+    }
+
+    /// Encrypt a small integer message using the client key, with the given standard deviation
+    /// for the encryption noise instead of the one of the parameters.
+    ///
+    /// The standard deviation replaces `lwe_modular_std_dev` for the [`CiphertextSmall`]
+    /// encrypted under the small LWE key. See [`ClientKey::encrypt_with_std_dev`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::{StandardDev, PARAM_SMALL_MESSAGE_2_CARRY_2};
+    /// use tfhe::shortint::ClientKey;
+    ///
+    /// let cks = ClientKey::new(PARAM_SMALL_MESSAGE_2_CARRY_2);
+    ///
+    /// let msg = 3;
+    ///
+    /// let ct = cks.encrypt_with_std_dev_small(msg, StandardDev(0.0));
+    /// let dec = cks.decrypt_message_and_carry_checked(&ct);
+    /// assert_eq!(msg, dec.message_and_carry);
+    /// assert_eq!(dec.noise_margin, 1.0);
+    /// ```
+    pub fn encrypt_with_std_dev_small(
+        &self,
+        message: u64,
+        std_dev: StandardDev,
+    ) -> CiphertextSmall {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine.encrypt_with_std_dev(self, message, std_dev).unwrap()
+        })
+    }
+
     /// Encrypt a small integer message using the client key.
     ///
     /// The input message is reduced to the encrypted message space modulus
@@ -106,6 +192,62 @@ impl ClientKey {
         ShortintEngine::with_thread_local_mut(|engine| engine.encrypt(self, message).unwrap())
     }
 
+    /// Encrypt a slice of small integer messages using the client key.
+    ///
+    /// Each input message is reduced to the encrypted message space modulus.
+    ///
+    /// This is equivalent to calling [`ClientKey::encrypt`] on each message, but the thread local
+    /// engine is only borrowed once for all the encryptions.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    /// use tfhe::shortint::ClientKey;
+    ///
+    /// let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let msgs = [0, 1, 2, 3, 5];
+    /// let cts = cks.encrypt_many(&msgs);
+    ///
+    /// let modulus = cks.parameters.message_modulus.0 as u64;
+    /// for (msg, ct) in msgs.iter().zip(cts.iter()) {
+    ///     let dec = cks.decrypt(ct);
+    ///     assert_eq!(msg % modulus, dec);
+    /// }
+    /// ```
+    pub fn encrypt_many(&self, messages: &[u64]) -> Vec<CiphertextBig> {
+        ShortintEngine::with_thread_local_mut(|engine| engine.encrypt_many(self, messages).unwrap())
+    }
+
+    /// Encrypt a slice of small integer messages using the client key.
+    ///
+    /// Each input message is reduced to the encrypted message space modulus.
+    ///
+    /// This is equivalent to calling [`ClientKey::encrypt_small`] on each message, but the thread
+    /// local engine is only borrowed once for all the encryptions.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::PARAM_SMALL_MESSAGE_2_CARRY_2;
+    /// use tfhe::shortint::ClientKey;
+    ///
+    /// let cks = ClientKey::new(PARAM_SMALL_MESSAGE_2_CARRY_2);
+    ///
+    /// let msgs = [0, 1, 2, 3, 5];
+    /// let cts = cks.encrypt_many_small(&msgs);
+    ///
+    /// let modulus = cks.parameters.message_modulus.0 as u64;
+    /// for (msg, ct) in msgs.iter().zip(cts.iter()) {
+    ///     let dec = cks.decrypt(ct);
+    ///     assert_eq!(msg % modulus, dec);
+    /// }
+    /// ```
+    pub fn encrypt_many_small(&self, messages: &[u64]) -> Vec<CiphertextSmall> {
+        ShortintEngine::with_thread_local_mut(|engine| engine.encrypt_many(self, messages).unwrap())
+    }
+
     /// Encrypt a small integer message using the client key returning a compressed ciphertext.
     ///
     /// The input message is reduced to the encrypted message space modulus
@@ -406,6 +548,46 @@ impl ClientKey {
         })
     }
 
+    /// Decrypt a ciphertext encrypting an integer message and carries using the client key and
+    /// estimate how close the noise was to the decoding bound.
+    ///
+    /// The decoded value is the same as the one returned by
+    /// [`ClientKey::decrypt_message_and_carry`]. The noise margin is computed from the plaintext
+    /// before rounding, see [`CheckedDecryption::noise_margin`].
+    ///
+    /// Note that noise exceeding the decoding bound cannot be detected, as the plaintext then
+    /// rounds to another encoding: a small margin is the sign that the parameters are misused or
+    /// that too many operations were computed without bootstrapping.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    /// use tfhe::shortint::ClientKey;
+    ///
+    /// // Generate the client key
+    /// let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let msg = 3;
+    ///
+    /// // Encryption of one message:
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// // Decryption:
+    /// let dec = cks.decrypt_message_and_carry_checked(&ct);
+    /// assert_eq!(msg, dec.message_and_carry);
+    /// // A fresh encryption has a small noise
+    /// assert!(dec.noise_margin > 0.5);
+    /// ```
+    pub fn decrypt_message_and_carry_checked<OpOrder: PBSOrderMarker>(
+        &self,
+        ct: &CiphertextBase<OpOrder>,
+    ) -> CheckedDecryption {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine.decrypt_message_and_carry_checked(self, ct).unwrap()
+        })
+    }
+
     /// Decrypt a ciphertext encrypting a message using the client key.
     ///
     /// # Example
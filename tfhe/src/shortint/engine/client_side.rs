@@ -1,9 +1,10 @@
 //! All the `ShortintEngine` method related to client side (encrypt / decrypt)
 use super::{EngineResult, ShortintEngine};
 use crate::core_crypto::algorithms::*;
-use crate::core_crypto::commons::dispersion::DispersionParameter;
+use crate::core_crypto::commons::dispersion::{DispersionParameter, StandardDev};
 use crate::core_crypto::entities::*;
 use crate::shortint::ciphertext::Degree;
+use crate::shortint::client_key::CheckedDecryption;
 use crate::shortint::parameters::{CarryModulus, MessageModulus};
 use crate::shortint::{
     CiphertextBase, ClientKey, CompressedCiphertextBase, PBSOrder, PBSOrderMarker, Parameters,
@@ -47,6 +48,17 @@ impl ShortintEngine {
         )
     }
 
+    pub(crate) fn encrypt_many<OpOrder: PBSOrderMarker>(
+        &mut self,
+        client_key: &ClientKey,
+        messages: &[u64],
+    ) -> EngineResult<Vec<CiphertextBase<OpOrder>>> {
+        messages
+            .iter()
+            .map(|&message| self.encrypt(client_key, message))
+            .collect()
+    }
+
     pub fn encrypt_compressed<OpOrder: PBSOrderMarker>(
         &mut self,
         client_key: &ClientKey,
@@ -94,15 +106,43 @@ impl ShortintEngine {
         message: u64,
         message_modulus: MessageModulus,
     ) -> EngineResult<CiphertextBase<OpOrder>> {
-        let (encryption_lwe_sk, encryption_noise) = match OpOrder::pbs_order() {
-            PBSOrder::KeyswitchBootstrap => (
-                &client_key.large_lwe_secret_key,
-                client_key.parameters.glwe_modular_std_dev,
-            ),
-            PBSOrder::BootstrapKeyswitch => (
-                &client_key.small_lwe_secret_key,
-                client_key.parameters.lwe_modular_std_dev,
-            ),
+        let encryption_noise = match OpOrder::pbs_order() {
+            PBSOrder::KeyswitchBootstrap => client_key.parameters.glwe_modular_std_dev,
+            PBSOrder::BootstrapKeyswitch => client_key.parameters.lwe_modular_std_dev,
+        };
+
+        self.encrypt_with_message_modulus_and_std_dev(
+            client_key,
+            message,
+            message_modulus,
+            encryption_noise,
+        )
+    }
+
+    pub(crate) fn encrypt_with_std_dev<OpOrder: PBSOrderMarker>(
+        &mut self,
+        client_key: &ClientKey,
+        message: u64,
+        std_dev: StandardDev,
+    ) -> EngineResult<CiphertextBase<OpOrder>> {
+        self.encrypt_with_message_modulus_and_std_dev(
+            client_key,
+            message,
+            client_key.parameters.message_modulus,
+            std_dev,
+        )
+    }
+
+    fn encrypt_with_message_modulus_and_std_dev<OpOrder: PBSOrderMarker>(
+        &mut self,
+        client_key: &ClientKey,
+        message: u64,
+        message_modulus: MessageModulus,
+        encryption_noise: StandardDev,
+    ) -> EngineResult<CiphertextBase<OpOrder>> {
+        let encryption_lwe_sk = match OpOrder::pbs_order() {
+            PBSOrder::KeyswitchBootstrap => &client_key.large_lwe_secret_key,
+            PBSOrder::BootstrapKeyswitch => &client_key.small_lwe_secret_key,
         };
 
         let ct = self.encrypt_inner_ct(
@@ -124,6 +164,7 @@ impl ShortintEngine {
             degree: Degree(message_modulus.0 - 1),
             message_modulus,
             carry_modulus: CarryModulus(carry_modulus),
+            has_padding_bit: Some(true),
             _order_marker: Default::default(),
         })
     }
@@ -176,6 +217,7 @@ impl ShortintEngine {
             degree: Degree(message_modulus.0 - 1),
             message_modulus,
             carry_modulus: CarryModulus(carry_modulus),
+            has_padding_bit: Some(true),
             _order_marker: Default::default(),
         })
     }
@@ -218,6 +260,7 @@ impl ShortintEngine {
             ),
             message_modulus: client_key.parameters.message_modulus,
             carry_modulus: client_key.parameters.carry_modulus,
+            has_padding_bit: Some(true),
             _order_marker: Default::default(),
         })
     }
@@ -227,6 +270,20 @@ impl ShortintEngine {
         client_key: &ClientKey,
         ct: &CiphertextBase<OpOrder>,
     ) -> EngineResult<u64> {
+        self.decrypt_message_and_carry_checked(client_key, ct)
+            .map(|decrypted| decrypted.message_and_carry)
+    }
+
+    pub(crate) fn decrypt_message_and_carry_checked<OpOrder: PBSOrderMarker>(
+        &mut self,
+        client_key: &ClientKey,
+        ct: &CiphertextBase<OpOrder>,
+    ) -> EngineResult<CheckedDecryption> {
+        assert!(
+            ct.has_padding_bit != Some(false),
+            "This operation requires a ciphertext encoded with a padding bit"
+        );
+
         let lwe_decryption_key = match OpOrder::pbs_order() {
             PBSOrder::KeyswitchBootstrap => &client_key.large_lwe_secret_key,
             PBSOrder::BootstrapKeyswitch => &client_key.small_lwe_secret_key,
@@ -247,7 +304,18 @@ impl ShortintEngine {
         //compute the rounding bit
         let rounding = (decrypted_u64 & rounding_bit) << 1;
 
-        Ok((decrypted_u64.wrapping_add(rounding)) / delta)
+        let message_and_carry = (decrypted_u64.wrapping_add(rounding)) / delta;
+
+        // The noise is the distance between the decrypted value and the closest encoding
+        let noise = (decrypted_u64.wrapping_sub(message_and_carry.wrapping_mul(delta)) as i64)
+            .unsigned_abs();
+
+        let noise_margin = 1.0 - noise as f64 / rounding_bit as f64;
+
+        Ok(CheckedDecryption {
+            message_and_carry,
+            noise_margin,
+        })
     }
 
     pub fn decrypt<OpOrder: PBSOrderMarker>(
@@ -298,6 +366,7 @@ impl ShortintEngine {
             degree: Degree(client_key.parameters.message_modulus.0 - 1),
             message_modulus: client_key.parameters.message_modulus,
             carry_modulus: client_key.parameters.carry_modulus,
+            has_padding_bit: Some(false),
             _order_marker: Default::default(),
         })
     }
@@ -341,6 +410,7 @@ impl ShortintEngine {
             degree: Degree(client_key.parameters.message_modulus.0 - 1),
             message_modulus: client_key.parameters.message_modulus,
             carry_modulus: client_key.parameters.carry_modulus,
+            has_padding_bit: Some(false),
             _order_marker: Default::default(),
         })
     }
@@ -350,6 +420,11 @@ impl ShortintEngine {
         client_key: &ClientKey,
         ct: &CiphertextBase<OpOrder>,
     ) -> EngineResult<u64> {
+        assert!(
+            ct.has_padding_bit != Some(true),
+            "This operation requires a ciphertext encoded without a padding bit"
+        );
+
         let lwe_decryption_key = match OpOrder::pbs_order() {
             PBSOrder::KeyswitchBootstrap => &client_key.large_lwe_secret_key,
             PBSOrder::BootstrapKeyswitch => &client_key.small_lwe_secret_key,
@@ -419,6 +494,7 @@ impl ShortintEngine {
             degree: Degree(message_modulus as usize - 1),
             message_modulus: MessageModulus(message_modulus as usize),
             carry_modulus: CarryModulus(carry_modulus),
+            has_padding_bit: Some(false),
             _order_marker: Default::default(),
         })
     }
@@ -459,6 +535,7 @@ impl ShortintEngine {
             degree: Degree(message_modulus as usize - 1),
             message_modulus: MessageModulus(message_modulus as usize),
             carry_modulus: CarryModulus(carry_modulus),
+            has_padding_bit: Some(false),
             _order_marker: Default::default(),
         })
     }
@@ -469,6 +546,11 @@ impl ShortintEngine {
         ct: &CiphertextBase<OpOrder>,
         basis: u64,
     ) -> EngineResult<u64> {
+        assert!(
+            ct.has_padding_bit != Some(true),
+            "This operation requires a ciphertext encoded without a padding bit"
+        );
+
         let lwe_decryption_key = match OpOrder::pbs_order() {
             PBSOrder::KeyswitchBootstrap => &client_key.large_lwe_secret_key,
             PBSOrder::BootstrapKeyswitch => &client_key.small_lwe_secret_key,
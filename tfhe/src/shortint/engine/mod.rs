@@ -3,11 +3,13 @@
 //! Engines are required to abstract cryptographic notions and efficiently manage memory from the
 //! underlying `core_crypto` module.
 
+use crate::core_crypto::algorithms::polynomial_algorithms::polynomial_wrapping_monic_monomial_div_assign;
 use crate::core_crypto::commons::computation_buffers::ComputationBuffers;
 use crate::core_crypto::commons::generators::{
     DeterministicSeeder, EncryptionRandomGenerator, SecretRandomGenerator,
 };
 use crate::core_crypto::commons::math::random::{ActivatedRandomGenerator, Seeder};
+use crate::core_crypto::commons::parameters::{MonomialDegree, PolynomialSize};
 use crate::core_crypto::entities::*;
 use crate::core_crypto::prelude::ContainerMut;
 use crate::core_crypto::seeders::new_seeder;
@@ -19,7 +21,7 @@ use crate::shortint::ServerKey;
 use std::cell::RefCell;
 use std::fmt::Debug;
 
-use super::parameters::MessageModulus;
+use super::parameters::{CarryModulus, MessageModulus};
 use super::server_key::BivariateLookupTable;
 
 mod client_side;
@@ -32,6 +34,29 @@ thread_local! {
     static LOCAL_ENGINE: RefCell<ShortintEngine> = RefCell::new(ShortintEngine::new());
 }
 
+/// Number of programmable bootstraps performed by all the engines, see
+/// [`ServerKey::pbs_count`](crate::shortint::ServerKey::pbs_count).
+#[cfg(feature = "pbs-count")]
+static PBS_COUNT: std::sync::atomic::AtomicU64 = std::sync::atomic::AtomicU64::new(0);
+
+/// Records that a programmable bootstrap was performed, this compiles to nothing unless the
+/// `pbs-count` feature is enabled.
+#[inline(always)]
+pub(crate) fn count_pbs() {
+    #[cfg(feature = "pbs-count")]
+    PBS_COUNT.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+}
+
+#[cfg(feature = "pbs-count")]
+pub(crate) fn pbs_count() -> u64 {
+    PBS_COUNT.load(std::sync::atomic::Ordering::Relaxed)
+}
+
+#[cfg(feature = "pbs-count")]
+pub(crate) fn reset_pbs_count() {
+    PBS_COUNT.store(0, std::sync::atomic::Ordering::Relaxed);
+}
+
 pub struct BuffersRef<'a> {
     pub(crate) accumulator: LookupTableMutView<'a>,
     // For the intermediate keyswitch result in the case of a big ciphertext
@@ -97,6 +122,33 @@ impl Memory {
     }
 }
 
+/// Checks that a lookup table for the given moduli can be encoded in a polynomial of the given
+/// size.
+///
+/// Each of the `message_modulus * carry_modulus` possible inputs is mapped to a box of
+/// `polynomial_size / (message_modulus * carry_modulus)` coefficients. The accumulator is rotated
+/// by half a box so that the noise around an input, in either direction, still lands in the box
+/// of that input: a box needs at least 2 coefficients, otherwise inputs with a negative noise are
+/// mapped to the previous box and the lookup table returns silently wrong results.
+///
+/// # Panics
+///
+/// Panics if the boxes have less than 2 coefficients or if they do not evenly divide the
+/// polynomial.
+pub(crate) fn assert_lut_is_resolvable(
+    message_modulus: MessageModulus,
+    carry_modulus: CarryModulus,
+    polynomial_size: PolynomialSize,
+) {
+    let modulus_sup = message_modulus.0 * carry_modulus.0;
+    assert!(
+        polynomial_size.0 % modulus_sup == 0 && polynomial_size.0 / modulus_sup >= 2,
+        "The lookup table cannot resolve the {modulus_sup} inputs of {message_modulus:?} and \
+        {carry_modulus:?} with a {polynomial_size:?}, it must be a multiple of 2 * {modulus_sup} \
+        for each input to have at least 2 coefficients"
+    );
+}
+
 fn fill_accumulator<F, C>(accumulator: &mut GlweCiphertext<C>, server_key: &ServerKey, f: F) -> u64
 where
     C: ContainerMut<Element = u64>,
@@ -110,6 +162,11 @@ where
         accumulator.glwe_size(),
         server_key.bootstrapping_key.glwe_size()
     );
+    assert_lut_is_resolvable(
+        server_key.message_modulus,
+        server_key.carry_modulus,
+        server_key.bootstrapping_key.polynomial_size(),
+    );
 
     let mut accumulator_view = accumulator.as_mut_view();
 
@@ -265,6 +322,31 @@ impl ShortintEngine {
         })
     }
 
+    /// Generates an accumulator whose body is rotated by `rotation` input values, see
+    /// [`ServerKey::generate_accumulator_with_rotation`]
+    fn generate_accumulator_with_rotation_with_engine<F>(
+        server_key: &ServerKey,
+        f: F,
+        rotation: usize,
+    ) -> EngineResult<LookupTableOwned>
+    where
+        F: Fn(u64) -> u64,
+    {
+        let mut accumulator = Self::generate_accumulator_with_engine(server_key, f)?;
+
+        let modulus_sup = server_key.message_modulus.0 * server_key.carry_modulus.0;
+        let box_size = server_key.bootstrapping_key.polynomial_size().0 / modulus_sup;
+
+        // Dividing by X^(rotation * box_size) shifts the inputs by rotation boxes, the values
+        // wrapping around the end of the polynomial are negated
+        polynomial_wrapping_monic_monomial_div_assign(
+            &mut accumulator.acc.get_mut_body().as_mut_polynomial(),
+            MonomialDegree(rotation * box_size),
+        );
+
+        Ok(accumulator)
+    }
+
     /// Generates a bivariate accumulator
     fn generate_accumulator_bivariate_with_engine<F>(
         server_key: &ServerKey,
@@ -185,6 +185,7 @@ impl ShortintEngine {
             degree: Degree(message_modulus.0 - 1),
             message_modulus,
             carry_modulus: CarryModulus(carry_modulus),
+            has_padding_bit: Some(true),
             _order_marker: Default::default(),
         })
     }
@@ -235,6 +236,7 @@ impl ShortintEngine {
             degree: Degree(message_modulus.0 - 1),
             message_modulus,
             carry_modulus: CarryModulus(carry_modulus),
+            has_padding_bit: Some(true),
             _order_marker: Default::default(),
         })
     }
@@ -274,6 +276,7 @@ impl ShortintEngine {
             degree: Degree(public_key.parameters.message_modulus.0 - 1),
             message_modulus: public_key.parameters.message_modulus,
             carry_modulus: public_key.parameters.carry_modulus,
+            has_padding_bit: Some(false),
             _order_marker: Default::default(),
         })
     }
@@ -313,6 +316,7 @@ impl ShortintEngine {
             degree: Degree(public_key.parameters.message_modulus.0 - 1),
             message_modulus: public_key.parameters.message_modulus,
             carry_modulus: public_key.parameters.carry_modulus,
+            has_padding_bit: Some(false),
             _order_marker: Default::default(),
         })
     }
@@ -349,6 +353,7 @@ impl ShortintEngine {
             degree: Degree(message_modulus as usize - 1),
             message_modulus: MessageModulus(message_modulus as usize),
             carry_modulus: CarryModulus(carry_modulus),
+            has_padding_bit: Some(false),
             _order_marker: Default::default(),
         })
     }
@@ -385,6 +390,7 @@ impl ShortintEngine {
             degree: Degree(message_modulus as usize - 1),
             message_modulus: MessageModulus(message_modulus as usize),
             carry_modulus: CarryModulus(carry_modulus),
+            has_padding_bit: Some(false),
             _order_marker: Default::default(),
         })
     }
@@ -422,6 +428,7 @@ impl ShortintEngine {
             ),
             message_modulus: public_key.parameters.message_modulus,
             carry_modulus: public_key.parameters.carry_modulus,
+            has_padding_bit: Some(true),
             _order_marker: Default::default(),
         })
     }
@@ -459,6 +466,7 @@ impl ShortintEngine {
             ),
             message_modulus: public_key.parameters.message_modulus,
             carry_modulus: public_key.parameters.carry_modulus,
+            has_padding_bit: Some(true),
             _order_marker: Default::default(),
         })
     }
@@ -5,7 +5,7 @@ use crate::core_crypto::entities::*;
 use crate::core_crypto::fft_impl::fft64::crypto::bootstrap::FourierLweBootstrapKey;
 use crate::core_crypto::fft_impl::fft64::math::fft::Fft;
 use crate::shortint::ciphertext::Degree;
-use crate::shortint::engine::EngineResult;
+use crate::shortint::engine::{count_pbs, EngineResult};
 use crate::shortint::parameters::MessageModulus;
 use crate::shortint::server_key::{BivariateLookupTableOwned, LookupTableOwned, MaxDegree};
 use crate::shortint::{
@@ -171,6 +171,18 @@ impl ShortintEngine {
         Self::generate_accumulator_with_engine(server_key, f)
     }
 
+    pub(crate) fn generate_accumulator_with_rotation<F>(
+        &mut self,
+        server_key: &ServerKey,
+        f: F,
+        rotation: usize,
+    ) -> EngineResult<LookupTableOwned>
+    where
+        F: Fn(u64) -> u64,
+    {
+        Self::generate_accumulator_with_rotation_with_engine(server_key, f, rotation)
+    }
+
     pub(crate) fn keyswitch_bootstrap_assign(
         &mut self,
         server_key: &ServerKey,
@@ -203,6 +215,7 @@ impl ShortintEngine {
         let stack = buffers.stack();
 
         // Compute a bootstrap
+        count_pbs();
         programmable_bootstrap_lwe_ciphertext_mem_optimized(
             &ciphertext_buffers.buffer_lwe_after_ks,
             &mut ct.ct,
@@ -278,6 +291,7 @@ impl ShortintEngine {
         let stack = buffers.stack();
 
         // Compute a bootstrap
+        count_pbs();
         programmable_bootstrap_lwe_ciphertext_mem_optimized(
             &ciphertext_buffers.buffer_lwe_after_ks,
             &mut ct.ct,
@@ -473,6 +487,7 @@ impl ShortintEngine {
         let stack = buffers.stack();
 
         // Compute a bootstrap
+        count_pbs();
         programmable_bootstrap_lwe_ciphertext_mem_optimized(
             &ct.ct,
             &mut ciphertext_buffers.buffer_lwe_after_pbs,
@@ -519,6 +534,7 @@ impl ShortintEngine {
         let stack = buffers.stack();
 
         // Compute a bootstrap
+        count_pbs();
         programmable_bootstrap_lwe_ciphertext_mem_optimized(
             &ct.ct,
             &mut ciphertext_buffers.buffer_lwe_after_pbs,
@@ -540,6 +556,31 @@ impl ShortintEngine {
         Ok(())
     }
 
+    pub(crate) fn keyswitch_only(
+        &mut self,
+        server_key: &ServerKey,
+        ct: &CiphertextBig,
+    ) -> EngineResult<CiphertextSmall> {
+        let mut ct_out = LweCiphertextOwned::new(
+            0u64,
+            server_key.key_switching_key.output_lwe_size(),
+            server_key.ciphertext_modulus,
+        );
+
+        // Compute a keyswitch
+        keyswitch_lwe_ciphertext(&server_key.key_switching_key, &ct.ct, &mut ct_out);
+
+        // The keyswitch does not change the encoded value
+        Ok(CiphertextSmall {
+            ct: ct_out,
+            degree: ct.degree,
+            message_modulus: ct.message_modulus,
+            carry_modulus: ct.carry_modulus,
+            has_padding_bit: ct.has_padding_bit,
+            _order_marker: Default::default(),
+        })
+    }
+
     pub(crate) fn apply_lookup_table_assign<OpOrder: PBSOrderMarker>(
         &mut self,
         server_key: &ServerKey,
@@ -688,6 +729,7 @@ impl ShortintEngine {
             degree,
             message_modulus: server_key.message_modulus,
             carry_modulus: server_key.carry_modulus,
+            has_padding_bit: Some(true),
             _order_marker: Default::default(),
         })
     }
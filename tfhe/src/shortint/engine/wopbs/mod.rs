@@ -6,7 +6,7 @@ use crate::core_crypto::entities::*;
 use crate::core_crypto::fft_impl::fft64::crypto::bootstrap::FourierLweBootstrapKey;
 use crate::core_crypto::fft_impl::fft64::math::fft::Fft;
 use crate::shortint::ciphertext::Degree;
-use crate::shortint::engine::{EngineResult, ShortintEngine};
+use crate::shortint::engine::{count_pbs, EngineResult, ShortintEngine};
 use crate::shortint::server_key::MaxDegree;
 use crate::shortint::wopbs::WopbsKey;
 use crate::shortint::{CiphertextBase, ClientKey, PBSOrderMarker, Parameters, ServerKey};
@@ -340,6 +340,7 @@ impl ShortintEngine {
             degree: Degree(sks.message_modulus.0 - 1),
             message_modulus: sks.message_modulus,
             carry_modulus: sks.carry_modulus,
+            has_padding_bit: ct_in.has_padding_bit,
             _order_marker: Default::default(),
         };
 
@@ -352,6 +353,11 @@ impl ShortintEngine {
         ct_in: &CiphertextBase<OpOrder>,
         lut: &[u64],
     ) -> EngineResult<CiphertextBase<OpOrder>> {
+        assert!(
+            ct_in.has_padding_bit != Some(true),
+            "This operation requires a ciphertext encoded without a padding bit"
+        );
+
         let sks = &wopbs_key.wopbs_server_key;
         let delta = (1_usize << 63) / (sks.message_modulus.0 * sks.carry_modulus.0) * 2;
         let delta_log = DeltaLog(f64::log2(delta as f64) as usize);
@@ -403,6 +409,7 @@ impl ShortintEngine {
             degree: ct_in.degree,
             message_modulus: ct_clean.message_modulus,
             carry_modulus: ct_clean.carry_modulus,
+            has_padding_bit: ct_in.has_padding_bit,
             _order_marker: Default::default(),
         })
     }
@@ -447,6 +454,7 @@ impl ShortintEngine {
         let stack = buffers.stack();
 
         // Compute a bootstrap
+        count_pbs();
         programmable_bootstrap_lwe_ciphertext_mem_optimized(
             &ciphertext_buffers.buffer_lwe_after_ks,
             &mut ct_out,
@@ -461,6 +469,7 @@ impl ShortintEngine {
             degree: ct_in.degree,
             message_modulus: ct_in.message_modulus,
             carry_modulus: ct_in.carry_modulus,
+            has_padding_bit: ct_in.has_padding_bit,
             _order_marker: Default::default(),
         })
     }
@@ -1,6 +1,3 @@
-use crate::shortint::parameters::parameters_wopbs::*;
-use crate::shortint::parameters::parameters_wopbs_message_carry::*;
-use crate::shortint::parameters::parameters_wopbs_prime_moduli::*;
 use crate::shortint::parameters::*;
 use crate::shortint::wopbs::WopbsKey;
 use crate::shortint::{ClientKey, ServerKey};
@@ -214,169 +211,9 @@ pub mod utils {
 
 impl NamedParam for Parameters {
     fn name(&self) -> String {
-        named_params_impl!(
-            self == (
-                PARAM_MESSAGE_1_CARRY_0,
-                PARAM_MESSAGE_1_CARRY_1,
-                PARAM_MESSAGE_1_CARRY_2,
-                PARAM_MESSAGE_1_CARRY_3,
-                PARAM_MESSAGE_1_CARRY_4,
-                PARAM_MESSAGE_1_CARRY_5,
-                PARAM_MESSAGE_1_CARRY_6,
-                PARAM_MESSAGE_1_CARRY_7,
-                PARAM_MESSAGE_2_CARRY_0,
-                PARAM_MESSAGE_2_CARRY_1,
-                PARAM_MESSAGE_2_CARRY_2,
-                PARAM_MESSAGE_2_CARRY_3,
-                PARAM_MESSAGE_2_CARRY_4,
-                PARAM_MESSAGE_2_CARRY_5,
-                PARAM_MESSAGE_2_CARRY_6,
-                PARAM_MESSAGE_3_CARRY_0,
-                PARAM_MESSAGE_3_CARRY_1,
-                PARAM_MESSAGE_3_CARRY_2,
-                PARAM_MESSAGE_3_CARRY_3,
-                PARAM_MESSAGE_3_CARRY_4,
-                PARAM_MESSAGE_3_CARRY_5,
-                PARAM_MESSAGE_4_CARRY_0,
-                PARAM_MESSAGE_4_CARRY_1,
-                PARAM_MESSAGE_4_CARRY_2,
-                PARAM_MESSAGE_4_CARRY_3,
-                PARAM_MESSAGE_4_CARRY_4,
-                PARAM_MESSAGE_5_CARRY_0,
-                PARAM_MESSAGE_5_CARRY_1,
-                PARAM_MESSAGE_5_CARRY_2,
-                PARAM_MESSAGE_5_CARRY_3,
-                PARAM_MESSAGE_6_CARRY_0,
-                PARAM_MESSAGE_6_CARRY_1,
-                PARAM_MESSAGE_6_CARRY_2,
-                PARAM_MESSAGE_7_CARRY_0,
-                PARAM_MESSAGE_7_CARRY_1,
-                PARAM_MESSAGE_8_CARRY_0,
-                // Small
-                PARAM_SMALL_MESSAGE_1_CARRY_1,
-                PARAM_SMALL_MESSAGE_2_CARRY_2,
-                PARAM_SMALL_MESSAGE_3_CARRY_3,
-                PARAM_SMALL_MESSAGE_4_CARRY_4,
-                // Wops
-                WOPBS_PARAM_MESSAGE_1_NORM2_2,
-                WOPBS_PARAM_MESSAGE_1_NORM2_4,
-                WOPBS_PARAM_MESSAGE_1_NORM2_6,
-                WOPBS_PARAM_MESSAGE_1_NORM2_8,
-                WOPBS_PARAM_MESSAGE_2_NORM2_2,
-                WOPBS_PARAM_MESSAGE_2_NORM2_4,
-                WOPBS_PARAM_MESSAGE_2_NORM2_6,
-                WOPBS_PARAM_MESSAGE_2_NORM2_8,
-                WOPBS_PARAM_MESSAGE_3_NORM2_2,
-                WOPBS_PARAM_MESSAGE_3_NORM2_4,
-                WOPBS_PARAM_MESSAGE_3_NORM2_6,
-                WOPBS_PARAM_MESSAGE_3_NORM2_8,
-                WOPBS_PARAM_MESSAGE_4_NORM2_2,
-                WOPBS_PARAM_MESSAGE_4_NORM2_4,
-                WOPBS_PARAM_MESSAGE_4_NORM2_6,
-                WOPBS_PARAM_MESSAGE_4_NORM2_8,
-                WOPBS_PARAM_MESSAGE_5_NORM2_2,
-                WOPBS_PARAM_MESSAGE_5_NORM2_4,
-                WOPBS_PARAM_MESSAGE_5_NORM2_6,
-                WOPBS_PARAM_MESSAGE_5_NORM2_8,
-                WOPBS_PARAM_MESSAGE_6_NORM2_2,
-                WOPBS_PARAM_MESSAGE_6_NORM2_4,
-                WOPBS_PARAM_MESSAGE_6_NORM2_6,
-                WOPBS_PARAM_MESSAGE_6_NORM2_8,
-                WOPBS_PARAM_MESSAGE_7_NORM2_2,
-                WOPBS_PARAM_MESSAGE_7_NORM2_4,
-                WOPBS_PARAM_MESSAGE_7_NORM2_6,
-                WOPBS_PARAM_MESSAGE_7_NORM2_8,
-                WOPBS_PARAM_MESSAGE_8_NORM2_2,
-                WOPBS_PARAM_MESSAGE_8_NORM2_4,
-                //WOPBS_PARAM_MESSAGE_8_NORM2_5,
-                WOPBS_PARAM_MESSAGE_8_NORM2_6,
-                WOPBS_PARAM_MESSAGE_1_CARRY_0,
-                WOPBS_PARAM_MESSAGE_1_CARRY_1,
-                WOPBS_PARAM_MESSAGE_1_CARRY_2,
-                WOPBS_PARAM_MESSAGE_1_CARRY_3,
-                WOPBS_PARAM_MESSAGE_1_CARRY_4,
-                WOPBS_PARAM_MESSAGE_1_CARRY_5,
-                WOPBS_PARAM_MESSAGE_1_CARRY_6,
-                WOPBS_PARAM_MESSAGE_1_CARRY_7,
-                WOPBS_PARAM_MESSAGE_2_CARRY_0,
-                WOPBS_PARAM_MESSAGE_2_CARRY_1,
-                WOPBS_PARAM_MESSAGE_2_CARRY_2,
-                WOPBS_PARAM_MESSAGE_2_CARRY_3,
-                WOPBS_PARAM_MESSAGE_2_CARRY_4,
-                WOPBS_PARAM_MESSAGE_2_CARRY_5,
-                WOPBS_PARAM_MESSAGE_2_CARRY_6,
-                WOPBS_PARAM_MESSAGE_3_CARRY_0,
-                WOPBS_PARAM_MESSAGE_3_CARRY_1,
-                WOPBS_PARAM_MESSAGE_3_CARRY_2,
-                WOPBS_PARAM_MESSAGE_3_CARRY_3,
-                WOPBS_PARAM_MESSAGE_3_CARRY_4,
-                WOPBS_PARAM_MESSAGE_3_CARRY_5,
-                WOPBS_PARAM_MESSAGE_4_CARRY_0,
-                WOPBS_PARAM_MESSAGE_4_CARRY_1,
-                WOPBS_PARAM_MESSAGE_4_CARRY_2,
-                WOPBS_PARAM_MESSAGE_4_CARRY_3,
-                WOPBS_PARAM_MESSAGE_4_CARRY_4,
-                WOPBS_PARAM_MESSAGE_5_CARRY_0,
-                WOPBS_PARAM_MESSAGE_5_CARRY_1,
-                WOPBS_PARAM_MESSAGE_5_CARRY_2,
-                WOPBS_PARAM_MESSAGE_5_CARRY_3,
-                WOPBS_PARAM_MESSAGE_6_CARRY_0,
-                WOPBS_PARAM_MESSAGE_6_CARRY_1,
-                WOPBS_PARAM_MESSAGE_6_CARRY_2,
-                WOPBS_PARAM_MESSAGE_7_CARRY_0,
-                WOPBS_PARAM_MESSAGE_7_CARRY_1,
-                WOPBS_PARAM_MESSAGE_8_CARRY_0,
-                WOPBS_PRIME_PARAM_MESSAGE_2_NORM2_2,
-                WOPBS_PRIME_PARAM_MESSAGE_2_NORM2_3,
-                WOPBS_PRIME_PARAM_MESSAGE_2_NORM2_4,
-                WOPBS_PRIME_PARAM_MESSAGE_2_NORM2_5,
-                WOPBS_PRIME_PARAM_MESSAGE_2_NORM2_6,
-                WOPBS_PRIME_PARAM_MESSAGE_2_NORM2_7,
-                WOPBS_PRIME_PARAM_MESSAGE_2_NORM2_8,
-                WOPBS_PRIME_PARAM_MESSAGE_3_NORM2_2,
-                WOPBS_PRIME_PARAM_MESSAGE_3_NORM2_3,
-                WOPBS_PRIME_PARAM_MESSAGE_3_NORM2_4,
-                WOPBS_PRIME_PARAM_MESSAGE_3_NORM2_5,
-                WOPBS_PRIME_PARAM_MESSAGE_3_NORM2_6,
-                WOPBS_PRIME_PARAM_MESSAGE_3_NORM2_7,
-                WOPBS_PRIME_PARAM_MESSAGE_3_NORM2_8,
-                WOPBS_PRIME_PARAM_MESSAGE_4_NORM2_2,
-                WOPBS_PRIME_PARAM_MESSAGE_4_NORM2_3,
-                WOPBS_PRIME_PARAM_MESSAGE_4_NORM2_4,
-                WOPBS_PRIME_PARAM_MESSAGE_4_NORM2_5,
-                WOPBS_PRIME_PARAM_MESSAGE_4_NORM2_6,
-                WOPBS_PRIME_PARAM_MESSAGE_4_NORM2_7,
-                WOPBS_PRIME_PARAM_MESSAGE_4_NORM2_8,
-                WOPBS_PRIME_PARAM_MESSAGE_5_NORM2_2,
-                WOPBS_PRIME_PARAM_MESSAGE_5_NORM2_3,
-                WOPBS_PRIME_PARAM_MESSAGE_5_NORM2_4,
-                WOPBS_PRIME_PARAM_MESSAGE_5_NORM2_5,
-                WOPBS_PRIME_PARAM_MESSAGE_5_NORM2_6,
-                WOPBS_PRIME_PARAM_MESSAGE_5_NORM2_7,
-                WOPBS_PRIME_PARAM_MESSAGE_5_NORM2_8,
-                WOPBS_PRIME_PARAM_MESSAGE_6_NORM2_2,
-                WOPBS_PRIME_PARAM_MESSAGE_6_NORM2_3,
-                WOPBS_PRIME_PARAM_MESSAGE_6_NORM2_4,
-                WOPBS_PRIME_PARAM_MESSAGE_6_NORM2_5,
-                WOPBS_PRIME_PARAM_MESSAGE_6_NORM2_6,
-                WOPBS_PRIME_PARAM_MESSAGE_6_NORM2_7,
-                WOPBS_PRIME_PARAM_MESSAGE_6_NORM2_8,
-                WOPBS_PRIME_PARAM_MESSAGE_7_NORM2_2,
-                WOPBS_PRIME_PARAM_MESSAGE_7_NORM2_3,
-                WOPBS_PRIME_PARAM_MESSAGE_7_NORM2_4,
-                WOPBS_PRIME_PARAM_MESSAGE_7_NORM2_5,
-                WOPBS_PRIME_PARAM_MESSAGE_7_NORM2_6,
-                WOPBS_PRIME_PARAM_MESSAGE_7_NORM2_7,
-                WOPBS_PRIME_PARAM_MESSAGE_7_NORM2_8,
-                WOPBS_PRIME_PARAM_MESSAGE_8_NORM2_2,
-                WOPBS_PRIME_PARAM_MESSAGE_8_NORM2_3,
-                WOPBS_PRIME_PARAM_MESSAGE_8_NORM2_4,
-                WOPBS_PRIME_PARAM_MESSAGE_8_NORM2_5,
-                WOPBS_PRIME_PARAM_MESSAGE_8_NORM2_6,
-                WOPBS_PRIME_PARAM_MESSAGE_8_NORM2_7,
-                PARAM_4_BITS_5_BLOCKS,
-            )
-        );
+        parameters_name(self)
+            .expect("Unnamed parameters")
+            .to_string()
     }
 }
 
@@ -59,10 +59,11 @@ pub mod server_key;
 pub mod wopbs;
 
 pub use ciphertext::{
-    CiphertextBase, CiphertextBig, CiphertextSmall, CompressedCiphertextBase,
-    CompressedCiphertextBig, CompressedCiphertextSmall, PBSOrder, PBSOrderMarker,
+    decompress_many_parallelized, CiphertextBase, CiphertextBig, CiphertextSmall,
+    CompressedCiphertextBase, CompressedCiphertextBig, CompressedCiphertextSmall, PBSOrder,
+    PBSOrderMarker,
 };
-pub use client_key::ClientKey;
+pub use client_key::{CheckedDecryption, ClientKey};
 pub use parameters::{CarryModulus, CiphertextModulus, MessageModulus, Parameters};
 pub use public_key::{
     CompressedPublicKeyBase, CompressedPublicKeyBig, CompressedPublicKeySmall, PublicKeyBase,
@@ -10,12 +10,17 @@ pub use crate::core_crypto::commons::parameters::{
     CiphertextModulus as CoreCiphertextModulus, DecompositionBaseLog, DecompositionLevelCount,
     GlweDimension, LweDimension, PolynomialSize,
 };
+use crate::shortint::ciphertext::{PBSOrder, PBSOrderMarker};
 use serde::{Deserialize, Serialize};
 
 pub mod parameters_wopbs;
 pub mod parameters_wopbs_message_carry;
 pub(crate) mod parameters_wopbs_prime_moduli;
 
+use parameters_wopbs::*;
+use parameters_wopbs_message_carry::*;
+use parameters_wopbs_prime_moduli::*;
+
 /// The number of bits on which the message will be encoded.
 #[derive(Debug, PartialEq, Eq, Copy, Clone, Serialize, Deserialize)]
 pub struct MessageModulus(pub usize);
@@ -98,6 +103,154 @@ impl Parameters {
             ciphertext_modulus,
         }
     }
+
+    /// Check that the parameters are consistent with one another.
+    ///
+    /// This does not check that the parameters are secure or that the error probability is
+    /// acceptable, only that they can be used to build keys yielding meaningful results.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::{MessageModulus, PARAM_MESSAGE_2_CARRY_2};
+    ///
+    /// assert!(PARAM_MESSAGE_2_CARRY_2.validate().is_ok());
+    ///
+    /// let mut params = PARAM_MESSAGE_2_CARRY_2;
+    /// params.message_modulus = MessageModulus(0);
+    /// assert!(params.validate().is_err());
+    /// ```
+    pub fn validate(&self) -> Result<(), String> {
+        if self.lwe_dimension.0 == 0 {
+            return Err("lwe_dimension must be non zero".to_string());
+        }
+        if self.glwe_dimension.0 == 0 {
+            return Err("glwe_dimension must be non zero".to_string());
+        }
+        if self.polynomial_size.0 == 0 || !self.polynomial_size.0.is_power_of_two() {
+            return Err(format!(
+                "polynomial_size must be a non zero power of two, got {}",
+                self.polynomial_size.0
+            ));
+        }
+        if self.message_modulus.0 == 0 || self.carry_modulus.0 == 0 {
+            return Err(format!(
+                "message_modulus and carry_modulus must be non zero, got {} and {}",
+                self.message_modulus.0, self.carry_modulus.0
+            ));
+        }
+
+        let ciphertext_modulus_bits = if self.ciphertext_modulus.is_native_modulus() {
+            u64::BITS
+        } else {
+            self.ciphertext_modulus.get().ilog2()
+        };
+        // The message and carry spaces need an extra bit of padding
+        let required_bits = (self.message_modulus.0 * self.carry_modulus.0)
+            .next_power_of_two()
+            .ilog2()
+            + 1;
+        if required_bits > ciphertext_modulus_bits {
+            return Err(format!(
+                "message_modulus * carry_modulus ({} * {}) and the padding bit do not fit in \
+                {:?}",
+                self.message_modulus.0, self.carry_modulus.0, self.ciphertext_modulus
+            ));
+        }
+
+        for (name, base_log, level, is_required) in [
+            ("pbs", self.pbs_base_log, self.pbs_level, true),
+            ("ks", self.ks_base_log, self.ks_level, true),
+            ("pfks", self.pfks_base_log, self.pfks_level, false),
+            ("cbs", self.cbs_base_log, self.cbs_level, false),
+        ] {
+            if is_required && (base_log.0 == 0 || level.0 == 0) {
+                return Err(format!(
+                    "{name}_base_log and {name}_level must be non zero, got {} and {}",
+                    base_log.0, level.0
+                ));
+            }
+            if base_log.0 * level.0 > ciphertext_modulus_bits as usize {
+                return Err(format!(
+                    "{name}_base_log * {name}_level ({} * {}) exceeds the {ciphertext_modulus_bits} \
+                    bits of the ciphertext modulus",
+                    base_log.0, level.0
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns the variance of the noise of a ciphertext freshly encrypted with these parameters.
+    ///
+    /// Ciphertexts in the [`KeyswitchBootstrap`](crate::shortint::ciphertext::KeyswitchBootstrap)
+    /// order are encrypted under the large LWE key and get the GLWE noise, the ones in the
+    /// [`BootstrapKeyswitch`](crate::shortint::ciphertext::BootstrapKeyswitch) order get the LWE
+    /// noise. The variance is expressed on the torus, like the standard deviations.
+    pub fn fresh_noise_variance<OpOrder: PBSOrderMarker>(&self) -> f64 {
+        match OpOrder::pbs_order() {
+            PBSOrder::KeyswitchBootstrap => self.glwe_modular_std_dev.get_variance(),
+            PBSOrder::BootstrapKeyswitch => self.lwe_modular_std_dev.get_variance(),
+        }
+    }
+
+    /// Estimates the noise variance of the result of an addition between two ciphertexts.
+    ///
+    /// This is a pure computation, no homomorphic operation is performed. The degree of a
+    /// ciphertext says nothing about its noise (a bootstrapped ciphertext carries a fresh bootstrap
+    /// noise whatever its degree, a trivial one carries no noise at all), so the noise variances of
+    /// the inputs have to be tracked by the caller, e.g. starting from
+    /// [`Self::fresh_noise_variance`] for fresh encryptions and from 0 for trivial ciphertexts.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::ciphertext::KeyswitchBootstrap;
+    /// use tfhe::shortint::parameters::{Parameters, PARAM_MESSAGE_2_CARRY_2};
+    ///
+    /// let fresh_variance = PARAM_MESSAGE_2_CARRY_2.fresh_noise_variance::<KeyswitchBootstrap>();
+    ///
+    /// // Sum of two fresh ciphertexts
+    /// let sum_variance = Parameters::noise_estimate_after_add(fresh_variance, fresh_variance);
+    /// assert_eq!(sum_variance, 2.0 * fresh_variance);
+    ///
+    /// // Adding a trivial ciphertext does not add any noise
+    /// let estimate = Parameters::noise_estimate_after_add(sum_variance, 0.0);
+    /// assert_eq!(estimate, sum_variance);
+    /// ```
+    pub fn noise_estimate_after_add(variance_left: f64, variance_right: f64) -> f64 {
+        variance_left + variance_right
+    }
+
+    /// Estimates the noise variance of the result of a subtraction between two ciphertexts.
+    ///
+    /// Negation does not change the noise variance, so this is the same estimate as
+    /// [`Self::noise_estimate_after_add`].
+    pub fn noise_estimate_after_sub(variance_left: f64, variance_right: f64) -> f64 {
+        Self::noise_estimate_after_add(variance_left, variance_right)
+    }
+
+    /// Estimates the noise variance of the result of a multiplication of a ciphertext by a
+    /// scalar.
+    ///
+    /// The variance is scaled by the square of the scalar.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::ciphertext::KeyswitchBootstrap;
+    /// use tfhe::shortint::parameters::{Parameters, PARAM_MESSAGE_2_CARRY_2};
+    ///
+    /// let fresh_variance = PARAM_MESSAGE_2_CARRY_2.fresh_noise_variance::<KeyswitchBootstrap>();
+    ///
+    /// let estimate = Parameters::noise_estimate_after_scalar_mul(fresh_variance, 3);
+    /// assert_eq!(estimate, 9.0 * fresh_variance);
+    /// ```
+    pub fn noise_estimate_after_scalar_mul(variance: f64, scalar: u8) -> f64 {
+        let scalar = f64::from(scalar);
+        scalar * scalar * variance
+    }
 }
 
 /// Vector containing all parameter sets
@@ -1016,3 +1169,198 @@ pub fn get_parameters_from_message_and_carry(msg_space: usize, carry_space: usiz
     }
     out
 }
+
+#[cfg(any(
+    test,
+    doctest,
+    feature = "internal-keycache",
+    feature = "shortint-client-js-wasm-api"
+))]
+macro_rules! named_parameters(
+    ( $($param:ident),* $(,)? ) => {
+        &[$((stringify!($param), $param)),*]
+    }
+);
+
+/// All the predefined parameter sets, with their names.
+#[cfg(any(
+    test,
+    doctest,
+    feature = "internal-keycache",
+    feature = "shortint-client-js-wasm-api"
+))]
+pub(crate) const NAMED_PARAMETERS: &[(&str, Parameters)] = named_parameters!(
+    PARAM_MESSAGE_1_CARRY_0,
+    PARAM_MESSAGE_1_CARRY_1,
+    PARAM_MESSAGE_1_CARRY_2,
+    PARAM_MESSAGE_1_CARRY_3,
+    PARAM_MESSAGE_1_CARRY_4,
+    PARAM_MESSAGE_1_CARRY_5,
+    PARAM_MESSAGE_1_CARRY_6,
+    PARAM_MESSAGE_1_CARRY_7,
+    PARAM_MESSAGE_2_CARRY_0,
+    PARAM_MESSAGE_2_CARRY_1,
+    PARAM_MESSAGE_2_CARRY_2,
+    PARAM_MESSAGE_2_CARRY_3,
+    PARAM_MESSAGE_2_CARRY_4,
+    PARAM_MESSAGE_2_CARRY_5,
+    PARAM_MESSAGE_2_CARRY_6,
+    PARAM_MESSAGE_3_CARRY_0,
+    PARAM_MESSAGE_3_CARRY_1,
+    PARAM_MESSAGE_3_CARRY_2,
+    PARAM_MESSAGE_3_CARRY_3,
+    PARAM_MESSAGE_3_CARRY_4,
+    PARAM_MESSAGE_3_CARRY_5,
+    PARAM_MESSAGE_4_CARRY_0,
+    PARAM_MESSAGE_4_CARRY_1,
+    PARAM_MESSAGE_4_CARRY_2,
+    PARAM_MESSAGE_4_CARRY_3,
+    PARAM_MESSAGE_4_CARRY_4,
+    PARAM_MESSAGE_5_CARRY_0,
+    PARAM_MESSAGE_5_CARRY_1,
+    PARAM_MESSAGE_5_CARRY_2,
+    PARAM_MESSAGE_5_CARRY_3,
+    PARAM_MESSAGE_6_CARRY_0,
+    PARAM_MESSAGE_6_CARRY_1,
+    PARAM_MESSAGE_6_CARRY_2,
+    PARAM_MESSAGE_7_CARRY_0,
+    PARAM_MESSAGE_7_CARRY_1,
+    PARAM_MESSAGE_8_CARRY_0,
+    // Small
+    PARAM_SMALL_MESSAGE_1_CARRY_1,
+    PARAM_SMALL_MESSAGE_2_CARRY_2,
+    PARAM_SMALL_MESSAGE_3_CARRY_3,
+    PARAM_SMALL_MESSAGE_4_CARRY_4,
+    // Wops
+    WOPBS_PARAM_MESSAGE_1_NORM2_2,
+    WOPBS_PARAM_MESSAGE_1_NORM2_4,
+    WOPBS_PARAM_MESSAGE_1_NORM2_6,
+    WOPBS_PARAM_MESSAGE_1_NORM2_8,
+    WOPBS_PARAM_MESSAGE_2_NORM2_2,
+    WOPBS_PARAM_MESSAGE_2_NORM2_4,
+    WOPBS_PARAM_MESSAGE_2_NORM2_6,
+    WOPBS_PARAM_MESSAGE_2_NORM2_8,
+    WOPBS_PARAM_MESSAGE_3_NORM2_2,
+    WOPBS_PARAM_MESSAGE_3_NORM2_4,
+    WOPBS_PARAM_MESSAGE_3_NORM2_6,
+    WOPBS_PARAM_MESSAGE_3_NORM2_8,
+    WOPBS_PARAM_MESSAGE_4_NORM2_2,
+    WOPBS_PARAM_MESSAGE_4_NORM2_4,
+    WOPBS_PARAM_MESSAGE_4_NORM2_6,
+    WOPBS_PARAM_MESSAGE_4_NORM2_8,
+    WOPBS_PARAM_MESSAGE_5_NORM2_2,
+    WOPBS_PARAM_MESSAGE_5_NORM2_4,
+    WOPBS_PARAM_MESSAGE_5_NORM2_6,
+    WOPBS_PARAM_MESSAGE_5_NORM2_8,
+    WOPBS_PARAM_MESSAGE_6_NORM2_2,
+    WOPBS_PARAM_MESSAGE_6_NORM2_4,
+    WOPBS_PARAM_MESSAGE_6_NORM2_6,
+    WOPBS_PARAM_MESSAGE_6_NORM2_8,
+    WOPBS_PARAM_MESSAGE_7_NORM2_2,
+    WOPBS_PARAM_MESSAGE_7_NORM2_4,
+    WOPBS_PARAM_MESSAGE_7_NORM2_6,
+    WOPBS_PARAM_MESSAGE_7_NORM2_8,
+    WOPBS_PARAM_MESSAGE_8_NORM2_2,
+    WOPBS_PARAM_MESSAGE_8_NORM2_4,
+    //WOPBS_PARAM_MESSAGE_8_NORM2_5,
+    WOPBS_PARAM_MESSAGE_8_NORM2_6,
+    WOPBS_PARAM_MESSAGE_1_CARRY_0,
+    WOPBS_PARAM_MESSAGE_1_CARRY_1,
+    WOPBS_PARAM_MESSAGE_1_CARRY_2,
+    WOPBS_PARAM_MESSAGE_1_CARRY_3,
+    WOPBS_PARAM_MESSAGE_1_CARRY_4,
+    WOPBS_PARAM_MESSAGE_1_CARRY_5,
+    WOPBS_PARAM_MESSAGE_1_CARRY_6,
+    WOPBS_PARAM_MESSAGE_1_CARRY_7,
+    WOPBS_PARAM_MESSAGE_2_CARRY_0,
+    WOPBS_PARAM_MESSAGE_2_CARRY_1,
+    WOPBS_PARAM_MESSAGE_2_CARRY_2,
+    WOPBS_PARAM_MESSAGE_2_CARRY_3,
+    WOPBS_PARAM_MESSAGE_2_CARRY_4,
+    WOPBS_PARAM_MESSAGE_2_CARRY_5,
+    WOPBS_PARAM_MESSAGE_2_CARRY_6,
+    WOPBS_PARAM_MESSAGE_3_CARRY_0,
+    WOPBS_PARAM_MESSAGE_3_CARRY_1,
+    WOPBS_PARAM_MESSAGE_3_CARRY_2,
+    WOPBS_PARAM_MESSAGE_3_CARRY_3,
+    WOPBS_PARAM_MESSAGE_3_CARRY_4,
+    WOPBS_PARAM_MESSAGE_3_CARRY_5,
+    WOPBS_PARAM_MESSAGE_4_CARRY_0,
+    WOPBS_PARAM_MESSAGE_4_CARRY_1,
+    WOPBS_PARAM_MESSAGE_4_CARRY_2,
+    WOPBS_PARAM_MESSAGE_4_CARRY_3,
+    WOPBS_PARAM_MESSAGE_4_CARRY_4,
+    WOPBS_PARAM_MESSAGE_5_CARRY_0,
+    WOPBS_PARAM_MESSAGE_5_CARRY_1,
+    WOPBS_PARAM_MESSAGE_5_CARRY_2,
+    WOPBS_PARAM_MESSAGE_5_CARRY_3,
+    WOPBS_PARAM_MESSAGE_6_CARRY_0,
+    WOPBS_PARAM_MESSAGE_6_CARRY_1,
+    WOPBS_PARAM_MESSAGE_6_CARRY_2,
+    WOPBS_PARAM_MESSAGE_7_CARRY_0,
+    WOPBS_PARAM_MESSAGE_7_CARRY_1,
+    WOPBS_PARAM_MESSAGE_8_CARRY_0,
+    WOPBS_PRIME_PARAM_MESSAGE_2_NORM2_2,
+    WOPBS_PRIME_PARAM_MESSAGE_2_NORM2_3,
+    WOPBS_PRIME_PARAM_MESSAGE_2_NORM2_4,
+    WOPBS_PRIME_PARAM_MESSAGE_2_NORM2_5,
+    WOPBS_PRIME_PARAM_MESSAGE_2_NORM2_6,
+    WOPBS_PRIME_PARAM_MESSAGE_2_NORM2_7,
+    WOPBS_PRIME_PARAM_MESSAGE_2_NORM2_8,
+    WOPBS_PRIME_PARAM_MESSAGE_3_NORM2_2,
+    WOPBS_PRIME_PARAM_MESSAGE_3_NORM2_3,
+    WOPBS_PRIME_PARAM_MESSAGE_3_NORM2_4,
+    WOPBS_PRIME_PARAM_MESSAGE_3_NORM2_5,
+    WOPBS_PRIME_PARAM_MESSAGE_3_NORM2_6,
+    WOPBS_PRIME_PARAM_MESSAGE_3_NORM2_7,
+    WOPBS_PRIME_PARAM_MESSAGE_3_NORM2_8,
+    WOPBS_PRIME_PARAM_MESSAGE_4_NORM2_2,
+    WOPBS_PRIME_PARAM_MESSAGE_4_NORM2_3,
+    WOPBS_PRIME_PARAM_MESSAGE_4_NORM2_4,
+    WOPBS_PRIME_PARAM_MESSAGE_4_NORM2_5,
+    WOPBS_PRIME_PARAM_MESSAGE_4_NORM2_6,
+    WOPBS_PRIME_PARAM_MESSAGE_4_NORM2_7,
+    WOPBS_PRIME_PARAM_MESSAGE_4_NORM2_8,
+    WOPBS_PRIME_PARAM_MESSAGE_5_NORM2_2,
+    WOPBS_PRIME_PARAM_MESSAGE_5_NORM2_3,
+    WOPBS_PRIME_PARAM_MESSAGE_5_NORM2_4,
+    WOPBS_PRIME_PARAM_MESSAGE_5_NORM2_5,
+    WOPBS_PRIME_PARAM_MESSAGE_5_NORM2_6,
+    WOPBS_PRIME_PARAM_MESSAGE_5_NORM2_7,
+    WOPBS_PRIME_PARAM_MESSAGE_5_NORM2_8,
+    WOPBS_PRIME_PARAM_MESSAGE_6_NORM2_2,
+    WOPBS_PRIME_PARAM_MESSAGE_6_NORM2_3,
+    WOPBS_PRIME_PARAM_MESSAGE_6_NORM2_4,
+    WOPBS_PRIME_PARAM_MESSAGE_6_NORM2_5,
+    WOPBS_PRIME_PARAM_MESSAGE_6_NORM2_6,
+    WOPBS_PRIME_PARAM_MESSAGE_6_NORM2_7,
+    WOPBS_PRIME_PARAM_MESSAGE_6_NORM2_8,
+    WOPBS_PRIME_PARAM_MESSAGE_7_NORM2_2,
+    WOPBS_PRIME_PARAM_MESSAGE_7_NORM2_3,
+    WOPBS_PRIME_PARAM_MESSAGE_7_NORM2_4,
+    WOPBS_PRIME_PARAM_MESSAGE_7_NORM2_5,
+    WOPBS_PRIME_PARAM_MESSAGE_7_NORM2_6,
+    WOPBS_PRIME_PARAM_MESSAGE_7_NORM2_7,
+    WOPBS_PRIME_PARAM_MESSAGE_7_NORM2_8,
+    WOPBS_PRIME_PARAM_MESSAGE_8_NORM2_2,
+    WOPBS_PRIME_PARAM_MESSAGE_8_NORM2_3,
+    WOPBS_PRIME_PARAM_MESSAGE_8_NORM2_4,
+    WOPBS_PRIME_PARAM_MESSAGE_8_NORM2_5,
+    WOPBS_PRIME_PARAM_MESSAGE_8_NORM2_6,
+    WOPBS_PRIME_PARAM_MESSAGE_8_NORM2_7,
+    PARAM_4_BITS_5_BLOCKS,
+);
+
+/// Returns the name of the predefined parameter set equal to `parameters`, if any.
+#[cfg(any(
+    test,
+    doctest,
+    feature = "internal-keycache",
+    feature = "shortint-client-js-wasm-api"
+))]
+pub(crate) fn parameters_name(parameters: &Parameters) -> Option<&'static str> {
+    NAMED_PARAMETERS
+        .iter()
+        .find(|(_, named_parameters)| named_parameters == parameters)
+        .map(|(name, _)| *name)
+}
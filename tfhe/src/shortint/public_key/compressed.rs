@@ -5,7 +5,7 @@ use crate::shortint::ciphertext::{
 };
 use crate::shortint::engine::ShortintEngine;
 use crate::shortint::parameters::{MessageModulus, Parameters};
-use crate::shortint::ClientKey;
+use crate::shortint::{ClientKey, PublicKeyBase};
 use serde::{Deserialize, Serialize};
 use std::fmt::Debug;
 
@@ -294,4 +294,39 @@ impl<OpOrder: PBSOrderMarker> CompressedPublicKeyBase<OpOrder> {
                 .unwrap()
         })
     }
+
+    /// Decompresses the public key, expanding the seeded LWE public key in parallel.
+    ///
+    /// The result is identical to the one obtained with the sequential conversion
+    /// `PublicKeyBase::from(compressed_public_key)`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    /// use tfhe::shortint::{ClientKey, CompressedPublicKeyBig};
+    ///
+    /// // Generate the client key:
+    /// let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let compressed_pk = CompressedPublicKeyBig::new(&cks);
+    /// let pk = compressed_pk.decompress_parallelized();
+    ///
+    /// let msg = 2;
+    /// let ct = pk.encrypt(msg);
+    ///
+    /// let dec = cks.decrypt(&ct);
+    /// assert_eq!(msg, dec);
+    /// ```
+    pub fn decompress_parallelized(self) -> PublicKeyBase<OpOrder> {
+        let parameters = self.parameters;
+
+        let decompressed_public_key = self.lwe_public_key.par_decompress_into_lwe_public_key();
+
+        PublicKeyBase {
+            lwe_public_key: decompressed_public_key,
+            parameters,
+            _order_marker: Default::default(),
+        }
+    }
 }
@@ -0,0 +1,124 @@
+//! Construction of lookup tables from a table of values.
+
+use crate::shortint::server_key::{LookupTableOwned, ServerKey};
+use std::fmt::{Display, Formatter};
+
+/// Error returned when a [`LookupTableBuilder`] cannot build a lookup table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LookupTableBuilderError {
+    /// The mapping does not have one entry per message.
+    WrongMappingSize { expected: usize, actual: usize },
+    /// A value of the mapping does not fit in the plaintext space.
+    ValueOutOfRange {
+        input: usize,
+        value: u64,
+        modulus_sup: u64,
+    },
+}
+
+impl Display for LookupTableBuilderError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LookupTableBuilderError::WrongMappingSize { expected, actual } => {
+                write!(
+                    f,
+                    "The mapping must have one entry per message, expected {expected} entries, \
+                    got {actual}"
+                )
+            }
+            LookupTableBuilderError::ValueOutOfRange {
+                input,
+                value,
+                modulus_sup,
+            } => {
+                write!(
+                    f,
+                    "The value {value} of the input {input} does not fit in the plaintext space \
+                    of {modulus_sup} values"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for LookupTableBuilderError {}
+
+/// Builds the lookup table of a univariate function given by the list of its values.
+///
+/// The mapping gives the output of the function for each message, i.e. `mapping[x]` is the result
+/// for an input `x`, so it must have exactly `message_modulus` entries. Inputs with non empty
+/// carries are mapped according to their message, `mapping[x % message_modulus]`.
+///
+/// The built lookup table is used with [`ServerKey::apply_lookup_table`], like the ones returned
+/// by [`ServerKey::generate_accumulator`].
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::shortint::gen_keys;
+/// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+/// use tfhe::shortint::server_key::LookupTableBuilder;
+///
+/// // Generate the client key and the server key:
+/// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+///
+/// // An arbitrary permutation of the 4 messages
+/// let mapping = [2, 0, 3, 1];
+/// let acc = LookupTableBuilder::new(mapping).build(&sks).unwrap();
+///
+/// let ct = cks.encrypt(1);
+/// let ct_res = sks.apply_lookup_table(&ct, &acc);
+///
+/// let dec = cks.decrypt(&ct_res);
+/// assert_eq!(dec, mapping[1]);
+///
+/// // The mapping must have one entry per message
+/// assert!(LookupTableBuilder::new([0, 1, 2]).build(&sks).is_err());
+/// ```
+#[derive(Clone, Debug)]
+pub struct LookupTableBuilder {
+    mapping: Vec<u64>,
+}
+
+impl LookupTableBuilder {
+    /// Creates a builder for the function mapping each input `x` to `mapping[x]`.
+    pub fn new(mapping: impl Into<Vec<u64>>) -> Self {
+        Self {
+            mapping: mapping.into(),
+        }
+    }
+
+    /// Builds the lookup table for the parameters of the given server key.
+    ///
+    /// Returns an error if the mapping does not have `message_modulus` entries, or if one of its
+    /// values does not fit in the `message_modulus * carry_modulus` plaintext space.
+    pub fn build(
+        &self,
+        server_key: &ServerKey,
+    ) -> Result<LookupTableOwned, LookupTableBuilderError> {
+        let message_modulus = server_key.message_modulus.0;
+        if self.mapping.len() != message_modulus {
+            return Err(LookupTableBuilderError::WrongMappingSize {
+                expected: message_modulus,
+                actual: self.mapping.len(),
+            });
+        }
+
+        let modulus_sup = (message_modulus * server_key.carry_modulus.0) as u64;
+        if let Some((input, &value)) = self
+            .mapping
+            .iter()
+            .enumerate()
+            .find(|&(_, &value)| value >= modulus_sup)
+        {
+            return Err(LookupTableBuilderError::ValueOutOfRange {
+                input,
+                value,
+                modulus_sup,
+            });
+        }
+
+        let message_modulus = message_modulus as u64;
+        Ok(server_key.generate_accumulator(|x| self.mapping[(x % message_modulus) as usize]))
+    }
+}
@@ -17,6 +17,9 @@ mod sub;
 pub mod compressed;
 pub use compressed::CompressedServerKey;
 
+mod lookup_table_builder;
+pub use lookup_table_builder::{LookupTableBuilder, LookupTableBuilderError};
+
 #[cfg(test)]
 mod tests;
 
@@ -24,7 +27,7 @@ use crate::core_crypto::algorithms::*;
 use crate::core_crypto::commons::traits::*;
 use crate::core_crypto::entities::*;
 use crate::core_crypto::fft_impl::fft64::crypto::bootstrap::FourierLweBootstrapKeyOwned;
-use crate::shortint::ciphertext::{CiphertextBase, Degree};
+use crate::shortint::ciphertext::{CiphertextBase, CiphertextBig, CiphertextSmall, Degree};
 use crate::shortint::client_key::ClientKey;
 use crate::shortint::engine::ShortintEngine;
 use crate::shortint::parameters::{CarryModulus, CiphertextModulus, MessageModulus};
@@ -147,6 +150,44 @@ impl ServerKey {
         })
     }
 
+    /// Return the number of programmable bootstraps performed since the program started or since
+    /// the last call to [`reset_pbs_count`](Self::reset_pbs_count).
+    ///
+    /// The count is shared by all the server keys and all the threads, the PBS dominating the
+    /// cost of homomorphic computations, it allows comparing the cost of algorithms without
+    /// profiling them.
+    ///
+    /// This is only available with the `pbs-count` feature, the counting compiles to nothing
+    /// otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    /// use tfhe::shortint::{gen_keys, ServerKey};
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let ct = cks.encrypt(3);
+    /// let acc = sks.generate_accumulator(|x| x * x % 4);
+    ///
+    /// ServerKey::reset_pbs_count();
+    /// let _ct_res = sks.apply_lookup_table(&ct, &acc);
+    /// assert_eq!(ServerKey::pbs_count(), 1);
+    /// ```
+    #[cfg(feature = "pbs-count")]
+    pub fn pbs_count() -> u64 {
+        crate::shortint::engine::pbs_count()
+    }
+
+    /// Reset the number of programmable bootstraps returned by [`pbs_count`](Self::pbs_count) to
+    /// 0.
+    #[cfg(feature = "pbs-count")]
+    pub fn reset_pbs_count() {
+        crate::shortint::engine::reset_pbs_count();
+    }
+
     /// Constructs the accumulator given a function as input.
     ///
     /// # Example
@@ -181,6 +222,60 @@ impl ServerKey {
         })
     }
 
+    /// Constructs the accumulator given a function as input, with its inputs shifted by
+    /// `rotation` values.
+    ///
+    /// Applying the returned lookup table to an encryption of `x` with
+    /// [`apply_lookup_table`](Self::apply_lookup_table) gives an encryption of
+    /// `f(x + rotation)`, as long as `x + rotation` is smaller than
+    /// `message_modulus * carry_modulus`.
+    ///
+    /// The programmable bootstrapping is negacyclic: inputs going past
+    /// `message_modulus * carry_modulus` reach the padding bit, and the accumulator values they
+    /// select come back negated. With the rotation, the accumulator therefore evaluates the
+    /// negacyclic extension of `f`, i.e. the function on `[0, 2 * message_modulus *
+    /// carry_modulus)` equal to `f(x + rotation)` on the first half and to
+    /// `-f(x + rotation - message_modulus * carry_modulus)` on the wrapped part. This is what is
+    /// needed to evaluate functions whose output depends on the padding bit, such as the sign of a
+    /// value using the padding bit as the sign bit.
+    ///
+    /// The degree of the returned lookup table is the maximum value of `f`, negated outputs are
+    /// not accounted for and their handling is the caller's responsibility.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let msg = 3;
+    ///
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// // Generate the accumulator for the function f: x -> x mod 2^2, shifted by 2 input values
+    /// let f = |x| x % 4;
+    ///
+    /// let acc = sks.generate_accumulator_with_rotation(f, 2);
+    /// let ct_res = sks.apply_lookup_table(&ct, &acc);
+    ///
+    /// let dec = cks.decrypt(&ct_res);
+    /// // (3 + 2) mod 4 = 1
+    /// assert_eq!(dec, f(msg + 2));
+    /// ```
+    pub fn generate_accumulator_with_rotation<F>(&self, f: F, rotation: usize) -> LookupTableOwned
+    where
+        F: Fn(u64) -> u64,
+    {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine
+                .generate_accumulator_with_rotation(self, f, rotation)
+                .unwrap()
+        })
+    }
+
     pub fn generate_accumulator_bivariate_with_factor<F>(
         &self,
         f: F,
@@ -741,6 +836,37 @@ impl ServerKey {
         ShortintEngine::with_thread_local_mut(|engine| engine.message_extract(self, ct).unwrap())
     }
 
+    /// Apply only the keyswitch step to a ciphertext encrypted under the big LWE secret key,
+    /// returning a ciphertext encrypted under the small LWE secret key.
+    ///
+    /// No bootstrap is computed, the carry buffer is therefore left untouched and the noise of
+    /// the result is the input noise increased by the keyswitch noise.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    /// use tfhe::shortint::{gen_keys, CiphertextSmall};
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let msg = 3;
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// let ct_res: CiphertextSmall = sks.keyswitch_only(&ct);
+    /// assert_eq!(
+    ///     ct_res.ct.lwe_size(),
+    ///     cks.parameters.lwe_dimension.to_lwe_size()
+    /// );
+    ///
+    /// let dec = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, msg);
+    /// ```
+    pub fn keyswitch_only(&self, ct: &CiphertextBig) -> CiphertextSmall {
+        ShortintEngine::with_thread_local_mut(|engine| engine.keyswitch_only(self, ct).unwrap())
+    }
+
     /// Compute a trivial shortint ciphertext with the dimension of the big LWE secret key from a
     /// given value.
     ///
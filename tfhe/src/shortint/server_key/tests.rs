@@ -1,5 +1,6 @@
 use crate::shortint::keycache::KEY_CACHE;
 use crate::shortint::parameters::*;
+use crate::shortint::server_key::{LookupTableBuilder, LookupTableBuilderError};
 use crate::shortint::CiphertextBig;
 use paste::paste;
 use rand::Rng;
@@ -92,12 +93,17 @@ macro_rules! create_parametrized_test_bivariate_pbs_compliant{
 //These functions are compatible with all parameter sets.
 create_parametrized_test!(shortint_encrypt_decrypt);
 create_parametrized_test!(shortint_encrypt_with_message_modulus_decrypt);
+create_parametrized_test!(shortint_encrypt_with_std_dev_decrypt);
+create_parametrized_test!(shortint_decompress_many_parallelized);
 create_parametrized_test!(shortint_encrypt_decrypt_without_padding);
+create_parametrized_test!(shortint_carries_padding_bit);
 create_parametrized_test!(shortint_keyswitch_bootstrap);
 create_parametrized_test!(shortint_keyswitch_programmable_bootstrap);
 create_parametrized_test!(shortint_carry_extract);
 create_parametrized_test!(shortint_message_extract);
 create_parametrized_test!(shortint_generate_accumulator);
+create_parametrized_test!(shortint_generate_accumulator_with_rotation);
+create_parametrized_test!(shortint_lookup_table_builder);
 create_parametrized_test!(shortint_unchecked_add);
 create_parametrized_test!(shortint_smart_add);
 create_parametrized_test!(shortint_default_add);
@@ -125,6 +131,100 @@ create_parametrized_test!(shortint_default_sub);
 create_parametrized_test!(shortint_mul_small_carry);
 create_parametrized_test!(shortint_mux);
 
+#[test]
+fn test_shortint_lut_is_resolvable() {
+    use crate::core_crypto::commons::parameters::PolynomialSize;
+    use crate::shortint::engine::assert_lut_is_resolvable;
+
+    assert_lut_is_resolvable(MessageModulus(4), CarryModulus(4), PolynomialSize(2048));
+    assert_lut_is_resolvable(MessageModulus(4), CarryModulus(4), PolynomialSize(32));
+
+    // Boxes of a single coefficient
+    let result = std::panic::catch_unwind(|| {
+        assert_lut_is_resolvable(MessageModulus(4), CarryModulus(4), PolynomialSize(16))
+    });
+    assert!(result.is_err());
+
+    // Boxes which do not divide the polynomial
+    let result = std::panic::catch_unwind(|| {
+        assert_lut_is_resolvable(MessageModulus(3), CarryModulus(1), PolynomialSize(1024))
+    });
+    assert!(result.is_err());
+}
+
+#[cfg(feature = "pbs-count")]
+#[test]
+fn test_shortint_pbs_count() {
+    let keys = KEY_CACHE.get_from_param(PARAM_MESSAGE_2_CARRY_2);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+
+    let ct = cks.encrypt(3);
+    let acc = sks.generate_accumulator(|x| x * x % 4);
+
+    // The count is shared with the tests running concurrently, so it can only be lower bounded
+    let before = crate::shortint::ServerKey::pbs_count();
+    let _ = sks.apply_lookup_table(&ct, &acc);
+    let _ = sks.apply_lookup_table(&ct, &acc);
+    assert!(crate::shortint::ServerKey::pbs_count() >= before + 2);
+}
+
+#[test]
+fn test_shortint_ciphertext_serialization_records_pbs_order() {
+    use crate::shortint::CiphertextSmall;
+
+    let keys = KEY_CACHE.get_from_param(PARAM_MESSAGE_2_CARRY_2);
+    let cks = keys.client_key();
+
+    let ct = cks.encrypt(3);
+    let serialized = bincode::serialize(&ct).unwrap();
+
+    let deserialized: CiphertextBig = bincode::deserialize(&serialized).unwrap();
+    assert_eq!(cks.decrypt(&deserialized), 3);
+
+    // Deserializing with the other PBS order is an error, not a garbage ciphertext
+    assert!(bincode::deserialize::<CiphertextSmall>(&serialized).is_err());
+}
+
+#[test]
+fn test_shortint_ciphertext_deserialization_of_data_without_padding_bit() {
+    use crate::core_crypto::entities::LweCiphertextOwned;
+    use crate::shortint::ciphertext::{Degree, PBSOrder};
+
+    // Layout of the ciphertexts serialized before the padding bit was recorded
+    #[derive(serde::Serialize)]
+    struct CiphertextWithoutPaddingBit {
+        ct: LweCiphertextOwned<u64>,
+        degree: Degree,
+        message_modulus: MessageModulus,
+        carry_modulus: CarryModulus,
+        op_order: PBSOrder,
+    }
+
+    let keys = KEY_CACHE.get_from_param(PARAM_MESSAGE_2_CARRY_2);
+    let cks = keys.client_key();
+
+    let ct = cks.encrypt(3);
+    let serialized = bincode::serialize(&CiphertextWithoutPaddingBit {
+        ct: ct.ct.clone(),
+        degree: ct.degree,
+        message_modulus: ct.message_modulus,
+        carry_modulus: ct.carry_modulus,
+        op_order: PBSOrder::KeyswitchBootstrap,
+    })
+    .unwrap();
+
+    // The encoding of older data is unknown, the decryption does not reject it
+    let deserialized: CiphertextBig = bincode::deserialize(&serialized).unwrap();
+    assert_eq!(deserialized.carries_padding_bit(), None);
+    assert_eq!(cks.decrypt(&deserialized), 3);
+
+    // Serializing it again keeps the older layout
+    assert_eq!(bincode::serialize(&deserialized).unwrap(), serialized);
+
+    // The PBS order is still checked
+    assert!(bincode::deserialize::<crate::shortint::CiphertextSmall>(&serialized).is_err());
+}
+
 // Public key tests are limited to small parameter sets to avoid blowing up memory and large testing
 // times. Compressed keygen takes 20 minutes for params 2_2 and for encryption as well.
 // 2_2 uncompressed keys take ~2 GB and 3_3 about ~34 GB, hence why we stop at 2_2.
@@ -133,6 +233,11 @@ fn test_shortint_compressed_public_key_smart_add_param_message_1_carry_1() {
     shortint_compressed_public_key_smart_add(PARAM_MESSAGE_1_CARRY_1)
 }
 
+#[test]
+fn test_shortint_compressed_public_key_decompress_parallelized_param_message_1_carry_1() {
+    shortint_compressed_public_key_decompress_parallelized(PARAM_MESSAGE_1_CARRY_1)
+}
+
 #[test]
 fn test_shortint_public_key_smart_add_param_message_1_carry_1() {
     shortint_public_key_smart_add(PARAM_MESSAGE_1_CARRY_1)
@@ -236,6 +341,31 @@ fn shortint_encrypt_with_message_modulus_decrypt(param: Parameters) {
     }
 }
 
+/// test encryption with a custom noise standard deviation
+fn shortint_encrypt_with_std_dev_decrypt(param: Parameters) {
+    let keys = KEY_CACHE.get_from_param(param);
+    let cks = keys.client_key();
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = cks.parameters.message_modulus.0 as u64;
+
+    for _ in 0..NB_TEST {
+        let clear = rng.gen::<u64>() % modulus;
+
+        // Without noise the decrypted plaintext is exactly the encoded message
+        let ct = cks.encrypt_with_std_dev(clear, StandardDev(0.0));
+        let dec = cks.decrypt_message_and_carry_checked(&ct);
+        assert_eq!(clear, dec.message_and_carry);
+        assert_eq!(dec.noise_margin, 1.0);
+
+        // The standard deviation of the parameters gives a regular encryption
+        let ct = cks.encrypt_with_std_dev(clear, cks.parameters.glwe_modular_std_dev);
+        let dec = cks.decrypt(&ct);
+        assert_eq!(clear, dec);
+    }
+}
+
 fn shortint_encrypt_decrypt_without_padding(param: Parameters) {
     let keys = KEY_CACHE.get_from_param(param);
     let cks = keys.client_key();
@@ -258,6 +388,31 @@ fn shortint_encrypt_decrypt_without_padding(param: Parameters) {
     }
 }
 
+fn shortint_carries_padding_bit(param: Parameters) {
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+
+    let ct = cks.encrypt(0);
+    assert_eq!(ct.carries_padding_bit(), Some(true));
+
+    // The flag is kept by operations and serialization
+    let ct_res = sks.unchecked_add(&ct, &ct);
+    assert_eq!(ct_res.carries_padding_bit(), Some(true));
+    let serialized = bincode::serialize(&ct_res).unwrap();
+    let ct_res: CiphertextBig = bincode::deserialize(&serialized).unwrap();
+    assert_eq!(ct_res.carries_padding_bit(), Some(true));
+
+    let ct = cks.encrypt_without_padding(0);
+    assert_eq!(ct.carries_padding_bit(), Some(false));
+    let serialized = bincode::serialize(&ct).unwrap();
+    let ct: CiphertextBig = bincode::deserialize(&serialized).unwrap();
+    assert_eq!(ct.carries_padding_bit(), Some(false));
+
+    let ct = cks.encrypt_without_padding_compressed(0);
+    assert_eq!(ct.carries_padding_bit(), Some(false));
+    assert_eq!(ct.decompress().carries_padding_bit(), Some(false));
+}
+
 fn shortint_keyswitch_bootstrap(param: Parameters) {
     let keys = KEY_CACHE.get_from_param(param);
     let (cks, sks) = (keys.client_key(), keys.server_key());
@@ -366,6 +521,7 @@ fn shortint_carry_extract(param: Parameters) {
 
         // extract the carry
         let ct_carry = sks.carry_extract(&ctxt);
+        assert_eq!(ct_carry.degree.0, cks.parameters.carry_modulus.0 - 1);
 
         // decryption of message and carry
         let dec = cks.decrypt_message_and_carry(&ct_carry);
@@ -398,6 +554,7 @@ fn shortint_message_extract(param: Parameters) {
 
         // message extraction
         let ct_msg = sks.message_extract(&ctxt);
+        assert_eq!(ct_msg.degree.0, param.message_modulus.0 - 1);
 
         // decryption of ct_msg
         let dec = cks.decrypt(&ct_msg);
@@ -547,6 +704,27 @@ fn shortint_default_add(param: Parameters) {
     }
 }
 
+/// test that the parallel decompression of compressed ciphertexts matches the sequential one
+fn shortint_decompress_many_parallelized(param: Parameters) {
+    let keys = KEY_CACHE.get_from_param(param);
+    let cks = keys.client_key();
+
+    let modulus = cks.parameters.message_modulus.0 as u64;
+    let msgs: Vec<u64> = (0..2 * modulus).collect();
+    let compressed: Vec<_> = msgs
+        .iter()
+        .map(|&msg| cks.encrypt_compressed(msg))
+        .collect();
+
+    let cts = crate::shortint::decompress_many_parallelized(&compressed);
+    assert_eq!(cts.len(), compressed.len());
+
+    for ((msg, ct), compressed_ct) in msgs.iter().zip(cts.iter()).zip(compressed) {
+        assert_eq!(ct.ct, compressed_ct.decompress().ct);
+        assert_eq!(msg % modulus, cks.decrypt(ct));
+    }
+}
+
 /// test addition with the LWE server key using the a public key for encryption
 fn shortint_compressed_public_key_smart_add(param: Parameters) {
     let keys = KEY_CACHE.get_from_param(param);
@@ -587,6 +765,22 @@ fn shortint_compressed_public_key_smart_add(param: Parameters) {
     }
 }
 
+/// test that the parallel decompression of a compressed public key matches the sequential one
+fn shortint_compressed_public_key_decompress_parallelized(param: Parameters) {
+    let keys = KEY_CACHE.get_from_param(param);
+    let cks = keys.client_key();
+    let compressed_pk = crate::shortint::CompressedPublicKeyBig::new(cks);
+
+    let pk = compressed_pk.clone().decompress_parallelized();
+    assert_eq!(pk, crate::shortint::PublicKeyBig::from(compressed_pk));
+
+    let modulus = cks.parameters.message_modulus.0 as u64;
+    for msg in 0..modulus {
+        let ct = pk.encrypt(msg);
+        assert_eq!(msg, cks.decrypt(&ct));
+    }
+}
+
 /// test addition with the LWE server key using the a public key for encryption
 fn shortint_public_key_smart_add(param: Parameters) {
     let keys = KEY_CACHE.get_from_param(param);
@@ -2632,3 +2826,117 @@ fn shortint_mux(param: Parameters) {
     println!("(msg_true - msg_false) * control_bit  + msg_false = {clear_mux}, res = {dec_res}");
     assert_eq!(clear_mux, dec_res);
 }
+
+/// test the accumulator rotation, without and with wrapping around the padding bit
+fn shortint_generate_accumulator_with_rotation(param: Parameters) {
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+
+    let modulus = cks.parameters.message_modulus.0 as u64;
+    let modulus_sup = modulus * cks.parameters.carry_modulus.0 as u64;
+
+    // A rotation of 0 gives back the regular accumulator
+    let f = |x: u64| (x * 3) % modulus;
+    let acc = sks.generate_accumulator_with_rotation(f, 0);
+    assert_eq!(acc.acc, sks.generate_accumulator(f).acc);
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..NB_TEST {
+        // Only check inputs which do not wrap around the padding bit
+        let rotation = rng.gen::<u64>() % modulus_sup;
+        let clear = rng.gen::<u64>() % (modulus_sup - rotation).min(modulus);
+
+        let acc = sks.generate_accumulator_with_rotation(f, rotation as usize);
+
+        // encryption of an integer
+        let ct = cks.encrypt(clear);
+
+        let ct_res = sks.apply_lookup_table(&ct, &acc);
+
+        // decryption of ct_res
+        let dec_res = cks.decrypt(&ct_res);
+
+        // assert
+        assert_eq!(f(clear + rotation), dec_res);
+    }
+
+    for _ in 0..NB_TEST {
+        // Inputs wrapping around the padding bit select the negated values of the accumulator
+        let clear = 1 + rng.gen::<u64>() % (modulus - 1);
+        let rotation = modulus_sup - 1 - rng.gen::<u64>() % clear;
+
+        let acc = sks.generate_accumulator_with_rotation(f, rotation as usize);
+
+        // encryption of an integer
+        let ct = cks.encrypt(clear);
+
+        let ct_res = sks.apply_lookup_table(&ct, &acc);
+
+        // decryption of ct_res
+        let dec_res = cks.decrypt(&ct_res);
+
+        // assert
+        let wrapped = clear + rotation - modulus_sup;
+        assert_eq!((modulus - f(wrapped)) % modulus, dec_res);
+    }
+}
+
+fn shortint_lookup_table_builder(param: Parameters) {
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+
+    let modulus = cks.parameters.message_modulus.0 as u64;
+    let modulus_sup = modulus * cks.parameters.carry_modulus.0 as u64;
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    // Same lookup table as the one generated from the equivalent function
+    let f = |x: u64| (x * 3) % modulus;
+    let mapping = (0..modulus).map(f).collect::<Vec<_>>();
+    let acc = LookupTableBuilder::new(mapping).build(sks).unwrap();
+    assert_eq!(acc, sks.generate_accumulator(f));
+
+    // Mappings of the wrong size or with values out of the plaintext space are rejected
+    let mapping = (0..modulus + 1).collect::<Vec<_>>();
+    assert_eq!(
+        LookupTableBuilder::new(mapping).build(sks).unwrap_err(),
+        LookupTableBuilderError::WrongMappingSize {
+            expected: modulus as usize,
+            actual: modulus as usize + 1,
+        }
+    );
+    let mut mapping = (0..modulus).collect::<Vec<_>>();
+    mapping[0] = modulus_sup;
+    assert_eq!(
+        LookupTableBuilder::new(mapping).build(sks).unwrap_err(),
+        LookupTableBuilderError::ValueOutOfRange {
+            input: 0,
+            value: modulus_sup,
+            modulus_sup,
+        }
+    );
+
+    for _ in 0..NB_TEST {
+        // A random mapping
+        let mapping = (0..modulus)
+            .map(|_| rng.gen::<u64>() % modulus)
+            .collect::<Vec<_>>();
+        let acc = LookupTableBuilder::new(&*mapping).build(sks).unwrap();
+
+        let clear = rng.gen::<u64>() % modulus;
+
+        // encryption of an integer
+        let ct = cks.encrypt(clear);
+
+        let ct_res = sks.apply_lookup_table(&ct, &acc);
+
+        // decryption of ct_res
+        let dec_res = cks.decrypt(&ct_res);
+
+        // assert
+        assert_eq!(mapping[clear as usize], dec_res);
+    }
+}